@@ -133,11 +133,17 @@ pub enum TypesyncCommands {
     /// Generate Macroforge TypeScript interfaces
     Macroforge(MacroforgeArgs),
 
+    /// Generate Zod validator schemas
+    Zod(ZodArgs),
+
     /// Generate FlatBuffers schema file
     Flatbuffers(FlatbuffersArgs),
 
     /// Generate Protocol Buffers schema file
     Protobuf(ProtobufArgs),
+
+    /// Generate JSON Schema document
+    JsonSchema(JsonSchemaArgs),
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ValueEnum)]
@@ -145,8 +151,10 @@ pub enum TypeFormat {
     Arktype,
     Effect,
     Macroforge,
+    Zod,
     Flatbuffers,
     Protobuf,
+    JsonSchema,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -170,6 +178,20 @@ pub struct MacroforgeArgs {
     pub output: Option<PathBuf>,
 }
 
+#[derive(Args, Debug, Clone)]
+pub struct ZodArgs {
+    /// Output file path (default: {output_path}/zod.ts)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct JsonSchemaArgs {
+    /// Output file path (default: {output_path}/schema.json)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
 #[derive(Args, Debug, Clone)]
 pub struct FlatbuffersArgs {
     /// Output file path (default: {output_path}/schema.fbs)
@@ -228,6 +250,24 @@ pub struct SchemasyncArgs {
     /// Force full refresh mode
     #[arg(long)]
     pub full_refresh: bool,
+
+    /// Leave the schema untouched and only top up mock data
+    #[arg(long, conflicts_with = "no_mocks")]
+    pub only_mocks: bool,
+
+    /// Only sync these tables and the tables they link to (comma-separated)
+    #[arg(long, value_delimiter = ',')]
+    pub tables: Option<Vec<String>>,
+
+    /// Write the canonical schema to evenframe.lock without touching the database.
+    /// The lock always covers every table, whatever --tables selects
+    #[arg(long, conflicts_with = "check_lock")]
+    pub write_lock: bool,
+
+    /// Fail if the generated schema does not match the committed evenframe.lock,
+    /// otherwise continue with the sync
+    #[arg(long)]
+    pub check_lock: bool,
 }
 
 #[derive(Subcommand, Debug, Clone)]
@@ -265,7 +305,7 @@ pub struct ApplyArgs {
     #[arg(short = 'y', long)]
     pub yes: bool,
 
-    /// Dry run - show what would be applied
+    /// Dry run - print the statements that would be applied without executing them
     #[arg(long)]
     pub dry_run: bool,
 }
@@ -364,7 +404,7 @@ pub struct InfoArgs {
     #[arg(long)]
     pub config: bool,
 
-    /// Show database schema information
+    /// Show tables with their fields and SurrealDB types
     #[arg(long)]
     pub schema: bool,
 
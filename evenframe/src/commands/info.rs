@@ -3,7 +3,12 @@
 use crate::cli::{Cli, InfoArgs, InfoFormat};
 use crate::config_builders;
 use crate::workspace_scanner::{TypeKind, WorkspaceScanner};
-use evenframe_core::{config::EvenframeConfig, error::Result};
+use evenframe_core::{
+    config::EvenframeConfig,
+    error::{EvenframeError, Result},
+    schemasync::overview,
+    types::ForeignTypeRegistry,
+};
 use tracing::{error, info};
 
 /// Runs the info command.
@@ -58,6 +63,14 @@ fn show_config(format: &InfoFormat) -> Result<()> {
                             "disabled"
                         }
                     );
+                    println!(
+                        "  Zod:         {}",
+                        if config.typesync.should_generate_zod_types {
+                            "enabled"
+                        } else {
+                            "disabled"
+                        }
+                    );
                     println!(
                         "  FlatBuffers: {}",
                         if config.typesync.should_generate_flatbuffers_types {
@@ -74,6 +87,14 @@ fn show_config(format: &InfoFormat) -> Result<()> {
                             "disabled"
                         }
                     );
+                    println!(
+                        "  JSON Schema: {}",
+                        if config.typesync.should_generate_json_schema_types {
+                            "enabled"
+                        } else {
+                            "disabled"
+                        }
+                    );
                     println!(
                         "\nMock Generation: {}",
                         if config.schemasync.should_generate_mocks {
@@ -95,8 +116,10 @@ fn show_config(format: &InfoFormat) -> Result<()> {
     "arktype": {},
     "effect": {},
     "macroforge": {},
+    "zod": {},
     "flatbuffers": {},
-    "protobuf": {}
+    "protobuf": {},
+    "json_schema": {}
   }},
   "mock_generation": {},
   "apply_aliases": {:?}
@@ -105,8 +128,10 @@ fn show_config(format: &InfoFormat) -> Result<()> {
                         config.typesync.should_generate_arktype_types,
                         config.typesync.should_generate_effect_types,
                         config.typesync.should_generate_macroforge_types,
+                        config.typesync.should_generate_zod_types,
                         config.typesync.should_generate_flatbuffers_types,
                         config.typesync.should_generate_protobuf_types,
+                        config.typesync.should_generate_json_schema_types,
                         config.schemasync.should_generate_mocks,
                         config.general.apply_aliases
                     );
@@ -118,16 +143,20 @@ generators:
   arktype: {}
   effect: {}
   macroforge: {}
+  zod: {}
   flatbuffers: {}
   protobuf: {}
+  json_schema: {}
 mock_generation: {}
 apply_aliases: {:?}"#,
                         config.typesync.output_path,
                         config.typesync.should_generate_arktype_types,
                         config.typesync.should_generate_effect_types,
                         config.typesync.should_generate_macroforge_types,
+                        config.typesync.should_generate_zod_types,
                         config.typesync.should_generate_flatbuffers_types,
                         config.typesync.should_generate_protobuf_types,
+                        config.typesync.should_generate_json_schema_types,
                         config.schemasync.should_generate_mocks,
                         config.general.apply_aliases
                     );
@@ -242,9 +271,42 @@ fn show_types(format: &InfoFormat) -> Result<()> {
     Ok(())
 }
 
-fn show_schema(_format: &InfoFormat) -> Result<()> {
+fn show_schema(format: &InfoFormat) -> Result<()> {
     let build_config = config_builders::BuildConfig::from_toml()?;
     let (enums, tables, objects) = config_builders::build_all_configs(&build_config)?;
+    let registry = EvenframeConfig::new()
+        .map(|config| ForeignTypeRegistry::from_config(&config.general.foreign_types))
+        .unwrap_or_default();
+    let tables_overview = overview::schema_overview(&tables, &objects, &enums, &registry);
+
+    match format {
+        InfoFormat::Pretty => {}
+        InfoFormat::Json => {
+            let json = serde_json::to_string_pretty(&tables_overview).map_err(|e| {
+                EvenframeError::config(format!("Failed to serialize schema overview: {e}"))
+            })?;
+            println!("{json}");
+            return Ok(());
+        }
+        InfoFormat::Yaml => {
+            println!("tables:");
+            for table in &tables_overview {
+                println!("  - name: {}", table.name);
+                if let Some(relation) = &table.relation {
+                    println!("    relation:");
+                    println!("      from: {:?}", relation.from);
+                    println!("      to: {:?}", relation.to);
+                }
+                println!("    fields:");
+                for field in &table.fields {
+                    println!("      - name: {}", field.name);
+                    println!("        type: \"{}\"", field.surql_type);
+                    println!("        edge: {}", field.edge);
+                }
+            }
+            return Ok(());
+        }
+    }
 
     println!("\n=== Schema Summary ===\n");
     println!("Tables: {}", tables.len());
@@ -268,6 +330,11 @@ fn show_schema(_format: &InfoFormat) -> Result<()> {
         }
     }
 
+    if !tables_overview.is_empty() {
+        println!("\nTable Fields:");
+        print!("{}", overview::render_overview(&tables_overview));
+    }
+
     info!("Use 'evenframe info --types' for detailed type information");
 
     Ok(())
@@ -101,10 +101,12 @@ output_path = "./src/generated/"
 should_generate_arktype_types = true
 should_generate_effect_types = false
 should_generate_macroforge_types = false
+should_generate_zod_types = false
 
 # Schema file generators
 should_generate_flatbuffers_types = false
 should_generate_protobuf_types = false
+should_generate_json_schema_types = false
 
 # FlatBuffers namespace (e.g., "com.example.app")
 # flatbuffers_namespace = ""
@@ -135,10 +137,12 @@ output_path = "./src/generated/"
 should_generate_arktype_types = true
 should_generate_effect_types = false
 should_generate_macroforge_types = false
+should_generate_zod_types = false
 
 # Schema file generators
 should_generate_flatbuffers_types = false
 should_generate_protobuf_types = false
+should_generate_json_schema_types = false
 "#,
             format!("{:?}", provider).to_lowercase()
         ),
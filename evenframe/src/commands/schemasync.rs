@@ -2,7 +2,12 @@
 
 use crate::cli::{Cli, DiffFormat, SchemasyncArgs, SchemasyncCommands};
 use crate::config_builders;
-use evenframe_core::{error::Result, schemasync::Schemasync};
+use evenframe_core::{
+    config::EvenframeConfig,
+    error::Result,
+    schemasync::{Schemasync, lock},
+    types::ForeignTypeRegistry,
+};
 use tracing::{debug, error, info};
 
 /// Runs the schemasync command.
@@ -14,6 +19,29 @@ pub async fn run(_cli: &Cli, args: SchemasyncArgs) -> Result<()> {
     let (enums, tables, objects) = config_builders::build_all_configs(&build_config)?;
     let (enums, tables, objects) = config_builders::filter_for_schemasync(enums, tables, objects);
 
+    // Lock operations only need the generated schema. Writing the lock stops
+    // here; checking it guards the normal run against schema drift. The lock
+    // always covers every table, so this runs before the --tables filter.
+    if args.write_lock || args.check_lock {
+        let config = EvenframeConfig::new()?;
+        let registry = ForeignTypeRegistry::from_config(&config.general.foreign_types);
+        let canonical = lock::canonical_schema(&tables, &objects, &enums, &registry);
+        let path = config.project_root().join(lock::LOCK_FILE_NAME);
+        if args.write_lock {
+            return lock::write_lock(&path, &canonical);
+        }
+        lock::check_lock(&path, &canonical)?;
+    }
+
+    // With --tables, tables outside the selection must be left alone rather than removed
+    let partial = args.tables.is_some();
+    let tables = match &args.tables {
+        Some(names) => {
+            config_builders::filter_tables_with_dependencies(tables, names, &objects, &enums)?
+        }
+        None => tables,
+    };
+
     info!(
         "Found {} enums, {} tables, {} objects",
         enums.len(),
@@ -30,7 +58,8 @@ pub async fn run(_cli: &Cli, args: SchemasyncArgs) -> Result<()> {
                 let schemasync = Schemasync::new()
                     .with_tables(&tables)
                     .with_objects(&objects)
-                    .with_enums(&enums);
+                    .with_enums(&enums)
+                    .with_partial_sync(partial);
 
                 let changes = schemasync.diff().await?;
 
@@ -72,10 +101,13 @@ pub async fn run(_cli: &Cli, args: SchemasyncArgs) -> Result<()> {
                     let schemasync = Schemasync::new()
                         .with_tables(&tables)
                         .with_objects(&objects)
-                        .with_enums(&enums);
+                        .with_enums(&enums)
+                        .with_partial_sync(partial)
+                        .with_dry_run(true);
 
-                    let changes = schemasync.diff().await?;
-                    println!("{}", changes.summary());
+                    for statements in schemasync.run().await? {
+                        println!("{}", statements.trim_end());
+                    }
                     return Ok(());
                 }
 
@@ -99,7 +131,7 @@ pub async fn run(_cli: &Cli, args: SchemasyncArgs) -> Result<()> {
                     }
                 }
 
-                run_schemasync(&enums, &tables, &objects).await?;
+                run_schemasync(&enums, &tables, &objects, false, partial).await?;
             }
             SchemasyncCommands::Mock(mock_args) => {
                 info!("Generating mock data only...");
@@ -119,18 +151,25 @@ pub async fn run(_cli: &Cli, args: SchemasyncArgs) -> Result<()> {
     }
 
     // Default: run full schemasync
-    run_schemasync(&enums, &tables, &objects).await
+    run_schemasync(&enums, &tables, &objects, args.only_mocks, partial).await
 }
 
 async fn run_schemasync(
     enums: &std::collections::BTreeMap<String, evenframe_core::types::TaggedUnion>,
     tables: &std::collections::BTreeMap<String, evenframe_core::schemasync::table::TableConfig>,
     objects: &std::collections::BTreeMap<String, evenframe_core::types::StructConfig>,
+    only_mocks: bool,
+    partial: bool,
 ) -> Result<()> {
-    let schemasync = Schemasync::new()
+    let mut schemasync = Schemasync::new()
         .with_tables(tables)
         .with_objects(objects)
-        .with_enums(enums);
+        .with_enums(enums)
+        .with_partial_sync(partial);
+    // Only override the config when the flag is given
+    if only_mocks {
+        schemasync = schemasync.with_skip_schema_definition(true);
+    }
 
     debug!(
         "Initialized Schemasync with {} tables, {} objects, {} enums",
@@ -56,6 +56,8 @@ pub async fn run(_cli: &Cli, args: TestPluginArgs) -> Result<()> {
             &empty_enums,
             false,
             evenframe_core::typesync::config::ArrayStyle::default(),
+            false,
+            evenframe_core::schemasync::TableCase::default(),
             &registry,
         );
         entry["generated_typesync"] = serde_json::Value::String(generated);
@@ -107,6 +109,8 @@ pub async fn run(_cli: &Cli, args: TestPluginArgs) -> Result<()> {
             &single_enum,
             false,
             evenframe_core::typesync::config::ArrayStyle::default(),
+            false,
+            evenframe_core::schemasync::TableCase::default(),
             &registry,
         );
         entry["generated_typesync"] = serde_json::Value::String(generated);
@@ -5,6 +5,7 @@ use crate::config_builders;
 use evenframe_core::{
     config::EvenframeConfig,
     error::Result,
+    schemasync::TableCase,
     types::ForeignTypeRegistry,
     typesync::{
         arktype::generate_arktype_type_string,
@@ -16,11 +17,13 @@ use evenframe_core::{
             barrel_filename, format_imports, generate_barrel_file, resolve_imports,
             type_name_to_filename,
         },
+        json_schema::generate_json_schema_string,
         macroforge::{
             compute_extra_imports, compute_macro_import_line, generate_macroforge_for_types,
             generate_macroforge_type_string,
         },
         protobuf::generate_protobuf_schema_string,
+        zod::generate_zod_schema_string,
     },
 };
 use std::collections::BTreeSet;
@@ -67,6 +70,8 @@ pub async fn run(_cli: &Cli, args: TypesyncArgs) -> Result<()> {
     let file_naming = config.typesync.output.file_naming;
     let file_extension = &config.typesync.output.file_extension;
     let array_style = config.typesync.output.array_style;
+    let branded_record_ids = config.typesync.output.branded_record_ids;
+    let table_naming = build_config.table_naming;
 
     // Handle subcommands for specific formats
     if let Some(cmd) = args.command {
@@ -110,9 +115,15 @@ pub async fn run(_cli: &Cli, args: TypesyncArgs) -> Result<()> {
                     .map(|p| p.to_string_lossy().to_string())
                     .unwrap_or_else(|| format!("{}macroforge.ts", config.typesync.output_path));
                 match output_mode {
-                    OutputMode::Single => {
-                        generate_macroforge(&structs, &enums, &output_path, array_style, &registry)?
-                    }
+                    OutputMode::Single => generate_macroforge(
+                        &structs,
+                        &enums,
+                        &output_path,
+                        array_style,
+                        branded_record_ids,
+                        table_naming,
+                        &registry,
+                    )?,
                     OutputMode::PerFile => generate_macroforge_per_file(MacroforgePerFileArgs {
                         structs: &structs,
                         enums: &enums,
@@ -121,10 +132,24 @@ pub async fn run(_cli: &Cli, args: TypesyncArgs) -> Result<()> {
                         naming: file_naming,
                         file_ext: file_extension,
                         array_style,
+                        branded_record_ids,
+                        table_naming,
                         registry: &registry,
                     })?,
                 }
             }
+            TypesyncCommands::Zod(zod_args) => {
+                let output_path = zod_args
+                    .output
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_else(|| format!("{}zod.ts", config.typesync.output_path));
+                if output_mode == OutputMode::PerFile {
+                    warn!(
+                        "Zod does not support per-file output yet. Falling back to single-file mode."
+                    );
+                }
+                generate_zod(&structs, &enums, &output_path, &registry)?;
+            }
             TypesyncCommands::Flatbuffers(fbs_args) => {
                 let output_path = fbs_args
                     .output
@@ -165,6 +190,13 @@ pub async fn run(_cli: &Cli, args: TypesyncArgs) -> Result<()> {
                     &registry,
                 )?;
             }
+            TypesyncCommands::JsonSchema(json_schema_args) => {
+                let output_path = json_schema_args
+                    .output
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_else(|| format!("{}schema.json", config.typesync.output_path));
+                generate_json_schema(&structs, &enums, &output_path, &registry)?;
+            }
         }
         return Ok(());
     }
@@ -186,12 +218,18 @@ pub async fn run(_cli: &Cli, args: TypesyncArgs) -> Result<()> {
         if config.typesync.should_generate_macroforge_types {
             formats_to_generate.insert(TypeFormat::Macroforge);
         }
+        if config.typesync.should_generate_zod_types {
+            formats_to_generate.insert(TypeFormat::Zod);
+        }
         if config.typesync.should_generate_flatbuffers_types {
             formats_to_generate.insert(TypeFormat::Flatbuffers);
         }
         if config.typesync.should_generate_protobuf_types {
             formats_to_generate.insert(TypeFormat::Protobuf);
         }
+        if config.typesync.should_generate_json_schema_types {
+            formats_to_generate.insert(TypeFormat::JsonSchema);
+        }
     }
 
     // Remove skipped formats
@@ -234,7 +272,15 @@ pub async fn run(_cli: &Cli, args: TypesyncArgs) -> Result<()> {
             TypeFormat::Macroforge => match output_mode {
                 OutputMode::Single => {
                     let path = format!("{}macroforge.ts", config.typesync.output_path);
-                    generate_macroforge(&structs, &enums, &path, array_style, &registry)?;
+                    generate_macroforge(
+                        &structs,
+                        &enums,
+                        &path,
+                        array_style,
+                        branded_record_ids,
+                        table_naming,
+                        &registry,
+                    )?;
                 }
                 OutputMode::PerFile => {
                     generate_macroforge_per_file(MacroforgePerFileArgs {
@@ -245,10 +291,21 @@ pub async fn run(_cli: &Cli, args: TypesyncArgs) -> Result<()> {
                         naming: file_naming,
                         file_ext: file_extension,
                         array_style,
+                        branded_record_ids,
+                        table_naming,
                         registry: &registry,
                     })?;
                 }
             },
+            TypeFormat::Zod => {
+                if output_mode == OutputMode::PerFile {
+                    warn!(
+                        "Zod does not support per-file output yet. Falling back to single-file mode."
+                    );
+                }
+                let path = format!("{}zod.ts", config.typesync.output_path);
+                generate_zod(&structs, &enums, &path, &registry)?;
+            }
             TypeFormat::Flatbuffers => {
                 let path = format!("{}schema.fbs", config.typesync.output_path);
                 generate_flatbuffers(
@@ -270,6 +327,10 @@ pub async fn run(_cli: &Cli, args: TypesyncArgs) -> Result<()> {
                     &registry,
                 )?;
             }
+            TypeFormat::JsonSchema => {
+                let path = format!("{}schema.json", config.typesync.output_path);
+                generate_json_schema(&structs, &enums, &path, &registry)?;
+            }
         }
     }
 
@@ -382,10 +443,20 @@ fn generate_macroforge(
     enums: &std::collections::BTreeMap<String, evenframe_core::types::TaggedUnion>,
     output_path: &str,
     array_style: evenframe_core::typesync::config::ArrayStyle,
+    branded_record_ids: bool,
+    table_naming: TableCase,
     registry: &ForeignTypeRegistry,
 ) -> Result<()> {
     info!("Generating Macroforge types to {}", output_path);
-    let content = generate_macroforge_type_string(structs, enums, false, array_style, registry);
+    let content = generate_macroforge_type_string(
+        structs,
+        enums,
+        false,
+        array_style,
+        branded_record_ids,
+        table_naming,
+        registry,
+    );
     std::fs::write(output_path, content)?;
     debug!("Macroforge types written successfully");
     Ok(())
@@ -399,6 +470,8 @@ struct MacroforgePerFileArgs<'a> {
     naming: FileNamingConvention,
     file_ext: &'a str,
     array_style: evenframe_core::typesync::config::ArrayStyle,
+    branded_record_ids: bool,
+    table_naming: TableCase,
     registry: &'a ForeignTypeRegistry,
 }
 
@@ -411,6 +484,8 @@ fn generate_macroforge_per_file(args: MacroforgePerFileArgs<'_>) -> Result<()> {
         naming,
         file_ext,
         array_style,
+        branded_record_ids,
+        table_naming,
         registry,
     } = args;
     let plan = compute_file_grouping(structs, enums);
@@ -427,8 +502,15 @@ fn generate_macroforge_per_file(args: MacroforgePerFileArgs<'_>) -> Result<()> {
     for group in &plan.groups {
         let imports = resolve_imports(group, &plan, structs, enums, naming, file_ext);
         let type_names = group.all_types();
-        let body =
-            generate_macroforge_for_types(&type_names, structs, enums, array_style, registry);
+        let body = generate_macroforge_for_types(
+            &type_names,
+            structs,
+            enums,
+            array_style,
+            branded_record_ids,
+            table_naming,
+            registry,
+        );
 
         let mut file_content = String::new();
 
@@ -439,7 +521,8 @@ fn generate_macroforge_per_file(args: MacroforgePerFileArgs<'_>) -> Result<()> {
         }
 
         // Add extra imports (effect types, RecordLink)
-        let extra_imports = compute_extra_imports(&type_names, structs, enums, registry);
+        let extra_imports =
+            compute_extra_imports(&type_names, structs, enums, branded_record_ids, registry);
         for import_line in &extra_imports {
             file_content.push_str(import_line);
             file_content.push('\n');
@@ -472,6 +555,20 @@ fn generate_macroforge_per_file(args: MacroforgePerFileArgs<'_>) -> Result<()> {
     Ok(())
 }
 
+fn generate_zod(
+    structs: &std::collections::BTreeMap<String, evenframe_core::types::StructConfig>,
+    enums: &std::collections::BTreeMap<String, evenframe_core::types::TaggedUnion>,
+    output_path: &str,
+    registry: &ForeignTypeRegistry,
+) -> Result<()> {
+    info!("Generating Zod schemas to {}", output_path);
+    let content = generate_zod_schema_string(structs, enums, true, registry);
+    let full_content = format!("import {{ z }} from \"zod\";\n\n{}", content);
+    std::fs::write(output_path, full_content)?;
+    debug!("Zod schemas written successfully");
+    Ok(())
+}
+
 fn generate_flatbuffers(
     structs: &std::collections::BTreeMap<String, evenframe_core::types::StructConfig>,
     enums: &std::collections::BTreeMap<String, evenframe_core::types::TaggedUnion>,
@@ -502,6 +599,19 @@ fn generate_protobuf(
     Ok(())
 }
 
+fn generate_json_schema(
+    structs: &std::collections::BTreeMap<String, evenframe_core::types::StructConfig>,
+    enums: &std::collections::BTreeMap<String, evenframe_core::types::TaggedUnion>,
+    output_path: &str,
+    registry: &ForeignTypeRegistry,
+) -> Result<()> {
+    info!("Generating JSON Schema to {}", output_path);
+    let content = generate_json_schema_string(structs, enums, registry);
+    std::fs::write(output_path, content)?;
+    debug!("JSON Schema written successfully");
+    Ok(())
+}
+
 /// Removes files in `dir` matching `*{file_ext}` that are not part of the current output plan.
 /// This cleans up obsolete files when types are regrouped into different files.
 fn cleanup_obsolete_files(
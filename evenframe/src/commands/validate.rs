@@ -3,7 +3,12 @@
 use crate::cli::{Cli, ValidateArgs};
 use crate::config_builders;
 use crate::workspace_scanner::WorkspaceScanner;
-use evenframe_core::{config::EvenframeConfig, error::Result};
+use evenframe_core::{
+    config::EvenframeConfig,
+    error::{EvenframeError, Result},
+    schemasync::database::surql::define::{DefineStatementError, collect_define_statement_errors},
+    types::ForeignTypeRegistry,
+};
 use tracing::{error, info, warn};
 
 /// Runs the validate command.
@@ -20,12 +25,14 @@ pub async fn run(_cli: &Cli, args: ValidateArgs) -> Result<()> {
                 info!("  Configuration file: OK");
                 info!("    Output path: {}", config.typesync.output_path);
                 info!(
-                    "    Generators: arktype={}, effect={}, macroforge={}, flatbuffers={}, protobuf={}",
+                    "    Generators: arktype={}, effect={}, macroforge={}, zod={}, flatbuffers={}, protobuf={}, json_schema={}",
                     config.typesync.should_generate_arktype_types,
                     config.typesync.should_generate_effect_types,
                     config.typesync.should_generate_macroforge_types,
+                    config.typesync.should_generate_zod_types,
                     config.typesync.should_generate_flatbuffers_types,
-                    config.typesync.should_generate_protobuf_types
+                    config.typesync.should_generate_protobuf_types,
+                    config.typesync.should_generate_json_schema_types
                 );
             }
             Err(e) => {
@@ -40,11 +47,24 @@ pub async fn run(_cli: &Cli, args: ValidateArgs) -> Result<()> {
     if !args.config_only {
         info!("Checking types...");
         match validate_types() {
-            Ok((enums, tables, objects)) => {
+            Ok((enums, tables, objects, define_errors)) => {
                 info!("  Types: OK");
                 info!("    Enums: {}", enums);
                 info!("    Tables: {}", tables);
                 info!("    Objects: {}", objects);
+
+                if define_errors.is_empty() {
+                    info!("  Define statements: OK");
+                } else {
+                    error!("  Define statements: FAILED");
+                    for define_error in &define_errors {
+                        error!(
+                            "    {}.{}: {}",
+                            define_error.table_name, define_error.field_name, define_error.error
+                        );
+                    }
+                    has_errors = true;
+                }
             }
             Err(e) => {
                 error!("  Types: FAILED");
@@ -71,14 +91,16 @@ pub async fn run(_cli: &Cli, args: ValidateArgs) -> Result<()> {
 
     if has_errors {
         error!("Validation failed with errors");
-    } else {
-        info!("Validation passed");
+        return Err(EvenframeError::validation("Validation failed with errors"));
     }
 
+    info!("Validation passed");
     Ok(())
 }
 
-fn validate_types() -> Result<(usize, usize, usize)> {
+/// Scan and build all types, then run define-statement generation for every
+/// schemasync table so schema bugs surface without a database connection.
+fn validate_types() -> Result<(usize, usize, usize, Vec<DefineStatementError>)> {
     let config = EvenframeConfig::new()?;
 
     let scanner =
@@ -91,7 +113,13 @@ fn validate_types() -> Result<(usize, usize, usize)> {
 
     let build_config = config_builders::BuildConfig::from_toml()?;
     let (enums, tables, objects) = config_builders::build_all_configs(&build_config)?;
-    Ok((enums.len(), tables.len(), objects.len()))
+    let counts = (enums.len(), tables.len(), objects.len());
+
+    let (enums, tables, objects) = config_builders::filter_for_schemasync(enums, tables, objects);
+    let registry = ForeignTypeRegistry::from_config(&config.general.foreign_types);
+    let define_errors = collect_define_statement_errors(&tables, &objects, &enums, &registry);
+
+    Ok((counts.0, counts.1, counts.2, define_errors))
 }
 
 async fn check_database() -> Result<()> {
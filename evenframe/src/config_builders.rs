@@ -4,5 +4,5 @@
 
 pub use evenframe_core::tooling::{
     BuildConfig, build_all_configs, filter_for_schemasync, filter_for_typesync,
-    merge_tables_and_objects,
+    filter_tables_with_dependencies, merge_tables_and_objects,
 };
@@ -11,6 +11,8 @@ pub mod prelude {
     pub use convert_case::{Case, Casing};
     pub use linkme;
     pub use regex;
+    // Re-exported for the `FieldType::Literal` values the derives emit.
+    pub use serde_json;
     // Re-exported so the validator-generator in `evenframe_core` can emit
     // `::evenframe::prelude::url::Url::parse(...)` /
     // `::evenframe::prelude::uuid::Uuid::parse_str(...)` without forcing
@@ -5,9 +5,17 @@ mod workspace_scanner;
 
 use clap::Parser;
 use cli::{Cli, Commands};
-use evenframe_core::{config::EvenframeConfig, error::Result, evenframe_log};
+use evenframe_core::{
+    config::EvenframeConfig,
+    error::{EvenframeError, Result},
+    evenframe_log,
+};
 use tracing::{error, info};
 
+/// Exit status when schemasync refuses to apply breaking changes, so CI can
+/// tell it apart from other failures (which exit with 1).
+const BREAKING_CHANGES_EXIT_CODE: i32 = 3;
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Early env load for logging macros that need env vars before full config init.
@@ -47,6 +55,10 @@ async fn main() -> Result<()> {
         }
         Err(e) => {
             error!("Evenframe failed: {}", e);
+            if matches!(e, EvenframeError::BreakingChanges(_)) {
+                eprintln!("Error: {e}");
+                std::process::exit(BREAKING_CHANGES_EXIT_CODE);
+            }
             Err(e)
         }
     }
@@ -85,6 +85,11 @@ pub struct ForeignTypeConfig {
     #[serde(default)]
     pub macroforge: String,
     #[serde(default)]
+    pub zod: String,
+    /// JSON text of the schema, e.g. `{"type": "string", "format": "date-time"}`
+    #[serde(default)]
+    pub json_schema: String,
+    #[serde(default)]
     pub flatbuffers: String,
     #[serde(default)]
     pub protobuf: String,
@@ -213,6 +218,11 @@ pub struct SyntheticItemPluginConfig {
     pub path: String,
 }
 
+/// Environment variable naming the config profile that [`EvenframeConfig::new`]
+/// and [`BuildConfig::from_toml`](crate::tooling::BuildConfig::from_toml)
+/// apply.
+pub const PROFILE_ENV_VAR: &str = "EVENFRAME_PROFILE";
+
 /// Unified configuration for Evenframe operations
 /// This is the root configuration that contains both schemasync and typesync configurations
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -250,8 +260,24 @@ impl EvenframeConfig {
 
     /// Load configuration by searching for evenframe.toml in the current
     /// directory and its ancestors.
+    ///
+    /// When `EVENFRAME_PROFILE` is set, the matching `[profiles.<name>]`
+    /// table is merged over the base config.
     pub fn new() -> Result<EvenframeConfig> {
-        info!("Loading Evenframe configuration");
+        let profile = env::var(PROFILE_ENV_VAR)
+            .ok()
+            .filter(|profile| !profile.is_empty());
+        Self::load(profile.as_deref())
+    }
+
+    /// Load configuration like [`EvenframeConfig::new`], merging the
+    /// `[profiles.<profile>]` table over the base config.
+    pub fn for_profile(profile: &str) -> Result<EvenframeConfig> {
+        Self::load(Some(profile))
+    }
+
+    fn load(profile: Option<&str>) -> Result<EvenframeConfig> {
+        info!(?profile, "Loading Evenframe configuration");
 
         let config_path = Self::find_config_file()?;
         info!("Found configuration file at: {:?}", config_path);
@@ -263,10 +289,7 @@ impl EvenframeConfig {
 
         debug!("Configuration file size: {} bytes", contents.len());
 
-        let mut config: EvenframeConfig = toml::from_str(&contents).map_err(|e| {
-            error!("Failed to parse TOML configuration: {}", e);
-            EvenframeError::config(e.to_string())
-        })?;
+        let mut config = Self::from_toml_str(&contents, profile)?;
 
         debug!("Successfully parsed TOML configuration");
 
@@ -280,6 +303,8 @@ impl EvenframeConfig {
         debug!("Substituting environment variables in configuration");
         Self::substitute_all_env_vars(&mut config)?;
 
+        config.schemasync.mock_gen_config.validate()?;
+
         // Resolve surql paths
         let project_root = config.project_root().to_path_buf();
 
@@ -307,6 +332,77 @@ impl EvenframeConfig {
         Ok(config)
     }
 
+    /// Parses a config file's contents, merging the `[profiles.<profile>]`
+    /// table over the base config when a profile is given.
+    ///
+    /// Profile tables mirror the root layout, so
+    /// `[profiles.dev.schemasync.mock_gen_config]` overrides keys of
+    /// `[schemasync.mock_gen_config]`. Tables are merged key by key; any other
+    /// value replaces the base value.
+    pub fn from_toml_str(contents: &str, profile: Option<&str>) -> Result<EvenframeConfig> {
+        let mut table: toml::Table = toml::from_str(contents).map_err(|e| {
+            error!("Failed to parse TOML configuration: {}", e);
+            EvenframeError::config(e.to_string())
+        })?;
+
+        Self::apply_profile(&mut table, profile)?;
+
+        toml::Value::Table(table)
+            .try_into()
+            .map_err(|e: toml::de::Error| {
+                error!("Failed to parse TOML configuration: {}", e);
+                EvenframeError::config(e.to_string())
+            })
+    }
+
+    /// Removes the `[profiles]` table from a parsed config file and merges
+    /// the `[profiles.<profile>]` table, if a profile is given, over the rest.
+    pub(crate) fn apply_profile(table: &mut toml::Table, profile: Option<&str>) -> Result<()> {
+        let profiles = match table.remove("profiles") {
+            Some(toml::Value::Table(profiles)) => profiles,
+            Some(_) => {
+                return Err(EvenframeError::config(
+                    "`profiles` must be a table of [profiles.<name>] sections",
+                ));
+            }
+            None => toml::Table::new(),
+        };
+
+        if let Some(profile) = profile {
+            let overrides = match profiles.get(profile) {
+                Some(toml::Value::Table(overrides)) => overrides,
+                Some(_) => {
+                    return Err(EvenframeError::config(format!(
+                        "Profile '{profile}' must be a table"
+                    )));
+                }
+                None => {
+                    return Err(EvenframeError::config(format!(
+                        "Unknown config profile '{profile}'. Available profiles: {}",
+                        profiles.keys().cloned().collect::<Vec<_>>().join(", ")
+                    )));
+                }
+            };
+            info!("Applying config profile '{}'", profile);
+            Self::merge_tables(table, overrides);
+        }
+        Ok(())
+    }
+
+    /// Recursively merges `overrides` into `base`.
+    fn merge_tables(base: &mut toml::Table, overrides: &toml::Table) {
+        for (key, value) in overrides {
+            match (base.get_mut(key), value) {
+                (Some(toml::Value::Table(base_table)), toml::Value::Table(override_table)) => {
+                    Self::merge_tables(base_table, override_table);
+                }
+                _ => {
+                    base.insert(key.clone(), value.clone());
+                }
+            }
+        }
+    }
+
     /// Searches for `.evenframe/config.toml` (preferred) or `evenframe.toml` (fallback)
     /// starting from the current directory and traversing up to the root.
     fn find_config_file() -> Result<PathBuf> {
@@ -964,6 +1060,116 @@ mod tests {
         assert_eq!(config.typesync.output_path, "./generated/");
     }
 
+    const PROFILED_CONFIG: &str = r#"
+        [schemasync]
+        should_generate_mocks = true
+
+        [schemasync.database]
+        provider = "surrealdb"
+        url = "http://localhost:8000"
+        namespace = "test"
+        database = "test"
+
+        [schemasync.mock_gen_config]
+        default_record_count = 10
+        default_preservation_mode = "Smart"
+        default_batch_size = 10
+        full_refresh_mode = false
+        coordination_groups = []
+
+        [schemasync.performance]
+        embedded_db_memory_limit = "256MB"
+        cache_duration_seconds = 60
+        use_progressive_loading = false
+
+        [typesync]
+        output_path = "./generated/"
+        should_generate_arktype_types = false
+        should_generate_effect_types = false
+        should_generate_macroforge_types = false
+        should_generate_surrealdb_schemas = false
+
+        [profiles.prod.schemasync]
+        should_generate_mocks = false
+
+        [profiles.prod.schemasync.database]
+        url = "https://db.example.com"
+
+        [profiles.prod.schemasync.mock_gen_config]
+        default_record_count = 500
+        default_preservation_mode = "Full"
+    "#;
+
+    #[test]
+    fn test_from_toml_str_merges_profile_over_base() {
+        let base = EvenframeConfig::from_toml_str(PROFILED_CONFIG, None).unwrap();
+        assert_eq!(base.schemasync.database.url, "http://localhost:8000");
+        assert_eq!(base.schemasync.mock_gen_config.default_record_count, 10);
+        assert!(base.schemasync.should_generate_mocks);
+
+        let prod = EvenframeConfig::from_toml_str(PROFILED_CONFIG, Some("prod")).unwrap();
+        assert_eq!(prod.schemasync.database.url, "https://db.example.com");
+        assert_eq!(prod.schemasync.mock_gen_config.default_record_count, 500);
+        assert_eq!(
+            prod.schemasync.mock_gen_config.default_preservation_mode,
+            crate::schemasync::PreservationMode::Full
+        );
+        assert!(!prod.schemasync.should_generate_mocks);
+        // Keys the profile does not mention keep their base values
+        assert_eq!(prod.schemasync.database.namespace, "test");
+        assert_eq!(prod.schemasync.mock_gen_config.default_batch_size, 10);
+    }
+
+    #[test]
+    fn test_from_toml_str_rejects_unknown_profile() {
+        let err = EvenframeConfig::from_toml_str(PROFILED_CONFIG, Some("staging"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown config profile 'staging'"), "{err}");
+        assert!(err.contains("prod"), "{err}");
+    }
+
+    #[test]
+    fn test_option_null_probability_defaults_and_validation() {
+        let mock_gen_config = |extra: &str| {
+            toml::from_str::<crate::schemasync::config::SchemasyncMockGenConfig>(&format!(
+                r#"
+                default_record_count = 10
+                default_preservation_mode = "Smart"
+                default_batch_size = 10
+                full_refresh_mode = false
+                coordination_groups = []
+                {extra}
+                "#
+            ))
+            .unwrap()
+        };
+
+        let config = mock_gen_config("");
+        assert_eq!(config.option_null_probability, 0.5);
+        assert!(config.validate().is_ok());
+
+        assert!(
+            mock_gen_config("option_null_probability = 0.1")
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            mock_gen_config("option_null_probability = 1.0")
+                .validate()
+                .is_ok()
+        );
+
+        let err = mock_gen_config("option_null_probability = 1.5")
+            .validate()
+            .unwrap_err();
+        assert!(matches!(err, EvenframeError::InvalidConfigValue { .. }));
+        assert!(
+            err.to_string().contains("option_null_probability"),
+            "got: {err}"
+        );
+    }
+
     #[test]
     fn test_evenframe_config_deserialize_with_general() {
         let toml_str = r#"
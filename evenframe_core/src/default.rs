@@ -28,23 +28,41 @@ pub fn field_type_to_default_value(
             trace!("Generating default for Unit type");
             "undefined".to_string()
         }
+        FieldType::Json => {
+            trace!("Generating default for Json type");
+            "null".to_string()
+        }
+        FieldType::Literal(value) => {
+            trace!("Generating default for Literal type");
+            value.to_string()
+        }
+        FieldType::Bytes => {
+            trace!("Generating default for Bytes type");
+            "[]".to_string()
+        }
+        FieldType::Geometry(kind) => {
+            trace!("Generating default for Geometry type");
+            kind.geojson(&mut || (0.0, 0.0))
+        }
         FieldType::F32
         | FieldType::F64
         | FieldType::I8
         | FieldType::I16
         | FieldType::I32
         | FieldType::I64
-        | FieldType::I128
         | FieldType::Isize
         | FieldType::U8
         | FieldType::U16
         | FieldType::U32
         | FieldType::U64
-        | FieldType::U128
         | FieldType::Usize => {
             trace!("Generating default for numeric type");
             "0".to_string()
         }
+        FieldType::I128 | FieldType::U128 => {
+            trace!("Generating default for 128-bit integer type");
+            r#""0""#.to_string()
+        }
         FieldType::Tuple(inner_types) => {
             trace!(
                 "Generating default for Tuple with {} types",
@@ -77,7 +95,7 @@ pub fn field_type_to_default_value(
             trace!("Generating default for Option type with inner: {:?}", inner);
             "null".to_string()
         }
-        FieldType::Vec(inner) => {
+        FieldType::Vec(inner) | FieldType::Set(inner) | FieldType::Array(inner, _) => {
             // Returns an empty array as the default
             // but recursively if you wanted an "example entry" you could do:
             //   format!("[{}]", field_type_to_default_value(inner, structs, enums))
@@ -108,7 +126,7 @@ pub fn field_type_to_default_value(
             trace!("Generating default for RecordLink with inner: {:?}", inner);
             "''".to_string()
         }
-        FieldType::Other(name) => {
+        FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
             // 0) Check if it's a configured foreign type
             if let Some(ftc) = registry.lookup(name) {
                 return ftc.default_value_ts.clone();
@@ -260,10 +278,22 @@ pub fn field_type_to_surql_default(
             trace!("Generating SURQL default for Bool");
             "false".to_string()
         }
-        FieldType::Unit => {
-            trace!("Generating SURQL default for Unit");
+        FieldType::Unit | FieldType::Json => {
+            trace!("Generating SURQL default for Unit/Json");
             "NULL".to_string()
         }
+        FieldType::Literal(value) => {
+            trace!("Generating SURQL default for Literal");
+            value.to_string()
+        }
+        FieldType::Bytes => {
+            trace!("Generating SURQL default for Bytes");
+            "b\"\"".to_string()
+        }
+        FieldType::Geometry(kind) => {
+            trace!("Generating SURQL default for Geometry");
+            kind.geojson(&mut || (0.0, 0.0))
+        }
         FieldType::F32 | FieldType::F64 => {
             trace!("Generating SURQL default for float type");
             "0.0f".to_string()
@@ -272,17 +302,19 @@ pub fn field_type_to_surql_default(
         | FieldType::I16
         | FieldType::I32
         | FieldType::I64
-        | FieldType::I128
         | FieldType::Isize
         | FieldType::U8
         | FieldType::U16
         | FieldType::U32
         | FieldType::U64
-        | FieldType::U128
         | FieldType::Usize => {
             trace!("Generating SURQL default for integer type");
             "0".to_string()
         }
+        FieldType::I128 | FieldType::U128 => {
+            trace!("Generating SURQL default for 128-bit integer type");
+            "'0'".to_string()
+        }
         FieldType::Tuple(inner_types) => {
             trace!(
                 "Generating SURQL default for Tuple with {} types",
@@ -335,9 +367,9 @@ pub fn field_type_to_surql_default(
                 "Generating SURQL default for Option with inner: {:?}",
                 inner
             );
-            "NULL".to_string()
+            "NONE".to_string()
         }
-        FieldType::Vec(inner) => {
+        FieldType::Vec(inner) | FieldType::Set(inner) | FieldType::Array(inner, _) => {
             trace!("Generating SURQL default for Vec with inner: {:?}", inner);
             "[]".to_string()
         }
@@ -355,7 +387,7 @@ pub fn field_type_to_surql_default(
             );
             "NULL".to_string()
         }
-        FieldType::Other(name) => {
+        FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
             debug!("Processing Other type '{}' for SURQL default", name);
 
             // Check if it's a configured foreign type
@@ -518,21 +550,35 @@ pub fn field_type_to_surreal_type(
         | FieldType::I16
         | FieldType::I32
         | FieldType::I64
-        | FieldType::I128
         | FieldType::Isize
         | FieldType::U8
         | FieldType::U16
         | FieldType::U32
         | FieldType::U64
-        | FieldType::U128
         | FieldType::Usize => {
             trace!("Converting integer to SurrealDB type");
             ("int".to_string(), false, None)
         }
-        FieldType::Unit => {
-            trace!("Converting Unit to SurrealDB type");
+        FieldType::I128 | FieldType::U128 => {
+            trace!("Converting 128-bit integer to SurrealDB string type");
+            ("string".to_string(), false, None)
+        }
+        FieldType::Unit | FieldType::Json => {
+            trace!("Converting Unit/Json to SurrealDB type");
             ("any".to_string(), false, None)
         }
+        FieldType::Literal(value) => {
+            trace!("Converting Literal to SurrealDB literal type");
+            (value.to_string(), false, None)
+        }
+        FieldType::Bytes => {
+            trace!("Converting Bytes to SurrealDB type");
+            ("bytes".to_string(), false, None)
+        }
+        FieldType::Geometry(kind) => {
+            trace!("Converting Geometry to SurrealDB type");
+            (kind.surql_type(), false, None)
+        }
         FieldType::HashMap(_key, value) => {
             trace!("Converting HashMap to SurrealDB type");
             let (value_type, _, _) = field_type_to_surreal_type(
@@ -575,7 +621,7 @@ pub fn field_type_to_surreal_type(
             );
             (inner_type, needs_wildcard, wildcard_type)
         }
-        FieldType::Other(name) => {
+        FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
             debug!(
                 "Processing Other type '{}' for SurrealDB type conversion",
                 name
@@ -701,10 +747,13 @@ pub fn field_type_to_surreal_type(
                     .collect();
 
                 (format!("{{ {} }}", field_defs.join(", ")), false, None)
-            } else if persistable_structs.get(name).is_some() {
+            } else if let Some(table) = persistable_structs.get(name) {
                 debug!("Creating record type for persistable struct '{}'", name);
                 (
-                    format!("record<{}>", name.to_case(Case::Snake)),
+                    format!(
+                        "record<{}>",
+                        crate::schemasync::surql_ident(&table.table_name)
+                    ),
                     false,
                     None,
                 )
@@ -728,7 +777,7 @@ pub fn field_type_to_surreal_type(
                 registry,
             );
             (
-                format!("null | {}", inner_type),
+                format!("option<{}>", inner_type),
                 needs_wildcard,
                 wildcard_type,
             )
@@ -746,6 +795,32 @@ pub fn field_type_to_surreal_type(
             );
             (format!("array<{}>", inner_type), false, None)
         }
+        FieldType::Set(inner) => {
+            trace!("Converting Set to SurrealDB type with inner: {:?}", inner);
+            let (inner_type, _, _) = field_type_to_surreal_type(
+                field_name,
+                table_name,
+                inner,
+                enums,
+                app_structs,
+                persistable_structs,
+                registry,
+            );
+            (format!("set<{}>", inner_type), false, None)
+        }
+        FieldType::Array(inner, len) => {
+            trace!("Converting Array to SurrealDB type with inner: {:?}", inner);
+            let (inner_type, _, _) = field_type_to_surreal_type(
+                field_name,
+                table_name,
+                inner,
+                enums,
+                app_structs,
+                persistable_structs,
+                registry,
+            );
+            (format!("array<{}, {}>", inner_type, len), false, None)
+        }
         FieldType::Tuple(inner_types) => {
             trace!(
                 "Converting Tuple to SurrealDB type with {} types",
@@ -807,19 +882,8 @@ mod tests {
 
     fn base_define_config(default: Option<&str>) -> DefineConfig {
         DefineConfig {
-            select_permissions: None,
-            update_permissions: None,
-            create_permissions: None,
-            data_type: None,
-            should_skip: false,
             default: default.map(|s| s.to_string()),
-            default_always: None,
-            value: None,
-            assert: None,
-            readonly: None,
-            flexible: None,
-            computed: None,
-            comment: None,
+            ..Default::default()
         }
     }
 
@@ -1,5 +1,5 @@
 use crate::evenframe_log;
-use crate::schemasync::TableConfig;
+use crate::schemasync::{TableConfig, find_table};
 use crate::types::{FieldType, StructConfig, TaggedUnion, VariantData};
 use convert_case::{Case, Casing};
 use petgraph::algo::toposort;
@@ -171,7 +171,7 @@ pub fn collect_refs(ft: &FieldType, known: &BTreeSet<String>, acc: &mut BTreeSet
     match ft {
         Tuple(v) => v.iter().for_each(|f| collect_refs(f, known, acc)),
         Struct(v) => v.iter().for_each(|(_, f)| collect_refs(f, known, acc)),
-        Option(i) | Vec(i) | RecordLink(i) => collect_refs(i, known, acc),
+        Option(i) | Vec(i) | Set(i) | RecordLink(i) => collect_refs(i, known, acc),
         HashMap(k, v) | BTreeMap(k, v) => {
             collect_refs(k, known, acc);
             collect_refs(v, known, acc);
@@ -255,7 +255,7 @@ pub fn deps_of_table(
 }
 
 /// Collect all dependencies of a table including nested objects and enums
-fn collect_table_dependencies(
+pub(crate) fn collect_table_dependencies(
     table_name: &str,
     tables: &BTreeMap<String, TableConfig>,
     objects: &BTreeMap<String, StructConfig>,
@@ -280,21 +280,15 @@ fn collect_table_dependencies(
             );
             // Add dependency on each 'from' table
             for from_table in &relation.from {
-                let from_snake = from_table.to_case(Case::Snake);
-                if tables.contains_key(from_table) {
-                    dependencies.insert(from_table.clone());
-                } else if tables.contains_key(&from_snake) {
-                    dependencies.insert(from_snake);
+                if let Some((key, _)) = find_table(tables, from_table) {
+                    dependencies.insert(key.clone());
                 }
             }
 
             // Add dependency on each 'to' table
             for to_table in &relation.to {
-                let to_snake = to_table.to_case(Case::Snake);
-                if tables.contains_key(to_table) {
-                    dependencies.insert(to_table.clone());
-                } else if tables.contains_key(&to_snake) {
-                    dependencies.insert(to_snake);
+                if let Some((key, _)) = find_table(tables, to_table) {
+                    dependencies.insert(key.clone());
                 }
             }
         }
@@ -335,7 +329,7 @@ pub fn collect_field_type_dependencies(
 ) {
     tracing::trace!(field_type = ?field_type, "Collecting field type dependencies");
     match field_type {
-        FieldType::Other(type_name) => {
+        FieldType::Enum(type_name) | FieldType::Object(type_name) | FieldType::Other(type_name) => {
             // Avoid infinite recursion
             if visited_types.contains(type_name) {
                 tracing::trace!(type_name = %type_name, "Type already visited, skipping to avoid recursion");
@@ -347,12 +341,9 @@ pub fn collect_field_type_dependencies(
             let snake_case_name = type_name.to_case(Case::Snake);
 
             // Check if it's a table reference
-            if tables.contains_key(type_name) {
-                tracing::trace!(type_name = %type_name, "Found table reference");
-                dependencies.insert(type_name.clone());
-            } else if tables.contains_key(&snake_case_name) {
-                tracing::trace!(type_name = %snake_case_name, "Found table reference (snake case)");
-                dependencies.insert(snake_case_name.clone());
+            if let Some((table_name, _)) = find_table(tables, type_name) {
+                tracing::trace!(type_name = %type_name, table = %table_name, "Found table reference");
+                dependencies.insert(table_name.clone());
             }
 
             // Check if it's an object/struct and recursively analyze its fields
@@ -417,7 +408,11 @@ pub fn collect_field_type_dependencies(
                 }
             }
         }
-        FieldType::Option(inner) | FieldType::Vec(inner) | FieldType::RecordLink(inner) => {
+        FieldType::Option(inner)
+        | FieldType::Vec(inner)
+        | FieldType::Set(inner)
+        | FieldType::Array(inner, _)
+        | FieldType::RecordLink(inner) => {
             collect_field_type_dependencies(
                 inner,
                 tables,
@@ -473,6 +468,47 @@ pub fn collect_field_type_dependencies(
     }
 }
 
+/// Orders the tables of one strongly connected component.
+///
+/// Tables whose dependencies inside the cycle are already placed go first.
+/// When none are left, the table with the fewest unplaced dependencies is
+/// placed anyway and the links it defers are reported with a warning, since
+/// its records will reference rows that are inserted after it.
+fn order_cycle(
+    mut remaining: Vec<String>,
+    dependency_graph: &BTreeMap<String, BTreeSet<String>>,
+) -> Vec<String> {
+    let mut ordered: Vec<String> = Vec::with_capacity(remaining.len());
+    while !remaining.is_empty() {
+        let unplaced = |table: &String| -> Vec<String> {
+            dependency_graph
+                .get(table)
+                .into_iter()
+                .flatten()
+                .filter(|dep| *dep != table && remaining.contains(dep))
+                .cloned()
+                .collect()
+        };
+        // `remaining` is sorted, so ties resolve alphabetically
+        let (index, deferred) = remaining
+            .iter()
+            .map(unplaced)
+            .enumerate()
+            .min_by_key(|(_, deps)| deps.len())
+            .expect("remaining is not empty");
+        let table = remaining.remove(index);
+        if !deferred.is_empty() {
+            tracing::warn!(
+                table = %table,
+                deferred = ?deferred,
+                "Circular dependency between tables; inserting before the tables it links to"
+            );
+        }
+        ordered.push(table);
+    }
+    ordered
+}
+
 /// Sort tables by dependencies using topological sort with SCC handling
 pub fn sort_tables_by_dependencies(
     tables: &BTreeMap<String, TableConfig>,
@@ -541,6 +577,9 @@ pub fn sort_tables_by_dependencies(
     }
 
     let mut condensation = DiGraphMap::<usize, ()>::new();
+    for idx in 0..sccs.len() {
+        condensation.add_node(idx);
+    }
     for (from, tos) in &dependency_graph {
         if let Some(&from_scc) = scc_map.get(from.as_str()) {
             for to in tos {
@@ -590,12 +629,8 @@ pub fn sort_tables_by_dependencies(
         // Sort within SCC for deterministic output
         scc_tables.sort();
 
-        // Log SCC info if it contains multiple tables
+        // Break cycles by deferring as few links as possible
         if scc_tables.len() > 1 {
-            tracing::info!(
-                tables = ?scc_tables,
-                "Circular dependency detected among tables"
-            );
             evenframe_log!(
                 &format!(
                     "Circular dependency detected among tables: {:?}",
@@ -604,6 +639,7 @@ pub fn sort_tables_by_dependencies(
                 "results.log",
                 true
             );
+            scc_tables = order_cycle(scc_tables, &dependency_graph);
         }
 
         for table in &scc_tables {
@@ -743,9 +779,13 @@ mod tests {
             validators: Vec::new(),
             always_regenerate: false,
             doccom: None,
+            doc: None,
             annotations: vec![],
             unique: false,
+            indexed: false,
+            search_analyzer: None,
             mock_plugin: None,
+            rename: None,
             output_override: None,
             raw_attributes: BTreeMap::new(),
         }
@@ -937,6 +977,7 @@ mod tests {
             fields,
             validators: Vec::new(),
             doccom: None,
+            doc: None,
             macroforge_derives: vec![],
             annotations: vec![],
             pipeline: Pipeline::default(),
@@ -1187,12 +1228,7 @@ mod tests {
         TableConfig {
             table_name: name.to_string(),
             struct_config: create_struct_config(name, fields),
-            relation: None,
-            permissions: None,
-            mock_generation_config: None,
-            events: Vec::new(),
-            indexes: Vec::new(),
-            output_override: None,
+            ..Default::default()
         }
     }
 
@@ -1397,6 +1433,37 @@ mod tests {
         assert_eq!(sorted.len(), 2);
         assert!(sorted.contains(&"A".to_string()));
         assert!(sorted.contains(&"B".to_string()));
+        // Neither can go first without deferring a link, so the tie is alphabetical
+        assert_eq!(sorted, ["A", "B"]);
+    }
+
+    #[test]
+    fn test_sort_tables_breaks_cycle_at_fewest_deferred_links() {
+        dotenv::dotenv().ok();
+        let link = |name: &str, target: &str| {
+            create_struct_field(
+                name,
+                FieldType::RecordLink(Box::new(FieldType::Other(target.to_string()))),
+            )
+        };
+        let mut tables = BTreeMap::new();
+        tables.insert(
+            "a".to_string(),
+            create_table_config("a", vec![link("b_ref", "b"), link("c_ref", "c")]),
+        );
+        tables.insert(
+            "b".to_string(),
+            create_table_config("b", vec![link("a_ref", "a")]),
+        );
+        tables.insert(
+            "c".to_string(),
+            create_table_config("c", vec![link("a_ref", "a")]),
+        );
+
+        let sorted = sort_tables_by_dependencies(&tables, &BTreeMap::new(), &BTreeMap::new());
+
+        // `b` defers one link, after which `a` only defers its link to `c`
+        assert_eq!(sorted, ["b", "a", "c"]);
     }
 
     // ==================== collect_field_type_dependencies Tests ====================
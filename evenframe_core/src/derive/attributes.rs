@@ -9,10 +9,17 @@ use tracing::{debug, error, info, trace};
 
 use crate::{
     schemasync::{
-        Direction, EdgeConfig, IndexConfig,
-        mockmake::{MockGenerationConfig, coordinate::Coordination, format::Format},
+        DefineConfig, Direction, EdgeConfig, EventConfig, IndexConfig,
+        mockmake::{
+            MockGenerationConfig,
+            coordinate::Coordination,
+            format::{DEFAULT_PHONE_LOCALE, Format},
+        },
+        table::duration_nanos,
+    },
+    types::{
+        DEFAULT_SEARCH_ANALYZER, EnumRepresentation, FieldType, SEARCH_ANALYZERS, StructField,
     },
-    types::{EnumRepresentation, StructField},
 };
 use std::collections::{BTreeSet, HashMap};
 use std::convert::TryFrom;
@@ -117,6 +124,21 @@ pub fn parse_mock_data_attribute(
                                     ));
                                 }
                             }
+                            Meta::NameValue(nv) if nv.path.is_ident("seed") => {
+                                debug!("Processing 'seed' parameter");
+                                if let Expr::Lit(ExprLit {
+                                    lit: Lit::Int(lit), ..
+                                }) = &nv.value
+                                    && let Ok(value) = lit.base10_parse::<u64>()
+                                {
+                                    base_config.seed = Some(value);
+                                } else {
+                                    return Err(syn::Error::new(
+                                        nv.value.span(),
+                                        "The 'seed' parameter must be an unsigned integer literal.\n\nExample: #[mock_data(n = 1000, seed = 42)]",
+                                    ));
+                                }
+                            }
                             Meta::NameValue(nv) => {
                                 let param_name = nv
                                     .path
@@ -126,7 +148,7 @@ pub fn parse_mock_data_attribute(
                                 return Err(syn::Error::new(
                                     nv.path.span(),
                                     format!(
-                                        "Unknown parameter '{}' in mock_data attribute.\n\nValid parameters are: n, overrides, coordinate, plugin\n\nExample: #[mock_data(n = 1000, plugin = \"my_plugin\")]",
+                                        "Unknown parameter '{}' in mock_data attribute.\n\nValid parameters are: n, overrides, coordinate, plugin, seed\n\nExample: #[mock_data(n = 1000, plugin = \"my_plugin\")]",
                                         param_name
                                     ),
                                 ));
@@ -265,7 +287,601 @@ pub fn parse_mockmake_attribute(attrs: &[Attribute]) -> Result<Option<String>, s
     Ok(None)
 }
 
-pub fn parse_event_attributes(attrs: &[Attribute]) -> Result<Vec<String>, syn::Error> {
+/// Parses `#[evenframe(rename = "...")]`, the field-level output name override.
+pub fn parse_evenframe_rename_attribute(attrs: &[Attribute]) -> Result<Option<String>, syn::Error> {
+    for attr in attrs {
+        if attr.path().is_ident("evenframe") {
+            let metas: Punctuated<Meta, Token![,]> = attr
+                .parse_args_with(Punctuated::parse_terminated)
+                .map_err(|err| {
+                    syn::Error::new(
+                        attr.span(),
+                        format!(
+                            "Failed to parse evenframe attribute: {}\n\nExample: #[evenframe(rename = \"userName\")]",
+                            err
+                        ),
+                    )
+                })?;
+
+            for meta in &metas {
+                // `index`, `search`, `default`, `skip`, `readonly`, `value`
+                // and `literal` have their own parsers
+                if meta.path().is_ident("index")
+                    || meta.path().is_ident("search")
+                    || meta.path().is_ident("default")
+                    || meta.path().is_ident("skip")
+                    || meta.path().is_ident("readonly")
+                    || meta.path().is_ident("value")
+                    || meta.path().is_ident("literal")
+                {
+                    continue;
+                }
+                if let Meta::NameValue(nv) = meta
+                    && nv.path.is_ident("rename")
+                {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit), ..
+                    }) = &nv.value
+                    {
+                        if lit.value().is_empty() {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "The 'rename' parameter cannot be empty.",
+                            ));
+                        }
+                        return Ok(Some(lit.value()));
+                    }
+                    return Err(syn::Error::new(
+                        nv.value.span(),
+                        "The 'rename' parameter must be a string literal.\n\nExample: #[evenframe(rename = \"userName\")]",
+                    ));
+                }
+                return Err(syn::Error::new(
+                    meta.span(),
+                    "Unknown parameter in evenframe attribute.\n\nValid parameters: rename, index, search, default, skip, readonly, value, literal\n\nExample: #[evenframe(rename = \"userName\")]",
+                ));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Parses `#[evenframe(index)]` / `#[evenframe(index(unique))]`, the
+/// field-level index marker. Returns `Some(unique)` when the field is indexed.
+pub fn parse_evenframe_index_attribute(attrs: &[Attribute]) -> Result<Option<bool>, syn::Error> {
+    for attr in attrs {
+        if attr.path().is_ident("evenframe") {
+            let metas: Punctuated<Meta, Token![,]> = attr
+                .parse_args_with(Punctuated::parse_terminated)
+                .map_err(|err| {
+                    syn::Error::new(
+                        attr.span(),
+                        format!(
+                            "Failed to parse evenframe attribute: {}\n\nExample: #[evenframe(index(unique))]",
+                            err
+                        ),
+                    )
+                })?;
+
+            for meta in &metas {
+                match meta {
+                    Meta::Path(path) if path.is_ident("index") => return Ok(Some(false)),
+                    Meta::List(list) if list.path.is_ident("index") => {
+                        let mut unique = false;
+                        list.parse_nested_meta(|nested| {
+                            if nested.path.is_ident("unique") {
+                                unique = true;
+                                Ok(())
+                            } else {
+                                Err(nested
+                                    .error("expected `unique` inside #[evenframe(index(...))]"))
+                            }
+                        })?;
+                        return Ok(Some(unique));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Parses `#[evenframe(search)]` / `#[evenframe(search(analyzer = "..."))]`,
+/// the field-level full-text search index. Returns the analyzer name, which
+/// is [`DEFAULT_SEARCH_ANALYZER`] when none is given and must otherwise be
+/// one of [`SEARCH_ANALYZERS`].
+pub fn parse_evenframe_search_attribute(attrs: &[Attribute]) -> Result<Option<String>, syn::Error> {
+    for attr in attrs {
+        if attr.path().is_ident("evenframe") {
+            let metas: Punctuated<Meta, Token![,]> = attr
+                .parse_args_with(Punctuated::parse_terminated)
+                .map_err(|err| {
+                    syn::Error::new(
+                        attr.span(),
+                        format!(
+                            "Failed to parse evenframe attribute: {}\n\nExample: #[evenframe(search(analyzer = \"ascii\"))]",
+                            err
+                        ),
+                    )
+                })?;
+
+            for meta in &metas {
+                match meta {
+                    Meta::Path(path) if path.is_ident("search") => {
+                        return Ok(Some(DEFAULT_SEARCH_ANALYZER.to_string()));
+                    }
+                    Meta::List(list) if list.path.is_ident("search") => {
+                        let mut analyzer = None;
+                        list.parse_nested_meta(|nested| {
+                            if nested.path.is_ident("analyzer") {
+                                let lit: LitStr = nested.value()?.parse()?;
+                                let name = lit.value();
+                                if !SEARCH_ANALYZERS.iter().any(|(known, _)| *known == name) {
+                                    let known: Vec<&str> =
+                                        SEARCH_ANALYZERS.iter().map(|(known, _)| *known).collect();
+                                    return Err(syn::Error::new(
+                                        lit.span(),
+                                        format!(
+                                            "Unknown search analyzer '{}'. Expected one of: {}",
+                                            name,
+                                            known.join(", ")
+                                        ),
+                                    ));
+                                }
+                                analyzer = Some(name);
+                                Ok(())
+                            } else {
+                                Err(nested.error(
+                                    "expected `analyzer = \"...\"` inside #[evenframe(search(...))]",
+                                ))
+                            }
+                        })?;
+                        return Ok(Some(
+                            analyzer.unwrap_or_else(|| DEFAULT_SEARCH_ANALYZER.to_string()),
+                        ));
+                    }
+                    Meta::NameValue(_) if meta.path().is_ident("search") => {
+                        return Err(syn::Error::new(
+                            meta.span(),
+                            "The 'search' parameter takes no value.\n\nExample: #[evenframe(search(analyzer = \"ascii\"))]",
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Parses the field-level `#[evenframe(skip)]` marker. Returns `true` when the
+/// field should be left out of the generated `StructConfig` entirely.
+pub fn parse_evenframe_skip_attribute(attrs: &[Attribute]) -> Result<bool, syn::Error> {
+    for attr in attrs {
+        if attr.path().is_ident("evenframe") {
+            let metas: Punctuated<Meta, Token![,]> = attr
+                .parse_args_with(Punctuated::parse_terminated)
+                .map_err(|err| {
+                    syn::Error::new(
+                        attr.span(),
+                        format!(
+                            "Failed to parse evenframe attribute: {}\n\nExample: #[evenframe(skip)]",
+                            err
+                        ),
+                    )
+                })?;
+
+            for meta in &metas {
+                match meta {
+                    Meta::Path(path) if path.is_ident("skip") => return Ok(true),
+                    Meta::List(_) | Meta::NameValue(_) if meta.path().is_ident("skip") => {
+                        return Err(syn::Error::new(
+                            meta.span(),
+                            "The 'skip' parameter takes no value.\n\nExample: #[evenframe(skip)]",
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Parses the field-level `#[evenframe(readonly)]` marker. Returns `true` when
+/// the field should be defined `READONLY`.
+pub fn parse_evenframe_readonly_attribute(attrs: &[Attribute]) -> Result<bool, syn::Error> {
+    for attr in attrs {
+        if attr.path().is_ident("evenframe") {
+            let metas: Punctuated<Meta, Token![,]> = attr
+                .parse_args_with(Punctuated::parse_terminated)
+                .map_err(|err| {
+                    syn::Error::new(
+                        attr.span(),
+                        format!(
+                            "Failed to parse evenframe attribute: {}\n\nExample: #[evenframe(readonly)]",
+                            err
+                        ),
+                    )
+                })?;
+
+            for meta in &metas {
+                match meta {
+                    Meta::Path(path) if path.is_ident("readonly") => return Ok(true),
+                    Meta::List(_) | Meta::NameValue(_) if meta.path().is_ident("readonly") => {
+                        return Err(syn::Error::new(
+                            meta.span(),
+                            "The 'readonly' parameter takes no value.\n\nExample: #[evenframe(readonly)]",
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Parses `#[evenframe(value = "...")]`, a SurrealQL expression the database
+/// computes the field from on every write.
+pub fn parse_evenframe_value_attribute(attrs: &[Attribute]) -> Result<Option<LitStr>, syn::Error> {
+    for attr in attrs {
+        if attr.path().is_ident("evenframe") {
+            let metas: Punctuated<Meta, Token![,]> = attr
+                .parse_args_with(Punctuated::parse_terminated)
+                .map_err(|err| {
+                    syn::Error::new(
+                        attr.span(),
+                        format!(
+                            "Failed to parse evenframe attribute: {}\n\nExample: #[evenframe(value = \"time::now()\")]",
+                            err
+                        ),
+                    )
+                })?;
+
+            for meta in &metas {
+                if let Meta::NameValue(nv) = meta
+                    && nv.path.is_ident("value")
+                {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit), ..
+                    }) = &nv.value
+                    {
+                        if lit.value().trim().is_empty() {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "The 'value' parameter cannot be empty.",
+                            ));
+                        }
+                        return Ok(Some(lit.clone()));
+                    }
+                    return Err(syn::Error::new(
+                        nv.value.span(),
+                        "The 'value' parameter must be a string literal.\n\nExample: #[evenframe(value = \"time::now()\")]",
+                    ));
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Parses `#[evenframe(literal = "user")]`, which fixes the field to a single
+/// string, integer, float or boolean value. The field becomes a
+/// [`FieldType::Literal`], e.g. a tagged-union discriminator.
+pub fn parse_evenframe_literal_attribute(
+    attrs: &[Attribute],
+) -> Result<Option<serde_json::Value>, syn::Error> {
+    for attr in attrs {
+        if attr.path().is_ident("evenframe") {
+            let metas: Punctuated<Meta, Token![,]> = attr
+                .parse_args_with(Punctuated::parse_terminated)
+                .map_err(|err| {
+                    syn::Error::new(
+                        attr.span(),
+                        format!(
+                            "Failed to parse evenframe attribute: {}\n\nExample: #[evenframe(literal = \"user\")]",
+                            err
+                        ),
+                    )
+                })?;
+
+            for meta in &metas {
+                if let Meta::NameValue(nv) = meta
+                    && nv.path.is_ident("literal")
+                {
+                    let value = match &nv.value {
+                        Expr::Lit(ExprLit { lit, .. }) => match lit {
+                            Lit::Str(lit) => Some(serde_json::Value::from(lit.value())),
+                            Lit::Int(lit) => lit.base10_parse::<i64>().ok().map(Into::into),
+                            Lit::Float(lit) => lit
+                                .base10_parse::<f64>()
+                                .ok()
+                                .and_then(serde_json::Number::from_f64)
+                                .map(serde_json::Value::Number),
+                            Lit::Bool(lit) => Some(lit.value.into()),
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+                    return value.map(Some).ok_or_else(|| {
+                        syn::Error::new(
+                            nv.value.span(),
+                            "The 'literal' parameter must be a string, integer, float or boolean literal.\n\nExample: #[evenframe(literal = \"user\")]",
+                        )
+                    });
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Parses `#[evenframe(default = "...")]`, the field-level typed default.
+/// The value is checked against the field type by [`apply_evenframe_default`].
+pub fn parse_evenframe_default_attribute(
+    attrs: &[Attribute],
+) -> Result<Option<LitStr>, syn::Error> {
+    for attr in attrs {
+        if attr.path().is_ident("evenframe") {
+            let metas: Punctuated<Meta, Token![,]> = attr
+                .parse_args_with(Punctuated::parse_terminated)
+                .map_err(|err| {
+                    syn::Error::new(
+                        attr.span(),
+                        format!(
+                            "Failed to parse evenframe attribute: {}\n\nExample: #[evenframe(default = \"Active\")]",
+                            err
+                        ),
+                    )
+                })?;
+
+            for meta in &metas {
+                if let Meta::NameValue(nv) = meta
+                    && nv.path.is_ident("default")
+                {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit), ..
+                    }) = &nv.value
+                    {
+                        return Ok(Some(lit.clone()));
+                    }
+                    return Err(syn::Error::new(
+                        nv.value.span(),
+                        "The 'default' parameter must be a string literal.\n\nExample: #[evenframe(default = \"Active\")]",
+                    ));
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Converts a `#[evenframe(default = "...")]` value into the SurrealQL literal
+/// for `field_type`, rejecting values the field cannot hold. Enum fields take
+/// the name of a unit variant.
+pub fn evenframe_default_to_surql(
+    lit: &LitStr,
+    field_type: &FieldType,
+) -> Result<String, syn::Error> {
+    let value = lit.value();
+    let invalid = |expected: &str| {
+        syn::Error::new(
+            lit.span(),
+            format!("invalid default \"{}\": expected {}", value, expected),
+        )
+    };
+    let int = |parsed: Option<String>| parsed.ok_or_else(|| invalid("an integer in range"));
+
+    match field_type {
+        FieldType::String => Ok(format!("'{}'", value.replace('\'', "\\'"))),
+        FieldType::Char => {
+            if value.chars().count() != 1 {
+                return Err(invalid("a single character"));
+            }
+            Ok(format!("'{}'", value.replace('\'', "\\'")))
+        }
+        FieldType::Bool => match value.as_str() {
+            "true" | "false" => Ok(value.clone()),
+            _ => Err(invalid("`true` or `false`")),
+        },
+        FieldType::I8 => int(value.parse::<i8>().ok().map(|n| n.to_string())),
+        FieldType::I16 => int(value.parse::<i16>().ok().map(|n| n.to_string())),
+        FieldType::I32 => int(value.parse::<i32>().ok().map(|n| n.to_string())),
+        FieldType::I64 => int(value.parse::<i64>().ok().map(|n| n.to_string())),
+        FieldType::Isize => int(value.parse::<isize>().ok().map(|n| n.to_string())),
+        FieldType::U8 => int(value.parse::<u8>().ok().map(|n| n.to_string())),
+        FieldType::U16 => int(value.parse::<u16>().ok().map(|n| n.to_string())),
+        FieldType::U32 => int(value.parse::<u32>().ok().map(|n| n.to_string())),
+        FieldType::U64 => int(value.parse::<u64>().ok().map(|n| n.to_string())),
+        FieldType::Usize => int(value.parse::<usize>().ok().map(|n| n.to_string())),
+        // 128-bit integers are stored as decimal strings
+        FieldType::I128 => int(value.parse::<i128>().ok().map(|n| format!("'{}'", n))),
+        FieldType::U128 => int(value.parse::<u128>().ok().map(|n| format!("'{}'", n))),
+        FieldType::F32 | FieldType::F64 => match value.parse::<f64>() {
+            Ok(n) if n.is_finite() => Ok(format!("{:?}f", n)),
+            _ => Err(invalid("a finite number")),
+        },
+        FieldType::Option(inner) => {
+            if value == "None" {
+                Ok("NONE".to_string())
+            } else {
+                evenframe_default_to_surql(lit, inner)
+            }
+        }
+        FieldType::Enum(_) | FieldType::Other(_) => match syn::parse_str::<Ident>(&value) {
+            Ok(variant) => Ok(format!("'{}'", variant)),
+            Err(_) => Err(invalid("the name of a unit variant")),
+        },
+        _ => Err(syn::Error::new(
+            lit.span(),
+            format!(
+                "#[evenframe(default = ...)] does not support fields of type {}; use #[define_field_statement(default(\"...\"))] instead",
+                field_type
+            ),
+        )),
+    }
+}
+
+/// Validates `#[evenframe(default = "...")]` against `field_type` and stores
+/// the resulting SurrealQL literal in the field's define config.
+pub fn apply_evenframe_default(
+    define_config: &mut Option<DefineConfig>,
+    lit: &LitStr,
+    field_type: &FieldType,
+) -> Result<(), syn::Error> {
+    let default = evenframe_default_to_surql(lit, field_type)?;
+    let define = define_config.get_or_insert_with(DefineConfig::default);
+    if define.default.is_some() {
+        return Err(syn::Error::new(
+            lit.span(),
+            "#[evenframe(default = ...)] conflicts with #[define_field_statement(default(...))]",
+        ));
+    }
+    define.default = Some(default);
+    Ok(())
+}
+
+/// Stores `#[evenframe(readonly)]` and `#[evenframe(value = "...")]` in the
+/// field's define config as its `READONLY` and `VALUE` clauses.
+pub fn apply_evenframe_readonly_and_value(
+    define_config: &mut Option<DefineConfig>,
+    readonly: bool,
+    value: Option<&LitStr>,
+) -> Result<(), syn::Error> {
+    if !readonly && value.is_none() {
+        return Ok(());
+    }
+    let define = define_config.get_or_insert_with(DefineConfig::default);
+    if readonly {
+        define.readonly = Some(true);
+    }
+    if let Some(lit) = value {
+        if define.value.is_some() {
+            return Err(syn::Error::new(
+                lit.span(),
+                "#[evenframe(value = ...)] conflicts with #[define_field_statement(value(...))]",
+            ));
+        }
+        define.value = Some(lit.value());
+    }
+    Ok(())
+}
+
+/// For enum-typed fields, a reference to the defaulted variant so that an
+/// unknown variant fails to compile at the attribute.
+pub fn evenframe_default_variant_check(
+    lit: &LitStr,
+    field_type: &FieldType,
+) -> Option<proc_macro2::TokenStream> {
+    match field_type {
+        FieldType::Option(inner) if lit.value() != "None" => {
+            evenframe_default_variant_check(lit, inner)
+        }
+        FieldType::Enum(name) | FieldType::Other(name) => {
+            let ty = syn::parse_str::<syn::Type>(name).ok()?;
+            let mut variant = syn::parse_str::<Ident>(&lit.value()).ok()?;
+            variant.set_span(lit.span());
+            Some(quote! {
+                const _: fn() = || {
+                    let _ = <#ty>::#variant;
+                };
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Parses the struct-level `#[evenframe(no_overwrite)]` marker. Returns `true`
+/// when the table's fields should be defined with `IF NOT EXISTS`.
+pub fn parse_evenframe_no_overwrite_attribute(attrs: &[Attribute]) -> Result<bool, syn::Error> {
+    let mut no_overwrite = false;
+    for attr in attrs {
+        if attr.path().is_ident("evenframe") {
+            let metas: Punctuated<Meta, Token![,]> = attr
+                .parse_args_with(Punctuated::parse_terminated)
+                .map_err(|err| {
+                    syn::Error::new(
+                        attr.span(),
+                        format!(
+                            "Failed to parse evenframe attribute: {}\n\nExample: #[evenframe(no_overwrite)]",
+                            err
+                        ),
+                    )
+                })?;
+
+            for meta in &metas {
+                match meta {
+                    Meta::Path(path) if path.is_ident("no_overwrite") => no_overwrite = true,
+                    Meta::NameValue(nv) if nv.path.is_ident("changefeed") => {}
+                    Meta::List(list) if list.path.is_ident("event") => {}
+                    _ => {
+                        return Err(syn::Error::new(
+                            meta.span(),
+                            "Unknown parameter in struct-level evenframe attribute.\n\nValid parameters: no_overwrite, changefeed = \"<duration>\", event(name = \"…\", when = \"…\", then = \"…\")\n\nExample: #[evenframe(no_overwrite)]",
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    Ok(no_overwrite)
+}
+
+/// Parses the struct-level `#[evenframe(changefeed = "3d")]` parameter: the
+/// retention of the table's change feed, as a SurrealQL duration.
+pub fn parse_evenframe_changefeed_attribute(
+    attrs: &[Attribute],
+) -> Result<Option<String>, syn::Error> {
+    for attr in attrs {
+        if attr.path().is_ident("evenframe") {
+            let metas: Punctuated<Meta, Token![,]> = attr
+                .parse_args_with(Punctuated::parse_terminated)
+                .map_err(|err| {
+                    syn::Error::new(
+                        attr.span(),
+                        format!(
+                            "Failed to parse evenframe attribute: {}\n\nExample: #[evenframe(changefeed = \"3d\")]",
+                            err
+                        ),
+                    )
+                })?;
+            for meta in &metas {
+                if let Meta::NameValue(nv) = meta
+                    && nv.path.is_ident("changefeed")
+                {
+                    let Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit), ..
+                    }) = &nv.value
+                    else {
+                        return Err(syn::Error::new(
+                            nv.value.span(),
+                            "changefeed expects a string duration.\n\nExample: #[evenframe(changefeed = \"3d\")]",
+                        ));
+                    };
+                    let duration = lit.value();
+                    if duration_nanos(&duration).is_none_or(|nanos| nanos == 0) {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!(
+                                "Invalid changefeed duration '{}'.\n\nExpected a SurrealQL duration such as \"12h\" or \"3d\"",
+                                duration
+                            ),
+                        ));
+                    }
+                    return Ok(Some(duration));
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Parses the table's events: `#[evenframe(event(name = "…", when = "…",
+/// then = "…"))]`, and complete statements given with
+/// `#[event("DEFINE EVENT …")]`. `when` defaults to `true`.
+pub fn parse_event_attributes(attrs: &[Attribute]) -> Result<Vec<EventConfig>, syn::Error> {
     info!(
         "Starting event attribute parsing for {} attributes",
         attrs.len()
@@ -296,7 +912,19 @@ pub fn parse_event_attributes(attrs: &[Attribute]) -> Result<Vec<String>, syn::E
                 ));
             }
 
-            events.push(value);
+            events.push(EventConfig::from_statement(value));
+        } else if attr.path().is_ident("evenframe") {
+            let metas: Punctuated<Meta, Token![,]> =
+                attr.parse_args_with(Punctuated::parse_terminated)?;
+            for meta in &metas {
+                if let Meta::List(list) = meta
+                    && list.path.is_ident("event")
+                {
+                    let event = parse_evenframe_event(list)?;
+                    trace!(event_name = %event.name, "Parsed evenframe event attribute");
+                    events.push(event);
+                }
+            }
         }
     }
 
@@ -307,6 +935,78 @@ pub fn parse_event_attributes(attrs: &[Attribute]) -> Result<Vec<String>, syn::E
     Ok(events)
 }
 
+/// Parses the parameters of one `event(name = "…", when = "…", then = "…")`.
+fn parse_evenframe_event(list: &syn::MetaList) -> Result<EventConfig, syn::Error> {
+    const EXAMPLE: &str = "Example: #[evenframe(event(name = \"audit_update\", when = \"$event = 'UPDATE'\", then = \"CREATE audit SET record = $after.id\"))]";
+
+    let params: Punctuated<syn::MetaNameValue, Token![,]> = list
+        .parse_args_with(Punctuated::parse_terminated)
+        .map_err(|e| {
+            syn::Error::new(
+                list.span(),
+                format!("Failed to parse event: {}\n\n{}", e, EXAMPLE),
+            )
+        })?;
+
+    let mut event = EventConfig::default();
+    for param in &params {
+        let Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) = &param.value
+        else {
+            return Err(syn::Error::new(
+                param.value.span(),
+                format!("Event parameters expect a string.\n\n{}", EXAMPLE),
+            ));
+        };
+        let target = if param.path.is_ident("name") {
+            &mut event.name
+        } else if param.path.is_ident("when") {
+            &mut event.when
+        } else if param.path.is_ident("then") {
+            &mut event.then
+        } else {
+            return Err(syn::Error::new(
+                param.path.span(),
+                format!(
+                    "Unknown event parameter.\n\nValid parameters: name, when, then\n\n{}",
+                    EXAMPLE
+                ),
+            ));
+        };
+        *target = lit.value();
+    }
+
+    let valid_name = event
+        .name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && event
+            .name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid_name {
+        return Err(syn::Error::new(
+            list.span(),
+            format!(
+                "Event name '{}' must be a non-empty identifier.\n\n{}",
+                event.name, EXAMPLE
+            ),
+        ));
+    }
+    if event.then.trim().is_empty() {
+        return Err(syn::Error::new(
+            list.span(),
+            format!("Event '{}' needs a `then`.\n\n{}", event.name, EXAMPLE),
+        ));
+    }
+    if event.when.trim().is_empty() {
+        event.when = "true".to_string();
+    }
+    Ok(event)
+}
+
 /// Parses every `#[index(fields(a, b, ...), unique?)]` attribute on a struct
 /// into a `Vec<IndexConfig>`, validating that each ident inside `fields(...)`
 /// names a real struct field (`known_fields` is the snake-cased field name set
@@ -598,6 +1298,38 @@ pub fn parse_doccom_attribute(attrs: &[Attribute]) -> Result<Option<String>, syn
     Ok(None)
 }
 
+/// Collects the `///` doc comment lines on an item, dropping the single
+/// space rustdoc puts after `///` and any blank lines around the text.
+pub fn parse_doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(nv) => match &nv.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) => Some(s.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .flat_map(|value| {
+            value
+                .lines()
+                .map(|line| {
+                    line.strip_prefix(' ')
+                        .unwrap_or(line)
+                        .trim_end()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let doc = lines.join("\n").trim_matches('\n').to_string();
+    (!doc.is_empty()).then_some(doc)
+}
+
 pub fn parse_macroforge_derive_attribute(attrs: &[Attribute]) -> Result<Vec<String>, syn::Error> {
     for attr in attrs {
         if attr.path().is_ident("macroforge_derive") {
@@ -775,6 +1507,10 @@ pub fn parse_format_attribute(
 
             // Transform the expression to add Format:: prefix if needed
             let format_expr = match &expr {
+                // A bare PhoneNumber uses the default locale
+                Expr::Path(path_expr) if path_expr.path.is_ident("PhoneNumber") => {
+                    syn::parse_quote!(Format::PhoneNumber { locale: #DEFAULT_PHONE_LOCALE })
+                }
                 // If it's just an identifier like DateTime, convert to Format::DateTime
                 Expr::Path(path_expr) if path_expr.path.segments.len() == 1 => {
                     let variant = &path_expr.path.segments[0];
@@ -819,6 +1555,16 @@ pub fn parse_format_attribute(
                         expr.clone()
                     }
                 }
+                // If it's a struct like PhoneNumber { locale: "DE" }, convert to
+                // Format::PhoneNumber { locale: "DE" }
+                Expr::Struct(struct_expr) if struct_expr.path.segments.len() == 1 => {
+                    let mut struct_expr = struct_expr.clone();
+                    struct_expr
+                        .path
+                        .segments
+                        .insert(0, PathSegment::from(syn::Ident::new("Format", expr.span())));
+                    Expr::Struct(struct_expr)
+                }
                 // Otherwise keep as is
                 _ => expr.clone(),
             };
@@ -835,7 +1581,7 @@ pub fn parse_format_attribute(
                     return Err(syn::Error::new(
                         expr.span(),
                         format!(
-                            "{}\n\nValid formats:\n- Simple: DateTime, Date, Time, Currency, Percentage, Phone, Email, FirstName, LastName, CompanyName, PhoneNumber, ColorHex, JwtToken, Oklch, PostalCode\n- With parameter: Url(\"domain.com\")",
+                            "{}\n\nValid formats:\n- Simple: DateTime, Date, Time, Currency, Percentage, Phone, Email, FirstName, LastName, CompanyName, PhoneNumber, ColorHex, JwtToken, Oklch, PostalCode\n- With parameter: Url(\"domain.com\")\n- With fields: PhoneNumber {{ locale: \"DE\" }}",
                             e
                         ),
                     ));
@@ -866,6 +1612,10 @@ pub fn parse_format_attribute_bin(attrs: &[Attribute]) -> Result<Option<Format>,
 
             // Transform the expression to add Format:: prefix if needed
             let format_expr = match &expr {
+                // A bare PhoneNumber uses the default locale
+                Expr::Path(path_expr) if path_expr.path.is_ident("PhoneNumber") => {
+                    syn::parse_quote!(Format::PhoneNumber { locale: #DEFAULT_PHONE_LOCALE })
+                }
                 // If it's just an identifier like DateTime, convert to Format::DateTime
                 Expr::Path(path_expr) if path_expr.path.segments.len() == 1 => {
                     let variant = &path_expr.path.segments[0];
@@ -910,6 +1660,16 @@ pub fn parse_format_attribute_bin(attrs: &[Attribute]) -> Result<Option<Format>,
                         expr.clone()
                     }
                 }
+                // If it's a struct like PhoneNumber { locale: "DE" }, convert to
+                // Format::PhoneNumber { locale: "DE" }
+                Expr::Struct(struct_expr) if struct_expr.path.segments.len() == 1 => {
+                    let mut struct_expr = struct_expr.clone();
+                    struct_expr
+                        .path
+                        .segments
+                        .insert(0, PathSegment::from(syn::Ident::new("Format", expr.span())));
+                    Expr::Struct(struct_expr)
+                }
                 // Otherwise keep as is
                 _ => expr.clone(),
             };
@@ -926,7 +1686,7 @@ pub fn parse_format_attribute_bin(attrs: &[Attribute]) -> Result<Option<Format>,
                     return Err(syn::Error::new(
                         expr.span(),
                         format!(
-                            "{}\n\nValid formats:\n- Simple: DateTime, Date, Time, Currency, Percentage, Phone, Email, FirstName, LastName, CompanyName, PhoneNumber, ColorHex, JwtToken, Oklch, PostalCode\n- With parameter: Url(\"domain.com\")",
+                            "{}\n\nValid formats:\n- Simple: DateTime, Date, Time, Currency, Percentage, Phone, Email, FirstName, LastName, CompanyName, PhoneNumber, ColorHex, JwtToken, Oklch, PostalCode\n- With parameter: Url(\"domain.com\")\n- With fields: PhoneNumber {{ locale: \"DE\" }}",
                             e
                         ),
                     ));
@@ -956,12 +1716,65 @@ mod tests {
         assert_eq!(
             events,
             vec![
-                "DEFINE EVENT foo ON TABLE user WHEN true THEN { RETURN true }".to_string(),
-                "DEFINE EVENT bar ON TABLE user WHEN true THEN { RETURN false }".to_string(),
+                EventConfig::from_statement(
+                    "DEFINE EVENT foo ON TABLE user WHEN true THEN { RETURN true }"
+                ),
+                EventConfig::from_statement(
+                    "DEFINE EVENT bar ON TABLE user WHEN true THEN { RETURN false }"
+                ),
             ]
         );
     }
 
+    #[test]
+    fn parse_event_attributes_reads_evenframe_events() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[evenframe(no_overwrite, event(name = "audit_update", when = "$event = 'UPDATE'", then = "CREATE audit SET record = $after.id"))]),
+            parse_quote!(#[evenframe(event(name = "touch", then = "UPDATE $after.id SET seen = true"))]),
+        ];
+
+        let events = parse_event_attributes(&attrs).unwrap();
+        assert_eq!(
+            events,
+            vec![
+                EventConfig {
+                    name: "audit_update".to_string(),
+                    when: "$event = 'UPDATE'".to_string(),
+                    then: "CREATE audit SET record = $after.id".to_string(),
+                    statement: None,
+                },
+                EventConfig {
+                    name: "touch".to_string(),
+                    when: "true".to_string(),
+                    then: "UPDATE $after.id SET seen = true".to_string(),
+                    statement: None,
+                },
+            ]
+        );
+        assert!(parse_evenframe_no_overwrite_attribute(&attrs).unwrap());
+
+        for bad in [
+            parse_quote!(#[evenframe(event(when = "true", then = "RETURN 1"))]),
+            parse_quote!(#[evenframe(event(name = "bad name", then = "RETURN 1"))]),
+            parse_quote!(#[evenframe(event(name = "no_then"))]),
+            parse_quote!(#[evenframe(event(name = "x", then = "RETURN 1", after = "y"))]),
+            parse_quote!(#[evenframe(event(name = "x", then = 1))]),
+        ] {
+            let bad: Vec<Attribute> = vec![bad];
+            assert!(parse_event_attributes(&bad).is_err());
+        }
+    }
+
+    #[test]
+    fn parse_mock_data_attribute_reads_seed() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[mock_data(n = 10, seed = 42)])];
+        let config = parse_mock_data_attribute(&attrs).unwrap().unwrap();
+        assert_eq!(config.seed, Some(42));
+
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[mock_data(seed = "42")])];
+        assert!(parse_mock_data_attribute(&attrs).is_err());
+    }
+
     #[test]
     fn parse_event_attributes_rejects_empty_statements() {
         let attrs: Vec<Attribute> = vec![parse_quote!(#[event("")])];
@@ -1029,4 +1842,308 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn parse_evenframe_rename_reads_override() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[unique]),
+            parse_quote!(#[evenframe(rename = "userName")]),
+        ];
+        let result = parse_evenframe_rename_attribute(&attrs).unwrap();
+        assert_eq!(result, Some("userName".to_string()));
+    }
+
+    #[test]
+    fn parse_evenframe_rename_absent_returns_none() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[serde(rename = "ignored")])];
+        let result = parse_evenframe_rename_attribute(&attrs).unwrap();
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn parse_evenframe_rename_rejects_unknown_and_non_string() {
+        let unknown: Vec<Attribute> = vec![parse_quote!(#[evenframe(alias = "x")])];
+        assert!(parse_evenframe_rename_attribute(&unknown).is_err());
+
+        let non_string: Vec<Attribute> = vec![parse_quote!(#[evenframe(rename = 5)])];
+        assert!(parse_evenframe_rename_attribute(&non_string).is_err());
+    }
+
+    #[test]
+    fn parse_evenframe_rename_ignores_index() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[evenframe(index, rename = "userName")])];
+        let result = parse_evenframe_rename_attribute(&attrs).unwrap();
+        assert_eq!(result, Some("userName".to_string()));
+    }
+
+    #[test]
+    fn parse_evenframe_index_forms() {
+        let plain: Vec<Attribute> = vec![parse_quote!(#[evenframe(index)])];
+        assert_eq!(
+            parse_evenframe_index_attribute(&plain).unwrap(),
+            Some(false)
+        );
+
+        let unique: Vec<Attribute> = vec![parse_quote!(#[evenframe(index(unique))])];
+        assert_eq!(
+            parse_evenframe_index_attribute(&unique).unwrap(),
+            Some(true)
+        );
+
+        let absent: Vec<Attribute> = vec![parse_quote!(#[evenframe(rename = "userName")])];
+        assert_eq!(parse_evenframe_index_attribute(&absent).unwrap(), None);
+
+        let bad: Vec<Attribute> = vec![parse_quote!(#[evenframe(index(sparse))])];
+        assert!(parse_evenframe_index_attribute(&bad).is_err());
+    }
+
+    #[test]
+    fn parse_evenframe_search_forms() {
+        let plain: Vec<Attribute> = vec![parse_quote!(#[evenframe(search)])];
+        assert_eq!(
+            parse_evenframe_search_attribute(&plain).unwrap(),
+            Some(DEFAULT_SEARCH_ANALYZER.to_string())
+        );
+
+        let named: Vec<Attribute> = vec![parse_quote!(#[evenframe(search(analyzer = "ascii"))])];
+        assert_eq!(
+            parse_evenframe_search_attribute(&named).unwrap(),
+            Some("ascii".to_string())
+        );
+
+        let absent: Vec<Attribute> = vec![parse_quote!(#[evenframe(index)])];
+        assert_eq!(parse_evenframe_search_attribute(&absent).unwrap(), None);
+
+        let renamed: Vec<Attribute> = vec![parse_quote!(#[evenframe(search, rename = "body")])];
+        assert_eq!(
+            parse_evenframe_rename_attribute(&renamed).unwrap(),
+            Some("body".to_string())
+        );
+
+        let bad: Vec<Attribute> = vec![parse_quote!(#[evenframe(search(analyzer = "a b"))])];
+        assert!(parse_evenframe_search_attribute(&bad).is_err());
+
+        let unknown: Vec<Attribute> =
+            vec![parse_quote!(#[evenframe(search(analyzer = "klingon"))])];
+        let err = parse_evenframe_search_attribute(&unknown).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Unknown search analyzer 'klingon'"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn parse_doc_comment_joins_lines() {
+        let field: syn::Field = parse_quote! {
+            ///
+            /// The user's display name.
+            ///   Shown in the header.
+            ///
+            #[doccom("Display name")]
+            pub name: String
+        };
+        assert_eq!(
+            parse_doc_comment(&field.attrs).as_deref(),
+            Some("The user's display name.\n  Shown in the header.")
+        );
+
+        let undocumented: syn::Field = parse_quote!(pub name: String);
+        assert_eq!(parse_doc_comment(&undocumented.attrs), None);
+    }
+
+    #[test]
+    fn parse_evenframe_skip_forms() {
+        let skip: Vec<Attribute> = vec![parse_quote!(#[evenframe(skip)])];
+        assert!(parse_evenframe_skip_attribute(&skip).unwrap());
+        assert_eq!(parse_evenframe_rename_attribute(&skip).unwrap(), None);
+
+        let absent: Vec<Attribute> = vec![parse_quote!(#[evenframe(rename = "userName")])];
+        assert!(!parse_evenframe_skip_attribute(&absent).unwrap());
+
+        let valued: Vec<Attribute> = vec![parse_quote!(#[evenframe(skip = true)])];
+        assert!(parse_evenframe_skip_attribute(&valued).is_err());
+    }
+
+    #[test]
+    fn parse_evenframe_readonly_and_value_forms() {
+        let attrs: Vec<Attribute> =
+            vec![parse_quote!(#[evenframe(readonly, value = "time::now()")])];
+        assert!(parse_evenframe_readonly_attribute(&attrs).unwrap());
+        let value = parse_evenframe_value_attribute(&attrs).unwrap().unwrap();
+        assert_eq!(value.value(), "time::now()");
+        assert_eq!(parse_evenframe_rename_attribute(&attrs).unwrap(), None);
+
+        let mut define_config = None;
+        apply_evenframe_readonly_and_value(&mut define_config, true, Some(&value)).unwrap();
+        let define = define_config.as_ref().unwrap();
+        assert_eq!(define.readonly, Some(true));
+        assert_eq!(define.value.as_deref(), Some("time::now()"));
+        assert!(
+            apply_evenframe_readonly_and_value(&mut define_config, false, Some(&value)).is_err()
+        );
+
+        let absent: Vec<Attribute> = vec![parse_quote!(#[evenframe(rename = "userName")])];
+        assert!(!parse_evenframe_readonly_attribute(&absent).unwrap());
+        assert!(parse_evenframe_value_attribute(&absent).unwrap().is_none());
+        let mut untouched = None;
+        apply_evenframe_readonly_and_value(&mut untouched, false, None).unwrap();
+        assert!(untouched.is_none());
+
+        let valued: Vec<Attribute> = vec![parse_quote!(#[evenframe(readonly = true)])];
+        assert!(parse_evenframe_readonly_attribute(&valued).is_err());
+        let non_string: Vec<Attribute> = vec![parse_quote!(#[evenframe(value = 5)])];
+        assert!(parse_evenframe_value_attribute(&non_string).is_err());
+        let empty: Vec<Attribute> = vec![parse_quote!(#[evenframe(value = " ")])];
+        assert!(parse_evenframe_value_attribute(&empty).is_err());
+    }
+
+    #[test]
+    fn parse_evenframe_literal_forms() {
+        let literal = |attr: Attribute| parse_evenframe_literal_attribute(&[attr]);
+
+        assert_eq!(
+            literal(parse_quote!(#[evenframe(literal = "user")])).unwrap(),
+            Some(serde_json::json!("user"))
+        );
+        assert_eq!(
+            literal(parse_quote!(#[evenframe(literal = 2)])).unwrap(),
+            Some(serde_json::json!(2))
+        );
+        assert_eq!(
+            literal(parse_quote!(#[evenframe(literal = 1.5)])).unwrap(),
+            Some(serde_json::json!(1.5))
+        );
+        assert_eq!(
+            literal(parse_quote!(#[evenframe(literal = true)])).unwrap(),
+            Some(serde_json::json!(true))
+        );
+        assert_eq!(
+            literal(parse_quote!(#[evenframe(rename = "kind")])).unwrap(),
+            None
+        );
+        assert_eq!(
+            parse_evenframe_rename_attribute(&[parse_quote!(#[evenframe(literal = "user")])])
+                .unwrap(),
+            None
+        );
+
+        let err = literal(parse_quote!(#[evenframe(literal = b'x')])).unwrap_err();
+        assert!(err.to_string().contains("'literal' parameter"), "{err}");
+    }
+
+    #[test]
+    fn parse_evenframe_no_overwrite_forms() {
+        let set: Vec<Attribute> = vec![parse_quote!(#[evenframe(no_overwrite)])];
+        assert!(parse_evenframe_no_overwrite_attribute(&set).unwrap());
+
+        let absent: Vec<Attribute> = vec![parse_quote!(#[index(fields(a))])];
+        assert!(!parse_evenframe_no_overwrite_attribute(&absent).unwrap());
+
+        let bad: Vec<Attribute> = vec![parse_quote!(#[evenframe(rename = "x")])];
+        assert!(parse_evenframe_no_overwrite_attribute(&bad).is_err());
+    }
+
+    #[test]
+    fn parse_evenframe_changefeed_forms() {
+        let both: Vec<Attribute> =
+            vec![parse_quote!(#[evenframe(changefeed = "7d", no_overwrite)])];
+        assert_eq!(
+            parse_evenframe_changefeed_attribute(&both).unwrap(),
+            Some("7d".to_string())
+        );
+        assert!(parse_evenframe_no_overwrite_attribute(&both).unwrap());
+
+        let changefeed_only: Vec<Attribute> = vec![parse_quote!(#[evenframe(changefeed = "12h")])];
+        assert!(!parse_evenframe_no_overwrite_attribute(&changefeed_only).unwrap());
+
+        let absent: Vec<Attribute> = vec![parse_quote!(#[evenframe(no_overwrite)])];
+        assert_eq!(parse_evenframe_changefeed_attribute(&absent).unwrap(), None);
+
+        for bad in [
+            parse_quote!(#[evenframe(changefeed = "three days")]),
+            parse_quote!(#[evenframe(changefeed = "0s")]),
+            parse_quote!(#[evenframe(changefeed = 3)]),
+        ] {
+            let bad: Vec<Attribute> = vec![bad];
+            assert!(parse_evenframe_changefeed_attribute(&bad).is_err());
+        }
+
+        let malformed: Vec<Attribute> = vec![parse_quote!(#[evenframe(changefeed = )])];
+        let err = parse_evenframe_changefeed_attribute(&malformed).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Example: #[evenframe(changefeed = \"3d\")]"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn evenframe_default_converts_to_typed_surql() {
+        let attrs: Vec<Attribute> =
+            vec![parse_quote!(#[evenframe(rename = "retries", default = "3")])];
+        let lit = parse_evenframe_default_attribute(&attrs).unwrap().unwrap();
+        assert_eq!(lit.value(), "3");
+        assert_eq!(
+            parse_evenframe_rename_attribute(&attrs).unwrap().as_deref(),
+            Some("retries")
+        );
+
+        let surql = |value: &str, ty: FieldType| {
+            evenframe_default_to_surql(&LitStr::new(value, proc_macro2::Span::call_site()), &ty)
+        };
+        assert_eq!(surql("3", FieldType::U8).unwrap(), "3");
+        assert_eq!(surql("it's", FieldType::String).unwrap(), "'it\\'s'");
+        assert_eq!(surql("1.5", FieldType::F64).unwrap(), "1.5f");
+        assert_eq!(surql("true", FieldType::Bool).unwrap(), "true");
+        assert_eq!(
+            surql("None", FieldType::Option(Box::new(FieldType::I32))).unwrap(),
+            "NONE"
+        );
+        assert_eq!(
+            surql("Active", FieldType::Other("Status".to_string())).unwrap(),
+            "'Active'"
+        );
+
+        assert!(surql("300", FieldType::U8).is_err());
+        assert!(surql("yes", FieldType::Bool).is_err());
+        assert!(surql("not a variant", FieldType::Other("Status".to_string())).is_err());
+        assert!(surql("[]", FieldType::Vec(Box::new(FieldType::String))).is_err());
+    }
+
+    #[test]
+    fn evenframe_default_conflicts_with_define_default() {
+        let field: syn::Field = parse_quote! {
+            #[define_field_statement(default("0"))]
+            pub count: u32
+        };
+        let mut define_config = DefineConfig::parse(&field).unwrap();
+        let lit: LitStr = parse_quote!("1");
+        assert!(apply_evenframe_default(&mut define_config, &lit, &FieldType::U32).is_err());
+
+        let mut empty = None;
+        apply_evenframe_default(&mut empty, &lit, &FieldType::U32).unwrap();
+        assert_eq!(empty.unwrap().default.as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn phone_number_format_accepts_locale() {
+        let parse = |attr: Attribute| parse_format_attribute_bin(&[attr]).unwrap().unwrap();
+        assert_eq!(
+            parse(parse_quote!(#[format(PhoneNumber)])),
+            Format::PhoneNumber {
+                locale: "US".to_string()
+            }
+        );
+        assert_eq!(
+            parse(parse_quote!(#[format(PhoneNumber { locale: "DE" })])),
+            Format::PhoneNumber {
+                locale: "DE".to_string()
+            }
+        );
+        assert!(
+            parse_format_attribute(&[parse_quote!(#[format(PhoneNumber { locale: "UK" })])])
+                .is_ok()
+        );
+    }
 }
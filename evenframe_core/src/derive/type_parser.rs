@@ -1,3 +1,4 @@
+use crate::types::FieldType;
 use quote::quote;
 use syn::spanned::Spanned;
 use syn::{GenericArgument, PathArguments, Type};
@@ -10,7 +11,7 @@ fn unsupported_type_error(ty: &Type, type_str: &str, hint: &str) -> proc_macro2:
         format!(
             "Unsupported type: '{}'. {}\n\nSupported types include:\n\
             - Primitives: bool, char, String, i8-i128, u8-u128, f32, f64\n\
-            - Containers: Option<T>, Vec<T>, HashMap<K,V>, BTreeMap<K,V>\n\
+            - Containers: Option<T>, Vec<T>, HashSet<T>, BTreeSet<T>, HashMap<K,V>, BTreeMap<K,V>\n\
             - Custom: RecordLink<T>, or any custom struct/enum\n\
             - Foreign types: configure via [general.foreign_types] in config",
             type_str, hint
@@ -31,7 +32,7 @@ fn parse_generic_args(
         args.len()
     );
     match (type_name, args.len()) {
-        ("Option" | "Vec" | "RecordLink", 1) => {
+        ("Option" | "Vec" | "HashSet" | "BTreeSet" | "RecordLink", 1) => {
             if let Some(GenericArgument::Type(inner_ty)) = args.first() {
                 trace!("Processing inner type for {}", type_name);
                 let inner_parsed = parse_data_type(inner_ty);
@@ -39,9 +40,15 @@ fn parse_generic_args(
                     "Option" => {
                         quote! { ::evenframe::types::FieldType::Option(Box::new(#inner_parsed)) }
                     }
+                    "Vec" if FieldType::parse_syn_ty(inner_ty) == FieldType::U8 => {
+                        quote! { ::evenframe::types::FieldType::Bytes }
+                    }
                     "Vec" => {
                         quote! { ::evenframe::types::FieldType::Vec(Box::new(#inner_parsed)) }
                     }
+                    "HashSet" | "BTreeSet" => {
+                        quote! { ::evenframe::types::FieldType::Set(Box::new(#inner_parsed)) }
+                    }
                     "RecordLink" => {
                         quote! { ::evenframe::types::FieldType::RecordLink(Box::new(#inner_parsed)) }
                     }
@@ -174,14 +181,21 @@ pub fn parse_data_type(ty: &Type) -> proc_macro2::TokenStream {
             .to_compile_error()
         }
 
-        // Handle array types
+        // Handle array types; a non-literal length falls back to Vec<T>
         Type::Array(arr) => {
-            warn!("Unsupported array type: {}", type_str);
-            syn::Error::new(
-                arr.span(),
-                "Fixed-size arrays are not supported. Use Vec<T> for dynamic arrays instead",
-            )
-            .to_compile_error()
+            let inner_parsed = parse_data_type(&arr.elem);
+            match FieldType::parse_syn_ty(ty) {
+                FieldType::Array(_, len) => {
+                    quote! { ::evenframe::types::FieldType::Array(Box::new(#inner_parsed), #len) }
+                }
+                _ => {
+                    debug!(
+                        "Array length is not a literal, treating {} as Vec",
+                        type_str
+                    );
+                    quote! { ::evenframe::types::FieldType::Vec(Box::new(#inner_parsed)) }
+                }
+            }
         }
 
         // Handle slice types
@@ -246,6 +260,16 @@ fn parse_path_type(ty: &Type, type_path: &syn::TypePath) -> proc_macro2::TokenSt
             return field_type;
         }
 
+        if ident_str == "Value" && FieldType::is_json_value_path(&type_path.path) {
+            debug!("Found JSON value type: {}", type_str);
+            return quote! { ::evenframe::types::FieldType::Json };
+        }
+
+        if let Some(kind) = FieldType::geometry_kind_for_path(&type_path.path) {
+            debug!("Found geometry type: {}", type_str);
+            return quote! { ::evenframe::types::FieldType::Geometry(#kind) };
+        }
+
         // Check if it has generic arguments
         if let PathArguments::AngleBracketed(angle_args) = &last_segment.arguments {
             debug!("Type has generic arguments: {}<...>", ident_str);
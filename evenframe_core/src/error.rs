@@ -24,6 +24,9 @@ pub enum EvenframeError {
     #[error("Database error: {0}")]
     Database(Box<String>),
 
+    #[error("Authentication error: {0}")]
+    Authentication(String),
+
     #[error("Configuration error: {0}")]
     Config(String),
 
@@ -54,6 +57,18 @@ pub enum EvenframeError {
     #[error("Mock generation error: {0}")]
     MockGeneration(String),
 
+    #[error(
+        "Failed to insert mock data into table `{table}` (batch {batch}): {source}\n  statement: {snippet}"
+    )]
+    MockInsertion {
+        table: String,
+        /// 1-based index of the batch that was rejected
+        batch: usize,
+        /// Leading part of the rejected statement, see [`EvenframeError::mock_insertion`]
+        snippet: String,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
     #[error("Plugin error: {0}")]
     Plugin(String),
 
@@ -131,6 +146,12 @@ pub enum EvenframeError {
     #[error("Comparison error: {0}")]
     Comparison(String),
 
+    #[error("Breaking schema changes detected:\n  {}", .0.join("\n  "))]
+    BreakingChanges(Vec<String>),
+
+    #[error("Generated mock data violates field validators:\n  {}", .0.join("\n  "))]
+    MockValidation(Vec<String>),
+
     #[error("Filter error: {0}")]
     Filter(String),
 
@@ -182,12 +203,21 @@ impl From<regex::Error> for EvenframeError {
 
 impl From<Box<dyn std::error::Error>> for EvenframeError {
     fn from(err: Box<dyn std::error::Error>) -> Self {
-        EvenframeError::Unknown(err.to_string())
+        // Keep structured errors that were only boxed to cross a
+        // `Box<dyn Error>` boundary.
+        match err.downcast::<EvenframeError>() {
+            Ok(err) => *err,
+            Err(err) => EvenframeError::Unknown(err.to_string()),
+        }
     }
 }
 
 pub type Result<T> = std::result::Result<T, EvenframeError>;
 
+/// Maximum number of characters of a failing statement kept in
+/// [`EvenframeError::MockInsertion`].
+pub const MOCK_INSERTION_SNIPPET_LEN: usize = 200;
+
 impl EvenframeError {
     pub fn parse_error(file: impl Into<PathBuf>, message: impl Into<String>) -> Self {
         EvenframeError::ParseError {
@@ -200,6 +230,10 @@ impl EvenframeError {
         EvenframeError::Database(Box::new(message.into()))
     }
 
+    pub fn authentication(message: impl Into<String>) -> Self {
+        EvenframeError::Authentication(message.into())
+    }
+
     pub fn config(message: impl Into<String>) -> Self {
         EvenframeError::Config(message.into())
     }
@@ -224,6 +258,29 @@ impl EvenframeError {
         EvenframeError::MockGeneration(message.into())
     }
 
+    /// Wraps a failed mock data batch. `statement` is the rejected
+    /// statement, or the whole batch when it is not known which statement
+    /// failed. Only its first [`MOCK_INSERTION_SNIPPET_LEN`] characters are
+    /// kept; the full batch is still written to `all_statements.surql`.
+    pub fn mock_insertion(
+        table: impl Into<String>,
+        batch: usize,
+        statement: &str,
+        source: impl std::fmt::Display,
+    ) -> Self {
+        let statement = statement.trim();
+        let snippet = match statement.char_indices().nth(MOCK_INSERTION_SNIPPET_LEN) {
+            Some((end, _)) => format!("{}...", &statement[..end]),
+            None => statement.to_string(),
+        };
+        EvenframeError::MockInsertion {
+            table: table.into(),
+            batch,
+            snippet,
+            source: source.to_string().into(),
+        }
+    }
+
     pub fn permission(message: impl Into<String>) -> Self {
         EvenframeError::Permission(message.into())
     }
@@ -565,6 +622,31 @@ mod tests {
         assert!(matches!(err, EvenframeError::Utf8(_)));
     }
 
+    #[test]
+    fn test_mock_insertion_display_and_snippet() {
+        let statements = format!("UPSERT post:1 CONTENT {{ title: '{}' }};", "x".repeat(500));
+        let err = EvenframeError::mock_insertion("post", 3, &statements, "Parse error");
+        let EvenframeError::MockInsertion { snippet, .. } = &err else {
+            panic!("expected MockInsertion, got {err:?}");
+        };
+        assert_eq!(snippet.chars().count(), MOCK_INSERTION_SNIPPET_LEN + 3);
+        assert!(snippet.starts_with("UPSERT post:1"));
+        assert!(snippet.ends_with("..."));
+
+        let display = err.to_string();
+        assert!(display.contains("table `post` (batch 3): Parse error"));
+        assert!(display.contains("statement: UPSERT post:1"));
+        assert!(std::error::Error::source(&err).is_some());
+    }
+
+    #[test]
+    fn test_from_boxed_evenframe_error_keeps_variant() {
+        let boxed_err: Box<dyn std::error::Error> =
+            EvenframeError::mock_insertion("post", 1, "UPSERT post:1;", "boom").into();
+        let err: EvenframeError = boxed_err.into();
+        assert!(matches!(err, EvenframeError::MockInsertion { ref table, .. } if table == "post"));
+    }
+
     #[test]
     fn test_from_boxed_dyn_error() {
         let boxed_err: Box<dyn std::error::Error> = Box::new(io::Error::other("boxed error"));
@@ -1,5 +1,5 @@
 use crate::{
-    schemasync::TableConfig,
+    schemasync::{TableCase, TableConfig},
     types::{Pipeline, StructConfig, TaggedUnion},
 };
 use linkme::distributed_slice;
@@ -168,6 +168,31 @@ pub fn get_all_union_of_tables_names() -> Vec<&'static str> {
     names
 }
 
+/// Get every registered table as `(table_name, config)`, sorted by table
+/// name. Table names are derived from the struct names with `table_naming`,
+/// the project's `schemasync.table_naming`, so they match the schema.
+pub fn all_table_configs(table_naming: TableCase) -> Vec<(String, TableConfig)> {
+    let mut tables: Vec<(String, TableConfig)> = TABLE_REGISTRY_ENTRIES
+        .iter()
+        .map(|entry| {
+            let mut config = (entry.table_config_fn)();
+            config.table_name = table_naming.apply(entry.type_name);
+            (config.table_name.clone(), config)
+        })
+        .collect();
+    tables.sort_by(|a, b| a.0.cmp(&b.0));
+    tables
+}
+
+/// Get the database names of all registered tables, sorted. See
+/// [`all_table_configs`] for how `table_naming` applies.
+pub fn table_names(table_naming: TableCase) -> Vec<String> {
+    all_table_configs(table_naming)
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect()
+}
+
 /// Get all registered type names across all categories
 pub fn get_all_type_names() -> BTreeMap<TypeCategory, Vec<&'static str>> {
     let mut result = BTreeMap::new();
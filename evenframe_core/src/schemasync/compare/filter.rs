@@ -105,7 +105,7 @@ impl Mockmaker<'_> {
                             .or_insert_with(|| table_config.clone());
                     }
                 }
-                PreservationMode::Full | PreservationMode::Smart => {
+                PreservationMode::Full | PreservationMode::Merge | PreservationMode::Smart => {
                     if table_change.is_none() && !has_always_regenerate_fields {
                         continue;
                     }
@@ -128,7 +128,7 @@ impl Mockmaker<'_> {
                                 }
                             }
                         } else {
-                            // Full mode includes all modified fields.
+                            // Full and Merge modes include all modified fields.
                             fields_to_include.extend(
                                 change.modified_fields.iter().map(|f| f.field_name.clone()),
                             );
@@ -210,6 +210,7 @@ impl Mockmaker<'_> {
                         |c| {
                             c.permission_changed
                                 || c.schema_type_changed
+                                || c.changefeed_changed
                                 || !c.removed_fields.is_empty()
                                 || !c.new_events.is_empty()
                                 || !c.removed_events.is_empty()
@@ -298,7 +299,11 @@ fn resolve_path(
     let mut current_type = field_type;
     loop {
         match current_type {
-            FieldType::Option(inner) | FieldType::Vec(inner) | FieldType::RecordLink(inner) => {
+            FieldType::Option(inner)
+            | FieldType::Vec(inner)
+            | FieldType::Set(inner)
+            | FieldType::Array(inner, _)
+            | FieldType::RecordLink(inner) => {
                 current_type = inner;
             }
             // For maps, we assume traversal into the value type.
@@ -311,7 +316,9 @@ fn resolve_path(
 
     // Now, match on the actual, unwrapped type.
     let found_field = match current_type {
-        FieldType::Other(struct_name) => {
+        FieldType::Enum(struct_name)
+        | FieldType::Object(struct_name)
+        | FieldType::Other(struct_name) => {
             let struct_def = objects.get(struct_name)?;
             struct_def
                 .fields
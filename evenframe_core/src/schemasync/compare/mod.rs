@@ -28,13 +28,15 @@ pub use types::{
 use crate::schemasync::config::{PerformanceConfig, SchemasyncMockGenConfig};
 use crate::{
     EvenframeError, Result,
-    schemasync::TableConfig,
+    schemasync::{TableConfig, table::duration_nanos},
     types::{FieldType, TaggedUnion, VariantData},
 };
 #[cfg(feature = "surrealdb")]
 use ::surrealdb::Surreal;
 #[cfg(feature = "surrealdb")]
 use ::surrealdb::engine::remote::http::Client;
+#[cfg(feature = "surrealdb")]
+use rand::rngs::StdRng;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
@@ -161,6 +163,51 @@ pub struct TableChanges {
     pub removed_events: Vec<String>,
     pub new_indexes: Vec<IndexDefinition>,
     pub removed_indexes: Vec<IndexDefinition>,
+    /// The subset of `new_fields` that must be set on every record
+    #[serde(default)]
+    pub new_required_fields: Vec<String>,
+    /// The `CHANGEFEED` was added, removed, or its retention changed
+    #[serde(default)]
+    pub changefeed_changed: bool,
+}
+
+impl TableChanges {
+    /// Combines another set of changes to the same table into this one.
+    fn merge(&mut self, other: TableChanges) {
+        extend_unique(&mut self.new_fields, other.new_fields, |f| f.clone());
+        extend_unique(&mut self.removed_fields, other.removed_fields, |f| {
+            f.clone()
+        });
+        extend_unique(&mut self.modified_fields, other.modified_fields, |f| {
+            f.field_name.clone()
+        });
+        self.permission_changed |= other.permission_changed;
+        self.schema_type_changed |= other.schema_type_changed;
+        self.changefeed_changed |= other.changefeed_changed;
+        extend_unique(&mut self.new_events, other.new_events, |e| e.clone());
+        extend_unique(&mut self.removed_events, other.removed_events, |e| {
+            e.clone()
+        });
+        extend_unique(&mut self.new_indexes, other.new_indexes, |i| i.name.clone());
+        extend_unique(&mut self.removed_indexes, other.removed_indexes, |i| {
+            i.name.clone()
+        });
+        extend_unique(
+            &mut self.new_required_fields,
+            other.new_required_fields,
+            |f| f.clone(),
+        );
+    }
+}
+
+/// Appends the items whose key is not already present in `target`.
+fn extend_unique<T, K: PartialEq>(target: &mut Vec<T>, items: Vec<T>, key: impl Fn(&T) -> K) {
+    for item in items {
+        let item_key = key(&item);
+        if !target.iter().any(|existing| key(existing) == item_key) {
+            target.push(item);
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -216,8 +263,13 @@ impl SchemaChanges {
         true
     }
 
-    /// Get all fields that need new data generation
-    pub fn get_fields_needing_generation(&self, table: &str) -> Vec<String> {
+    /// Get all fields that need new data generation. Modified fields are only
+    /// included when `include_modified` is set.
+    pub fn get_fields_needing_generation(
+        &self,
+        table: &str,
+        include_modified: bool,
+    ) -> Vec<String> {
         let mut fields = Vec::new();
 
         // If table is new, all fields need generation
@@ -231,10 +283,10 @@ impl SchemaChanges {
                 // Add all new fields
                 fields.extend(table_change.new_fields.clone());
 
-                // Optionally add modified fields based on configuration
-                // For now, we'll regenerate modified fields
-                for field_change in &table_change.modified_fields {
-                    fields.push(field_change.field_name.clone());
+                if include_modified {
+                    for field_change in &table_change.modified_fields {
+                        fields.push(field_change.field_name.clone());
+                    }
                 }
             }
         }
@@ -242,6 +294,82 @@ impl SchemaChanges {
         fields
     }
 
+    /// Returns true when no table or access changed.
+    pub fn is_empty(&self) -> bool {
+        self.new_tables.is_empty()
+            && self.removed_tables.is_empty()
+            && self.modified_tables.is_empty()
+            && self.new_accesses.is_empty()
+            && self.removed_accesses.is_empty()
+            && self.modified_accesses.is_empty()
+    }
+
+    /// Combines the changes of another comparison pass into this one.
+    ///
+    /// Table and access lists are unioned. Modified entries for the same
+    /// table or access are merged into a single entry.
+    pub fn merge(&mut self, other: SchemaChanges) {
+        extend_unique(&mut self.new_tables, other.new_tables, |t| t.clone());
+        extend_unique(&mut self.removed_tables, other.removed_tables, |t| {
+            t.clone()
+        });
+        extend_unique(&mut self.new_accesses, other.new_accesses, |a| a.clone());
+        extend_unique(&mut self.removed_accesses, other.removed_accesses, |a| {
+            a.clone()
+        });
+
+        for table in other.modified_tables {
+            match self
+                .modified_tables
+                .iter_mut()
+                .find(|t| t.table_name == table.table_name)
+            {
+                Some(existing) => existing.merge(table),
+                None => self.modified_tables.push(table),
+            }
+        }
+
+        for access in other.modified_accesses {
+            match self
+                .modified_accesses
+                .iter_mut()
+                .find(|a| a.access_name == access.access_name)
+            {
+                Some(existing) => {
+                    extend_unique(&mut existing.changes, access.changes, |c| c.clone())
+                }
+                None => self.modified_accesses.push(access),
+            }
+        }
+    }
+
+    /// Changes that can lose data or reject existing records: removed tables,
+    /// removed fields, and new fields that are required without a default.
+    pub fn breaking_changes(&self) -> Vec<String> {
+        let mut breaking: Vec<String> = self
+            .removed_tables
+            .iter()
+            .map(|table| format!("table `{table}` removed"))
+            .collect();
+
+        for table in &self.modified_tables {
+            breaking.extend(
+                table
+                    .removed_fields
+                    .iter()
+                    .map(|field| format!("field `{}.{field}` removed", table.table_name)),
+            );
+            breaking.extend(
+                table
+                    .new_required_fields
+                    .iter()
+                    .map(|field| format!("required field `{}.{field}` added", table.table_name)),
+            );
+        }
+
+        breaking
+    }
+
     /// Create a summary of changes
     pub fn summary(&self) -> String {
         let mut summary = Vec::new();
@@ -501,6 +629,8 @@ impl Comparator {
             removed_events: Vec::new(),
             new_indexes: Vec::new(),
             removed_indexes: Vec::new(),
+            new_required_fields: Vec::new(),
+            changefeed_changed: false,
         };
 
         // Check schema type change
@@ -513,6 +643,17 @@ impl Comparator {
             table_changes.permission_changed = true;
         }
 
+        // Check changefeed changes, comparing durations rather than their spelling
+        fn changefeed(table: &TableDefinition) -> Option<std::result::Result<u128, &str>> {
+            table
+                .changefeed
+                .as_deref()
+                .map(|duration| duration_nanos(duration).ok_or(duration))
+        }
+        if changefeed(old_table) != changefeed(new_table) {
+            table_changes.changefeed_changed = true;
+        }
+
         // Compare regular fields
         let old_fields: BTreeSet<String> = old_table.fields.keys().cloned().collect();
         let new_fields: BTreeSet<String> = new_table.fields.keys().cloned().collect();
@@ -520,6 +661,9 @@ impl Comparator {
         // Find new fields
         for field in new_fields.difference(&old_fields) {
             table_changes.new_fields.push(field.clone());
+            if new_table.fields[field].required {
+                table_changes.new_required_fields.push(field.clone());
+            }
         }
 
         // Find removed fields
@@ -560,8 +704,8 @@ impl Comparator {
                     old_type: old_field.field_type.to_string(),
                     new_type: new_field.field_type.to_string(),
                     change_type: ChangeType::Modified,
-                    required_changed: false,
-                    default_changed: false,
+                    required_changed: old_field.required != new_field.required,
+                    default_changed: old_field.default_value != new_field.default_value,
                 });
             } else {
                 // Types are the same, check for other changes (required, default)
@@ -657,6 +801,7 @@ impl Comparator {
             && table_changes.modified_fields.is_empty()
             && !table_changes.permission_changed
             && !table_changes.schema_type_changed
+            && !table_changes.changefeed_changed
             && table_changes.new_events.is_empty()
             && table_changes.removed_events.is_empty()
             && table_changes.new_indexes.is_empty()
@@ -915,31 +1060,48 @@ pub fn collect_referenced_objects(
     objects_to_process: &mut Vec<String>,
     enums: &BTreeMap<String, TaggedUnion>,
 ) {
-    match field_type {
-        FieldType::Other(type_name) => {
-            // Check if this is an enum
-            if let Some(enum_def) = enums.get(type_name) {
-                // For enums, we need to collect all variant data types
-                for variant in &enum_def.variants {
-                    if let Some(variant_data) = &variant.data {
-                        match variant_data {
-                            VariantData::InlineStruct(enum_struct) => {
-                                objects_to_process.push(enum_struct.struct_name.clone())
-                            }
-                            VariantData::DataStructureRef(referenced_field_type) => {
-                                if let FieldType::Other(data) = referenced_field_type {
-                                    objects_to_process.push(data.clone());
-                                }
-                            }
+    // For enums, we need to collect all variant data types
+    let mut collect_variant_objects = |enum_def: &TaggedUnion| {
+        for variant in &enum_def.variants {
+            if let Some(variant_data) = &variant.data {
+                match variant_data {
+                    VariantData::InlineStruct(enum_struct) => {
+                        objects_to_process.push(enum_struct.struct_name.clone())
+                    }
+                    VariantData::DataStructureRef(referenced_field_type) => {
+                        if let FieldType::Enum(data)
+                        | FieldType::Object(data)
+                        | FieldType::Other(data) = referenced_field_type
+                        {
+                            objects_to_process.push(data.clone());
                         }
                     }
                 }
+            }
+        }
+    };
+
+    match field_type {
+        FieldType::Enum(type_name) => {
+            if let Some(enum_def) = enums.get(type_name) {
+                collect_variant_objects(enum_def);
+            }
+        }
+        FieldType::Object(type_name) => objects_to_process.push(type_name.clone()),
+        FieldType::Other(type_name) => {
+            // Unresolved: check if this is an enum
+            if let Some(enum_def) = enums.get(type_name) {
+                collect_variant_objects(enum_def);
             } else {
                 // Not an enum, just a regular object/struct
                 objects_to_process.push(type_name.clone());
             }
         }
-        FieldType::Option(inner) | FieldType::Vec(inner) | FieldType::RecordLink(inner) => {
+        FieldType::Option(inner)
+        | FieldType::Vec(inner)
+        | FieldType::Set(inner)
+        | FieldType::Array(inner, _)
+        | FieldType::RecordLink(inner) => {
             collect_referenced_objects(inner, objects_to_process, enums);
         }
         FieldType::Tuple(types) => {
@@ -970,6 +1132,8 @@ pub struct Merger<'a> {
     pub client: &'a Surreal<Client>,
     pub default_mock_gen_config: SchemasyncMockGenConfig,
     pub performance: PerformanceConfig,
+    /// Named structs, used to walk `Object`/`Other` fields when merging
+    pub objects: Option<&'a BTreeMap<String, crate::types::StructConfig>>,
 }
 
 #[cfg(feature = "surrealdb")]
@@ -984,9 +1148,19 @@ impl<'a> Merger<'a> {
             client,
             default_mock_gen_config,
             performance,
+            objects: None,
         })
     }
 
+    /// Sets the named structs that `Object`/`Other` fields refer to
+    pub fn with_objects(
+        mut self,
+        objects: &'a BTreeMap<String, crate::types::StructConfig>,
+    ) -> Self {
+        self.objects = Some(objects);
+        self
+    }
+
     /// Import schema from production database
     pub async fn import_schema_from_db(&self) -> Result<SchemaDefinition> {
         tracing::debug!("Importing schema from production database");
@@ -1028,13 +1202,6 @@ impl<'a> Merger<'a> {
         Comparator::compare(old, new)
     }
 
-    /// Export mock data to file
-    pub async fn export_mock_data(&self, _file_path: &str) -> Result<()> {
-        // Implementation will use the generated statements
-        // and write them to the specified file
-        todo!("Implement export_mock_data")
-    }
-
     /// Generate preserved data for a specific table
     pub async fn generate_preserved_data(
         &self,
@@ -1044,17 +1211,33 @@ impl<'a> Merger<'a> {
         existing_records: Vec<serde_json::Value>,
         target_count: usize,
         schema_changes: Option<&SchemaChanges>,
-    ) -> Vec<serde_json::Value> {
+    ) -> Result<Vec<serde_json::Value>> {
         use serde_json::Value;
 
+        let seed = mock_config.seed.or(self.default_mock_gen_config.seed);
+        let mut rng = crate::schemasync::mockmake::seeded_rng(seed, table_name);
+        let reference_time = crate::schemasync::mockmake::seeded_reference_time(seed);
+
         // Determine how many records to preserve vs generate
         let existing_count = existing_records.len();
+        let existing_ids: BTreeSet<String> = existing_records
+            .iter()
+            .filter_map(|record| record.get("id"))
+            .map(|id| id.as_str().map_or_else(|| id.to_string(), str::to_string))
+            .collect();
         let mut result = Vec::new();
 
         match mock_config.preservation_mode {
             PreservationMode::None => {
                 // No preservation - generate all new data
-                result = self.generate_new_records(table_name, table_config, target_count);
+                result = self.generate_new_records(
+                    table_name,
+                    table_config,
+                    target_count,
+                    &BTreeSet::new(),
+                    &mut rng,
+                    reference_time,
+                );
             }
             PreservationMode::Smart => {
                 // Smart preservation - keep unchanged fields, regenerate specified fields
@@ -1065,15 +1248,24 @@ impl<'a> Merger<'a> {
                     // If schema changes are provided, add fields that changed
                     if let Some(changes) = schema_changes {
                         // Get fields that need regeneration based on schema changes
-                        let schema_fields_needing_generation =
-                            changes.get_fields_needing_generation(table_name);
+                        let schema_fields_needing_generation = changes
+                            .get_fields_needing_generation(
+                                table_name,
+                                self.default_mock_gen_config.regenerate_modified_fields,
+                            );
 
                         // If all fields need generation (new table), regenerate everything
                         if schema_fields_needing_generation.contains(&"*".to_string()) {
                             // Generate all new records for new tables
-                            result =
-                                self.generate_new_records(table_name, table_config, target_count);
-                            return result;
+                            result = self.generate_new_records(
+                                table_name,
+                                table_config,
+                                target_count,
+                                &BTreeSet::new(),
+                                &mut rng,
+                                reference_time,
+                            );
+                            return Ok(result);
                         }
 
                         // Add schema-detected fields to the regeneration list
@@ -1084,29 +1276,48 @@ impl<'a> Merger<'a> {
                         }
                     }
 
+                    // `always_regenerate` fields are refreshed on every run
+                    for field in &table_config.struct_config.fields {
+                        if field.always_regenerate
+                            && !fields_to_regenerate.contains(&field.field_name)
+                        {
+                            fields_to_regenerate.push(field.field_name.clone());
+                        }
+                    }
+
                     for mut record in existing_records {
                         // Regenerate specified fields
                         if let Value::Object(ref mut map) = record {
                             // First, add any new fields that don't exist in the record
-                            for field in &table_config.struct_config.fields {
+                            for field in mockable_fields(table_config) {
                                 if !map.contains_key(&field.field_name) {
                                     // This is a new field, generate value
-                                    let new_value = Self::generate_field_value(field, table_config);
+                                    let new_value = Self::generate_field_value(
+                                        field,
+                                        table_config,
+                                        self.default_mock_gen_config.max_recursion_depth,
+                                        self.default_mock_gen_config.option_null_probability,
+                                        &mut rng,
+                                        reference_time,
+                                    );
                                     map.insert(field.field_name.clone(), new_value);
                                 }
                             }
 
-                            // Then, regenerate fields that need it
-                            for field_name in &fields_to_regenerate {
-                                if let Some(field) = table_config
-                                    .struct_config
-                                    .fields
-                                    .iter()
-                                    .find(|f| &f.field_name == field_name)
-                                {
-                                    // Generate new value for this field
-                                    let new_value = Self::generate_field_value(field, table_config);
-                                    map.insert(field_name.clone(), new_value);
+                            // Then, regenerate fields matching any name or glob in the list
+                            for field in mockable_fields(table_config) {
+                                if fields_to_regenerate.iter().any(|pattern| {
+                                    field_matches_pattern(pattern, &field.field_name)
+                                }) {
+                                    let new_value = Self::generate_field_value(
+                                        field,
+                                        table_config,
+                                        self.default_mock_gen_config.max_recursion_depth,
+                                        self.default_mock_gen_config.option_null_probability,
+                                        &mut rng,
+                                        reference_time,
+                                    );
+                                    map.insert(field.field_name.clone(), new_value);
                                 }
                             }
                         }
@@ -1120,58 +1331,64 @@ impl<'a> Merger<'a> {
                             table_name,
                             table_config,
                             target_count - existing_count,
+                            &existing_ids,
+                            &mut rng,
+                            reference_time,
                         );
                         result.extend(additional);
                     }
                 } else {
                     // No existing data or preservation disabled
-                    result = self.generate_new_records(table_name, table_config, target_count);
+                    result = self.generate_new_records(
+                        table_name,
+                        table_config,
+                        target_count,
+                        &existing_ids,
+                        &mut rng,
+                        reference_time,
+                    );
                 }
             }
-            PreservationMode::Full => {
-                // Full preservation - keep all data, only add new fields
+            PreservationMode::Full | PreservationMode::Merge => {
+                // Full preservation - keep all data, only add new fields.
+                // Merge also fills in fields added to nested objects.
+                let deep = mock_config.preservation_mode == PreservationMode::Merge;
                 if existing_count > 0 {
                     // Check if target count is less than existing count
                     if target_count < existing_count {
-                        eprintln!(
-                            "\n  WARNING: Full preservation mode with data reduction detected!"
-                        );
-                        eprintln!(
-                            "   Table '{}' has {} existing records but target count is set to {}",
-                            table_name, existing_count, target_count
-                        );
-                        eprintln!(
-                            "   This will DELETE {} records!",
-                            existing_count - target_count
-                        );
-                        eprintln!("\n   Options:");
-                        eprintln!(
-                            "   1. Change the target count (n) to {} or higher to preserve all records",
-                            existing_count
-                        );
-                        eprintln!("   2. Use Smart preservation mode instead of Full");
-                        eprintln!(
-                            "   3. Set preservation_mode to None if you want to regenerate all data"
-                        );
-                        eprintln!(
-                            "\n   In a production environment, this would require user confirmation."
-                        );
-                        eprintln!(
-                            "   For now, proceeding with target count of {} records.\n",
-                            target_count
+                        let records_to_delete = existing_count - target_count;
+                        if !self.default_mock_gen_config.confirm_destructive {
+                            return Err(EvenframeError::mock_generation(format!(
+                                "{:?} preservation mode would delete {} records from table '{}' \
+                                 ({} existing, target count {}). Raise the target count (n) to {} \
+                                 or higher, switch to Smart or None preservation, or set \
+                                 `confirm_destructive = true` to allow the reduction",
+                                mock_config.preservation_mode,
+                                records_to_delete,
+                                table_name,
+                                existing_count,
+                                target_count,
+                                existing_count
+                            )));
+                        }
+
+                        tracing::warn!(
+                            table = %table_name,
+                            existing_count,
+                            target_count,
+                            records_to_delete,
+                            "Full preservation data reduction confirmed; proceeding"
                         );
 
-                        for mut record in existing_records {
-                            // Only add new fields that don't exist
+                        for mut record in existing_records.into_iter().take(target_count) {
                             if let Value::Object(ref mut map) = record {
-                                for field in &table_config.struct_config.fields {
-                                    if !map.contains_key(&field.field_name) {
-                                        // This is a new field, generate value
-                                        let new_value =
-                                            Self::generate_field_value(field, table_config);
-                                        map.insert(field.field_name.clone(), new_value);
-                                    }
-                                }
+                                self.fill_missing_fields(
+                                    map,
+                                    table_config,
+                                    deep,
+                                    &mut rng,
+                                    reference_time,
+                                );
                             }
 
                             result.push(record);
@@ -1179,16 +1396,14 @@ impl<'a> Merger<'a> {
                     } else {
                         // Normal case: preserve all existing records
                         for mut record in existing_records {
-                            // Only add new fields that don't exist
                             if let Value::Object(ref mut map) = record {
-                                for field in &table_config.struct_config.fields {
-                                    if !map.contains_key(&field.field_name) {
-                                        // This is a new field, generate value
-                                        let new_value =
-                                            Self::generate_field_value(field, table_config);
-                                        map.insert(field.field_name.clone(), new_value);
-                                    }
-                                }
+                                self.fill_missing_fields(
+                                    map,
+                                    table_config,
+                                    deep,
+                                    &mut rng,
+                                    reference_time,
+                                );
                             }
 
                             result.push(record);
@@ -1200,38 +1415,192 @@ impl<'a> Merger<'a> {
                                 table_name,
                                 table_config,
                                 target_count - existing_count,
+                                &existing_ids,
+                                &mut rng,
+                                reference_time,
                             );
                             result.extend(additional);
                         }
                     }
                 } else {
-                    result = self.generate_new_records(table_name, table_config, target_count);
+                    result = self.generate_new_records(
+                        table_name,
+                        table_config,
+                        target_count,
+                        &existing_ids,
+                        &mut rng,
+                        reference_time,
+                    );
                 }
             }
         }
 
-        result
+        Ok(result)
     }
 
-    /// Generate new records for a table
+    /// Adds values for fields missing from an existing record and refreshes
+    /// `always_regenerate` ones. With `deep`, existing nested objects are
+    /// walked as well, so fields added to inline structs get values while
+    /// the data already there is kept.
+    fn fill_missing_fields(
+        &self,
+        record: &mut serde_json::Map<String, serde_json::Value>,
+        table_config: &TableConfig,
+        deep: bool,
+        rng: &mut StdRng,
+        reference_time: chrono::DateTime<chrono::Utc>,
+    ) {
+        for field in mockable_fields(table_config) {
+            match record.get_mut(&field.field_name) {
+                Some(existing) if !field.always_regenerate => {
+                    if deep {
+                        self.merge_nested_fields(
+                            existing,
+                            &field.field_type,
+                            table_config,
+                            rng,
+                            reference_time,
+                        );
+                    }
+                }
+                _ => {
+                    let new_value = Self::generate_field_value(
+                        field,
+                        table_config,
+                        self.default_mock_gen_config.max_recursion_depth,
+                        self.default_mock_gen_config.option_null_probability,
+                        rng,
+                        reference_time,
+                    );
+                    record.insert(field.field_name.clone(), new_value);
+                }
+            }
+        }
+    }
+
+    /// Generates the keys of a nested struct that are missing from
+    /// `existing`, recursing into nested structs that are already present.
+    /// Named structs are looked up in `objects`.
+    fn merge_nested_fields(
+        &self,
+        existing: &mut serde_json::Value,
+        field_type: &crate::types::FieldType,
+        table_config: &TableConfig,
+        rng: &mut StdRng,
+        reference_time: chrono::DateTime<chrono::Utc>,
+    ) {
+        use crate::types::FieldType;
+
+        match field_type {
+            FieldType::Option(inner) => {
+                self.merge_nested_fields(existing, inner, table_config, rng, reference_time)
+            }
+            FieldType::Struct(fields) => {
+                let fields: Vec<_> = fields
+                    .iter()
+                    .map(|(name, inner_type)| crate::types::StructField {
+                        field_name: name.clone(),
+                        field_type: inner_type.clone(),
+                        ..Default::default()
+                    })
+                    .collect();
+                self.merge_struct_fields(existing, &fields, table_config, rng, reference_time)
+            }
+            FieldType::Object(name) | FieldType::Other(name) => {
+                if let Some(object) = self.objects.and_then(|objects| objects.get(name)) {
+                    self.merge_struct_fields(
+                        existing,
+                        &object.effective().fields,
+                        table_config,
+                        rng,
+                        reference_time,
+                    )
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn merge_struct_fields(
+        &self,
+        existing: &mut serde_json::Value,
+        fields: &[crate::types::StructField],
+        table_config: &TableConfig,
+        rng: &mut StdRng,
+        reference_time: chrono::DateTime<chrono::Utc>,
+    ) {
+        let serde_json::Value::Object(map) = existing else {
+            return;
+        };
+        for field in fields {
+            match map.get_mut(&field.field_name) {
+                Some(value) => self.merge_nested_fields(
+                    value,
+                    &field.field_type,
+                    table_config,
+                    rng,
+                    reference_time,
+                ),
+                None => {
+                    let new_value = Self::generate_field_value(
+                        field,
+                        table_config,
+                        self.default_mock_gen_config.max_recursion_depth,
+                        self.default_mock_gen_config.option_null_probability,
+                        rng,
+                        reference_time,
+                    );
+                    map.insert(field.field_name.clone(), new_value);
+                }
+            }
+        }
+    }
+
+    /// Generate new records for a table. Ids come from the configured
+    /// `id_strategy` through [`allocate_record_ids`], as in a full refresh,
+    /// so sequential ids fill gaps left by deleted records and never collide
+    /// with the existing ones.
+    ///
+    /// [`allocate_record_ids`]: crate::schemasync::mockmake::allocate_record_ids
     fn generate_new_records(
         &self,
-        _table_name: &str,
+        table_name: &str,
         table_config: &TableConfig,
         count: usize,
+        existing_ids: &BTreeSet<String>,
+        rng: &mut StdRng,
+        reference_time: chrono::DateTime<chrono::Utc>,
     ) -> Vec<serde_json::Value> {
         use serde_json::Value;
 
-        let mut records = Vec::new();
+        let mut ids: Vec<String> = existing_ids.iter().cloned().collect();
+        crate::schemasync::mockmake::allocate_record_ids(
+            &mut ids,
+            existing_ids.len() + count,
+            self.default_mock_gen_config.id_strategy,
+            table_name,
+            rng,
+            reference_time,
+        );
+        let new_ids = ids.split_off(existing_ids.len());
 
-        for _ in 0..count {
+        let mut records = Vec::new();
+        for id in new_ids {
             let mut record = serde_json::Map::new();
 
             // Generate values for each field
-            for field in &table_config.struct_config.fields {
-                let value = Self::generate_field_value(field, table_config);
+            for field in mockable_fields(table_config) {
+                let value = Self::generate_field_value(
+                    field,
+                    table_config,
+                    self.default_mock_gen_config.max_recursion_depth,
+                    self.default_mock_gen_config.option_null_probability,
+                    rng,
+                    reference_time,
+                );
                 record.insert(field.field_name.clone(), value);
             }
+            record.insert("id".to_string(), Value::String(id));
 
             records.push(Value::Object(record));
         }
@@ -1239,24 +1608,31 @@ impl<'a> Merger<'a> {
         records
     }
 
-    /// Generate a value for a specific field
+    /// Generate a value for a specific field; `Option`s are `null` with
+    /// `null_probability`, and always once nested deeper than `remaining_depth`
     fn generate_field_value(
         field: &crate::types::StructField,
         _table_config: &TableConfig,
+        remaining_depth: usize,
+        null_probability: f64,
+        rng: &mut StdRng,
+        reference_time: chrono::DateTime<chrono::Utc>,
     ) -> serde_json::Value {
         use crate::types::FieldType;
+        use rand::RngExt;
         use serde_json::json;
 
         // Use format if available
         if let Some(format) = &field.format {
-            let value = format.generate_formatted_value();
+            let value = format.generate_formatted_value_at(rng, reference_time);
 
             // Check if the format generates numeric values
             match format {
                 crate::schemasync::mockmake::format::Format::Percentage
                 | crate::schemasync::mockmake::format::Format::Latitude
                 | crate::schemasync::mockmake::format::Format::Longitude
-                | crate::schemasync::mockmake::format::Format::CurrencyAmount => {
+                | crate::schemasync::mockmake::format::Format::CurrencyAmount
+                | crate::schemasync::mockmake::format::Format::Currency(_) => {
                     // Try to parse as number
                     if let Ok(num) = value.parse::<f64>() {
                         return json!(num);
@@ -1268,41 +1644,85 @@ impl<'a> Merger<'a> {
             return json!(value);
         }
 
+        // Validators are mirrored into ASSERT clauses, so values must honour them
+        if let Some(value) =
+            crate::schemasync::mockmake::validator_gen::generate_json_with_validators(
+                &field.field_type,
+                &field.validators,
+                rng,
+            )
+        {
+            return value;
+        }
+
         // Generate based on field type
         match &field.field_type {
-            FieldType::String => json!(crate::schemasync::Mockmaker::random_string(8)),
-            FieldType::Bool => json!(rand::random::<bool>()),
+            FieldType::String => json!(crate::schemasync::Mockmaker::random_string(8, rng)),
+            FieldType::Bool => json!(rng.random::<bool>()),
             FieldType::U8
             | FieldType::U16
             | FieldType::U32
             | FieldType::U64
             | FieldType::U128
-            | FieldType::Usize => json!(rand::random::<u32>() % 100),
+            | FieldType::Usize => json!(rng.random::<u32>() % 100),
             FieldType::I8
             | FieldType::I16
             | FieldType::I32
             | FieldType::I64
             | FieldType::I128
-            | FieldType::Isize => json!(rand::random::<i32>() % 100),
-            FieldType::F32 | FieldType::F64 => json!(rand::random::<f64>() * 100.0),
+            | FieldType::Isize => json!(rng.random::<i32>() % 100),
+            FieldType::F32 | FieldType::F64 => json!(rng.random::<f64>() * 100.0),
+            FieldType::Literal(value) => value.clone(),
             FieldType::Option(inner) => {
-                if rand::random::<bool>() {
+                if remaining_depth > 0 && !rng.random_bool(null_probability) {
                     let inner_field = crate::types::StructField {
                         field_name: field.field_name.clone(),
                         field_type: *inner.clone(),
                         format: field.format.clone(),
+                        validators: field.validators.clone(),
                         ..Default::default()
                     };
-                    Self::generate_field_value(&inner_field, _table_config)
+                    Self::generate_field_value(
+                        &inner_field,
+                        _table_config,
+                        remaining_depth - 1,
+                        null_probability,
+                        rng,
+                        reference_time,
+                    )
                 } else {
                     json!(null)
                 }
             }
-            FieldType::Vec(_) => json!([]),
-            FieldType::Other(type_name) => {
+            FieldType::Vec(_) | FieldType::Set(_) | FieldType::Array(_, _) => json!([]),
+            FieldType::Struct(fields) if remaining_depth > 0 => {
+                let object: serde_json::Map<String, serde_json::Value> = fields
+                    .iter()
+                    .map(|(name, field_type)| {
+                        let inner_field = crate::types::StructField {
+                            field_name: name.clone(),
+                            field_type: field_type.clone(),
+                            ..Default::default()
+                        };
+                        let value = Self::generate_field_value(
+                            &inner_field,
+                            _table_config,
+                            remaining_depth - 1,
+                            null_probability,
+                            rng,
+                            reference_time,
+                        );
+                        (name.clone(), value)
+                    })
+                    .collect();
+                serde_json::Value::Object(object)
+            }
+            FieldType::Enum(type_name)
+            | FieldType::Object(type_name)
+            | FieldType::Other(type_name) => {
                 // Handle common types
                 if type_name.contains("DateTime") {
-                    json!(chrono::Utc::now().to_rfc3339())
+                    json!(reference_time.to_rfc3339())
                 } else {
                     json!(format!("{}:1", type_name.to_lowercase()))
                 }
@@ -1312,6 +1732,31 @@ impl<'a> Merger<'a> {
     }
 }
 
+/// Fields mock data may write to, leaving out those SurrealDB derives itself.
+fn mockable_fields(table_config: &TableConfig) -> impl Iterator<Item = &crate::types::StructField> {
+    table_config.struct_config.fields.iter().filter(|field| {
+        !field
+            .define_config
+            .as_ref()
+            .is_some_and(|define_config| define_config.is_database_derived())
+    })
+}
+
+/// Match a `regenerate_fields` entry against a field name: `*` matches every
+/// field, `prefix_*` / `*_suffix` match by prefix or suffix, and anything else
+/// must match exactly.
+fn field_matches_pattern(pattern: &str, field_name: &str) -> bool {
+    if pattern == "*" {
+        true
+    } else if let Some(prefix) = pattern.strip_suffix('*') {
+        field_name.starts_with(prefix)
+    } else if let Some(suffix) = pattern.strip_prefix('*') {
+        field_name.ends_with(suffix)
+    } else {
+        pattern == field_name
+    }
+}
+
 #[cfg(test)]
 mod index_diff_tests {
     use super::*;
@@ -1326,6 +1771,7 @@ mod index_diff_tests {
             permissions: None,
             indexes,
             events: Vec::new(),
+            changefeed: None,
         }
     }
 
@@ -1395,6 +1841,43 @@ mod index_diff_tests {
         assert!(tc.removed_indexes.is_empty());
     }
 
+    fn user_config(indexed: bool) -> BTreeMap<String, TableConfig> {
+        let config = TableConfig {
+            table_name: "user".to_string(),
+            struct_config: crate::types::StructConfig {
+                struct_name: "User".to_string(),
+                fields: vec![crate::types::StructField {
+                    field_name: "created_at".to_string(),
+                    field_type: crate::types::FieldType::String,
+                    indexed,
+                    ..crate::types::StructField::default()
+                }],
+                ..crate::types::StructConfig::default()
+            },
+            ..Default::default()
+        };
+        BTreeMap::from([("user".to_string(), config)])
+    }
+
+    #[test]
+    fn compare_flags_field_level_index_changes() {
+        let plain = SchemaDefinition::from_table_configs(&user_config(false)).expect("schema");
+        let indexed = SchemaDefinition::from_table_configs(&user_config(true)).expect("schema");
+
+        let added = Comparator::compare(&plain, &indexed).expect("compare");
+        let tc = &added.modified_tables[0];
+        assert!(tc.new_fields.is_empty() && tc.modified_fields.is_empty());
+        assert_eq!(
+            tc.new_indexes,
+            vec![idx("idx_user_created_at", &["created_at"], false)]
+        );
+
+        let removed = Comparator::compare(&indexed, &plain).expect("compare");
+        let tc = &removed.modified_tables[0];
+        assert!(tc.new_indexes.is_empty());
+        assert_eq!(tc.removed_indexes[0].name, "idx_user_created_at");
+    }
+
     #[test]
     fn compare_returns_no_change_when_indexes_identical() {
         let indexes = vec![idx("idx_reaction_user_message", &["user", "message"], true)];
@@ -1404,4 +1887,925 @@ mod index_diff_tests {
         let changes = Comparator::compare(&old, &new).expect("compare");
         assert!(changes.modified_tables.is_empty());
     }
+
+    #[test]
+    fn scoped_comparison_keeps_unselected_tables() {
+        let mut old = schema_with("post", table_with_indexes("post", vec![]));
+        old.tables
+            .insert("legacy".to_string(), table_with_indexes("legacy", vec![]));
+        let mut new = schema_with("post", table_with_indexes("post", vec![]));
+
+        let unscoped = Comparator::compare(&old, &new).expect("compare");
+        assert_eq!(unscoped.removed_tables, ["legacy"]);
+
+        let scope = BTreeSet::from(["post".to_string()]);
+        old.retain_tables(&scope);
+        new.retain_tables(&scope);
+        let scoped = Comparator::compare(&old, &new).expect("compare");
+        assert!(scoped.removed_tables.is_empty());
+        assert!(
+            !crate::schemasync::database::surql::remove::generate_remove_table_statements(
+                &scoped,
+                crate::schemasync::TableCase::Snake,
+            )
+            .contains("legacy")
+        );
+    }
+}
+
+#[cfg(test)]
+mod changefeed_diff_tests {
+    use super::*;
+    use crate::schemasync::compare::types::SchemaType;
+
+    fn schema(changefeed: Option<&str>) -> SchemaDefinition {
+        let table = TableDefinition {
+            name: "audit".to_string(),
+            schema_type: SchemaType::Schemafull,
+            fields: BTreeMap::new(),
+            array_wildcard_fields: BTreeMap::new(),
+            permissions: None,
+            indexes: Vec::new(),
+            events: Vec::new(),
+            changefeed: changefeed.map(str::to_string),
+        };
+        SchemaDefinition {
+            tables: BTreeMap::from([("audit".to_string(), table)]),
+            edges: BTreeMap::new(),
+            accesses: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn compare_detects_changefeed_changes() {
+        let changefeed_changed = |old: Option<&str>, new: Option<&str>| {
+            let changes = Comparator::compare(&schema(old), &schema(new)).expect("compare");
+            changes
+                .modified_tables
+                .iter()
+                .any(|table| table.table_name == "audit" && table.changefeed_changed)
+        };
+
+        assert!(changefeed_changed(Some("3d"), Some("7d")));
+        assert!(changefeed_changed(None, Some("3d")));
+        assert!(changefeed_changed(Some("3d"), None));
+        assert!(!changefeed_changed(Some("3d"), Some("3d")));
+        // SurrealDB may print the same retention in another unit
+        assert!(!changefeed_changed(Some("72h"), Some("3d")));
+    }
+}
+
+#[cfg(test)]
+mod field_diff_tests {
+    use super::*;
+    use crate::types::{FieldType, StructConfig, StructField};
+
+    fn person_schema(age_type: FieldType) -> SchemaDefinition {
+        let config = TableConfig {
+            table_name: "person".to_string(),
+            struct_config: StructConfig {
+                struct_name: "Person".to_string(),
+                fields: vec![StructField {
+                    field_name: "age".to_string(),
+                    field_type: age_type,
+                    ..StructField::default()
+                }],
+                ..StructConfig::default()
+            },
+            ..Default::default()
+        };
+        SchemaDefinition::from_table_configs(&BTreeMap::from([("person".to_string(), config)]))
+            .expect("schema")
+    }
+
+    #[test]
+    fn option_fields_are_not_required() {
+        let required = person_schema(FieldType::I32);
+        let optional = person_schema(FieldType::Option(Box::new(FieldType::I32)));
+
+        assert!(required.tables["person"].fields["age"].required);
+        assert!(!optional.tables["person"].fields["age"].required);
+    }
+
+    #[test]
+    fn making_a_field_optional_changes_required() {
+        let old = person_schema(FieldType::I32);
+        let new = person_schema(FieldType::Option(Box::new(FieldType::I32)));
+
+        let changes = Comparator::compare(&old, &new).expect("compare");
+        let field = &changes.modified_tables[0].modified_fields[0];
+        assert_eq!(field.field_name, "age");
+        assert!(field.required_changed);
+
+        let changes = Comparator::compare(&new, &old).expect("compare");
+        assert!(changes.modified_tables[0].modified_fields[0].required_changed);
+    }
+
+    #[test]
+    fn new_fields_record_whether_they_are_required() {
+        let mut new = person_schema(FieldType::I32);
+        let table = new.tables.get_mut("person").unwrap();
+        for (name, required) in [("email", true), ("nickname", false)] {
+            let mut field = table.fields["age"].clone();
+            field.name = name.to_string();
+            field.required = required;
+            table.fields.insert(name.to_string(), field);
+        }
+
+        let changes = Comparator::compare(&person_schema(FieldType::I32), &new).expect("compare");
+        let table = &changes.modified_tables[0];
+        assert_eq!(table.new_fields, vec!["email", "nickname"]);
+        assert_eq!(table.new_required_fields, vec!["email"]);
+    }
+
+    #[test]
+    fn breaking_changes_lists_removals_and_required_additions() {
+        let changes = SchemaChanges {
+            new_tables: vec!["tag".to_string()],
+            removed_tables: vec!["legacy".to_string()],
+            modified_tables: vec![TableChanges {
+                table_name: "person".to_string(),
+                new_fields: vec!["email".to_string(), "nickname".to_string()],
+                removed_fields: vec!["age".to_string()],
+                modified_fields: vec![],
+                permission_changed: true,
+                schema_type_changed: false,
+                new_events: vec![],
+                removed_events: vec![],
+                new_indexes: vec![],
+                removed_indexes: vec![],
+                new_required_fields: vec!["email".to_string()],
+                changefeed_changed: false,
+            }],
+            new_accesses: vec![],
+            removed_accesses: vec![],
+            modified_accesses: vec![],
+        };
+
+        assert_eq!(
+            changes.breaking_changes(),
+            vec![
+                "table `legacy` removed",
+                "field `person.age` removed",
+                "required field `person.email` added",
+            ]
+        );
+    }
+
+    fn person_changes(new_fields: &[&str], permission_changed: bool) -> TableChanges {
+        TableChanges {
+            table_name: "person".to_string(),
+            new_fields: new_fields.iter().map(|f| f.to_string()).collect(),
+            removed_fields: vec![],
+            modified_fields: vec![],
+            permission_changed,
+            schema_type_changed: false,
+            new_events: vec![],
+            removed_events: vec![],
+            new_indexes: vec![],
+            removed_indexes: vec![],
+            new_required_fields: vec![],
+            changefeed_changed: false,
+        }
+    }
+
+    #[test]
+    fn merge_combines_partial_diffs() {
+        let mut tables = SchemaChanges {
+            new_tables: vec!["tag".to_string()],
+            removed_tables: vec![],
+            modified_tables: vec![person_changes(&["email"], false)],
+            new_accesses: vec![],
+            removed_accesses: vec![],
+            modified_accesses: vec![],
+        };
+        let accesses = SchemaChanges {
+            new_tables: vec!["tag".to_string(), "note".to_string()],
+            removed_tables: vec!["legacy".to_string()],
+            modified_tables: vec![person_changes(&["email", "nickname"], true)],
+            new_accesses: vec!["user".to_string()],
+            removed_accesses: vec![],
+            modified_accesses: vec![AccessChange {
+                access_name: "admin".to_string(),
+                changes: vec![AccessChangeType::SigninChanged],
+            }],
+        };
+
+        tables.merge(accesses);
+
+        assert_eq!(tables.new_tables, ["tag", "note"]);
+        assert_eq!(tables.removed_tables, ["legacy"]);
+        assert_eq!(tables.new_accesses, ["user"]);
+        assert_eq!(tables.modified_accesses.len(), 1);
+        assert_eq!(tables.modified_tables.len(), 1);
+        let person = &tables.modified_tables[0];
+        assert_eq!(person.new_fields, ["email", "nickname"]);
+        assert!(person.permission_changed);
+    }
+
+    #[test]
+    fn is_empty_reflects_merged_changes() {
+        let empty = || SchemaChanges {
+            new_tables: vec![],
+            removed_tables: vec![],
+            modified_tables: vec![],
+            new_accesses: vec![],
+            removed_accesses: vec![],
+            modified_accesses: vec![],
+        };
+        let mut changes = empty();
+        assert!(changes.is_empty());
+
+        changes.merge(empty());
+        assert!(changes.is_empty());
+
+        let mut removed = empty();
+        removed.removed_accesses.push("legacy".to_string());
+        changes.merge(removed);
+        assert!(!changes.is_empty());
+    }
+
+    #[test]
+    fn additive_changes_are_not_breaking() {
+        let old = person_schema(FieldType::I32);
+        let new = person_schema(FieldType::Option(Box::new(FieldType::I32)));
+        let changes = Comparator::compare(&old, &new).expect("compare");
+
+        assert!(!changes.modified_tables.is_empty());
+        assert!(changes.breaking_changes().is_empty());
+    }
+
+    // `schemasync diff --format json` prints this structure; deploy pipelines
+    // key off these names.
+    #[test]
+    fn schema_changes_serialize_to_json() {
+        let old = person_schema(FieldType::I32);
+        let new = person_schema(FieldType::Option(Box::new(FieldType::I32)));
+        let changes = Comparator::compare(&old, &new).expect("compare");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string_pretty(&changes).unwrap()).unwrap();
+
+        assert_eq!(json["new_tables"], serde_json::json!([]));
+        let table = &json["modified_tables"][0];
+        assert_eq!(table["table_name"], "person");
+        assert_eq!(table["modified_fields"][0]["field_name"], "age");
+        assert_eq!(table["modified_fields"][0]["required_changed"], true);
+    }
+}
+
+#[cfg(all(test, feature = "surrealdb"))]
+mod preserved_data_tests {
+    use super::*;
+    use crate::types::{StructConfig, StructField};
+    use serde_json::json;
+
+    fn merger_config(confirm_destructive: bool) -> SchemasyncMockGenConfig {
+        SchemasyncMockGenConfig {
+            default_record_count: 2,
+            default_preservation_mode: PreservationMode::Full,
+            default_batch_size: 100,
+            coordination_groups: vec![],
+            full_refresh_mode: false,
+            confirm_destructive,
+            max_recursion_depth: crate::schemasync::config::default_max_recursion_depth(),
+            id_strategy: crate::schemasync::config::IdStrategy::default(),
+            regenerate_modified_fields: false,
+            option_null_probability: crate::schemasync::config::default_option_null_probability(),
+            validate_generated_data: true,
+            recreate_unpreserved_tables: false,
+            seed: None,
+        }
+    }
+
+    fn full_mock_config() -> MockGenerationConfig {
+        MockGenerationConfig {
+            n: 2,
+            table_level_override: None,
+            coordination_rules: vec![],
+            batch_size: 100,
+            regenerate_fields: vec![],
+            preservation_mode: PreservationMode::Full,
+            plugin: None,
+            seed: None,
+        }
+    }
+
+    fn post_table() -> TableConfig {
+        TableConfig {
+            table_name: "post".to_string(),
+            struct_config: StructConfig {
+                struct_name: "Post".to_string(),
+                fields: vec![StructField {
+                    field_name: "title".to_string(),
+                    field_type: FieldType::String,
+                    ..StructField::default()
+                }],
+                ..StructConfig::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn existing_posts() -> Vec<serde_json::Value> {
+        (1..=5)
+            .map(|i| json!({ "title": format!("post {i}") }))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn full_preservation_refuses_reduction_by_default() {
+        let client = Surreal::<Client>::init();
+        let merger = Merger::new(&client, merger_config(false), PerformanceConfig::default())
+            .await
+            .unwrap();
+
+        let err = merger
+            .generate_preserved_data(
+                "post",
+                &post_table(),
+                full_mock_config(),
+                existing_posts(),
+                2,
+                None,
+            )
+            .await
+            .expect_err("reduction should be refused without confirmation");
+
+        assert!(matches!(err, EvenframeError::MockGeneration(_)));
+        assert!(
+            err.to_string().contains("delete 3 records"),
+            "error should report how many records would be deleted: {err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn full_preservation_reduces_when_confirmed() {
+        let client = Surreal::<Client>::init();
+        let merger = Merger::new(&client, merger_config(true), PerformanceConfig::default())
+            .await
+            .unwrap();
+
+        let records = merger
+            .generate_preserved_data(
+                "post",
+                &post_table(),
+                full_mock_config(),
+                existing_posts(),
+                2,
+                None,
+            )
+            .await
+            .expect("confirmed reduction should succeed");
+
+        assert_eq!(records, existing_posts()[..2].to_vec());
+    }
+
+    fn audited_post_table() -> TableConfig {
+        let mut table = post_table();
+        for name in ["body", "audit_by", "audit_at", "slug"] {
+            table.struct_config.fields.push(StructField {
+                field_name: name.to_string(),
+                field_type: FieldType::String,
+                ..StructField::default()
+            });
+        }
+        table
+    }
+
+    fn existing_audited_post() -> serde_json::Value {
+        json!({
+            "title": "kept",
+            "body": "kept",
+            "audit_by": "stale",
+            "audit_at": "stale",
+            "slug": "stale",
+        })
+    }
+
+    async fn smart_preserve(regenerate_fields: Vec<String>) -> serde_json::Value {
+        let client = Surreal::<Client>::init();
+        let merger = Merger::new(&client, merger_config(false), PerformanceConfig::default())
+            .await
+            .unwrap();
+        let mock_config = MockGenerationConfig {
+            regenerate_fields,
+            preservation_mode: PreservationMode::Smart,
+            ..full_mock_config()
+        };
+
+        let mut records = merger
+            .generate_preserved_data(
+                "post",
+                &audited_post_table(),
+                mock_config,
+                vec![existing_audited_post()],
+                1,
+                None,
+            )
+            .await
+            .expect("smart preservation should succeed");
+        records.remove(0)
+    }
+
+    #[tokio::test]
+    async fn smart_preservation_regenerates_globs_and_exact_names() {
+        let record = smart_preserve(vec!["audit_*".to_string(), "slug".to_string()]).await;
+
+        assert_eq!(record["title"], json!("kept"));
+        assert_eq!(record["body"], json!("kept"));
+        for field in ["audit_by", "audit_at", "slug"] {
+            assert_ne!(
+                record[field],
+                json!("stale"),
+                "{field} should be regenerated"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn smart_preservation_wildcard_regenerates_every_field() {
+        let record = smart_preserve(vec!["*".to_string()]).await;
+
+        for (field, value) in existing_audited_post().as_object().unwrap() {
+            assert_ne!(&record[field], value, "{field} should be regenerated");
+        }
+    }
+
+    /// Smart preservation of `existing_audited_post` after `audit_by` was
+    /// added to the schema and `slug` changed type.
+    async fn preserve_after_schema_change(regenerate_modified_fields: bool) -> serde_json::Value {
+        let client = Surreal::<Client>::init();
+        let config = SchemasyncMockGenConfig {
+            regenerate_modified_fields,
+            ..merger_config(false)
+        };
+        let merger = Merger::new(&client, config, PerformanceConfig::default())
+            .await
+            .unwrap();
+        let changes = SchemaChanges {
+            new_tables: vec![],
+            removed_tables: vec![],
+            modified_tables: vec![TableChanges {
+                table_name: "post".to_string(),
+                new_fields: vec!["audit_by".to_string()],
+                removed_fields: vec![],
+                modified_fields: vec![FieldChange {
+                    field_name: "slug".to_string(),
+                    old_type: "int".to_string(),
+                    new_type: "string".to_string(),
+                    change_type: ChangeType::Modified,
+                    required_changed: false,
+                    default_changed: false,
+                }],
+                permission_changed: false,
+                schema_type_changed: false,
+                new_events: vec![],
+                removed_events: vec![],
+                new_indexes: vec![],
+                removed_indexes: vec![],
+                new_required_fields: Vec::new(),
+                changefeed_changed: false,
+            }],
+            new_accesses: vec![],
+            removed_accesses: vec![],
+            modified_accesses: vec![],
+        };
+        let mock_config = MockGenerationConfig {
+            preservation_mode: PreservationMode::Smart,
+            ..full_mock_config()
+        };
+
+        let mut records = merger
+            .generate_preserved_data(
+                "post",
+                &audited_post_table(),
+                mock_config,
+                vec![existing_audited_post()],
+                1,
+                Some(&changes),
+            )
+            .await
+            .expect("smart preservation should succeed");
+        records.remove(0)
+    }
+
+    #[tokio::test]
+    async fn smart_preservation_keeps_modified_fields_by_default() {
+        let record = preserve_after_schema_change(false).await;
+
+        assert_eq!(record["slug"], json!("stale"));
+        assert_eq!(record["title"], json!("kept"));
+        assert_ne!(record["audit_by"], json!("stale"));
+    }
+
+    #[tokio::test]
+    async fn smart_preservation_regenerates_modified_fields_when_enabled() {
+        let record = preserve_after_schema_change(true).await;
+
+        assert_ne!(record["slug"], json!("stale"));
+        assert_eq!(record["title"], json!("kept"));
+        assert_ne!(record["audit_by"], json!("stale"));
+    }
+
+    #[test]
+    fn field_matches_pattern_supports_prefix_suffix_and_exact() {
+        assert!(field_matches_pattern("*", "anything"));
+        assert!(field_matches_pattern("audit_*", "audit_by"));
+        assert!(!field_matches_pattern("audit_*", "created_by"));
+        assert!(field_matches_pattern("*_at", "created_at"));
+        assert!(!field_matches_pattern("*_at", "created_by"));
+        assert!(field_matches_pattern("slug", "slug"));
+        assert!(!field_matches_pattern("slug", "slugs"));
+    }
+
+    #[tokio::test]
+    async fn full_preservation_regenerates_always_regenerate_fields() {
+        let client = Surreal::<Client>::init();
+        let merger = Merger::new(&client, merger_config(false), PerformanceConfig::default())
+            .await
+            .unwrap();
+
+        let mut table = post_table();
+        table.struct_config.fields.push(StructField {
+            field_name: "updated_at".to_string(),
+            field_type: FieldType::String,
+            always_regenerate: true,
+            ..StructField::default()
+        });
+        let existing: Vec<serde_json::Value> = (1..=2)
+            .map(|i| json!({ "title": format!("post {i}"), "updated_at": "stale" }))
+            .collect();
+
+        let records = merger
+            .generate_preserved_data("post", &table, full_mock_config(), existing, 2, None)
+            .await
+            .expect("full preservation should succeed");
+
+        assert_eq!(records.len(), 2);
+        for (i, record) in records.iter().enumerate() {
+            assert_eq!(record["title"], json!(format!("post {}", i + 1)));
+            assert_ne!(record["updated_at"], json!("stale"));
+        }
+    }
+
+    #[tokio::test]
+    async fn new_records_get_stable_ids() {
+        let client = Surreal::<Client>::init();
+        let merger = Merger::new(&client, merger_config(false), PerformanceConfig::default())
+            .await
+            .unwrap();
+        let mut mock_config = full_mock_config();
+        mock_config.preservation_mode = PreservationMode::None;
+
+        let mut runs = Vec::new();
+        for _ in 0..2 {
+            let records = merger
+                .generate_preserved_data(
+                    "post",
+                    &post_table(),
+                    mock_config.clone(),
+                    vec![],
+                    3,
+                    None,
+                )
+                .await
+                .expect("generation should succeed");
+            let ids: Vec<serde_json::Value> = records.iter().map(|r| r["id"].clone()).collect();
+            runs.push(ids);
+        }
+
+        assert_eq!(runs[0], [json!("post:1"), json!("post:2"), json!("post:3")]);
+        assert_eq!(runs[0], runs[1]);
+    }
+
+    #[tokio::test]
+    async fn additional_records_fill_gaps_without_reusing_ids() {
+        let client = Surreal::<Client>::init();
+        let merger = Merger::new(&client, merger_config(false), PerformanceConfig::default())
+            .await
+            .unwrap();
+        let existing = vec![
+            json!({ "id": "post:1", "title": "first" }),
+            json!({ "id": "post:3", "title": "third" }),
+        ];
+
+        let records = merger
+            .generate_preserved_data("post", &post_table(), full_mock_config(), existing, 4, None)
+            .await
+            .expect("full preservation should succeed");
+
+        let ids: Vec<&serde_json::Value> = records.iter().map(|r| &r["id"]).collect();
+        assert_eq!(
+            ids,
+            [
+                &json!("post:1"),
+                &json!("post:3"),
+                &json!("post:2"),
+                &json!("post:4")
+            ]
+        );
+    }
+
+    /// `settings.display` gained a `density` field since the record was stored
+    fn post_with_settings_table() -> TableConfig {
+        let display = FieldType::Struct(vec![
+            ("theme".to_string(), FieldType::String),
+            ("font_size".to_string(), FieldType::U8),
+            ("density".to_string(), FieldType::String),
+        ]);
+        let settings = FieldType::Struct(vec![
+            ("locale".to_string(), FieldType::String),
+            ("display".to_string(), display),
+        ]);
+        let mut table = post_table();
+        table.struct_config.fields.push(StructField {
+            field_name: "settings".to_string(),
+            field_type: settings,
+            ..StructField::default()
+        });
+        table
+    }
+
+    fn existing_post_with_settings() -> serde_json::Value {
+        json!({
+            "title": "kept",
+            "settings": {
+                "locale": "en",
+                "display": { "theme": "dark", "font_size": 12 },
+            },
+        })
+    }
+
+    async fn preserve_settings(preservation_mode: PreservationMode) -> serde_json::Value {
+        let client = Surreal::<Client>::init();
+        let merger = Merger::new(&client, merger_config(false), PerformanceConfig::default())
+            .await
+            .unwrap();
+        let mock_config = MockGenerationConfig {
+            preservation_mode,
+            ..full_mock_config()
+        };
+
+        let mut records = merger
+            .generate_preserved_data(
+                "post",
+                &post_with_settings_table(),
+                mock_config,
+                vec![existing_post_with_settings()],
+                1,
+                None,
+            )
+            .await
+            .expect("preservation should succeed");
+        records.remove(0)
+    }
+
+    #[tokio::test]
+    async fn merge_preservation_fills_new_nested_fields() {
+        let record = preserve_settings(PreservationMode::Merge).await;
+
+        assert_eq!(record["title"], "kept");
+        assert_eq!(record["settings"]["locale"], "en");
+        let display = &record["settings"]["display"];
+        assert_eq!(display["theme"], "dark");
+        assert_eq!(display["font_size"], 12);
+        assert!(display["density"].is_string(), "got: {record}");
+    }
+
+    #[tokio::test]
+    async fn full_preservation_leaves_nested_objects_untouched() {
+        let record = preserve_settings(PreservationMode::Full).await;
+
+        assert_eq!(record, existing_post_with_settings());
+    }
+
+    #[tokio::test]
+    async fn merge_preservation_fills_new_fields_of_named_objects() {
+        let field = |name: &str, field_type| StructField {
+            field_name: name.to_string(),
+            field_type,
+            ..StructField::default()
+        };
+        let object = |name: &str, fields| StructConfig {
+            struct_name: name.to_string(),
+            fields,
+            ..StructConfig::default()
+        };
+        // Derived configs refer to named structs by name
+        let objects = BTreeMap::from([
+            (
+                "Settings".to_string(),
+                object(
+                    "Settings",
+                    vec![
+                        field("locale", FieldType::String),
+                        field("display", FieldType::Other("Display".to_string())),
+                    ],
+                ),
+            ),
+            (
+                "Display".to_string(),
+                object(
+                    "Display",
+                    vec![
+                        field("theme", FieldType::String),
+                        field("font_size", FieldType::U8),
+                        field("density", FieldType::String),
+                    ],
+                ),
+            ),
+        ]);
+        let mut table = post_table();
+        table
+            .struct_config
+            .fields
+            .push(field("settings", FieldType::Other("Settings".to_string())));
+
+        let client = Surreal::<Client>::init();
+        let merger = Merger::new(&client, merger_config(false), PerformanceConfig::default())
+            .await
+            .unwrap()
+            .with_objects(&objects);
+        let mock_config = MockGenerationConfig {
+            preservation_mode: PreservationMode::Merge,
+            ..full_mock_config()
+        };
+        let record = merger
+            .generate_preserved_data(
+                "post",
+                &table,
+                mock_config,
+                vec![existing_post_with_settings()],
+                1,
+                None,
+            )
+            .await
+            .expect("preservation should succeed")
+            .remove(0);
+
+        assert_eq!(record["settings"]["locale"], "en");
+        let display = &record["settings"]["display"];
+        assert_eq!(display["theme"], "dark");
+        assert_eq!(display["font_size"], 12);
+        assert!(display["density"].is_string(), "got: {record}");
+    }
+}
+
+#[cfg(all(test, feature = "surrealdb"))]
+mod generate_field_value_tests {
+    use super::*;
+    use crate::schemasync::mockmake::format::Format;
+    use crate::types::{FieldType, StructConfig, StructField};
+    use crate::validator::{NumberValidator, Validator};
+    use ordered_float::OrderedFloat;
+
+    fn ranged_field(field_type: FieldType, min: f64, max: f64) -> StructField {
+        StructField {
+            field_name: "score".to_string(),
+            field_type,
+            validators: vec![Validator::NumberValidator(NumberValidator::Between(
+                OrderedFloat(min),
+                OrderedFloat(max),
+            ))],
+            ..StructField::default()
+        }
+    }
+
+    fn table() -> TableConfig {
+        TableConfig {
+            table_name: "game".to_string(),
+            struct_config: StructConfig::default(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn integer_samples_stay_within_between_range() {
+        let field = ranged_field(FieldType::I32, -5.0, 5.0);
+        let table = table();
+        let mut rng = rand::make_rng();
+        for _ in 0..1000 {
+            let value =
+                Merger::generate_field_value(&field, &table, 16, 0.5, &mut rng, chrono::Utc::now());
+            let n = value.as_i64().expect("integer value");
+            assert!((-5..=5).contains(&n), "out of range: {n}");
+        }
+    }
+
+    #[test]
+    fn float_samples_stay_within_between_range() {
+        let field = ranged_field(FieldType::F64, 0.5, 1.5);
+        let table = table();
+        let mut rng = rand::make_rng();
+        for _ in 0..1000 {
+            let value =
+                Merger::generate_field_value(&field, &table, 16, 0.5, &mut rng, chrono::Utc::now());
+            let n = value.as_f64().expect("float value");
+            assert!((0.5..=1.5).contains(&n), "out of range: {n}");
+        }
+    }
+
+    #[test]
+    fn optional_samples_keep_inner_range() {
+        let field = ranged_field(FieldType::Option(Box::new(FieldType::U8)), 10.0, 20.0);
+        let table = table();
+        let mut rng = rand::make_rng();
+        for _ in 0..1000 {
+            let value =
+                Merger::generate_field_value(&field, &table, 16, 0.5, &mut rng, chrono::Utc::now());
+            if let Some(n) = value.as_u64() {
+                assert!((10..=20).contains(&n), "out of range: {n}");
+            } else {
+                assert!(value.is_null(), "unexpected value: {value}");
+            }
+        }
+    }
+
+    #[test]
+    fn deeply_nested_options_stop_at_depth_limit() {
+        let field_type = (0..1_000).fold(FieldType::String, |inner, _| {
+            FieldType::Option(Box::new(inner))
+        });
+        let field = StructField {
+            field_name: "nested".to_string(),
+            field_type,
+            ..StructField::default()
+        };
+        let table = table();
+        let mut rng = rand::make_rng();
+        for _ in 0..100 {
+            let value =
+                Merger::generate_field_value(&field, &table, 8, 0.5, &mut rng, chrono::Utc::now());
+            assert!(value.is_null(), "unexpected value: {value}");
+        }
+        let shallow = StructField {
+            field_type: FieldType::Option(Box::new(FieldType::Bool)),
+            ..field
+        };
+        assert!(
+            Merger::generate_field_value(&shallow, &table, 0, 0.5, &mut rng, chrono::Utc::now())
+                .is_null()
+        );
+    }
+
+    #[test]
+    fn phone_numbers_are_stored_as_strings() {
+        let field = StructField {
+            field_name: "phone".to_string(),
+            field_type: FieldType::String,
+            format: Some(Format::PhoneNumber {
+                locale: "DE".to_string(),
+            }),
+            ..StructField::default()
+        };
+        let table = table();
+        let mut rng = rand::make_rng();
+        for _ in 0..100 {
+            let value =
+                Merger::generate_field_value(&field, &table, 16, 0.5, &mut rng, chrono::Utc::now());
+            let phone = value.as_str().expect("string value");
+            assert!(
+                phone.starts_with("+491") || phone.starts_with("01"),
+                "unexpected phone number: {phone}"
+            );
+        }
+    }
+
+    #[test]
+    fn seeded_datetimes_come_from_reference_time() {
+        let field = StructField {
+            field_name: "created_at".to_string(),
+            field_type: FieldType::Other("DateTime".to_string()),
+            ..StructField::default()
+        };
+        let reference_time = crate::schemasync::mockmake::seeded_reference_time(Some(7));
+        let value = Merger::generate_field_value(
+            &field,
+            &table(),
+            16,
+            0.5,
+            &mut rand::make_rng(),
+            reference_time,
+        );
+        assert_eq!(value, serde_json::json!("2024-01-01T00:00:00+00:00"));
+    }
+
+    #[test]
+    fn literal_fields_generate_their_value() {
+        let field = StructField {
+            field_name: "kind".to_string(),
+            field_type: FieldType::Literal(serde_json::json!("user")),
+            ..StructField::default()
+        };
+        let value = Merger::generate_field_value(
+            &field,
+            &table(),
+            16,
+            0.5,
+            &mut rand::make_rng(),
+            chrono::Utc::now(),
+        );
+        assert_eq!(value, serde_json::json!("user"));
+    }
 }
@@ -113,10 +113,12 @@ impl<'a> SqlSchemaComparator<'a> {
             modified_fields: Vec::new(),
             permission_changed: false,
             schema_type_changed: false,
+            changefeed_changed: false,
             new_events: Vec::new(),
             removed_events: Vec::new(),
             new_indexes: Vec::new(),
             removed_indexes: Vec::new(),
+            new_required_fields: Vec::new(),
         };
 
         // Get column names
@@ -128,6 +130,13 @@ impl<'a> SqlSchemaComparator<'a> {
         // Find new columns
         for col_name in expected_columns.difference(&current_columns) {
             changes.new_fields.push(col_name.clone());
+            if expected
+                .columns
+                .iter()
+                .any(|c| &c.name == col_name && !c.nullable && c.default.is_none())
+            {
+                changes.new_required_fields.push(col_name.clone());
+            }
         }
 
         // Find removed columns
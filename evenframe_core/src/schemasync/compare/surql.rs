@@ -14,7 +14,7 @@ use crate::{
     schemasync::{config::AccessType, database::surql::access::setup_access_definitions},
 };
 use futures::StreamExt;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use surrealdb::engine::local::{Db, Mem};
 use surrealdb::{Surreal, engine::remote::http::Client};
 use tracing;
@@ -32,6 +32,7 @@ pub struct SurrealdbComparator<'a> {
     remote_schema_string: String,
     new_schema_string: String,
     schema_changes: Option<SchemaChanges>,
+    table_scope: Option<BTreeSet<String>>,
 }
 
 impl<'a> SurrealdbComparator<'a> {
@@ -48,9 +49,16 @@ impl<'a> SurrealdbComparator<'a> {
             remote_schema_string: String::new(),
             new_schema_string: String::new(),
             schema_changes: None,
+            table_scope: None,
         }
     }
 
+    /// Limit the comparison to the given tables, so tables outside of the
+    /// scope are neither modified nor reported as removed.
+    pub fn restrict_to_tables(&mut self, tables: BTreeSet<String>) {
+        self.table_scope = Some(tables);
+    }
+
     pub async fn run(&mut self, define_statements: &str) -> Result<()> {
         tracing::info!("Starting SurrealdbComparator pipeline");
 
@@ -136,8 +144,13 @@ impl<'a> SurrealdbComparator<'a> {
     /// Compare schemas to find changes
     async fn compare_schemas(&mut self) -> Result<()> {
         tracing::trace!("Starting schema comparison");
-        let changes =
-            compare_schemas(self.db, &self.remote_schema_string, &self.new_schema_string).await?;
+        let changes = compare_schemas(
+            self.db,
+            &self.remote_schema_string,
+            &self.new_schema_string,
+            self.table_scope.as_ref(),
+        )
+        .await?;
 
         tracing::info!(
             new_tables = changes.new_tables.len(),
@@ -165,16 +178,20 @@ impl<'a> SurrealdbComparator<'a> {
 }
 
 /// Compare two schema export strings and return the differences
+///
+/// When `table_scope` is given, both schemas are restricted to those tables
+/// before comparing.
 pub async fn compare_schemas(
     db: &Surreal<Client>,
     remote_schema_string: &str,
     new_schema_string: &str,
+    table_scope: Option<&BTreeSet<String>>,
 ) -> Result<SchemaChanges> {
     tracing::debug!("Parsing and comparing schema exports");
     let importer = SchemaImporter::new(db);
 
     // Parse exports with error propagation instead of panicking
-    let remote_schema = importer
+    let mut remote_schema = importer
         .parse_schema_from_export(remote_schema_string)
         .map_err(|e| {
             tracing::error!(
@@ -185,7 +202,7 @@ pub async fn compare_schemas(
             e
         })?;
 
-    let new_schema = importer
+    let mut new_schema = importer
         .parse_schema_from_export(new_schema_string)
         .map_err(|e| {
             tracing::error!(
@@ -196,6 +213,11 @@ pub async fn compare_schemas(
             e
         })?;
 
+    if let Some(scope) = table_scope {
+        remote_schema.retain_tables(scope);
+        new_schema.retain_tables(scope);
+    }
+
     let schema_changes = super::Comparator::compare(&remote_schema, &new_schema)?;
 
     evenframe_log!(format!("{:#?}", schema_changes), "changes.log");
@@ -385,7 +407,7 @@ impl<'a> SchemaImporter<'a> {
         }
 
         // Parse the exported statements into our schema structure
-        self.parse_schema_statements(schema_statements)
+        Self::parse_schema_statements(schema_statements)
     }
 
     /// Export schema only as raw DEFINE statements
@@ -416,17 +438,22 @@ impl<'a> SchemaImporter<'a> {
 
     /// Parse schema from raw export string
     pub fn parse_schema_from_export(&self, export_data: &str) -> Result<SchemaDefinition> {
+        Self::parse_export(export_data)
+    }
+
+    /// Parse schema from raw export string without a client
+    pub fn parse_export(export_data: &str) -> Result<SchemaDefinition> {
         let statements: Vec<String> = export_data
             .lines()
             .map(|s| s.to_string())
             .filter(|s| !s.trim().is_empty())
             .collect();
 
-        self.parse_schema_statements(statements)
+        Self::parse_schema_statements(statements)
     }
 
     /// Parse SurrealDB export statements into structured schema
-    fn parse_schema_statements(&self, statements: Vec<String>) -> Result<SchemaDefinition> {
+    fn parse_schema_statements(statements: Vec<String>) -> Result<SchemaDefinition> {
         let mut tables = BTreeMap::new();
         let edges = BTreeMap::new();
         let mut accesses = Vec::new();
@@ -458,6 +485,9 @@ impl<'a> SchemaImporter<'a> {
                         permissions: None,
                         indexes: table_indexes.remove(&table_name).unwrap_or_default(),
                         events: table_events.remove(&table_name).unwrap_or_default(),
+                        changefeed: current_table_statement
+                            .as_deref()
+                            .and_then(Self::extract_changefeed),
                     };
                     tables.insert(table_name, table_def);
                     current_fields.clear();
@@ -520,6 +550,9 @@ impl<'a> SchemaImporter<'a> {
                 permissions: None,
                 indexes: table_indexes.remove(&table_name).unwrap_or_default(),
                 events: table_events.remove(&table_name).unwrap_or_default(),
+                changefeed: current_table_statement
+                    .as_deref()
+                    .and_then(Self::extract_changefeed),
             };
             tables.insert(table_name, table_def);
         }
@@ -531,15 +564,22 @@ impl<'a> SchemaImporter<'a> {
         })
     }
 
-    /// Extract schema type from DEFINE TABLE statement
+    /// Extract schema type from DEFINE TABLE statement. Only a standalone
+    /// keyword counts, so e.g. a COMMENT mentioning "schemafull" is ignored.
     fn extract_schema_type(statement: &str) -> SchemaType {
-        let statement_upper = statement.to_uppercase();
-        if statement_upper.contains("SCHEMAFULL") {
-            SchemaType::Schemafull
-        } else {
+        statement
+            .split_whitespace()
+            .find_map(SchemaType::from_keyword)
             // Default to Schemaless (includes explicit SCHEMALESS or unspecified)
-            SchemaType::Schemaless
-        }
+            .unwrap_or(SchemaType::Schemaless)
+    }
+
+    /// Extract the retention following `CHANGEFEED` in a DEFINE TABLE statement.
+    fn extract_changefeed(statement: &str) -> Option<String> {
+        let mut words = statement.split_whitespace();
+        words.find(|word| word.eq_ignore_ascii_case("CHANGEFEED"))?;
+        let duration = words.next()?.trim_end_matches(';');
+        (!duration.is_empty()).then(|| duration.to_string())
     }
 
     /// Extract table name from DEFINE TABLE statement
@@ -839,7 +879,7 @@ impl<'a> SchemaImporter<'a> {
     }
 
     /// Parse a DEFINE FIELD statement
-    fn parse_field_definition(statement: &str) -> Option<FieldDefinition> {
+    pub(crate) fn parse_field_definition(statement: &str) -> Option<FieldDefinition> {
         // Basic validation
         if !statement.starts_with("DEFINE FIELD") {
             return None;
@@ -1067,10 +1107,15 @@ impl<'a> SchemaImporter<'a> {
             None
         };
 
+        // `option<T>` fields may be absent, so they are never required
+        let required = !has_default
+            && computed_expression.is_none()
+            && !matches!(&field_type, ObjectType::Simple(t) if t.starts_with("option<"));
+
         Some(FieldDefinition {
             name: field_name.to_string(),
             field_type,
-            required: !has_default && computed_expression.is_none(),
+            required,
             default_value,
             assertions,
             parent_array_field: parent_array,
@@ -1102,7 +1147,7 @@ impl<'a> SchemaImporter<'a> {
     /// Parse a DEFINE INDEX statement into (table_name, IndexDefinition)
     ///
     /// Format: `DEFINE INDEX [OVERWRITE | IF NOT EXISTS] <name> ON [TABLE] <table> FIELDS|COLUMNS <col1>, <col2> [UNIQUE];`
-    fn parse_index_definition(statement: &str) -> Option<(String, IndexDefinition)> {
+    pub(crate) fn parse_index_definition(statement: &str) -> Option<(String, IndexDefinition)> {
         if !statement.starts_with("DEFINE INDEX") {
             return None;
         }
@@ -1154,11 +1199,12 @@ impl<'a> SchemaImporter<'a> {
         };
         let after_columns = statement[columns_keyword_pos + keyword_len..].trim();
 
-        // Columns end at UNIQUE, SEARCH, COMMENT, or semicolon
+        // Columns end at UNIQUE, SEARCH, FULLTEXT, COMMENT, or semicolon
         let columns_end = after_columns
             .to_uppercase()
             .find(" UNIQUE")
             .or_else(|| after_columns.to_uppercase().find(" SEARCH"))
+            .or_else(|| after_columns.to_uppercase().find(" FULLTEXT"))
             .or_else(|| after_columns.to_uppercase().find(" COMMENT"))
             .unwrap_or(after_columns.len());
         let columns_str = after_columns[..columns_end].trim().trim_end_matches(';');
@@ -1431,6 +1477,82 @@ mod tests {
         assert_eq!(field.comment, Some("Auto-uppercased".to_string()));
     }
 
+    #[test]
+    fn parse_option_field_is_not_required() {
+        let stmt = "DEFINE FIELD OVERWRITE age ON TABLE user TYPE option<int> PERMISSIONS FULL";
+        let field = SchemaImporter::parse_field_definition(stmt).unwrap();
+        assert!(!field.required);
+
+        let stmt = "DEFINE FIELD OVERWRITE age ON TABLE user TYPE int PERMISSIONS FULL";
+        let field = SchemaImporter::parse_field_definition(stmt).unwrap();
+        assert!(field.required);
+    }
+
+    #[test]
+    fn schema_type_ignores_casing_and_whitespace() {
+        assert_eq!(
+            SchemaType::from_keyword("SCHEMAFULL"),
+            Some(SchemaType::Schemafull)
+        );
+        assert_eq!(
+            SchemaType::from_keyword("schemafull "),
+            Some(SchemaType::Schemafull)
+        );
+        assert_eq!(
+            SchemaType::from_keyword("SCHEMALESS;"),
+            Some(SchemaType::Schemaless)
+        );
+        assert_eq!(SchemaType::from_keyword("TYPE"), None);
+
+        assert_eq!(
+            SchemaImporter::extract_schema_type("DEFINE TABLE user TYPE NORMAL schemafull ;"),
+            SchemaType::Schemafull
+        );
+        assert_eq!(
+            SchemaImporter::extract_schema_type(
+                "DEFINE TABLE user TYPE NORMAL SCHEMALESS COMMENT 'was schemafull'"
+            ),
+            SchemaType::Schemaless
+        );
+    }
+
+    #[test]
+    fn parse_export_reads_table_changefeed() {
+        let schema = SchemaImporter::parse_export(
+            "DEFINE TABLE audit TYPE NORMAL SCHEMAFULL CHANGEFEED 7d PERMISSIONS NONE;\n\
+             DEFINE TABLE note TYPE NORMAL SCHEMAFULL PERMISSIONS NONE;",
+        )
+        .expect("parse");
+
+        assert_eq!(schema.tables["audit"].changefeed.as_deref(), Some("7d"));
+        assert_eq!(schema.tables["note"].changefeed, None);
+    }
+
+    #[test]
+    fn cosmetic_schema_type_differences_are_not_changes() {
+        let table = |statement: &str| TableDefinition {
+            name: "user".to_string(),
+            schema_type: SchemaImporter::extract_schema_type(statement),
+            fields: BTreeMap::new(),
+            array_wildcard_fields: BTreeMap::new(),
+            permissions: None,
+            indexes: Vec::new(),
+            events: Vec::new(),
+            changefeed: None,
+        };
+        let schema = |table: TableDefinition| SchemaDefinition {
+            tables: BTreeMap::from([("user".to_string(), table)]),
+            edges: BTreeMap::new(),
+            accesses: Vec::new(),
+        };
+
+        let old = schema(table("DEFINE TABLE user TYPE NORMAL SCHEMAFULL"));
+        let new = schema(table("DEFINE TABLE user TYPE NORMAL schemafull "));
+
+        let changes = crate::schemasync::compare::Comparator::compare(&old, &new).expect("compare");
+        assert!(changes.modified_tables.is_empty(), "{changes:?}");
+    }
+
     #[test]
     fn parse_regular_field_no_computed() {
         let stmt = "DEFINE FIELD name ON TABLE user TYPE string DEFAULT '' PERMISSIONS FOR select FULL FOR create FULL FOR update FULL";
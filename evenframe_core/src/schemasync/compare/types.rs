@@ -3,10 +3,10 @@
 //! These types represent database schemas in a provider-agnostic way,
 //! allowing comparison between code-defined schemas and database schemas.
 
-use crate::{Result, schemasync::TableConfig, schemasync::config::AccessType};
+use crate::{Result, schemasync::TableConfig, schemasync::config::AccessType, types::FieldType};
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     fmt::{self, Display, Formatter},
 };
 use tracing;
@@ -75,6 +75,9 @@ pub struct TableDefinition {
     pub permissions: Option<PermissionSet>,
     pub indexes: Vec<IndexDefinition>,
     pub events: Vec<String>,
+    /// Retention of the table's `CHANGEFEED`, if it has one
+    #[serde(default)]
+    pub changefeed: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -83,6 +86,24 @@ pub enum SchemaType {
     Schemaless,
 }
 
+impl SchemaType {
+    /// Parses a `SCHEMAFULL` / `SCHEMALESS` keyword, ignoring case, surrounding
+    /// whitespace and a trailing `;` so cosmetic export differences compare equal.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword
+            .trim()
+            .trim_end_matches(';')
+            .trim()
+            .to_uppercase()
+            .as_str()
+        {
+            "SCHEMAFULL" => Some(Self::Schemafull),
+            "SCHEMALESS" => Some(Self::Schemaless),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PermissionSet {
     pub select: String,
@@ -146,13 +167,25 @@ impl SchemaDefinition {
                         .struct_config
                         .fields
                         .iter()
-                        .filter(|f| f.unique)
+                        .filter(|f| f.unique || f.indexed)
                         .map(|f| IndexDefinition {
-                            name: format!("idx_{}_{}", name, f.field_name),
-                            columns: vec![f.field_name.clone()],
-                            unique: true,
+                            name: format!("idx_{}_{}", name, f.output_name()),
+                            columns: vec![f.output_name().to_string()],
+                            unique: f.unique,
                         })
                         .collect();
+                    v.extend(
+                        config
+                            .struct_config
+                            .fields
+                            .iter()
+                            .filter(|f| f.search_analyzer.is_some())
+                            .map(|f| IndexDefinition {
+                                name: format!("idx_{}_{}_search", name, f.output_name()),
+                                columns: vec![f.output_name().to_string()],
+                                unique: false,
+                            }),
+                    );
                     v.extend(config.indexes.iter().map(|idx| IndexDefinition {
                         name: format!("idx_{}_{}", name, idx.fields.join("_")),
                         columns: idx.fields.clone(),
@@ -163,8 +196,9 @@ impl SchemaDefinition {
                 events: config
                     .events
                     .iter()
-                    .map(|event| event.statement.clone())
+                    .map(|event| event.define_statement(name))
                     .collect(),
+                changefeed: Some(config.changefeed().to_string()),
             };
 
             if config.relation.is_some() {
@@ -189,6 +223,12 @@ impl SchemaDefinition {
         Ok(definition)
     }
 
+    /// Drop every table and edge whose name is not in `names`
+    pub fn retain_tables(&mut self, names: &BTreeSet<String>) {
+        self.tables.retain(|name, _| names.contains(name));
+        self.edges.retain(|name, _| names.contains(name));
+    }
+
     fn extract_fields_from_config(
         config: &TableConfig,
     ) -> Result<BTreeMap<String, FieldDefinition>> {
@@ -201,8 +241,10 @@ impl SchemaDefinition {
                 .as_ref()
                 .and_then(|dc| dc.default.clone().or(dc.default_always.clone()));
 
-            // Field is required if it doesn't have a default value and isn't skipped
-            let is_required = default_value.is_none()
+            // Field is required if it isn't an Option, doesn't have a default
+            // value and isn't skipped
+            let is_required = !matches!(field.field_type, FieldType::Option(_))
+                && default_value.is_none()
                 && !field
                     .define_config
                     .as_ref()
@@ -210,16 +252,11 @@ impl SchemaDefinition {
                     .unwrap_or(false);
 
             let field_def = FieldDefinition {
-                name: field.field_name.clone(),
+                name: field.output_name().to_string(),
                 field_type: ObjectType::Simple(field.field_type.to_string()),
                 required: is_required,
                 default_value,
-                assertions: field
-                    .define_config
-                    .as_ref()
-                    .and_then(|dc| dc.assert.clone())
-                    .map(|a| vec![a])
-                    .unwrap_or_default(),
+                assertions: field.assert_clause().map(|a| vec![a]).unwrap_or_default(),
                 parent_array_field: None,
                 computed_expression: field
                     .define_config
@@ -230,7 +267,7 @@ impl SchemaDefinition {
                     .as_ref()
                     .and_then(|dc| dc.comment.clone()),
             };
-            fields.insert(field.field_name.clone(), field_def);
+            fields.insert(field.output_name().to_string(), field_def);
         }
 
         Ok(fields)
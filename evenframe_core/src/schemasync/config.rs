@@ -1,5 +1,6 @@
 use crate::schemasync::{PreservationMode, mockmake::coordinate::CoordinationGroup};
 use bon::Builder;
+use convert_case::{Case, Casing};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use tracing::{debug, trace};
@@ -26,6 +27,76 @@ pub struct SchemasyncConfig {
     #[serde(default)]
     #[builder(default)]
     pub plugins: BTreeMap<String, PluginConfig>,
+    /// Collect the statements the pipeline would execute instead of running them
+    /// against the database. Schema comparison still runs against a live export.
+    #[serde(default)]
+    #[builder(default)]
+    pub dry_run: bool,
+    /// Abort before touching the database when the comparison finds breaking
+    /// changes (see `SchemaChanges::breaking_changes`)
+    #[serde(default)]
+    #[builder(default)]
+    pub fail_on_breaking: bool,
+    /// Leave the database schema untouched and only generate mock data: no
+    /// comparison, access, define, function or remove statements are run.
+    /// Existing record ids are still read, and every table is treated as
+    /// changed when deciding what to generate.
+    #[serde(default)]
+    #[builder(default)]
+    pub skip_schema_definition: bool,
+    /// Casing used to derive table names from struct names (see [`TableCase`])
+    #[serde(default)]
+    #[builder(default)]
+    pub table_naming: TableCase,
+    /// How many times to retry connecting (connect, sign-in, namespace
+    /// selection) before giving up. Rejected credentials fail immediately.
+    #[serde(default = "default_connect_retries")]
+    #[builder(default = default_connect_retries())]
+    pub connect_retries: u32,
+    /// Delay before the first connection retry, doubled after each attempt
+    #[serde(default = "default_connect_retry_delay_ms")]
+    #[builder(default = default_connect_retry_delay_ms())]
+    pub connect_retry_delay_ms: u64,
+}
+
+#[cfg(feature = "schemasync")]
+impl SchemasyncConfig {
+    /// Provider connection config for [`Self::database`], carrying the
+    /// connection retry settings.
+    pub fn to_provider_config(&self) -> crate::schemasync::database::DatabaseConfig {
+        crate::schemasync::database::DatabaseConfig {
+            connect_retries: self.connect_retries,
+            connect_retry_delay_ms: self.connect_retry_delay_ms,
+            ..self.database.to_provider_config()
+        }
+    }
+}
+
+/// Casing convention applied when a struct name becomes a table name.
+/// Kebab-case names are not plain SurrealQL identifiers; generated
+/// statements escape them with [`surql_ident`](crate::schemasync::surql_ident).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TableCase {
+    /// `UserProfile` -> `user_profile`
+    #[default]
+    Snake,
+    /// `UserProfile` -> `user-profile`
+    Kebab,
+    /// `UserProfile` stays `UserProfile`
+    Verbatim,
+}
+
+impl TableCase {
+    /// Converts a struct (or already converted table) name to a table name.
+    /// Idempotent, so table names can be passed through again safely.
+    pub fn apply(self, name: &str) -> String {
+        match self {
+            TableCase::Snake => name.to_case(Case::Snake),
+            TableCase::Kebab => name.to_case(Case::Kebab),
+            TableCase::Verbatim => name.to_string(),
+        }
+    }
 }
 
 /// Database provider type for configuration
@@ -94,6 +165,26 @@ fn default_timeout() -> u64 {
     60
 }
 
+pub(crate) fn default_connect_retries() -> u32 {
+    5
+}
+
+pub(crate) fn default_connect_retry_delay_ms() -> u64 {
+    500
+}
+
+pub(crate) fn default_max_recursion_depth() -> usize {
+    16
+}
+
+pub(crate) fn default_option_null_probability() -> f64 {
+    0.5
+}
+
+pub(crate) fn default_validate_generated_data() -> bool {
+    cfg!(debug_assertions)
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AccessConfig {
     pub name: String,
@@ -154,6 +245,80 @@ pub struct SchemasyncMockGenConfig {
     pub coordination_groups: Vec<CoordinationGroup>,
 
     pub full_refresh_mode: bool,
+
+    /// allow `Full` preservation to delete existing records when the target count
+    /// is lower than what is already stored; refused with an error by default
+    #[serde(default)]
+    #[builder(default)]
+    pub confirm_destructive: bool,
+
+    /// nesting depth past which recursive mock values collapse to `null`, so
+    /// self-referential types terminate
+    #[serde(default = "default_max_recursion_depth")]
+    #[builder(default = default_max_recursion_depth())]
+    pub max_recursion_depth: usize,
+
+    /// how keys are generated for new mock records
+    #[serde(default)]
+    #[builder(default)]
+    pub id_strategy: IdStrategy,
+
+    /// regenerate preserved values of fields whose definition changed; by
+    /// default only newly added fields receive generated values
+    #[serde(default)]
+    #[builder(default)]
+    pub regenerate_modified_fields: bool,
+
+    /// chance in `[0, 1]` that a mock value for an `Option` field is `NONE`
+    #[serde(default = "default_option_null_probability")]
+    #[builder(default = default_option_null_probability())]
+    pub option_null_probability: f64,
+
+    /// check generated values against their field's validators before they
+    /// are inserted; on by default in debug builds
+    #[serde(default = "default_validate_generated_data")]
+    #[builder(default = default_validate_generated_data())]
+    pub validate_generated_data: bool,
+
+    /// clear tables with `PreservationMode::None` and `CREATE` their mock
+    /// records instead of `UPSERT`ing over stale ones
+    #[serde(default)]
+    #[builder(default)]
+    pub recreate_unpreserved_tables: bool,
+
+    /// seed for the mock data random generator, overriden by table level
+    /// configs; runs with the same seed and config generate identical data
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+impl SchemasyncMockGenConfig {
+    /// Rejects values the mock generators cannot sample with.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        if !(0.0..=1.0).contains(&self.option_null_probability) {
+            return Err(crate::error::EvenframeError::InvalidConfigValue {
+                key: "mock_gen_config.option_null_probability".to_string(),
+                value: format!(
+                    "{} (expected a value between 0 and 1)",
+                    self.option_null_probability
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Key format for mock record ids created by `generate_ids`
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IdStrategy {
+    /// `table:1`, `table:2`, ... (default)
+    #[default]
+    Sequential,
+    /// `table:⟨<uuid v4>⟩`
+    Uuid,
+    /// `table:<ulid>`
+    Ulid,
 }
 
 impl Default for DatabaseConfig {
@@ -268,6 +433,8 @@ impl DatabaseConfig {
             min_connections: self.min_connections,
             schema: self.schema.clone(),
             timeout_secs: self.timeout,
+            connect_retries: default_connect_retries(),
+            connect_retry_delay_ms: default_connect_retry_delay_ms(),
         }
     }
 }
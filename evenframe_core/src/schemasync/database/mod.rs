@@ -16,9 +16,13 @@ pub mod surql;
 pub mod sql;
 
 use async_trait::async_trait;
+use futures::FutureExt;
+use futures::future::BoxFuture;
 use std::collections::BTreeMap;
+use std::panic::AssertUnwindSafe;
 
-use crate::error::Result;
+use crate::error::{EvenframeError, Result};
+use crate::schemasync::compare::{Comparator, SchemaChanges, SchemaDefinition};
 use crate::schemasync::{EdgeConfig, TableConfig};
 use crate::types::StructField;
 
@@ -167,11 +171,148 @@ pub trait DatabaseProvider: Send + Sync {
     /// Begin a transaction (returns a transaction handle)
     async fn begin_transaction(&self) -> Result<Box<dyn Transaction>>;
 
+    /// Run `f` inside a transaction started with [`Self::begin_transaction`],
+    /// committing when it returns `Ok` and rolling back when it returns `Err`
+    /// or panics. See [`run_in_transaction`].
+    ///
+    /// Being generic, this is not callable on a `dyn DatabaseProvider` such as
+    /// the one [`connect`] returns; pass `provider.begin_transaction().await?`
+    /// to [`run_in_transaction`] instead.
+    async fn execute_in_transaction<F, T>(&self, f: F) -> Result<T>
+    where
+        Self: Sized,
+        F: for<'t> FnOnce(&'t dyn Transaction) -> BoxFuture<'t, Result<T>> + Send,
+        T: Send,
+    {
+        let transaction = self.begin_transaction().await?;
+        run_in_transaction(transaction, f).await
+    }
+
     // === Embedded Mode (for schema comparison) ===
 
     /// Create an embedded/in-memory instance for schema comparison
     /// Returns None if the provider doesn't support embedded mode
     async fn create_embedded_instance(&self) -> Result<Option<Box<dyn DatabaseProvider>>>;
+
+    // === Migration ===
+
+    /// Parse an export from [`Self::export_schema`] into a schema definition
+    /// the [`Comparator`] can diff. Providers that can't leave the default,
+    /// which makes [`Self::migrate`] fail.
+    fn parse_schema_export(&self, _export: &SchemaExport) -> Result<SchemaDefinition> {
+        Err(EvenframeError::comparison(format!(
+            "The {} provider does not support schema migration",
+            self.name()
+        )))
+    }
+
+    /// The schema `tables` describe, in the form [`Self::parse_schema_export`]
+    /// produces. The default lists relation tables alongside normal ones, as
+    /// database exports do.
+    async fn target_schema(
+        &self,
+        tables: &BTreeMap<String, TableConfig>,
+        _objects: &BTreeMap<String, crate::types::StructConfig>,
+        _enums: &BTreeMap<String, crate::types::TaggedUnion>,
+    ) -> Result<SchemaDefinition> {
+        let mut target = SchemaDefinition::from_table_configs(tables)?;
+        let edges = std::mem::take(&mut target.edges);
+        target.tables.extend(edges);
+        Ok(target)
+    }
+
+    /// Generate the statements that apply `changes` to the database: new
+    /// tables are created and new or modified fields of existing tables are
+    /// defined again. Removals are left to the caller.
+    ///
+    /// The default only handles field changes; new indexes or events and
+    /// changed permissions, table types or changefeeds are an error unless
+    /// the provider overrides this to redefine them.
+    fn generate_migration(
+        &self,
+        changes: &SchemaChanges,
+        tables: &BTreeMap<String, TableConfig>,
+        objects: &BTreeMap<String, crate::types::StructConfig>,
+        enums: &BTreeMap<String, crate::types::TaggedUnion>,
+    ) -> Result<Vec<String>> {
+        let mut statements = Vec::new();
+        for table_name in &changes.new_tables {
+            if let Some(config) = tables.get(table_name) {
+                statements
+                    .push(self.generate_create_table(table_name, config, tables, objects, enums));
+            }
+        }
+        for table_changes in &changes.modified_tables {
+            let Some(config) = tables.get(&table_changes.table_name) else {
+                continue;
+            };
+            let unsupported: Vec<&str> = [
+                (!table_changes.new_indexes.is_empty(), "new indexes"),
+                (!table_changes.new_events.is_empty(), "new events"),
+                (table_changes.permission_changed, "changed permissions"),
+                (table_changes.schema_type_changed, "a changed table type"),
+                (table_changes.changefeed_changed, "a changed changefeed"),
+            ]
+            .into_iter()
+            .filter_map(|(changed, what)| changed.then_some(what))
+            .collect();
+            if !unsupported.is_empty() {
+                return Err(EvenframeError::comparison(format!(
+                    "The {} provider cannot migrate table '{}': it has {}",
+                    self.name(),
+                    table_changes.table_name,
+                    unsupported.join(", ")
+                )));
+            }
+            let changed_fields = table_changes.new_fields.iter().chain(
+                table_changes
+                    .modified_fields
+                    .iter()
+                    .map(|change| &change.field_name),
+            );
+            for field_name in changed_fields {
+                if let Some(field) = config
+                    .struct_config
+                    .fields
+                    .iter()
+                    .find(|field| field.output_name() == field_name)
+                {
+                    statements.push(self.generate_create_field(
+                        &table_changes.table_name,
+                        field,
+                        objects,
+                        enums,
+                    ));
+                }
+            }
+        }
+        Ok(statements)
+    }
+
+    /// Bring the database schema in line with `tables` in one call: export
+    /// the current schema, compare it with the one the configs describe and
+    /// apply only the new and modified definitions. Returns the changes found.
+    async fn migrate(
+        &self,
+        tables: &BTreeMap<String, TableConfig>,
+        objects: &BTreeMap<String, crate::types::StructConfig>,
+        enums: &BTreeMap<String, crate::types::TaggedUnion>,
+    ) -> Result<SchemaChanges> {
+        let current = self.parse_schema_export(&self.export_schema().await?)?;
+        let target = self.target_schema(tables, objects, enums).await?;
+        let changes = Comparator::compare(&current, &target)?;
+
+        let statements = self.generate_migration(&changes, tables, objects, enums)?;
+        if !statements.is_empty() {
+            tracing::info!(
+                provider = self.name(),
+                statement_count = statements.len(),
+                "Applying schema migration"
+            );
+            self.apply_schema(&statements).await?;
+        }
+        Ok(changes)
+    }
 }
 
 /// Transaction trait for atomic database operations
@@ -187,6 +328,41 @@ pub trait Transaction: Send + Sync {
     async fn execute(&self, query: &str) -> Result<Vec<serde_json::Value>>;
 }
 
+/// Run `f` against an open transaction, then commit it if `f` returned `Ok`
+/// and roll it back otherwise. A panic inside `f` rolls the transaction back
+/// before the panic is resumed. When rollback itself fails the error from `f`
+/// is still the one returned.
+///
+/// This is the entry point for `Box<dyn DatabaseProvider>`, where
+/// [`DatabaseProvider::execute_in_transaction`] is unavailable.
+pub async fn run_in_transaction<F, T>(transaction: Box<dyn Transaction>, f: F) -> Result<T>
+where
+    F: for<'t> FnOnce(&'t dyn Transaction) -> BoxFuture<'t, Result<T>> + Send,
+    T: Send,
+{
+    let outcome = AssertUnwindSafe(f(transaction.as_ref()))
+        .catch_unwind()
+        .await;
+    match outcome {
+        Ok(Ok(value)) => {
+            transaction.commit().await?;
+            Ok(value)
+        }
+        Ok(Err(e)) => {
+            if let Err(rollback_error) = transaction.rollback().await {
+                tracing::warn!(error = %rollback_error, "Failed to roll back transaction");
+            }
+            Err(e)
+        }
+        Err(panic) => {
+            if let Err(rollback_error) = transaction.rollback().await {
+                tracing::warn!(error = %rollback_error, "Failed to roll back transaction");
+            }
+            std::panic::resume_unwind(panic)
+        }
+    }
+}
+
 /// Database configuration for connecting to a database
 #[derive(Debug, Clone)]
 pub struct DatabaseConfig {
@@ -211,6 +387,12 @@ pub struct DatabaseConfig {
     /// Connection timeout in seconds
     pub timeout_secs: u64,
 
+    /// Number of connection retries before giving up
+    pub connect_retries: u32,
+
+    /// Delay before the first retry in milliseconds, doubled after each attempt
+    pub connect_retry_delay_ms: u64,
+
     /// SQL-specific: maximum connection pool size
     pub max_connections: Option<u32>,
 
@@ -231,6 +413,8 @@ impl Default for DatabaseConfig {
             username: None,
             password: None,
             timeout_secs: 30,
+            connect_retries: crate::schemasync::config::default_connect_retries(),
+            connect_retry_delay_ms: crate::schemasync::config::default_connect_retry_delay_ms(),
             max_connections: Some(10),
             min_connections: Some(1),
             schema: Some("public".to_string()),
@@ -319,3 +503,160 @@ pub async fn connect(config: &DatabaseConfig) -> Result<Box<dyn DatabaseProvider
     provider.connect(config).await?;
     Ok(provider)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Transaction that records every call made on it.
+    struct RecordingTransaction {
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl Transaction for RecordingTransaction {
+        async fn commit(self: Box<Self>) -> Result<()> {
+            self.calls.lock().unwrap().push("commit".to_string());
+            Ok(())
+        }
+
+        async fn rollback(self: Box<Self>) -> Result<()> {
+            self.calls.lock().unwrap().push("rollback".to_string());
+            Ok(())
+        }
+
+        async fn execute(&self, query: &str) -> Result<Vec<serde_json::Value>> {
+            self.calls.lock().unwrap().push(query.to_string());
+            Ok(vec![])
+        }
+    }
+
+    fn recording() -> (Box<dyn Transaction>, Arc<Mutex<Vec<String>>>) {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let transaction = RecordingTransaction {
+            calls: calls.clone(),
+        };
+        (Box::new(transaction), calls)
+    }
+
+    #[tokio::test]
+    async fn run_in_transaction_commits_on_ok() {
+        let (transaction, calls) = recording();
+        let value = run_in_transaction(transaction, |tx| {
+            async move {
+                tx.execute("CREATE post").await?;
+                Ok(7)
+            }
+            .boxed()
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(value, 7);
+        assert_eq!(*calls.lock().unwrap(), ["CREATE post", "commit"]);
+    }
+
+    #[tokio::test]
+    async fn run_in_transaction_rolls_back_on_err() {
+        let (transaction, calls) = recording();
+        let err = run_in_transaction(transaction, |tx| {
+            async move {
+                tx.execute("CREATE post").await?;
+                Err::<(), _>(EvenframeError::database("constraint violated"))
+            }
+            .boxed()
+        })
+        .await
+        .unwrap_err();
+
+        assert!(err.to_string().contains("constraint violated"), "{err}");
+        assert_eq!(*calls.lock().unwrap(), ["CREATE post", "rollback"]);
+    }
+
+    #[tokio::test]
+    async fn run_in_transaction_rolls_back_on_panic() {
+        let (transaction, calls) = recording();
+        let result = AssertUnwindSafe(run_in_transaction::<_, ()>(transaction, |_tx| {
+            async move { panic!("boom") }.boxed()
+        }))
+        .catch_unwind()
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(*calls.lock().unwrap(), ["rollback"]);
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[tokio::test]
+    async fn execute_in_transaction_surfaces_begin_errors() {
+        let provider = SurrealdbProvider::new();
+        let err = provider
+            .execute_in_transaction(|_tx| async move { Ok(()) }.boxed())
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("transactions"), "{err}");
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[tokio::test]
+    async fn trait_objects_run_transactions_through_run_in_transaction() {
+        let provider: Box<dyn DatabaseProvider> = Box::new(SurrealdbProvider::new());
+        let outcome: Result<Vec<serde_json::Value>> = async {
+            let transaction = provider.begin_transaction().await?;
+            run_in_transaction(transaction, |tx| {
+                async move { tx.execute("CREATE post").await }.boxed()
+            })
+            .await
+        }
+        .await;
+
+        let err = outcome.unwrap_err();
+        assert!(err.to_string().contains("transactions"), "{err}");
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn default_migration_rejects_unsupported_table_changes() {
+        use crate::schemasync::compare::{IndexDefinition, TableChanges};
+
+        let provider = sql::sqlite::SqliteProvider::new();
+        let tables = BTreeMap::from([("post".to_string(), TableConfig::default())]);
+        let changes = SchemaChanges {
+            new_tables: vec![],
+            removed_tables: vec![],
+            modified_tables: vec![TableChanges {
+                table_name: "post".to_string(),
+                new_fields: vec![],
+                removed_fields: vec![],
+                modified_fields: vec![],
+                permission_changed: true,
+                schema_type_changed: false,
+                new_events: vec![],
+                removed_events: vec![],
+                new_indexes: vec![IndexDefinition {
+                    name: "idx_post_title".to_string(),
+                    columns: vec!["title".to_string()],
+                    unique: true,
+                }],
+                removed_indexes: vec![],
+                new_required_fields: vec![],
+                changefeed_changed: false,
+            }],
+            new_accesses: vec![],
+            removed_accesses: vec![],
+            modified_accesses: vec![],
+        };
+
+        let err = provider
+            .generate_migration(&changes, &tables, &BTreeMap::new(), &BTreeMap::new())
+            .unwrap_err();
+
+        assert!(
+            err.to_string()
+                .contains("table 'post': it has new indexes, changed permissions"),
+            "{err}"
+        );
+    }
+}
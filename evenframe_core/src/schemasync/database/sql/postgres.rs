@@ -605,40 +605,27 @@ impl DatabaseProvider for PostgresProvider {
         record_id: &str,
         direction: RelationshipDirection,
     ) -> Result<Vec<Relationship>> {
-        let query = match direction {
-            RelationshipDirection::Outgoing => {
-                format!(
-                    "SELECT * FROM \"{}\" WHERE from_id = '{}'",
-                    edge_table, record_id
-                )
-            }
-            RelationshipDirection::Incoming => {
-                format!(
-                    "SELECT * FROM \"{}\" WHERE to_id = '{}'",
-                    edge_table, record_id
-                )
-            }
-            RelationshipDirection::Both => {
-                format!(
-                    "SELECT * FROM \"{}\" WHERE from_id = '{}' OR to_id = '{}'",
-                    edge_table, record_id, record_id
-                )
-            }
-        };
-
         let pool = self
             .pool
             .as_ref()
             .ok_or_else(|| EvenframeError::database("Not connected to PostgreSQL"))?;
 
-        let rows = sqlx::query(&query)
-            .fetch_all(pool)
-            .await
-            .map_err(|e| EvenframeError::database(format!("Failed to get relationships: {e}")))?;
+        let mut relationships = Vec::new();
+        for &side in direction.sides() {
+            let column = match side {
+                RelationshipDirection::Incoming => "to_id",
+                RelationshipDirection::Outgoing | RelationshipDirection::Both => "from_id",
+            };
+            let query = format!(
+                "SELECT * FROM \"{}\" WHERE {} = '{}'",
+                edge_table, column, record_id
+            );
 
-        let relationships = rows
-            .iter()
-            .filter_map(|row| {
+            let rows = sqlx::query(&query).fetch_all(pool).await.map_err(|e| {
+                EvenframeError::database(format!("Failed to get relationships: {e}"))
+            })?;
+
+            relationships.extend(rows.iter().filter_map(|row| {
                 let id: String = row.try_get::<String, _>("id").ok()?;
                 let from_id: String = row.try_get::<String, _>("from_id").ok()?;
                 let to_id: String = row.try_get::<String, _>("to_id").ok()?;
@@ -647,10 +634,11 @@ impl DatabaseProvider for PostgresProvider {
                     id,
                     from_id,
                     to_id,
+                    direction: side,
                     data: None,
                 })
-            })
-            .collect();
+            }));
+        }
 
         Ok(relationships)
     }
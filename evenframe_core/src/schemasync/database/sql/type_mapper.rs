@@ -38,7 +38,7 @@ impl<'a> TypeMapper for PostgresTypeMapper<'a> {
             FieldType::F64 => "DOUBLE PRECISION".to_string(),
             FieldType::Unit => "".to_string(), // Skip
             FieldType::Option(inner) => self.field_type_to_native(inner),
-            FieldType::Vec(inner) => {
+            FieldType::Vec(inner) | FieldType::Set(inner) | FieldType::Array(inner, _) => {
                 // Use native array for primitives, JSONB for complex types
                 if is_primitive(inner) {
                     format!("{}[]", self.field_type_to_native(inner))
@@ -50,8 +50,14 @@ impl<'a> TypeMapper for PostgresTypeMapper<'a> {
             FieldType::Struct(_) => "JSONB".to_string(),
             FieldType::HashMap(_, _) => "JSONB".to_string(),
             FieldType::BTreeMap(_, _) => "JSONB".to_string(),
+            FieldType::Json => "JSONB".to_string(),
+            FieldType::Literal(value) => {
+                self.field_type_to_native(&FieldType::literal_base_type(value))
+            }
+            FieldType::Bytes => "BYTEA".to_string(),
+            FieldType::Geometry(_) => "JSONB".to_string(), // GeoJSON
             FieldType::RecordLink(_) => "UUID".to_string(), // Foreign key
-            FieldType::Other(name) => {
+            FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
                 if let Some(ftc) = self.registry.lookup(name) {
                     ftc.postgres.clone()
                 } else {
@@ -74,10 +80,13 @@ impl<'a> TypeMapper for PostgresTypeMapper<'a> {
             }
             .to_string(),
             FieldType::Vec(_)
+            | FieldType::Set(_)
+            | FieldType::Array(_, _)
             | FieldType::Tuple(_)
             | FieldType::Struct(_)
             | FieldType::HashMap(_, _)
-            | FieldType::BTreeMap(_, _) => {
+            | FieldType::BTreeMap(_, _)
+            | FieldType::Json => {
                 // Use JSON format
                 format!("'{}'::JSONB", value.to_string().replace('\'', "''"))
             }
@@ -128,7 +137,10 @@ impl<'a> TypeMapper for PostgresTypeMapper<'a> {
     }
 
     fn format_array(&self, field_type: &FieldType, values: &[serde_json::Value]) -> String {
-        let inner = if let FieldType::Vec(inner) = field_type {
+        let inner = if let FieldType::Vec(inner)
+        | FieldType::Set(inner)
+        | FieldType::Array(inner, _) = field_type
+        {
             inner.as_ref()
         } else {
             &FieldType::String
@@ -192,13 +204,19 @@ impl<'a> TypeMapper for MysqlTypeMapper<'a> {
             FieldType::F64 => "DOUBLE".to_string(),
             FieldType::Unit => "".to_string(),
             FieldType::Option(inner) => self.field_type_to_native(inner),
-            FieldType::Vec(_) => "JSON".to_string(),
+            FieldType::Vec(_) | FieldType::Set(_) | FieldType::Array(_, _) => "JSON".to_string(),
             FieldType::Tuple(_) => "JSON".to_string(),
             FieldType::Struct(_) => "JSON".to_string(),
             FieldType::HashMap(_, _) => "JSON".to_string(),
             FieldType::BTreeMap(_, _) => "JSON".to_string(),
+            FieldType::Json => "JSON".to_string(),
+            FieldType::Literal(value) => {
+                self.field_type_to_native(&FieldType::literal_base_type(value))
+            }
+            FieldType::Bytes => "LONGBLOB".to_string(),
+            FieldType::Geometry(_) => "JSON".to_string(), // GeoJSON
             FieldType::RecordLink(_) => "VARCHAR(255)".to_string(),
-            FieldType::Other(name) => {
+            FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
                 if let Some(ftc) = self.registry.lookup(name) {
                     ftc.mysql.clone()
                 } else {
@@ -221,10 +239,13 @@ impl<'a> TypeMapper for MysqlTypeMapper<'a> {
             }
             .to_string(),
             FieldType::Vec(_)
+            | FieldType::Set(_)
+            | FieldType::Array(_, _)
             | FieldType::Tuple(_)
             | FieldType::Struct(_)
             | FieldType::HashMap(_, _)
-            | FieldType::BTreeMap(_, _) => {
+            | FieldType::BTreeMap(_, _)
+            | FieldType::Json => {
                 format!("'{}'", value.to_string().replace('\'', "''"))
             }
             FieldType::Option(inner) => {
@@ -323,13 +344,19 @@ impl<'a> TypeMapper for SqliteTypeMapper<'a> {
             FieldType::F32 | FieldType::F64 => "REAL".to_string(),
             FieldType::Unit => "".to_string(),
             FieldType::Option(inner) => self.field_type_to_native(inner),
-            FieldType::Vec(_) => "TEXT".to_string(), // JSON string
+            FieldType::Vec(_) | FieldType::Set(_) | FieldType::Array(_, _) => "TEXT".to_string(), // JSON string
             FieldType::Tuple(_) => "TEXT".to_string(),
             FieldType::Struct(_) => "TEXT".to_string(),
             FieldType::HashMap(_, _) => "TEXT".to_string(),
             FieldType::BTreeMap(_, _) => "TEXT".to_string(),
+            FieldType::Json => "TEXT".to_string(), // JSON string
+            FieldType::Literal(value) => {
+                self.field_type_to_native(&FieldType::literal_base_type(value))
+            }
+            FieldType::Bytes => "BLOB".to_string(),
+            FieldType::Geometry(_) => "TEXT".to_string(), // GeoJSON string
             FieldType::RecordLink(_) => "TEXT".to_string(),
-            FieldType::Other(name) => {
+            FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
                 if let Some(ftc) = self.registry.lookup(name) {
                     ftc.sqlite.clone()
                 } else {
@@ -352,10 +379,13 @@ impl<'a> TypeMapper for SqliteTypeMapper<'a> {
             }
             .to_string(),
             FieldType::Vec(_)
+            | FieldType::Set(_)
+            | FieldType::Array(_, _)
             | FieldType::Tuple(_)
             | FieldType::Struct(_)
             | FieldType::HashMap(_, _)
-            | FieldType::BTreeMap(_, _) => {
+            | FieldType::BTreeMap(_, _)
+            | FieldType::Json => {
                 format!("'{}'", value.to_string().replace('\'', "''"))
             }
             FieldType::Option(inner) => {
@@ -442,3 +472,152 @@ fn is_primitive(field_type: &FieldType) -> bool {
             | FieldType::F64
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ForeignTypeConfig;
+    use std::collections::BTreeMap;
+
+    fn registry() -> ForeignTypeRegistry {
+        let mut foreign_types = BTreeMap::new();
+        foreign_types.insert(
+            "DateTime".to_string(),
+            ForeignTypeConfig {
+                rust_type_names: vec!["DateTime".to_string()],
+                postgres: "TIMESTAMPTZ".to_string(),
+                mysql: "DATETIME".to_string(),
+                sqlite: "TEXT".to_string(),
+                ..Default::default()
+            },
+        );
+        ForeignTypeRegistry::from_config(&foreign_types)
+    }
+
+    fn map_of_strings() -> FieldType {
+        FieldType::HashMap(Box::new(FieldType::String), Box::new(FieldType::String))
+    }
+
+    #[test]
+    fn postgres_maps_representative_types() {
+        let registry = registry();
+        let mapper = PostgresTypeMapper::new(&registry);
+        let native = |ty: FieldType| mapper.field_type_to_native(&ty);
+
+        assert_eq!(native(FieldType::String), "TEXT");
+        assert_eq!(native(FieldType::I64), "BIGINT");
+        assert_eq!(native(FieldType::F64), "DOUBLE PRECISION");
+        assert_eq!(native(FieldType::Bool), "BOOLEAN");
+        assert_eq!(
+            native(FieldType::Other("DateTime".to_string())),
+            "TIMESTAMPTZ"
+        );
+        assert_eq!(native(map_of_strings()), "JSONB");
+        assert_eq!(native(FieldType::Struct(vec![])), "JSONB");
+        assert_eq!(native(FieldType::Json), "JSONB");
+        // primitive lists use native arrays, anything else is stored as JSONB
+        assert_eq!(native(FieldType::Vec(Box::new(FieldType::I64))), "BIGINT[]");
+        assert_eq!(
+            native(FieldType::Vec(Box::new(FieldType::String))),
+            "TEXT[]"
+        );
+        assert_eq!(native(FieldType::Vec(Box::new(map_of_strings()))), "JSONB");
+        // nullability is applied by the provider; the column type is the inner one
+        assert_eq!(
+            native(FieldType::Option(Box::new(FieldType::I64))),
+            "BIGINT"
+        );
+
+        assert_eq!(
+            mapper.format_value(&FieldType::Bool, &serde_json::json!(true)),
+            "TRUE"
+        );
+        assert_eq!(
+            mapper.format_value(
+                &FieldType::Option(Box::new(FieldType::String)),
+                &serde_json::Value::Null
+            ),
+            "NULL"
+        );
+        assert_eq!(
+            mapper.format_value(&map_of_strings(), &serde_json::json!({ "k": "it's" })),
+            "'{\"k\":\"it''s\"}'::JSONB"
+        );
+        assert_eq!(
+            mapper.format_array(
+                &FieldType::Vec(Box::new(FieldType::I64)),
+                &[serde_json::json!(1), serde_json::json!(2)]
+            ),
+            "ARRAY[1, 2]"
+        );
+        assert_eq!(mapper.quote_char(), '"');
+    }
+
+    #[test]
+    fn mysql_maps_representative_types() {
+        let registry = registry();
+        let mapper = MysqlTypeMapper::new(&registry);
+        let native = |ty: FieldType| mapper.field_type_to_native(&ty);
+
+        assert_eq!(native(FieldType::String), "TEXT");
+        assert_eq!(native(FieldType::I64), "BIGINT");
+        assert_eq!(native(FieldType::F64), "DOUBLE");
+        assert_eq!(native(FieldType::Bool), "TINYINT(1)");
+        assert_eq!(native(FieldType::Other("DateTime".to_string())), "DATETIME");
+        assert_eq!(native(map_of_strings()), "JSON");
+        assert_eq!(native(FieldType::Struct(vec![])), "JSON");
+        assert_eq!(native(FieldType::Json), "JSON");
+        assert_eq!(
+            native(FieldType::RecordLink(Box::new(FieldType::Other(
+                "User".to_string()
+            )))),
+            "VARCHAR(255)"
+        );
+        // nullability is applied by the provider; the column type is the inner one
+        assert_eq!(
+            native(FieldType::Option(Box::new(FieldType::I64))),
+            "BIGINT"
+        );
+
+        assert_eq!(
+            mapper.format_value(&FieldType::Bool, &serde_json::json!(true)),
+            "1"
+        );
+        assert_eq!(
+            mapper.format_value(
+                &FieldType::Option(Box::new(FieldType::String)),
+                &serde_json::Value::Null
+            ),
+            "NULL"
+        );
+        assert_eq!(mapper.quote_char(), '`');
+    }
+
+    #[test]
+    fn sqlite_maps_representative_types() {
+        let registry = registry();
+        let mapper = SqliteTypeMapper::new(&registry);
+        let native = |ty: FieldType| mapper.field_type_to_native(&ty);
+
+        assert_eq!(native(FieldType::String), "TEXT");
+        assert_eq!(native(FieldType::I64), "INTEGER");
+        assert_eq!(native(FieldType::F64), "REAL");
+        assert_eq!(native(FieldType::Bool), "INTEGER");
+        assert_eq!(native(FieldType::Other("DateTime".to_string())), "TEXT");
+        assert_eq!(native(map_of_strings()), "TEXT");
+        assert_eq!(native(FieldType::Struct(vec![])), "TEXT");
+        assert_eq!(native(FieldType::Json), "TEXT");
+        assert_eq!(native(FieldType::U128), "TEXT");
+        assert_eq!(native(FieldType::Option(Box::new(FieldType::F32))), "REAL");
+
+        assert_eq!(
+            mapper.format_value(&FieldType::String, &serde_json::json!("it's")),
+            "'it''s'"
+        );
+        assert_eq!(
+            mapper.format_value(&map_of_strings(), &serde_json::json!({ "k": "v" })),
+            "'{\"k\":\"v\"}'"
+        );
+        assert_eq!(mapper.quote_char(), '"');
+    }
+}
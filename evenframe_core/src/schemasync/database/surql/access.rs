@@ -1,5 +1,7 @@
 use crate::evenframe_log;
+use crate::schemasync::compare::SchemaChanges;
 use crate::schemasync::config::{AccessConfig, AccessType, AccessesSource};
+use std::collections::BTreeMap;
 use std::env;
 use surrealdb::{
     Surreal,
@@ -20,12 +22,13 @@ pub fn generate_access_definition(access_config: &AccessConfig) -> String {
     match &access_config.access_type {
         AccessType::Record => {
             tracing::trace!(table = %access_config.table_name, "Generating RECORD type access");
+            let table = crate::schemasync::surql_ident(&access_config.table_name);
             query.push_str(&format!(
                 " TYPE RECORD
     SIGNUP ( CREATE {} SET email = $email, password = crypto::argon2::generate($password) )
     SIGNIN ( SELECT * FROM {} WHERE email = $email AND crypto::argon2::compare(password, $password) )
     DURATION FOR TOKEN 15m, FOR SESSION 6h",
-                access_config.table_name, access_config.table_name
+                table, table
             ));
         }
         AccessType::Jwt => {
@@ -56,6 +59,100 @@ pub fn generate_access_definition(access_config: &AccessConfig) -> String {
     tracing::trace!(query_length = query.len(), "Access definition generated");
     query
 }
+
+/// Split a block of SurrealQL into its `DEFINE ACCESS` statements, keyed by
+/// access name. Statements are split on `;` outside parentheses, braces and
+/// string literals, since SIGNUP/SIGNIN clauses can contain their own.
+fn define_access_statements(access_query: &str) -> BTreeMap<String, String> {
+    let mut statements = Vec::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in access_query.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match (quote, c) {
+            (Some(_), '\\') => escaped = true,
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(' | '{') => depth += 1,
+            (None, ')' | '}') => depth = depth.saturating_sub(1),
+            (None, ';') if depth == 0 => {
+                statements.push(access_query[start..=i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    statements.push(access_query[start..].trim());
+
+    statements
+        .into_iter()
+        .filter_map(|statement| {
+            let rest = statement.strip_prefix("DEFINE ACCESS")?.trim_start();
+            let rest = rest
+                .strip_prefix("OVERWRITE")
+                .or_else(|| rest.strip_prefix("IF NOT EXISTS"))
+                .map(str::trim_start)
+                .unwrap_or(rest);
+            let name = rest.split_whitespace().next()?.trim_matches('`');
+            let statement = if statement.ends_with(';') {
+                statement.to_string()
+            } else {
+                format!("{statement};")
+            };
+            Some((name.to_string(), statement))
+        })
+        .collect()
+}
+
+/// Generate the statements that bring the database's accesses in line with
+/// `access_query`: new accesses are defined, and accesses with changes other
+/// than key rotation are removed and defined again from their new definition.
+/// Accesses whose only changes are rotated JWT or issuer keys are left alone.
+pub fn generate_access_statements(schema_changes: &SchemaChanges, access_query: &str) -> String {
+    let definitions = define_access_statements(access_query);
+    let definition = |name: &str| {
+        let statement = definitions.get(name);
+        if statement.is_none() {
+            tracing::warn!(access_name = %name, "No DEFINE ACCESS statement found for access");
+        }
+        statement
+    };
+
+    let mut output = String::new();
+    for access_name in &schema_changes.new_accesses {
+        if let Some(statement) = definition(access_name) {
+            output.push_str(statement);
+            output.push('\n');
+        }
+    }
+    for access_change in &schema_changes.modified_accesses {
+        if access_change
+            .changes
+            .iter()
+            .all(|change| change.is_ignorable())
+        {
+            tracing::debug!(
+                access_name = %access_change.access_name,
+                "Skipping access with only key rotation changes"
+            );
+            continue;
+        }
+        if let Some(statement) = definition(&access_change.access_name) {
+            output.push_str(&format!(
+                "REMOVE ACCESS IF EXISTS {} ON DATABASE;\n{statement}\n",
+                access_change.access_name
+            ));
+        }
+    }
+    output
+}
+
 pub async fn execute_access_query(
     db: &Surreal<Client>,
     access_query: &str,
@@ -108,8 +205,11 @@ pub async fn setup_access_definitions(
             let mut query = String::new();
             for access in accesses {
                 tracing::trace!(access_name = %access.name, "Processing access definition");
-                query = generate_access_definition(access);
-                if let Err(e) = new_schema.query(&query).await {
+                let definition = generate_access_definition(access);
+                if definition.is_empty() {
+                    continue;
+                }
+                if let Err(e) = new_schema.query(&definition).await {
                     tracing::error!(
                         access_name = %access.name,
                         error = %e,
@@ -118,6 +218,8 @@ pub async fn setup_access_definitions(
                 } else {
                     tracing::debug!(access_name = %access.name, "Access created successfully");
                 }
+                query.push_str(&definition);
+                query.push('\n');
             }
             query
         }
@@ -149,3 +251,81 @@ pub async fn setup_access_definitions(
     evenframe_log!(&access_query, "access_query.surql");
     Ok(access_query)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemasync::compare::{AccessChange, AccessChangeType};
+
+    const ACCESS_QUERY: &str = "\
+DEFINE ACCESS OVERWRITE user ON DATABASE TYPE RECORD
+    SIGNUP ( CREATE user SET email = $email; )
+    SIGNIN ( SELECT * FROM user WHERE email = $email );
+DEFINE ACCESS OVERWRITE api ON DATABASE TYPE JWT ALGORITHM HS256 KEY 'rotated;key';
+DEFINE ACCESS OVERWRITE admin ON DATABASE TYPE BEARER FOR RECORD;
+";
+
+    fn schema_changes(
+        new_accesses: &[&str],
+        modified_accesses: Vec<(&str, AccessChangeType)>,
+    ) -> SchemaChanges {
+        SchemaChanges {
+            new_tables: Vec::new(),
+            removed_tables: Vec::new(),
+            modified_tables: Vec::new(),
+            new_accesses: new_accesses.iter().map(|name| name.to_string()).collect(),
+            removed_accesses: Vec::new(),
+            modified_accesses: modified_accesses
+                .into_iter()
+                .map(|(name, change)| AccessChange {
+                    access_name: name.to_string(),
+                    changes: vec![change],
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn define_access_statements_splits_on_top_level_semicolons() {
+        let statements = define_access_statements(ACCESS_QUERY);
+        assert_eq!(
+            statements.keys().collect::<Vec<_>>(),
+            ["admin", "api", "user"]
+        );
+        assert!(statements["user"].ends_with("WHERE email = $email );"));
+        assert!(statements["api"].ends_with("KEY 'rotated;key';"));
+    }
+
+    #[test]
+    fn signin_change_recreates_access_but_key_rotation_does_not() {
+        let changes = schema_changes(
+            &[],
+            vec![
+                ("user", AccessChangeType::SigninChanged),
+                ("api", AccessChangeType::JwtKeyChanged),
+            ],
+        );
+
+        assert_eq!(
+            generate_access_statements(&changes, ACCESS_QUERY),
+            "REMOVE ACCESS IF EXISTS user ON DATABASE;\n\
+             DEFINE ACCESS OVERWRITE user ON DATABASE TYPE RECORD\n    \
+             SIGNUP ( CREATE user SET email = $email; )\n    \
+             SIGNIN ( SELECT * FROM user WHERE email = $email );\n"
+        );
+    }
+
+    #[test]
+    fn new_accesses_are_defined_without_removal() {
+        let changes = schema_changes(&["admin"], Vec::new());
+
+        assert_eq!(
+            generate_access_statements(&changes, ACCESS_QUERY),
+            "DEFINE ACCESS OVERWRITE admin ON DATABASE TYPE BEARER FOR RECORD;\n"
+        );
+        assert_eq!(
+            generate_access_statements(&schema_changes(&[], Vec::new()), ACCESS_QUERY),
+            ""
+        );
+    }
+}
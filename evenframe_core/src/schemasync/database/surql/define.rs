@@ -1,8 +1,9 @@
 use crate::{
-    schemasync::table::TableConfig,
-    types::{StructConfig, TaggedUnion},
+    error::EvenframeError,
+    schemasync::table::{TableConfig, surql_ident},
+    types::{SEARCH_ANALYZERS, StructConfig, TaggedUnion},
 };
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use tracing::{debug, error, info, trace};
 
 pub fn generate_define_statements(
@@ -27,6 +28,9 @@ pub fn generate_define_statements(
 
     let mut output = String::new();
     debug!(table_name = %table_name, "Starting statement generation");
+    let table = surql_ident(table_name);
+    // Index names stay plain identifiers even for kebab-case tables
+    let index_prefix = table_name.replace('-', "_");
 
     {
         let table_type = if let Some(relation) = &table_config.relation {
@@ -36,8 +40,15 @@ pub fn generate_define_statements(
                 to = ?relation.to,
                 "Table is a relation."
             );
-            let from_clause = relation.from.join(" | ");
-            let to_clause = relation.to.join(" | ");
+            let join = |tables: &[String]| {
+                tables
+                    .iter()
+                    .map(|t| surql_ident(t))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            };
+            let from_clause = join(&relation.from);
+            let to_clause = join(&relation.to);
             format!("RELATION FROM {} TO {}", from_clause, to_clause)
         } else {
             debug!(table_name = %table_name, "Table is normal type");
@@ -65,8 +76,13 @@ pub fn generate_define_statements(
             .unwrap_or("FULL");
 
         output.push_str(&format!(
-            "DEFINE TABLE OVERWRITE {table_name} SCHEMAFULL TYPE {table_type} CHANGEFEED 3d PERMISSIONS FOR select {select_permissions} FOR update {update_permissions} FOR create {create_permissions} FOR delete {delete_permissions};\n"
+            "DEFINE TABLE OVERWRITE {table} SCHEMAFULL TYPE {table_type} CHANGEFEED {} PERMISSIONS FOR select {select_permissions} FOR update {update_permissions} FOR create {create_permissions} FOR delete {delete_permissions}",
+            table_config.changefeed()
         ));
+        if let Some(doc) = &table_config.struct_config.doc {
+            output.push_str(&comment_clause(doc));
+        }
+        output.push_str(";\n");
     }
 
     debug!(table_name = %table_name, field_count = table_config.struct_config.fields.len(), "Processing table fields");
@@ -95,31 +111,65 @@ pub fn generate_define_statements(
                         );
                         // Continue with a fallback definition
                         output.push_str(&format!(
-                            "DEFINE FIELD OVERWRITE {} ON TABLE {} TYPE any PERMISSIONS FULL;\n",
-                            table_field.field_name, table_name
+                            "DEFINE FIELD {} {} ON TABLE {} TYPE any PERMISSIONS FULL;\n",
+                            table_config.define_field_mode(),
+                            table_field.output_name(),
+                            table
                         ));
                     }
                 }
             } else {
                 output.push_str(&format!(
-                    "DEFINE FIELD OVERWRITE {} ON TABLE {} TYPE any PERMISSIONS FULL;\n",
-                    table_field.field_name, table_name
+                    "DEFINE FIELD {} {} ON TABLE {} TYPE any PERMISSIONS FULL{};\n",
+                    table_config.define_field_mode(),
+                    table_field.output_name(),
+                    table,
+                    table_field
+                        .comment()
+                        .map(comment_clause)
+                        .unwrap_or_default()
                 ))
             }
         }
     }
 
-    // Generate DEFINE INDEX statements for unique fields
+    // Generate DEFINE INDEX statements for unique and indexed fields
+    for table_field in &table_config.struct_config.fields {
+        if table_field.unique || table_field.indexed {
+            let unique_kw = if table_field.unique { " UNIQUE" } else { "" };
+            debug!(
+                table_name = %table_name,
+                field_name = %table_field.field_name,
+                unique = table_field.unique,
+                "Generating index for field"
+            );
+            output.push_str(&format!(
+                "DEFINE INDEX OVERWRITE idx_{}_{} ON TABLE {} FIELDS {}{};\n",
+                index_prefix,
+                table_field.output_name(),
+                table,
+                table_field.output_name(),
+                unique_kw
+            ));
+        }
+    }
+
+    // Generate full-text search indexes for #[evenframe(search)] fields
     for table_field in &table_config.struct_config.fields {
-        if table_field.unique {
+        if let Some(analyzer) = &table_field.search_analyzer {
             debug!(
                 table_name = %table_name,
                 field_name = %table_field.field_name,
-                "Generating unique index for field"
+                analyzer = %analyzer,
+                "Generating search index for field"
             );
             output.push_str(&format!(
-                "DEFINE INDEX OVERWRITE idx_{}_{} ON TABLE {} FIELDS {} UNIQUE;\n",
-                table_name, table_field.field_name, table_name, table_field.field_name
+                "DEFINE INDEX OVERWRITE idx_{}_{}_search ON TABLE {} FIELDS {} FULLTEXT ANALYZER {} BM25;\n",
+                index_prefix,
+                table_field.output_name(),
+                table,
+                table_field.output_name(),
+                analyzer
             ));
         }
     }
@@ -138,7 +188,7 @@ pub fn generate_define_statements(
         );
         output.push_str(&format!(
             "DEFINE INDEX OVERWRITE idx_{}_{} ON TABLE {} FIELDS {}{};\n",
-            table_name, joined_name, table_name, joined_fields, unique_kw
+            index_prefix, joined_name, table, joined_fields, unique_kw
         ));
     }
 
@@ -151,12 +201,9 @@ pub fn generate_define_statements(
     }
 
     for event in &table_config.events {
-        let statement = event.statement.trim();
+        let statement = event.define_statement(table_name);
         trace!(table_name = %table_name, "Adding event statement: {}", statement);
-        output.push_str(statement);
-        if !statement.ends_with(';') {
-            output.push(';');
-        }
+        output.push_str(&statement);
         output.push('\n');
     }
 
@@ -165,11 +212,81 @@ pub fn generate_define_statements(
     output
 }
 
+/// Renders a ` COMMENT '...'` clause, escaping backslashes and quotes so the
+/// text survives as a SurrealQL string literal.
+pub fn comment_clause(text: &str) -> String {
+    format!(
+        " COMMENT '{}'",
+        text.replace('\\', "\\\\").replace('\'', "\\'")
+    )
+}
+
+/// Generates one `DEFINE ANALYZER` statement for each of the
+/// [`SEARCH_ANALYZERS`] a table's search field uses.
+pub fn generate_analyzer_statements(tables: &BTreeMap<String, TableConfig>) -> String {
+    let used: BTreeSet<&str> = tables
+        .values()
+        .flat_map(|table| &table.effective().struct_config.fields)
+        .filter_map(|field| field.search_analyzer.as_deref())
+        .collect();
+    SEARCH_ANALYZERS
+        .iter()
+        .filter(|(name, _)| used.contains(name))
+        .map(|(name, definition)| format!("DEFINE ANALYZER OVERWRITE {name} {definition};\n"))
+        .collect()
+}
+
+/// A field whose define statement could not be generated.
+#[derive(Debug)]
+pub struct DefineStatementError {
+    pub table_name: String,
+    pub field_name: String,
+    pub error: EvenframeError,
+}
+
+/// Run field define-statement generation for every table without a database
+/// connection, collecting the failures that [`generate_define_statements`]
+/// would otherwise paper over with a `TYPE any` fallback.
+pub fn collect_define_statement_errors(
+    tables: &BTreeMap<String, TableConfig>,
+    objects: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> Vec<DefineStatementError> {
+    let mut errors = Vec::new();
+    for (table_name, table_config) in tables {
+        for table_field in &table_config.struct_config.fields {
+            if table_field.edge_config.is_some()
+                || table_field.define_config.is_none()
+                || matches!(table_field.field_name.as_str(), "in" | "out" | "id")
+            {
+                continue;
+            }
+            if let Err(error) = table_field.generate_define_statement(
+                enums.clone(),
+                objects.clone(),
+                tables.clone(),
+                table_name,
+                registry,
+            ) {
+                errors.push(DefineStatementError {
+                    table_name: table_name.clone(),
+                    field_name: table_field.field_name.clone(),
+                    error,
+                });
+            }
+        }
+    }
+    errors
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::schemasync::{DefineConfig, EventConfig};
-    use crate::types::{FieldType, StructConfig, StructField, TaggedUnion};
+    use crate::types::{
+        DEFAULT_SEARCH_ANALYZER, FieldType, StructConfig, StructField, TaggedUnion,
+    };
 
     #[test]
     fn generate_define_statements_appends_events() {
@@ -180,6 +297,7 @@ mod tests {
                 fields: Vec::new(),
                 validators: Vec::new(),
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: crate::types::Pipeline::default(),
@@ -187,15 +305,18 @@ mod tests {
                 output_override: None,
                 raw_attributes: BTreeMap::new(),
             },
-            relation: None,
-            permissions: None,
-            mock_generation_config: None,
-            events: vec![EventConfig {
-                statement: "DEFINE EVENT user_change ON TABLE user WHEN true THEN { RETURN true };"
-                    .to_string(),
-            }],
-            indexes: vec![],
-            output_override: None,
+            events: vec![
+                EventConfig::from_statement(
+                    "DEFINE EVENT user_change ON TABLE user WHEN true THEN { RETURN true }",
+                ),
+                EventConfig {
+                    name: "user_audit".to_string(),
+                    when: "$event = \"UPDATE\"".to_string(),
+                    then: "CREATE audit SET user = $after.id".to_string(),
+                    statement: None,
+                },
+            ],
+            ..Default::default()
         };
 
         let query_details: BTreeMap<String, TableConfig> = BTreeMap::new();
@@ -212,8 +333,137 @@ mod tests {
             &crate::types::ForeignTypeRegistry::default(),
         );
 
-        assert!(statements.contains("DEFINE EVENT user_change ON TABLE user"));
-        assert!(statements.trim().ends_with(';'));
+        assert!(
+            statements.contains(
+                "DEFINE EVENT user_change ON TABLE user WHEN true THEN { RETURN true };\n"
+            )
+        );
+        assert!(statements.contains(
+            "DEFINE EVENT OVERWRITE user_audit ON TABLE user WHEN $event = \"UPDATE\" THEN { CREATE audit SET user = $after.id };\n"
+        ));
+    }
+
+    fn table_permissions_clause(permissions: crate::schemasync::PermissionsConfig) -> String {
+        let table_config = TableConfig {
+            table_name: "post".to_string(),
+            struct_config: StructConfig {
+                struct_name: "Post".to_string(),
+                ..StructConfig::default()
+            },
+            permissions: Some(permissions),
+            ..Default::default()
+        };
+        let statements = generate_define_statements(
+            "post",
+            &table_config,
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+            false,
+            &crate::types::ForeignTypeRegistry::default(),
+        );
+        let table_statement = statements.lines().next().unwrap();
+        let (_, clause) = table_statement.split_once(" PERMISSIONS ").unwrap();
+        clause.to_string()
+    }
+
+    #[test]
+    fn permission_presets_generate_table_permissions() {
+        use crate::schemasync::PermissionsConfig;
+
+        assert_eq!(
+            table_permissions_clause(PermissionsConfig::public_read()),
+            "FOR select FULL FOR update WHERE $auth.id != NONE \
+             FOR create WHERE $auth.id != NONE FOR delete WHERE $auth.id != NONE;"
+        );
+        assert_eq!(
+            table_permissions_clause(PermissionsConfig::authenticated_only()),
+            "FOR select WHERE $auth.id != NONE FOR update WHERE $auth.id != NONE \
+             FOR create WHERE $auth.id != NONE FOR delete WHERE $auth.id != NONE;"
+        );
+        assert_eq!(
+            table_permissions_clause(PermissionsConfig::owner_based("author")),
+            "FOR select WHERE author = $auth.id FOR update WHERE author = $auth.id \
+             FOR create WHERE author = $auth.id FOR delete WHERE author = $auth.id;"
+        );
+    }
+
+    #[test]
+    fn changefeed_sets_table_retention() {
+        let table_statement = |changefeed: Option<&str>| {
+            let table_config = TableConfig {
+                table_name: "audit".to_string(),
+                struct_config: StructConfig {
+                    struct_name: "Audit".to_string(),
+                    ..StructConfig::default()
+                },
+                changefeed: changefeed.map(str::to_string),
+                ..Default::default()
+            };
+            generate_define_statements(
+                "audit",
+                &table_config,
+                &BTreeMap::new(),
+                &BTreeMap::new(),
+                &BTreeMap::new(),
+                false,
+                &crate::types::ForeignTypeRegistry::default(),
+            )
+        };
+
+        let custom = table_statement(Some("7d"));
+        assert!(
+            custom.starts_with(
+                "DEFINE TABLE OVERWRITE audit SCHEMAFULL TYPE NORMAL CHANGEFEED 7d PERMISSIONS"
+            ),
+            "{custom}"
+        );
+        let default = table_statement(None);
+        assert!(default.contains(" CHANGEFEED 3d PERMISSIONS"), "{default}");
+    }
+
+    #[test]
+    fn no_overwrite_tables_define_fields_if_not_exists() {
+        let statements = |no_overwrite: bool| {
+            let table_config = TableConfig {
+                table_name: "note".to_string(),
+                struct_config: StructConfig {
+                    struct_name: "Note".to_string(),
+                    fields: vec![StructField {
+                        field_name: "body".to_string(),
+                        field_type: FieldType::String,
+                        define_config: Some(DefineConfig::default()),
+                        ..StructField::default()
+                    }],
+                    ..StructConfig::default()
+                },
+                no_overwrite,
+                ..Default::default()
+            };
+            let query_details = BTreeMap::from([("note".to_string(), table_config.clone())]);
+            generate_define_statements(
+                "note",
+                &table_config,
+                &query_details,
+                &BTreeMap::new(),
+                &BTreeMap::new(),
+                false,
+                &crate::types::ForeignTypeRegistry::default(),
+            )
+        };
+
+        let overwrite = statements(false);
+        assert!(
+            overwrite.contains("DEFINE FIELD OVERWRITE body ON TABLE note"),
+            "{overwrite}"
+        );
+
+        let preserved = statements(true);
+        assert!(
+            preserved.contains("DEFINE FIELD IF NOT EXISTS body ON TABLE note"),
+            "{preserved}"
+        );
+        assert!(!preserved.contains("DEFINE FIELD OVERWRITE"), "{preserved}");
     }
 
     #[test]
@@ -227,24 +477,21 @@ mod tests {
                 select_permissions: Some("FULL".to_string()),
                 update_permissions: Some("FULL".to_string()),
                 create_permissions: Some("FULL".to_string()),
-                data_type: None,
-                should_skip: false,
-                default: None,
-                default_always: None,
-                value: None,
-                assert: None,
-                readonly: None,
                 flexible: Some(false),
                 computed: Some("string::uppercase($value.name)".to_string()),
-                comment: None,
+                ..Default::default()
             }),
             format: None,
             validators: Vec::new(),
             always_regenerate: false,
             doccom: None,
+            doc: None,
             annotations: vec![],
             unique: false,
+            indexed: false,
+            search_analyzer: None,
             mock_plugin: None,
+            rename: None,
             output_override: None,
             raw_attributes: BTreeMap::new(),
         };
@@ -278,24 +525,22 @@ mod tests {
                 select_permissions: Some("FULL".to_string()),
                 update_permissions: Some("FULL".to_string()),
                 create_permissions: Some("FULL".to_string()),
-                data_type: None,
-                should_skip: false,
-                default: None,
-                default_always: None,
-                value: None,
-                assert: None,
-                readonly: None,
                 flexible: Some(false),
                 computed: Some("string::uppercase($value.name)".to_string()),
                 comment: Some("Auto-uppercased name".to_string()),
+                ..Default::default()
             }),
             format: None,
             validators: Vec::new(),
             always_regenerate: false,
             doccom: None,
+            doc: None,
             annotations: vec![],
             unique: false,
+            indexed: false,
+            search_analyzer: None,
             mock_plugin: None,
+            rename: None,
             output_override: None,
             raw_attributes: BTreeMap::new(),
         };
@@ -325,24 +570,22 @@ mod tests {
                 select_permissions: Some("FULL".to_string()),
                 update_permissions: Some("FULL".to_string()),
                 create_permissions: Some("FULL".to_string()),
-                data_type: None,
-                should_skip: false,
                 default: Some("''".to_string()),
-                default_always: None,
-                value: None,
-                assert: None,
-                readonly: None,
                 flexible: Some(false),
-                computed: None,
                 comment: Some("User email address".to_string()),
+                ..Default::default()
             }),
             format: None,
             validators: Vec::new(),
             always_regenerate: false,
             doccom: None,
+            doc: None,
             annotations: vec![],
             unique: false,
+            indexed: false,
+            search_analyzer: None,
             mock_plugin: None,
+            rename: None,
             output_override: None,
             raw_attributes: BTreeMap::new(),
         };
@@ -379,24 +622,20 @@ mod tests {
                             select_permissions: Some("FULL".to_string()),
                             update_permissions: Some("FULL".to_string()),
                             create_permissions: Some("FULL".to_string()),
-                            data_type: None,
-                            should_skip: false,
-                            default: None,
-                            default_always: None,
-                            value: None,
-                            assert: None,
-                            readonly: None,
                             flexible: Some(false),
-                            computed: None,
-                            comment: None,
+                            ..Default::default()
                         }),
                         format: None,
                         validators: Vec::new(),
                         always_regenerate: false,
                         doccom: None,
+                        doc: None,
                         annotations: vec![],
                         unique: true,
+                        indexed: false,
+                        search_analyzer: None,
                         mock_plugin: None,
+                        rename: None,
                         output_override: None,
                         raw_attributes: BTreeMap::new(),
                     },
@@ -408,30 +647,27 @@ mod tests {
                             select_permissions: Some("FULL".to_string()),
                             update_permissions: Some("FULL".to_string()),
                             create_permissions: Some("FULL".to_string()),
-                            data_type: None,
-                            should_skip: false,
-                            default: None,
-                            default_always: None,
-                            value: None,
-                            assert: None,
-                            readonly: None,
                             flexible: Some(false),
-                            computed: None,
-                            comment: None,
+                            ..Default::default()
                         }),
                         format: None,
                         validators: Vec::new(),
                         always_regenerate: false,
                         doccom: None,
+                        doc: None,
                         annotations: vec![],
                         unique: false,
+                        indexed: false,
+                        search_analyzer: None,
                         mock_plugin: None,
+                        rename: None,
                         output_override: None,
                         raw_attributes: BTreeMap::new(),
                     },
                 ],
                 validators: Vec::new(),
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: crate::types::Pipeline::default(),
@@ -439,12 +675,7 @@ mod tests {
                 output_override: None,
                 raw_attributes: BTreeMap::new(),
             },
-            relation: None,
-            permissions: None,
-            mock_generation_config: None,
-            events: vec![],
-            indexes: vec![],
-            output_override: None,
+            ..Default::default()
         };
 
         let query_details: BTreeMap<String, TableConfig> = BTreeMap::new();
@@ -483,24 +714,20 @@ mod tests {
                 select_permissions: Some("FULL".to_string()),
                 update_permissions: Some("FULL".to_string()),
                 create_permissions: Some("FULL".to_string()),
-                data_type: None,
-                should_skip: false,
-                default: None,
-                default_always: None,
-                value: None,
-                assert: None,
-                readonly: None,
                 flexible: Some(false),
-                computed: None,
-                comment: None,
+                ..Default::default()
             }),
             format: None,
             validators: Vec::new(),
             always_regenerate: false,
             doccom: None,
+            doc: None,
             annotations: vec![],
             unique: false,
+            indexed: false,
+            search_analyzer: None,
             mock_plugin: None,
+            rename: None,
             output_override: None,
             raw_attributes: BTreeMap::new(),
         };
@@ -516,6 +743,7 @@ mod tests {
                 ],
                 validators: Vec::new(),
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: crate::types::Pipeline::default(),
@@ -523,10 +751,6 @@ mod tests {
                 output_override: None,
                 raw_attributes: BTreeMap::new(),
             },
-            relation: None,
-            permissions: None,
-            mock_generation_config: None,
-            events: vec![],
             indexes: vec![
                 IndexConfig {
                     fields: vec!["user".to_string(), "message".to_string()],
@@ -537,7 +761,7 @@ mod tests {
                     unique: false,
                 },
             ],
-            output_override: None,
+            ..Default::default()
         };
 
         let query_details: BTreeMap<String, TableConfig> = BTreeMap::new();
@@ -569,4 +793,401 @@ mod tests {
             statements
         );
     }
+
+    #[test]
+    fn generate_define_statements_includes_field_level_index() {
+        dotenv::dotenv().ok();
+        let table_config = TableConfig {
+            table_name: "post".to_string(),
+            struct_config: StructConfig {
+                struct_name: "Post".to_string(),
+                fields: vec![
+                    StructField {
+                        field_name: "published_at".to_string(),
+                        field_type: FieldType::String,
+                        indexed: true,
+                        ..StructField::default()
+                    },
+                    StructField {
+                        field_name: "slug".to_string(),
+                        field_type: FieldType::String,
+                        unique: true,
+                        ..StructField::default()
+                    },
+                ],
+                ..StructConfig::default()
+            },
+            ..Default::default()
+        };
+
+        let statements = generate_define_statements(
+            "post",
+            &table_config,
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+            false,
+            &crate::types::ForeignTypeRegistry::default(),
+        );
+
+        assert!(
+            statements.contains(
+                "DEFINE INDEX OVERWRITE idx_post_published_at ON TABLE post FIELDS published_at;\n"
+            ),
+            "missing field-level index line; output was:\n{}",
+            statements
+        );
+        assert!(
+            statements.contains(
+                "DEFINE INDEX OVERWRITE idx_post_slug ON TABLE post FIELDS slug UNIQUE;\n"
+            ),
+            "missing field-level UNIQUE index line; output was:\n{}",
+            statements
+        );
+    }
+
+    fn search_tables(analyzer: &str) -> BTreeMap<String, TableConfig> {
+        let table_config = TableConfig {
+            table_name: "article".to_string(),
+            struct_config: StructConfig {
+                struct_name: "Article".to_string(),
+                fields: vec![StructField {
+                    field_name: "body".to_string(),
+                    field_type: FieldType::String,
+                    search_analyzer: Some(analyzer.to_string()),
+                    ..StructField::default()
+                }],
+                ..StructConfig::default()
+            },
+            ..Default::default()
+        };
+        BTreeMap::from([("article".to_string(), table_config)])
+    }
+
+    #[tokio::test]
+    async fn structured_events_fire_in_surrealdb() {
+        use surrealdb::{Surreal, engine::local::Mem};
+
+        let table_config = TableConfig {
+            table_name: "user".to_string(),
+            struct_config: StructConfig {
+                struct_name: "User".to_string(),
+                fields: vec![StructField {
+                    field_name: "name".to_string(),
+                    field_type: FieldType::String,
+                    ..StructField::default()
+                }],
+                ..StructConfig::default()
+            },
+            events: vec![EventConfig {
+                name: "user_audit".to_string(),
+                when: "$event = \"UPDATE\"".to_string(),
+                then: "CREATE audit SET user = $after.id".to_string(),
+                statement: None,
+            }],
+            ..Default::default()
+        };
+        let tables = BTreeMap::from([("user".to_string(), table_config)]);
+        let statements = generate_define_statements(
+            "user",
+            &tables["user"],
+            &tables,
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+            false,
+            &crate::types::ForeignTypeRegistry::default(),
+        );
+
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        // Defining twice must work, as every sync does
+        for _ in 0..2 {
+            if let Err(e) = db.query(&statements).await.unwrap().check() {
+                panic!("event statements were rejected: {e}\n{statements}");
+            }
+        }
+        db.query("CREATE user:1 SET name = 'a'; UPDATE user:1 SET name = 'b';")
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        let mut response = db.query("SELECT VALUE user FROM audit").await.unwrap();
+        let audited: Vec<surrealdb::types::RecordId> = response.take(0).unwrap();
+        assert_eq!(audited.len(), 1, "{audited:?}");
+    }
+
+    #[tokio::test]
+    async fn doc_comments_become_define_comments() {
+        use surrealdb::{Surreal, engine::local::Mem};
+
+        let define_config = |comment: Option<&str>| DefineConfig {
+            comment: comment.map(str::to_string),
+            ..Default::default()
+        };
+        let table_config = TableConfig {
+            table_name: "account".to_string(),
+            struct_config: StructConfig {
+                struct_name: "Account".to_string(),
+                doc: Some("A user's account".to_string()),
+                fields: vec![
+                    StructField {
+                        field_name: "name".to_string(),
+                        field_type: FieldType::String,
+                        define_config: Some(define_config(None)),
+                        doc: Some("Display name, e.g. 'Ada'".to_string()),
+                        ..StructField::default()
+                    },
+                    StructField {
+                        field_name: "email".to_string(),
+                        field_type: FieldType::String,
+                        define_config: Some(define_config(Some("Login address"))),
+                        doc: Some("Ignored in favor of the explicit comment".to_string()),
+                        ..StructField::default()
+                    },
+                    StructField {
+                        field_name: "notes".to_string(),
+                        field_type: FieldType::String,
+                        doc: Some("Free-form C:\\notes".to_string()),
+                        ..StructField::default()
+                    },
+                ],
+                ..StructConfig::default()
+            },
+            ..Default::default()
+        };
+        let tables = BTreeMap::from([("account".to_string(), table_config)]);
+        let statements = generate_define_statements(
+            "account",
+            &tables["account"],
+            &tables,
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+            false,
+            &crate::types::ForeignTypeRegistry::default(),
+        );
+
+        for expected in [
+            "FOR delete FULL COMMENT 'A user\\'s account';\n",
+            "TYPE string DEFAULT '' COMMENT 'Display name, e.g. \\'Ada\\'';\n",
+            "TYPE string DEFAULT '' COMMENT 'Login address';\n",
+            "TYPE any PERMISSIONS FULL COMMENT 'Free-form C:\\\\notes';\n",
+        ] {
+            assert!(
+                statements.contains(expected),
+                "missing {expected:?} in:\n{statements}"
+            );
+        }
+
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        if let Err(e) = db.query(&statements).await.unwrap().check() {
+            panic!("comment statements were rejected: {e}\n{statements}");
+        }
+    }
+
+    #[tokio::test]
+    async fn literal_field_defines_literal_type() {
+        use surrealdb::{Surreal, engine::local::Mem};
+
+        let field = |name: &str, value: serde_json::Value| StructField {
+            field_name: name.to_string(),
+            field_type: FieldType::Literal(value),
+            define_config: Some(DefineConfig::default()),
+            ..StructField::default()
+        };
+        let table_config = TableConfig {
+            table_name: "member".to_string(),
+            struct_config: StructConfig {
+                struct_name: "Member".to_string(),
+                fields: vec![
+                    field("kind", serde_json::json!("user")),
+                    field("version", serde_json::json!(2)),
+                ],
+                ..StructConfig::default()
+            },
+            ..Default::default()
+        };
+        let tables = BTreeMap::from([("member".to_string(), table_config)]);
+        let statements = generate_define_statements(
+            "member",
+            &tables["member"],
+            &tables,
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+            false,
+            &crate::types::ForeignTypeRegistry::default(),
+        );
+        for expected in [
+            "DEFINE FIELD OVERWRITE kind ON TABLE member TYPE \"user\" DEFAULT \"user\";\n",
+            "DEFINE FIELD OVERWRITE version ON TABLE member TYPE 2 DEFAULT 2;\n",
+        ] {
+            assert!(
+                statements.contains(expected),
+                "missing {expected:?} in:\n{statements}"
+            );
+        }
+
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        if let Err(e) = db.query(&statements).await.unwrap().check() {
+            panic!("literal statements were rejected: {e}\n{statements}");
+        }
+        db.query("CREATE member:a SET kind = 'user'")
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        let rejected = db
+            .query("CREATE member:b SET kind = 'admin'")
+            .await
+            .unwrap()
+            .check();
+        assert!(rejected.is_err(), "a different value must be rejected");
+    }
+
+    #[test]
+    fn generate_define_statements_includes_search_index_and_analyzer() {
+        let tables = search_tables(DEFAULT_SEARCH_ANALYZER);
+        let statements = generate_define_statements(
+            "article",
+            &tables["article"],
+            &tables,
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+            false,
+            &crate::types::ForeignTypeRegistry::default(),
+        );
+        assert!(
+            statements.contains(
+                "DEFINE INDEX OVERWRITE idx_article_body_search ON TABLE article FIELDS body FULLTEXT ANALYZER evenframe_search BM25;\n"
+            ),
+            "missing search index line; output was:\n{statements}"
+        );
+        assert_eq!(
+            generate_analyzer_statements(&tables),
+            "DEFINE ANALYZER OVERWRITE evenframe_search TOKENIZERS class FILTERS lowercase, ascii;\n"
+        );
+
+        // A named analyzer is defined in place of the default one
+        let tables = search_tables("ascii");
+        let statements = generate_define_statements(
+            "article",
+            &tables["article"],
+            &tables,
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+            false,
+            &crate::types::ForeignTypeRegistry::default(),
+        );
+        assert!(
+            statements.contains("FIELDS body FULLTEXT ANALYZER ascii BM25;"),
+            "{statements}"
+        );
+        assert_eq!(
+            generate_analyzer_statements(&tables),
+            "DEFINE ANALYZER OVERWRITE ascii TOKENIZERS blank, class FILTERS lowercase, ascii;\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn search_statements_are_accepted_by_surrealdb() {
+        use surrealdb::{Surreal, engine::local::Mem};
+
+        for (analyzer, _) in SEARCH_ANALYZERS {
+            let tables = search_tables(analyzer);
+            let mut statements = generate_analyzer_statements(&tables);
+            statements.push_str(&generate_define_statements(
+                "article",
+                &tables["article"],
+                &tables,
+                &BTreeMap::new(),
+                &BTreeMap::new(),
+                false,
+                &crate::types::ForeignTypeRegistry::default(),
+            ));
+
+            let db = Surreal::new::<Mem>(()).await.unwrap();
+            db.use_ns("test").use_db("test").await.unwrap();
+            if let Err(e) = db.query(&statements).await.unwrap().check() {
+                panic!("search statements for '{analyzer}' were rejected: {e}\n{statements}");
+            }
+
+            let mut response = db
+                .query(
+                    "CREATE article:1 SET body = 'Searching the archives';\n\
+                     SELECT VALUE id FROM article WHERE body @@ 'archives';",
+                )
+                .await
+                .unwrap();
+            let found: Vec<surrealdb::types::RecordId> = response.take(1).unwrap();
+            assert_eq!(found.len(), 1, "'{analyzer}' index found no match");
+        }
+    }
+
+    #[test]
+    fn collect_define_statement_errors_reports_missing_inline_struct() {
+        let shape_field = StructField {
+            field_name: "shape".to_string(),
+            field_type: FieldType::Other("Shape".to_string()),
+            define_config: Some(DefineConfig {
+                select_permissions: Some("FULL".to_string()),
+                update_permissions: Some("FULL".to_string()),
+                create_permissions: Some("FULL".to_string()),
+                ..Default::default()
+            }),
+            ..StructField::default()
+        };
+        let tables = BTreeMap::from([(
+            "drawing".to_string(),
+            TableConfig {
+                table_name: "drawing".to_string(),
+                struct_config: StructConfig {
+                    struct_name: "Drawing".to_string(),
+                    fields: vec![shape_field],
+                    ..StructConfig::default()
+                },
+                ..Default::default()
+            },
+        )]);
+        let enums = BTreeMap::from([(
+            "Shape".to_string(),
+            TaggedUnion {
+                enum_name: "Shape".to_string(),
+                variants: vec![crate::types::Variant {
+                    name: "Circle".to_string(),
+                    data: Some(crate::types::VariantData::InlineStruct(StructConfig {
+                        struct_name: "Circle".to_string(),
+                        ..StructConfig::default()
+                    })),
+                    doccom: None,
+                    annotations: vec![],
+                    output_override: None,
+                    raw_attributes: BTreeMap::new(),
+                    is_default: false,
+                }],
+                representation: crate::types::EnumRepresentation::default(),
+                doccom: None,
+                macroforge_derives: vec![],
+                annotations: vec![],
+                pipeline: crate::types::Pipeline::default(),
+                rust_derives: vec![],
+                output_override: None,
+                raw_attributes: BTreeMap::new(),
+            },
+        )]);
+        let registry = crate::types::ForeignTypeRegistry::default();
+
+        let errors = collect_define_statement_errors(&tables, &BTreeMap::new(), &enums, &registry);
+
+        assert_eq!(errors.len(), 1, "errors: {:?}", errors);
+        assert_eq!(errors[0].table_name, "drawing");
+        assert_eq!(errors[0].field_name, "shape");
+        assert!(
+            errors[0].error.to_string().contains(
+                "Inline enum struct 'Circle' should have corresponding object definition"
+            ),
+            "{}",
+            errors[0].error
+        );
+    }
 }
@@ -11,6 +11,46 @@ pub struct QueryValidationError {
     pub statement: Option<String>,
 }
 
+impl std::fmt::Display for QueryValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Statement {}: {:?} - {}",
+            self.statement_index, self.error_type, self.message
+        )
+    }
+}
+
+/// Every statement of a query that failed validation, returned by
+/// [`execute_and_validate`]
+#[derive(Debug)]
+pub struct QueryValidationErrors {
+    pub operation_type: String,
+    pub table_name: String,
+    pub errors: Vec<QueryValidationError>,
+}
+
+impl std::fmt::Display for QueryValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SurrealDB query validation failed for {} on table {}:",
+            self.operation_type, self.table_name
+        )?;
+        for error in &self.errors {
+            write!(
+                f,
+                "\n  - {}\n    {}",
+                error,
+                error.statement.as_deref().unwrap_or("<no statement>")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for QueryValidationErrors {}
+
 #[derive(Debug)]
 pub enum QueryErrorType {
     ParseError,
@@ -23,7 +63,7 @@ pub enum QueryErrorType {
     UnknownError,
 }
 
-/// Validates a SurrealDB response and panics if any errors are found
+/// Validates a SurrealDB response and returns every error found
 /// This includes checking for:
 /// - Parse errors
 /// - Validation errors
@@ -121,7 +161,8 @@ pub async fn validate_surql_response(
     }
 }
 
-/// Executes a query and validates the response, panicking on any errors.
+/// Executes a query and validates the response. Statements that fail are
+/// returned as [`QueryValidationErrors`].
 /// If the request fails with 413 Payload Too Large, falls back to writing
 /// a `.surql` file and importing it via `surreal import`.
 pub async fn execute_and_validate<C>(
@@ -172,7 +213,7 @@ where
             Ok(results)
         }
         Err(errors) => {
-            // Log all errors before panicking
+            // Log all errors before returning them
             evenframe_log!(
                 &format!(
                     "ERRORS executing {} for table {}: {} errors found",
@@ -200,25 +241,11 @@ where
                 }
             }
 
-            // Panic with detailed error information
-            panic!(
-                "SurrealDB query validation failed for {} on table {}:\n{}",
-                operation_type,
-                table_name,
-                errors
-                    .iter()
-                    .map(|e| format!(
-                        "  - Statement {}: {:?} - {}\n    {}",
-                        e.statement_index,
-                        e.error_type,
-                        e.message,
-                        e.statement
-                            .as_ref()
-                            .unwrap_or(&"<no statement>".to_string())
-                    ))
-                    .collect::<Vec<_>>()
-                    .join("\n")
-            );
+            Err(Box::new(QueryValidationErrors {
+                operation_type: operation_type.to_string(),
+                table_name: table_name.to_string(),
+                errors,
+            }))
         }
     }
 }
@@ -1,27 +1,28 @@
+use crate::error::Result;
 use crate::evenframe_log;
 use crate::schemasync::mockmake::Mockmaker;
 use crate::schemasync::mockmake::field_value::FieldValueGenerator;
-use crate::schemasync::table::TableConfig;
+use crate::schemasync::table::{TableConfig, surql_ident};
 use crate::types::FieldType;
-use convert_case::{Case, Casing};
-use tracing::{debug, info};
+use std::ops::Range;
+use tracing::{debug, debug_span, info};
 
 impl Mockmaker<'_> {
+    /// Generates the `INSERT` statements for the records of `table_name` at
+    /// the indexes in `records`.
     pub fn generate_insert_statements(
         &self,
         table_name: &str,
         table_config: &TableConfig,
-    ) -> String {
+        records: Range<usize>,
+    ) -> Result<String> {
+        let _span = debug_span!("gen_table", table = %table_name).entered();
         info!(table_name = %table_name, "Generating insert statements for table");
         debug!("Table config: {:?}", table_config);
         let log_name = format!("insert_logs/{}.log", table_name);
         evenframe_log!("", log_name);
 
         let mut output = String::new();
-        let config = self
-            .tables
-            .get(table_name)
-            .expect("TableConfig was not found");
 
         evenframe_log!(
             format!(
@@ -32,14 +33,13 @@ impl Mockmaker<'_> {
             true
         );
 
-        let n = config
-            .mock_generation_config
-            .as_ref()
-            .map(|c| c.n)
-            .unwrap_or(self.schemasync_config.mock_gen_config.default_record_count);
+        let n = self.record_count_for(table_name);
 
         evenframe_log!(
-            format!("Will generate {} records for table {}", n, table_name),
+            format!(
+                "Will generate records {:?} of {} for table {}",
+                records, n, table_name
+            ),
             log_name,
             true
         );
@@ -50,7 +50,8 @@ impl Mockmaker<'_> {
             log_name,
             true
         );
-        for i in 0..n {
+        let record_count = records.len();
+        for i in records {
             evenframe_log!(
                 format!("Generating INSERT statement for record {}/{}", i + 1, n),
                 log_name,
@@ -69,7 +70,7 @@ impl Mockmaker<'_> {
                     );
                     ids[i].clone()
                 } else {
-                    let id = format!("{}:{}", table_name.to_case(Case::Snake), i + 1);
+                    let id = format!("{}:{}", surql_ident(table_name), i + 1);
                     evenframe_log!(
                         format!("Generated fallback ID (index beyond id_map): {}", id),
                         log_name,
@@ -78,7 +79,7 @@ impl Mockmaker<'_> {
                     id
                 }
             } else {
-                let id = format!("{}:{}", table_name.to_case(Case::Snake), i + 1);
+                let id = format!("{}:{}", surql_ident(table_name), i + 1);
                 evenframe_log!(
                     format!("Generated default ID (no id_map entry): {}", id),
                     log_name,
@@ -121,12 +122,15 @@ impl Mockmaker<'_> {
                         continue;
                     }
 
-                    // Skip computed fields (they are auto-calculated by the database)
+                    // Skip VALUE/COMPUTED fields (they are calculated by the database)
                     if let Some(ref define_config) = table_field.define_config
-                        && define_config.computed.is_some()
+                        && define_config.is_database_derived()
                     {
                         evenframe_log!(
-                            format!("Skipping computed field '{}'", table_field.field_name),
+                            format!(
+                                "Skipping database-derived field '{}'",
+                                table_field.field_name
+                            ),
                             log_name,
                             true
                         );
@@ -140,7 +144,8 @@ impl Mockmaker<'_> {
                         .table_config(table_config)
                         .registry(self.registry)
                         .build()
-                        .run();
+                        .run()?;
+                    self.check_generated_value(table_name, table_field, &field_val);
 
                     // For relation tables, preserve existing 'in'/'out' endpoints if the edge already exists.
                     // Use a selective fetch from ONLY r'<id>' to keep prior endpoints; otherwise use the newly generated value.
@@ -165,7 +170,8 @@ impl Mockmaker<'_> {
                         log_name,
                         true
                     );
-                    field_assignments.push(format!("{}: {assigned_value}", table_field.field_name));
+                    field_assignments
+                        .push(format!("{}: {assigned_value}", table_field.output_name()));
 
                     // For relations, we don't update in/out fields
                     if !(table_config.relation.is_some()
@@ -178,14 +184,15 @@ impl Mockmaker<'_> {
                             // For nullable fields, preserve NULL values on update
                             update_assignments.push(format!(
                                 "{} = (IF {} != NULL THEN $input.{} ELSE NULL END)",
-                                table_field.field_name,
-                                table_field.field_name,
-                                table_field.field_name
+                                table_field.output_name(),
+                                table_field.output_name(),
+                                table_field.output_name()
                             ));
                         } else {
                             update_assignments.push(format!(
                                 "{} = $input.{}",
-                                table_field.field_name, table_field.field_name
+                                table_field.output_name(),
+                                table_field.output_name()
                             ));
                         }
                     }
@@ -221,7 +228,9 @@ impl Mockmaker<'_> {
                     );
                     output.push_str(&format!(
                         "INSERT RELATION INTO {} {{ {} }} ON DUPLICATE KEY UPDATE {};\n",
-                        table_name, fields_str, updates_str
+                        surql_ident(table_name),
+                        fields_str,
+                        updates_str
                     ));
                 } else {
                     // No fields to update (only in/out or all readonly)
@@ -232,7 +241,8 @@ impl Mockmaker<'_> {
                     );
                     output.push_str(&format!(
                         "INSERT RELATION INTO {} {{ {} }};\n",
-                        table_name, fields_str
+                        surql_ident(table_name),
+                        fields_str
                     ));
                 }
             } else if !update_assignments.is_empty() {
@@ -244,7 +254,9 @@ impl Mockmaker<'_> {
                 let updates_str = update_assignments.join(", ");
                 output.push_str(&format!(
                     "INSERT INTO {} {{ {} }} ON DUPLICATE KEY UPDATE {};\n",
-                    table_name, fields_str, updates_str
+                    surql_ident(table_name),
+                    fields_str,
+                    updates_str
                 ));
             } else {
                 // No fields to update (all readonly), just do a simple insert
@@ -255,7 +267,8 @@ impl Mockmaker<'_> {
                 );
                 output.push_str(&format!(
                     "INSERT INTO {} {{ {} }};\n",
-                    table_name, fields_str
+                    surql_ident(table_name),
+                    fields_str
                 ));
             }
         }
@@ -263,7 +276,7 @@ impl Mockmaker<'_> {
         evenframe_log!(
             format!(
                 "Successfully generated {} INSERT statements for table '{}' (total length: {} bytes)",
-                n,
+                record_count,
                 table_name,
                 output.len()
             ),
@@ -271,6 +284,6 @@ impl Mockmaker<'_> {
             true
         );
 
-        output
+        Ok(output)
     }
 }
@@ -14,24 +14,29 @@ pub mod upsert;
 pub mod value;
 
 use async_trait::async_trait;
+use futures::StreamExt;
 use std::collections::BTreeMap;
 use surrealdb::{
     Surreal,
-    engine::remote::http::{Client, Http},
+    engine::any::{self, Any},
+    engine::local::Mem,
     opt::auth::Root,
 };
-use tracing::{debug, info, trace};
+use tracing::{debug, info, trace, warn};
 
 use crate::error::{EvenframeError, Result};
-use crate::schemasync::{EdgeConfig, TableConfig};
+use crate::schemasync::{EdgeConfig, TableConfig, surql_ident, surql_record_id};
 use crate::types::{FieldType, ForeignTypeRegistry, StructConfig, StructField, TaggedUnion};
 
 use self::define::generate_define_statements;
 use self::value::to_surreal_string;
 
 use super::{
-    DatabaseConfig, DatabaseProvider, ProviderType, Relationship, RelationshipDirection,
-    SchemaExport, TableInfo, Transaction,
+    ColumnInfo, DatabaseConfig, DatabaseProvider, IndexInfo, ProviderType, Relationship,
+    RelationshipDirection, SchemaExport, TableInfo, Transaction,
+};
+use crate::schemasync::compare::{
+    ObjectType, SchemaChanges, SchemaDefinition, surql::SchemaImporter,
 };
 
 pub use type_mapper::SurrealdbTypeMapper;
@@ -41,8 +46,8 @@ pub use type_mapper::SurrealdbTypeMapper;
 /// This provider wraps the existing SurrealDB functionality and implements
 /// the DatabaseProvider trait for use with the abstracted SchemaSync system.
 pub struct SurrealdbProvider {
-    /// The SurrealDB client connection
-    client: Option<Surreal<Client>>,
+    /// The SurrealDB client connection, remote or embedded depending on the URL
+    client: Option<Surreal<Any>>,
     /// Connection configuration
     config: Option<DatabaseConfig>,
     /// Foreign type registry for type mapping
@@ -77,19 +82,19 @@ impl SurrealdbProvider {
     ///
     /// This is useful for backward compatibility with existing code
     /// that needs direct access to the client.
-    pub fn client(&self) -> Option<&Surreal<Client>> {
+    pub fn client(&self) -> Option<&Surreal<Any>> {
         self.client.as_ref()
     }
 
     /// Get a mutable reference to the underlying SurrealDB client.
-    pub fn client_mut(&mut self) -> Option<&mut Surreal<Client>> {
+    pub fn client_mut(&mut self) -> Option<&mut Surreal<Any>> {
         self.client.as_mut()
     }
 
     /// Take ownership of the underlying client.
     ///
     /// After calling this, the provider will be disconnected.
-    pub fn take_client(&mut self) -> Option<Surreal<Client>> {
+    pub fn take_client(&mut self) -> Option<Surreal<Any>> {
         self.client.take()
     }
 }
@@ -124,31 +129,43 @@ impl DatabaseProvider for SurrealdbProvider {
 
         info!("Connecting to SurrealDB at {}", config.url);
 
-        let client = Surreal::new::<Http>(&config.url).await.map_err(|e| {
-            EvenframeError::database(format!("Failed to create SurrealDB HTTP client: {e}"))
-        })?;
-
-        // Sign in if credentials provided
-        if let (Some(username), Some(password)) = (&config.username, &config.password) {
-            debug!("Signing in to SurrealDB as {}", username);
-            client
-                .signin(Root {
-                    username: username.to_string(),
-                    password: password.to_string(),
-                })
-                .await
-                .map_err(|e| {
-                    EvenframeError::database(format!("Failed to sign in to SurrealDB: {e}"))
-                })?;
-        }
+        let client = retry_with_backoff(
+            "SurrealDB connection",
+            config.connect_retries,
+            config.connect_retry_delay_ms,
+            || async move {
+                let client = any::connect(surreal_endpoint(&config.url))
+                    .await
+                    .map_err(|e| {
+                        EvenframeError::database(format!("Failed to create SurrealDB client: {e}"))
+                    })?;
+
+                // Sign in if credentials provided
+                if let (Some(username), Some(password)) = (&config.username, &config.password) {
+                    debug!("Signing in to SurrealDB as {}", username);
+                    client
+                        .signin(Root {
+                            username: username.to_string(),
+                            password: password.to_string(),
+                        })
+                        .await
+                        .map_err(|e| signin_error("Failed to sign in to SurrealDB", e))?;
+                }
+
+                // Select namespace and database
+                if let (Some(ns), Some(db)) = (&config.namespace, &config.database) {
+                    debug!("Using namespace '{}' and database '{}'", ns, db);
+                    client.use_ns(ns).use_db(db).await.map_err(|e| {
+                        EvenframeError::database(format!(
+                            "Failed to select namespace/database: {e}"
+                        ))
+                    })?;
+                }
 
-        // Select namespace and database
-        if let (Some(ns), Some(db)) = (&config.namespace, &config.database) {
-            debug!("Using namespace '{}' and database '{}'", ns, db);
-            client.use_ns(ns).use_db(db).await.map_err(|e| {
-                EvenframeError::database(format!("Failed to select namespace/database: {e}"))
-            })?;
-        }
+                Ok(client)
+            },
+        )
+        .await?;
 
         self.client = Some(client);
         self.config = Some(config.clone());
@@ -173,13 +190,14 @@ impl DatabaseProvider for SurrealdbProvider {
             .as_ref()
             .ok_or_else(|| EvenframeError::database("Not connected to SurrealDB"))?;
 
-        // Use SurrealDB's export functionality
+        // Use SurrealDB's export functionality, leaving out the records
         let mut export_stream = client
             .export(())
+            .with_config()
+            .records(false)
             .await
             .map_err(|e| EvenframeError::database(format!("Failed to export schema: {e}")))?;
 
-        use futures::StreamExt;
         let mut raw_statements = String::new();
         while let Some(chunk) = export_stream.next().await {
             let chunk = chunk.map_err(|e| {
@@ -220,18 +238,26 @@ impl DatabaseProvider for SurrealdbProvider {
             .as_ref()
             .ok_or_else(|| EvenframeError::database("Not connected to SurrealDB"))?;
 
-        let query = format!("INFO FOR TABLE {}", table_name);
-        let response: surrealdb::IndexedResults = client
+        // INFO FOR TABLE succeeds with empty maps for unknown tables, so check
+        // the database's table list to tell "missing" apart from "empty".
+        if !self.list_tables().await?.iter().any(|t| t == table_name) {
+            return Ok(None);
+        }
+
+        let query = format!("INFO FOR TABLE {}", surql_ident(table_name));
+        let mut response = client
             .query(&query)
             .await
             .map_err(|e| EvenframeError::database(format!("Failed to get table info: {e}")))?;
 
-        // This method is not currently used — the schema comparison pipeline uses
-        // export-based parsing (SchemaImporter::import_schema_only) instead of
-        // per-table INFO queries. Reserved for future use (e.g., per-table
-        // introspection or SQL provider parity).
-        let _ = response;
-        Ok(None)
+        let result: Option<serde_json::Value> = response
+            .take(0)
+            .map_err(|e| EvenframeError::database(format!("Failed to parse table info: {e}")))?;
+
+        Ok(Some(parse_table_info(
+            table_name,
+            &result.unwrap_or(serde_json::Value::Null),
+        )))
     }
 
     async fn list_tables(&self) -> Result<Vec<String>> {
@@ -272,11 +298,12 @@ impl DatabaseProvider for SurrealdbProvider {
             .await
             .map_err(|e| EvenframeError::database(format!("Failed to execute query: {e}")))?;
 
-        let results: Vec<serde_json::Value> = response
+        // Record ids only convert to JSON through SurrealDB's own value type
+        let results: surrealdb::types::Value = response
             .take(0)
             .map_err(|e| EvenframeError::database(format!("Failed to parse query results: {e}")))?;
 
-        Ok(results)
+        Ok(json_rows(results))
     }
 
     async fn execute_batch(&self, queries: &[String]) -> Result<Vec<Vec<serde_json::Value>>> {
@@ -357,9 +384,9 @@ impl DatabaseProvider for SurrealdbProvider {
 
     async fn select(&self, table: &str, filter: Option<&str>) -> Result<Vec<serde_json::Value>> {
         let query = if let Some(f) = filter {
-            format!("SELECT * FROM {} WHERE {}", table, f)
+            format!("SELECT * FROM {} WHERE {}", surql_ident(table), f)
         } else {
-            format!("SELECT * FROM {}", table)
+            format!("SELECT * FROM {}", surql_ident(table))
         };
 
         self.execute(&query).await
@@ -367,9 +394,13 @@ impl DatabaseProvider for SurrealdbProvider {
 
     async fn count(&self, table: &str, filter: Option<&str>) -> Result<u64> {
         let query = if let Some(f) = filter {
-            format!("SELECT count() FROM {} WHERE {} GROUP ALL", table, f)
+            format!(
+                "SELECT count() FROM {} WHERE {} GROUP ALL",
+                surql_ident(table),
+                f
+            )
         } else {
-            format!("SELECT count() FROM {} GROUP ALL", table)
+            format!("SELECT count() FROM {} GROUP ALL", surql_ident(table))
         };
 
         let results = self.execute(&query).await?;
@@ -392,9 +423,9 @@ impl DatabaseProvider for SurrealdbProvider {
         for id in ids {
             // Parse the ID to get table:id format or just id
             let record_id = if id.contains(':') {
-                id.clone()
+                surql_record_id(id)
             } else {
-                format!("{}:{}", table, id)
+                format!("{}:{}", surql_ident(table), id)
             };
 
             client
@@ -439,8 +470,10 @@ impl DatabaseProvider for SurrealdbProvider {
         // Generate a DEFINE FIELD statement
         let field_type = self.type_mapper().field_type_to_surql(&field.field_type);
         format!(
-            "DEFINE FIELD {} ON TABLE {} TYPE {};",
-            field.field_name, table_name, field_type
+            "DEFINE FIELD OVERWRITE {} ON TABLE {} TYPE {};",
+            field.field_name,
+            surql_ident(table_name),
+            field_type
         )
     }
 
@@ -457,16 +490,36 @@ impl DatabaseProvider for SurrealdbProvider {
         // The actual RELATE statements create relationships
         let mut statements = Vec::new();
 
-        statements.push(format!("DEFINE TABLE {} SCHEMAFULL;", edge.edge_name));
+        // Without known endpoints, fall back to an unconstrained edge table
+        if edge.from.is_empty() || edge.to.is_empty() {
+            statements.push(format!(
+                "DEFINE TABLE OVERWRITE {} SCHEMAFULL;",
+                edge.edge_name
+            ));
+            statements.push(format!(
+                "DEFINE FIELD OVERWRITE in ON TABLE {} TYPE record;",
+                edge.edge_name
+            ));
+            statements.push(format!(
+                "DEFINE FIELD OVERWRITE out ON TABLE {} TYPE record;",
+                edge.edge_name
+            ));
+            return statements;
+        }
 
-        // Define in and out fields for edges
+        let from_clause = edge.from.join(" | ");
+        let to_clause = edge.to.join(" | ");
         statements.push(format!(
-            "DEFINE FIELD in ON TABLE {} TYPE record;",
-            edge.edge_name
+            "DEFINE TABLE OVERWRITE {} SCHEMAFULL TYPE RELATION FROM {} TO {};",
+            edge.edge_name, from_clause, to_clause
         ));
         statements.push(format!(
-            "DEFINE FIELD out ON TABLE {} TYPE record;",
-            edge.edge_name
+            "DEFINE FIELD OVERWRITE in ON TABLE {} TYPE record<{}>;",
+            edge.edge_name, from_clause
+        ));
+        statements.push(format!(
+            "DEFINE FIELD OVERWRITE out ON TABLE {} TYPE record<{}>;",
+            edge.edge_name, to_clause
         ));
 
         statements
@@ -534,37 +587,19 @@ impl DatabaseProvider for SurrealdbProvider {
         record_id: &str,
         direction: RelationshipDirection,
     ) -> Result<Vec<Relationship>> {
-        let query = match direction {
-            RelationshipDirection::Outgoing => {
-                format!("SELECT * FROM {} WHERE in = {}", edge_table, record_id)
-            }
-            RelationshipDirection::Incoming => {
-                format!("SELECT * FROM {} WHERE out = {}", edge_table, record_id)
-            }
-            RelationshipDirection::Both => {
-                format!(
-                    "SELECT * FROM {} WHERE in = {} OR out = {}",
-                    edge_table, record_id, record_id
-                )
-            }
-        };
-
-        let results = self.execute(&query).await?;
-
-        let relationships = results
-            .into_iter()
-            .filter_map(|v| {
-                let id = v.get("id")?.as_str()?.to_string();
-                let from_id = v.get("in")?.as_str()?.to_string();
-                let to_id = v.get("out")?.as_str()?.to_string();
-                Some(Relationship {
-                    id,
-                    from_id,
-                    to_id,
-                    data: Some(v),
-                })
-            })
-            .collect();
+        // `Both` queries each side separately so every result knows which
+        // side of the edge the record is on
+        let mut relationships = Vec::new();
+        for &side in direction.sides() {
+            let results = self
+                .execute(&relationships_query(edge_table, record_id, side))
+                .await?;
+            relationships.extend(
+                results
+                    .into_iter()
+                    .filter_map(|row| relationship_from_row(row, side)),
+            );
+        }
 
         Ok(relationships)
     }
@@ -581,4 +616,601 @@ impl DatabaseProvider for SurrealdbProvider {
         // when we refactor the comparator to use the provider abstraction
         Ok(None)
     }
+
+    fn parse_schema_export(&self, export: &SchemaExport) -> Result<SchemaDefinition> {
+        let raw_statements = export.raw_statements.as_deref().ok_or_else(|| {
+            EvenframeError::comparison("SurrealDB schema export has no statements")
+        })?;
+        SchemaImporter::parse_export(raw_statements)
+    }
+
+    async fn target_schema(
+        &self,
+        tables: &BTreeMap<String, TableConfig>,
+        objects: &BTreeMap<String, StructConfig>,
+        enums: &BTreeMap<String, TaggedUnion>,
+    ) -> Result<SchemaDefinition> {
+        // Define the tables on an in-memory instance and parse its export, so
+        // both sides of the comparison are in SurrealDB's own normalized form
+        let db = Surreal::new::<Mem>(()).await.map_err(|e| {
+            EvenframeError::database(format!("Failed to start in-memory SurrealDB: {e}"))
+        })?;
+        db.use_ns("new").use_db("memory").await.map_err(|e| {
+            EvenframeError::database(format!(
+                "Failed to select namespace/database on in-memory SurrealDB: {e}"
+            ))
+        })?;
+
+        for (table_name, config) in tables {
+            let statements = self.generate_create_table(table_name, config, tables, objects, enums);
+            db.query(&statements)
+                .await
+                .and_then(|response| response.check())
+                .map_err(|e| {
+                    EvenframeError::database(format!(
+                        "Failed to define table {table_name} on in-memory SurrealDB: {e}\nStatements: {statements}"
+                    ))
+                })?;
+        }
+
+        let mut export_stream = db
+            .export(())
+            .with_config()
+            .records(false)
+            .await
+            .map_err(|e| {
+                EvenframeError::database(format!("Failed to export in-memory schema: {e}"))
+            })?;
+        let mut raw_statements = String::new();
+        while let Some(chunk) = export_stream.next().await {
+            let chunk = chunk.map_err(|e| {
+                EvenframeError::database(format!("Error reading export stream: {e}"))
+            })?;
+            raw_statements.push_str(&String::from_utf8_lossy(&chunk));
+        }
+
+        SchemaImporter::parse_export(&raw_statements)
+    }
+
+    fn generate_migration(
+        &self,
+        changes: &SchemaChanges,
+        tables: &BTreeMap<String, TableConfig>,
+        objects: &BTreeMap<String, StructConfig>,
+        enums: &BTreeMap<String, TaggedUnion>,
+    ) -> Result<Vec<String>> {
+        // Table definitions overwrite their fields, indexes, events,
+        // permissions and changefeed in place, so a modified table is simply
+        // defined again in full
+        Ok(changes
+            .new_tables
+            .iter()
+            .chain(
+                changes
+                    .modified_tables
+                    .iter()
+                    .map(|table_changes| &table_changes.table_name),
+            )
+            .filter_map(|table_name| {
+                tables.get(table_name).map(|config| {
+                    self.generate_create_table(table_name, config, tables, objects, enums)
+                })
+            })
+            .collect())
+    }
+}
+
+/// Wraps a failed sign-in. Rejected credentials become
+/// [`EvenframeError::Authentication`], which [`retry_with_backoff`] returns
+/// straight away; anything else (e.g. the server is still starting) is a
+/// retryable database error.
+pub fn signin_error(context: &str, error: surrealdb::Error) -> EvenframeError {
+    if error.is_not_allowed() {
+        EvenframeError::authentication(format!("{context}: {error}"))
+    } else {
+        EvenframeError::database(format!("{context}: {error}"))
+    }
+}
+
+/// The endpoint to connect to for `url`. URLs without a scheme are taken to
+/// be HTTP servers; `memory` and `mem://` select the embedded engine.
+fn surreal_endpoint(url: &str) -> String {
+    if url.contains("://") || url == "memory" || url.starts_with("memory?") {
+        url.to_string()
+    } else {
+        format!("http://{url}")
+    }
+}
+
+/// Run `attempt` until it succeeds, retrying up to `retries` more times with
+/// exponential backoff starting at `delay_ms`. Each failure before the last is
+/// logged at `warn`; only the final error is returned. Authentication errors
+/// are returned without retrying, since waiting will not fix them.
+pub async fn retry_with_backoff<T, F, Fut>(
+    what: &str,
+    retries: u32,
+    delay_ms: u64,
+    mut attempt: F,
+) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T>>,
+{
+    let mut delay = std::time::Duration::from_millis(delay_ms);
+    let mut failures = 0;
+    loop {
+        match attempt().await {
+            Ok(value) => return Ok(value),
+            Err(e) if failures < retries && !matches!(e, EvenframeError::Authentication(_)) => {
+                failures += 1;
+                warn!(
+                    "{} failed (attempt {} of {}): {}; retrying in {:?}",
+                    what,
+                    failures,
+                    retries + 1,
+                    e,
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+                delay = delay.saturating_mul(2);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Build a [`TableInfo`] from the JSON returned by `INFO FOR TABLE`.
+///
+/// The `fields` and `indexes` maps hold the `DEFINE` statement for each entry,
+/// which are parsed with the same routines used for schema exports. Nested
+/// field definitions (`address.city`, `tags[*]`) are folded into their
+/// top-level column and are not reported separately.
+fn parse_table_info(table_name: &str, info: &serde_json::Value) -> TableInfo {
+    let mut columns = BTreeMap::new();
+    if let Some(fields) = info.get("fields").and_then(|v| v.as_object()) {
+        for (name, statement) in fields {
+            if name.contains('.') || name.contains('[') {
+                continue;
+            }
+            let Some(definition) = statement
+                .as_str()
+                .and_then(SchemaImporter::parse_field_definition)
+            else {
+                continue;
+            };
+
+            columns.insert(
+                name.clone(),
+                ColumnInfo {
+                    name: name.clone(),
+                    data_type: definition.field_type.to_string(),
+                    nullable: is_nullable_type(&definition.field_type),
+                    default: definition.default_value,
+                    is_primary_key: name == "id",
+                    max_length: None,
+                    numeric_precision: None,
+                    numeric_scale: None,
+                },
+            );
+        }
+    }
+
+    let mut indexes = Vec::new();
+    if let Some(defined) = info.get("indexes").and_then(|v| v.as_object()) {
+        for statement in defined.values() {
+            if let Some((_, index)) = statement
+                .as_str()
+                .and_then(SchemaImporter::parse_index_definition)
+            {
+                indexes.push(IndexInfo {
+                    name: index.name,
+                    columns: index.columns,
+                    unique: index.unique,
+                    index_type: None,
+                });
+            }
+        }
+    }
+
+    TableInfo {
+        name: table_name.to_string(),
+        columns,
+        primary_key: vec!["id".to_string()],
+        foreign_keys: Vec::new(),
+        indexes,
+        row_count: None,
+    }
+}
+
+/// The rows of a query result as JSON, with record ids rendered as strings.
+fn json_rows(value: surrealdb::types::Value) -> Vec<serde_json::Value> {
+    match value.into_json_value() {
+        serde_json::Value::Array(rows) => rows,
+        serde_json::Value::Null => Vec::new(),
+        row => vec![row],
+    }
+}
+
+/// The query for the edges on one side of a record. `side` is `Outgoing` or
+/// `Incoming`; see [`RelationshipDirection::sides`].
+fn relationships_query(edge_table: &str, record_id: &str, side: RelationshipDirection) -> String {
+    let column = match side {
+        RelationshipDirection::Incoming => "out",
+        RelationshipDirection::Outgoing | RelationshipDirection::Both => "in",
+    };
+    format!("SELECT * FROM {edge_table} WHERE {column} = {record_id}")
+}
+
+/// Build a [`Relationship`] from an edge record returned for one side of a
+/// record, skipping rows without string `id`, `in` and `out` fields.
+fn relationship_from_row(
+    row: serde_json::Value,
+    side: RelationshipDirection,
+) -> Option<Relationship> {
+    let id = row.get("id")?.as_str()?.to_string();
+    let from_id = row.get("in")?.as_str()?.to_string();
+    let to_id = row.get("out")?.as_str()?.to_string();
+    Some(Relationship {
+        id,
+        from_id,
+        to_id,
+        direction: side,
+        data: Some(row),
+    })
+}
+
+/// Whether a SurrealDB field type accepts `NONE` or `NULL`.
+fn is_nullable_type(field_type: &ObjectType) -> bool {
+    match field_type {
+        ObjectType::Nullable(_) => true,
+        ObjectType::Simple(s) => {
+            s.starts_with("option<") || s == "none" || s == "null" || s == "any"
+        }
+        ObjectType::Union(types) => types.iter().any(is_nullable_type),
+        ObjectType::Object(_) | ObjectType::Array(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[tokio::test]
+    async fn retry_with_backoff_succeeds_after_transient_failures() {
+        let calls = AtomicU32::new(0);
+        let value = retry_with_backoff("test", 3, 0, || async {
+            if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                Err(EvenframeError::database("not ready"))
+            } else {
+                Ok(42)
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(value, 42);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn retry_with_backoff_returns_last_error() {
+        let calls = AtomicU32::new(0);
+        let err = retry_with_backoff("test", 2, 0, || async {
+            let n = calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(EvenframeError::database(format!("attempt {n}")))
+        })
+        .await
+        .unwrap_err();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(err.to_string().contains("attempt 2"), "got: {err}");
+    }
+
+    #[tokio::test]
+    async fn rejected_credentials_are_not_retried() {
+        let db = Surreal::new::<surrealdb::engine::local::Mem>(())
+            .await
+            .unwrap();
+        let calls = AtomicU32::new(0);
+        let err = retry_with_backoff("test", 3, 0, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            db.signin(Root {
+                username: "root".to_string(),
+                password: "wrong".to_string(),
+            })
+            .await
+            .map_err(|e| signin_error("Failed to sign in", e))
+        })
+        .await
+        .unwrap_err();
+
+        assert!(
+            matches!(err, EvenframeError::Authentication(_)),
+            "got: {err:?}"
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn relationship_table_is_constrained_to_its_endpoints() {
+        let provider = SurrealdbProvider::new();
+        let edge = EdgeConfig {
+            edge_name: "authored".to_string(),
+            from: vec!["user".to_string()],
+            to: vec!["post".to_string(), "comment".to_string()],
+            direction: None,
+        };
+
+        assert_eq!(
+            provider.generate_relationship_table(&edge),
+            vec![
+                "DEFINE TABLE OVERWRITE authored SCHEMAFULL TYPE RELATION FROM user TO post | comment;",
+                "DEFINE FIELD OVERWRITE in ON TABLE authored TYPE record<user>;",
+                "DEFINE FIELD OVERWRITE out ON TABLE authored TYPE record<post | comment>;",
+            ]
+        );
+    }
+
+    #[test]
+    fn relationship_table_without_endpoints_stays_generic() {
+        let provider = SurrealdbProvider::new();
+        let edge = EdgeConfig {
+            edge_name: "linked".to_string(),
+            from: vec![],
+            to: vec!["post".to_string()],
+            direction: None,
+        };
+
+        assert_eq!(
+            provider.generate_relationship_table(&edge),
+            vec![
+                "DEFINE TABLE OVERWRITE linked SCHEMAFULL;",
+                "DEFINE FIELD OVERWRITE in ON TABLE linked TYPE record;",
+                "DEFINE FIELD OVERWRITE out ON TABLE linked TYPE record;",
+            ]
+        );
+    }
+
+    // The provider only connects over HTTP, so the per-side queries
+    // `get_relationships` runs are issued against an in-memory database.
+    #[tokio::test]
+    async fn relationships_report_which_side_the_record_is_on() {
+        use surrealdb::engine::local::Mem;
+
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        db.query(
+            "RELATE user:alice->follows->user:bob; \
+             RELATE user:carol->follows->user:alice; \
+             RELATE user:bob->follows->user:carol;",
+        )
+        .await
+        .unwrap()
+        .check()
+        .unwrap();
+
+        let mut relationships = Vec::new();
+        for &side in RelationshipDirection::Both.sides() {
+            let rows = db
+                .query(relationships_query("follows", "user:alice", side))
+                .await
+                .unwrap()
+                .take(0)
+                .unwrap();
+            relationships.extend(
+                json_rows(rows)
+                    .into_iter()
+                    .filter_map(|row| relationship_from_row(row, side)),
+            );
+        }
+
+        let found: Vec<(&str, &str, RelationshipDirection)> = relationships
+            .iter()
+            .map(|r| (r.from_id.as_str(), r.to_id.as_str(), r.direction))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("user:alice", "user:bob", RelationshipDirection::Outgoing),
+                ("user:carol", "user:alice", RelationshipDirection::Incoming),
+            ]
+        );
+    }
+
+    // Every statement the provider generates must be safe to apply to a
+    // database that already has the schema.
+    #[tokio::test]
+    async fn generated_schema_can_be_applied_twice() {
+        use surrealdb::engine::local::Mem;
+
+        let provider = SurrealdbProvider::new();
+        let title = StructField {
+            field_name: "title".to_string(),
+            field_type: FieldType::String,
+            ..StructField::default()
+        };
+        let post = TableConfig {
+            table_name: "post".to_string(),
+            struct_config: StructConfig {
+                struct_name: "Post".to_string(),
+                fields: vec![title.clone()],
+                ..StructConfig::default()
+            },
+            ..Default::default()
+        };
+        let tables = BTreeMap::from([("post".to_string(), post.clone())]);
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let mut statements = vec![
+            provider.generate_create_table("post", &post, &tables, &objects, &enums),
+            provider.generate_create_field("post", &title, &objects, &enums),
+        ];
+        statements.extend(provider.generate_relationship_table(&EdgeConfig {
+            edge_name: "liked".to_string(),
+            from: vec!["post".to_string()],
+            to: vec!["post".to_string()],
+            direction: None,
+        }));
+
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        for pass in 1..=2 {
+            for statement in &statements {
+                let response = db.query(statement).await.unwrap();
+                if let Err(e) = response.check() {
+                    panic!("pass {pass} failed on `{statement}`: {e}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn surreal_endpoint_defaults_to_http() {
+        assert_eq!(surreal_endpoint("localhost:8000"), "http://localhost:8000");
+        assert_eq!(
+            surreal_endpoint("https://db.example.com"),
+            "https://db.example.com"
+        );
+        assert_eq!(surreal_endpoint("memory"), "memory");
+        assert_eq!(surreal_endpoint("mem://"), "mem://");
+    }
+
+    #[tokio::test]
+    async fn migrate_applies_only_new_and_modified_tables() {
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let mut provider = SurrealdbProvider::new();
+        provider
+            .connect(&DatabaseConfig {
+                url: "memory".to_string(),
+                namespace: Some("test".to_string()),
+                database: Some("test".to_string()),
+                ..DatabaseConfig::default()
+            })
+            .await
+            .unwrap();
+        let migrate = |tables| provider.migrate(tables, &objects, &enums);
+
+        let table = |name: &str, fields: &[&str]| TableConfig {
+            table_name: name.to_string(),
+            struct_config: StructConfig {
+                struct_name: name.to_string(),
+                fields: fields
+                    .iter()
+                    .map(|field| StructField {
+                        field_name: field.to_string(),
+                        field_type: FieldType::String,
+                        ..StructField::default()
+                    })
+                    .collect(),
+                ..StructConfig::default()
+            },
+            ..Default::default()
+        };
+
+        let v1 = BTreeMap::from([("post".to_string(), table("post", &["title"]))]);
+        let changes = migrate(&v1).await.unwrap();
+        assert_eq!(changes.new_tables, vec!["post".to_string()]);
+
+        // Records are not part of the schema export, so they can't be
+        // mistaken for definitions
+        let db = provider.client().unwrap();
+        db.query("CREATE post:1 SET title = 'hello'")
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+
+        let v2 = BTreeMap::from([
+            ("post".to_string(), table("post", &["title", "body"])),
+            ("comment".to_string(), table("comment", &["text"])),
+        ]);
+        let changes = migrate(&v2).await.unwrap();
+        assert_eq!(changes.new_tables, vec!["comment".to_string()]);
+        assert_eq!(changes.modified_tables.len(), 1, "{changes:#?}");
+        assert_eq!(changes.modified_tables[0].table_name, "post");
+        assert_eq!(
+            changes.modified_tables[0].new_fields,
+            vec!["body".to_string()]
+        );
+
+        let mut info = db.query("INFO FOR TABLE post").await.unwrap();
+        let info: Option<serde_json::Value> = info.take(0).unwrap();
+        assert!(info.unwrap()["fields"].get("body").is_some());
+
+        let changes = migrate(&v2).await.unwrap();
+        assert!(changes.new_tables.is_empty(), "{changes:#?}");
+        assert!(changes.modified_tables.is_empty(), "{changes:#?}");
+
+        // An index-only change still redefines the table
+        let mut v3 = v2.clone();
+        v3.get_mut("post").unwrap().indexes = vec![crate::schemasync::IndexConfig {
+            fields: vec!["title".to_string()],
+            unique: true,
+        }];
+        let changes = migrate(&v3).await.unwrap();
+        assert_eq!(changes.modified_tables.len(), 1, "{changes:#?}");
+        assert_eq!(changes.modified_tables[0].new_indexes.len(), 1);
+
+        let mut info = db.query("INFO FOR TABLE post").await.unwrap();
+        let info: Option<serde_json::Value> = info.take(0).unwrap();
+        assert!(info.unwrap()["indexes"].get("idx_post_title").is_some());
+
+        let changes = migrate(&v3).await.unwrap();
+        assert!(changes.modified_tables.is_empty(), "{changes:#?}");
+    }
+
+    #[test]
+    fn parse_table_info_extracts_columns_and_indexes() {
+        let info = serde_json::json!({
+            "events": {},
+            "fields": {
+                "email": "DEFINE FIELD email ON user TYPE string PERMISSIONS FULL",
+                "nickname": "DEFINE FIELD nickname ON user TYPE option<string> PERMISSIONS FULL",
+                "role": "DEFINE FIELD role ON user TYPE string DEFAULT 'member' PERMISSIONS FULL",
+                "address": "DEFINE FIELD address ON user TYPE object PERMISSIONS FULL",
+                "address.city": "DEFINE FIELD address.city ON user TYPE string PERMISSIONS FULL",
+                "tags": "DEFINE FIELD tags ON user TYPE array<string> PERMISSIONS FULL",
+                "tags[*]": "DEFINE FIELD tags[*] ON user TYPE string PERMISSIONS FULL"
+            },
+            "indexes": {
+                "user_email": "DEFINE INDEX user_email ON user FIELDS email UNIQUE"
+            },
+            "lives": {},
+            "tables": {}
+        });
+
+        let table = parse_table_info("user", &info);
+
+        assert_eq!(table.name, "user");
+        assert_eq!(table.primary_key, vec!["id".to_string()]);
+        assert_eq!(
+            table.columns.keys().collect::<Vec<_>>(),
+            vec!["address", "email", "nickname", "role", "tags"]
+        );
+
+        let email = &table.columns["email"];
+        assert_eq!(email.data_type, "string");
+        assert!(!email.nullable);
+        assert_eq!(email.default, None);
+
+        let nickname = &table.columns["nickname"];
+        assert_eq!(nickname.data_type, "option<string>");
+        assert!(nickname.nullable);
+
+        assert_eq!(table.columns["role"].default.as_deref(), Some("'member'"));
+        assert_eq!(table.columns["tags"].data_type, "array<string>");
+
+        assert_eq!(table.indexes.len(), 1);
+        assert_eq!(table.indexes[0].name, "user_email");
+        assert_eq!(table.indexes[0].columns, vec!["email".to_string()]);
+        assert!(table.indexes[0].unique);
+    }
+
+    #[test]
+    fn parse_table_info_handles_empty_response() {
+        let table = parse_table_info("ghost", &serde_json::Value::Null);
+        assert_eq!(table.name, "ghost");
+        assert!(table.columns.is_empty());
+        assert!(table.indexes.is_empty());
+    }
 }
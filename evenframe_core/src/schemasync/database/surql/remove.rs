@@ -1,23 +1,26 @@
-use crate::schemasync::{compare::SchemaChanges, mockmake::Mockmaker};
-use convert_case::{Case, Casing};
+use crate::schemasync::{TableCase, compare::SchemaChanges, mockmake::Mockmaker, surql_ident};
 use tracing::{debug, info};
 
 /// Generate `REMOVE INDEX` statements for indexes that exist in the database
 /// but are no longer declared in Rust (orphans). Kept as a free function so it
 /// can be unit-tested without standing up a `Mockmaker` (which owns a live
 /// `Surreal<Client>`).
-pub fn generate_remove_index_statements(schema_changes: &SchemaChanges) -> String {
+pub fn generate_remove_index_statements(
+    schema_changes: &SchemaChanges,
+    table_naming: TableCase,
+) -> String {
     let mut output = String::new();
     for table_change in &schema_changes.modified_tables {
         if table_change.removed_indexes.is_empty() {
             continue;
         }
-        let table_name = table_change.table_name.to_case(Case::Snake);
+        let table_name = table_naming.apply(&table_change.table_name);
         output.push_str(&format!("-- Removing indexes from table {}\n", table_name));
         for index in &table_change.removed_indexes {
             output.push_str(&format!(
                 "REMOVE INDEX IF EXISTS {} ON TABLE {};\n",
-                index.name, table_name
+                index.name,
+                surql_ident(&table_name)
             ));
         }
         output.push('\n');
@@ -45,13 +48,16 @@ fn extract_event_name(statement: &str) -> Option<String> {
 /// Generate `REMOVE EVENT` statements for events that exist in the database
 /// but are no longer declared in Rust. Without this, deleting a `#[event(...)]`
 /// attribute leaves the event live in SurrealDB forever.
-pub fn generate_remove_event_statements(schema_changes: &SchemaChanges) -> String {
+pub fn generate_remove_event_statements(
+    schema_changes: &SchemaChanges,
+    table_naming: TableCase,
+) -> String {
     let mut output = String::new();
     for table_change in &schema_changes.modified_tables {
         if table_change.removed_events.is_empty() {
             continue;
         }
-        let table_name = table_change.table_name.to_case(Case::Snake);
+        let table_name = table_naming.apply(&table_change.table_name);
         output.push_str(&format!("-- Removing events from table {}\n", table_name));
         for statement in &table_change.removed_events {
             let Some(name) = extract_event_name(statement) else {
@@ -64,7 +70,8 @@ pub fn generate_remove_event_statements(schema_changes: &SchemaChanges) -> Strin
             };
             output.push_str(&format!(
                 "REMOVE EVENT IF EXISTS {} ON TABLE {};\n",
-                name, table_name
+                name,
+                surql_ident(&table_name)
             ));
         }
         output.push('\n');
@@ -72,6 +79,52 @@ pub fn generate_remove_event_statements(schema_changes: &SchemaChanges) -> Strin
     output
 }
 
+/// Generate `REMOVE FIELD` statements for fields that exist in the database
+/// but were deleted from their Rust struct. `DEFINE FIELD OVERWRITE` never
+/// drops a column, so without these the field lingers in SurrealDB.
+pub fn generate_remove_field_statements(
+    schema_changes: &SchemaChanges,
+    table_naming: TableCase,
+) -> String {
+    let mut output = String::new();
+    for table_change in &schema_changes.modified_tables {
+        if table_change.removed_fields.is_empty() {
+            continue;
+        }
+        let table_name = table_naming.apply(&table_change.table_name);
+        output.push_str(&format!("-- Removing fields from table {}\n", table_name));
+        for field_name in &table_change.removed_fields {
+            output.push_str(&format!(
+                "REMOVE FIELD IF EXISTS {} ON TABLE {};\n",
+                field_name,
+                surql_ident(&table_name)
+            ));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// Generate `REMOVE TABLE` statements for tables that no longer have a Rust
+/// struct.
+pub fn generate_remove_table_statements(
+    schema_changes: &SchemaChanges,
+    table_naming: TableCase,
+) -> String {
+    if schema_changes.removed_tables.is_empty() {
+        return String::new();
+    }
+    let mut output = String::from("-- Removing tables\n");
+    for table_name in &schema_changes.removed_tables {
+        output.push_str(&format!(
+            "REMOVE TABLE IF EXISTS {};\n",
+            surql_ident(&table_naming.apply(table_name))
+        ));
+    }
+    output.push('\n');
+    output
+}
+
 impl Mockmaker<'_> {
     /// Generate REMOVE statements based on schema changes and record differences
     ///
@@ -94,42 +147,16 @@ impl Mockmaker<'_> {
         );
         let mut output = String::new();
 
-        // Process removed accesses first
-        if !schema_changes.modified_accesses.is_empty()
-            || !schema_changes.removed_accesses.is_empty()
-        {
-            let mut has_accesses_to_remove = false;
-
-            // Always remove fully removed accesses
+        // Process removed accesses first. Modified accesses are removed and
+        // defined again together by `generate_access_statements`.
+        if !schema_changes.removed_accesses.is_empty() {
             for access_name in &schema_changes.removed_accesses {
                 output.push_str(&format!(
                     "REMOVE ACCESS IF EXISTS {} ON DATABASE;\n",
                     access_name
                 ));
-                has_accesses_to_remove = true;
-            }
-
-            // For modified accesses, check if changes are only ignorable (JWT/Issuer key changes)
-            for access_change in &schema_changes.modified_accesses {
-                // Check if all changes are ignorable (using the enum's is_ignorable method)
-                let only_ignorable_changes = access_change
-                    .changes
-                    .iter()
-                    .all(|change| change.is_ignorable());
-
-                // Only remove and recreate if there are changes that aren't ignorable
-                if !only_ignorable_changes {
-                    output.push_str(&format!(
-                        "REMOVE ACCESS IF EXISTS {} ON DATABASE;\n",
-                        access_change.access_name
-                    ));
-                    has_accesses_to_remove = true;
-                }
-            }
-
-            if has_accesses_to_remove {
-                output.push('\n');
             }
+            output.push('\n');
         }
 
         // Process excess records (negative diffs mean we have too many records)
@@ -145,7 +172,7 @@ impl Mockmaker<'_> {
             output.push_str("-- Removing excess records\n");
             for (table_name, diff) in &self.record_diffs {
                 if *diff < 0 {
-                    let table_name_snake = table_name.to_case(Case::Snake);
+                    let table_name_cased = self.schemasync_config.table_naming.apply(table_name);
                     let excess_count = diff.unsigned_abs() as usize;
 
                     // Get the IDs for this table
@@ -164,7 +191,7 @@ impl Mockmaker<'_> {
                     }
                     output.push_str(&format!(
                         "-- Removed {} excess records from table {}\n",
-                        excess_count, table_name_snake
+                        excess_count, table_name_cased
                     ));
                 }
             }
@@ -175,34 +202,24 @@ impl Mockmaker<'_> {
         // `REMOVE FIELD` on a column that still has a live index referencing
         // it, so orphan indexes must be dropped first. Events can reference
         // fields via $before/$after/$value, so drop orphan events here too.
-        output.push_str(&generate_remove_index_statements(schema_changes));
-        output.push_str(&generate_remove_event_statements(schema_changes));
+        output.push_str(&generate_remove_index_statements(
+            schema_changes,
+            self.schemasync_config.table_naming,
+        ));
+        output.push_str(&generate_remove_event_statements(
+            schema_changes,
+            self.schemasync_config.table_naming,
+        ));
 
         // Process removed fields first (before removing tables)
-        for table_change in &schema_changes.modified_tables {
-            if !table_change.removed_fields.is_empty() {
-                let table_name = table_change.table_name.to_case(Case::Snake);
-                output.push_str(&format!("-- Removing fields from table {}\n", table_name));
-
-                for field_name in &table_change.removed_fields {
-                    output.push_str(&format!(
-                        "REMOVE FIELD IF EXISTS {} ON TABLE {};\n",
-                        field_name, table_name
-                    ));
-                }
-                output.push('\n');
-            }
-        }
-
-        // Process removed tables
-        if !schema_changes.removed_tables.is_empty() {
-            output.push_str("-- Removing tables\n");
-            for table_name in &schema_changes.removed_tables {
-                let table_name_snake = table_name.to_case(Case::Snake);
-                output.push_str(&format!("REMOVE TABLE IF EXISTS {};\n", table_name_snake));
-            }
-            output.push('\n');
-        }
+        output.push_str(&generate_remove_field_statements(
+            schema_changes,
+            self.schemasync_config.table_naming,
+        ));
+        output.push_str(&generate_remove_table_statements(
+            schema_changes,
+            self.schemasync_config.table_naming,
+        ));
 
         output
     }
@@ -225,6 +242,8 @@ mod tests {
             removed_events: Vec::new(),
             new_indexes: Vec::new(),
             removed_indexes: Vec::new(),
+            new_required_fields: Vec::new(),
+            changefeed_changed: false,
         }
     }
 
@@ -246,7 +265,7 @@ mod tests {
             modified_accesses: Vec::new(),
         };
 
-        let out = generate_remove_index_statements(&changes);
+        let out = generate_remove_index_statements(&changes, TableCase::Snake);
         assert!(
             out.contains("REMOVE INDEX IF EXISTS idx_reaction_created_at ON TABLE reaction;"),
             "missing REMOVE INDEX line; got:\n{out}"
@@ -265,7 +284,7 @@ mod tests {
             removed_accesses: Vec::new(),
             modified_accesses: Vec::new(),
         };
-        assert!(generate_remove_index_statements(&changes).is_empty());
+        assert!(generate_remove_index_statements(&changes, TableCase::Snake).is_empty());
     }
 
     #[test]
@@ -306,7 +325,7 @@ mod tests {
             modified_accesses: Vec::new(),
         };
 
-        let out = generate_remove_event_statements(&changes);
+        let out = generate_remove_event_statements(&changes, TableCase::Snake);
         assert!(
             out.contains("REMOVE EVENT IF EXISTS sync_attachment_created ON TABLE attachment;"),
             "missing REMOVE EVENT line; got:\n{out}"
@@ -314,6 +333,31 @@ mod tests {
         assert!(out.contains("-- Removing events from table attachment"));
     }
 
+    #[test]
+    fn emits_remove_field_for_removed_field() {
+        let mut tc = empty_table_change("UserProfile");
+        tc.removed_fields.push("nickname".to_string());
+
+        let changes = SchemaChanges {
+            new_tables: Vec::new(),
+            removed_tables: vec!["LegacyAudit".to_string()],
+            modified_tables: vec![tc, empty_table_change("Post")],
+            new_accesses: Vec::new(),
+            removed_accesses: Vec::new(),
+            modified_accesses: Vec::new(),
+        };
+
+        assert_eq!(
+            generate_remove_field_statements(&changes, TableCase::Snake),
+            "-- Removing fields from table user_profile\n\
+             REMOVE FIELD IF EXISTS nickname ON TABLE user_profile;\n\n"
+        );
+        assert_eq!(
+            generate_remove_table_statements(&changes, TableCase::Snake),
+            "-- Removing tables\nREMOVE TABLE IF EXISTS legacy_audit;\n\n"
+        );
+    }
+
     #[test]
     fn emits_nothing_when_no_orphan_events() {
         let tc = empty_table_change("Attachment");
@@ -325,6 +369,6 @@ mod tests {
             removed_accesses: Vec::new(),
             modified_accesses: Vec::new(),
         };
-        assert!(generate_remove_event_statements(&changes).is_empty());
+        assert!(generate_remove_event_statements(&changes, TableCase::Snake).is_empty());
     }
 }
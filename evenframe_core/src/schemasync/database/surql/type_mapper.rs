@@ -4,12 +4,46 @@
 
 use crate::schemasync::TableConfig;
 use crate::schemasync::database::types::mapper::TypeMapper;
-use crate::types::{FieldType, ForeignTypeRegistry, StructConfig};
-use convert_case::{Case, Casing};
+use crate::schemasync::{TableCase, find_table, surql_ident};
+use crate::types::{FieldType, ForeignTypeRegistry, GeometryKind, StructConfig};
 use std::collections::BTreeMap;
 
 use super::value::to_surreal_string;
 
+/// Splits `s` on `separator` where it isn't nested inside `<>`, `{}`, `()`
+/// or `[]`, trimming each part.
+fn split_top_level(s: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' | '{' | '(' | '[' => depth += 1,
+            '>' | '}' | ')' | ']' => depth = depth.saturating_sub(1),
+            c if c == separator && depth == 0 => {
+                parts.push(s[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(s[start..].trim());
+    parts
+}
+
+/// Parses a scalar literal type: a string (single-quoted as SurrealDB exports
+/// it, or double-quoted as generated), number, or boolean.
+fn parse_literal(t: &str) -> Option<serde_json::Value> {
+    if let Some(s) = t.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')) {
+        return Some(serde_json::Value::String(
+            s.replace("\\'", "'").replace("\\\\", "\\"),
+        ));
+    }
+    serde_json::from_str::<serde_json::Value>(t)
+        .ok()
+        .filter(|value| value.is_string() || value.is_number() || value.is_boolean())
+}
+
 /// Type mapper for SurrealDB
 pub struct SurrealdbTypeMapper<'a> {
     registry: &'a ForeignTypeRegistry,
@@ -46,21 +80,107 @@ impl<'a> SurrealdbTypeMapper<'a> {
     }
 
     /// Resolve a `RecordLink` target name through `output_override`. Tries the
-    /// struct registry first (PascalCase keys), then the table registry
-    /// (snake_case keys). Returns `None` when neither registry is supplied or
-    /// the name doesn't match any known type.
+    /// struct registry first (PascalCase keys), then the table registry, whose
+    /// keys already carry the configured table casing. A struct without a
+    /// table gets the default casing. Returns `None` when neither registry is
+    /// supplied or the name doesn't match any known type.
     fn resolve_record_link_target(&self, name: &str) -> Option<String> {
+        let table = |name: &str| {
+            self.tables
+                .and_then(|tables| find_table(tables, name))
+                .map(|(_, tc)| tc.effective().table_name.clone())
+        };
         if let Some(structs) = self.structs
             && let Some(sc) = structs.get(name)
         {
-            return Some(sc.effective().struct_name.to_case(Case::Snake));
+            let name = &sc.effective().struct_name;
+            return Some(table(name).unwrap_or_else(|| TableCase::default().apply(name)));
         }
-        if let Some(tables) = self.tables
-            && let Some(tc) = tables.get(&name.to_case(Case::Snake))
-        {
-            return Some(tc.effective().table_name.clone());
+        table(name)
+    }
+
+    /// Parse SurrealQL type syntax (as written by `field_type_to_surql` or
+    /// returned in an `INFO` export) back into a FieldType. Names of
+    /// configured foreign types map back to the foreign type; anything else
+    /// that isn't recognised is kept as `FieldType::Other`.
+    pub fn surql_to_field_type(&self, surql: &str) -> FieldType {
+        let t = surql.trim();
+
+        let union = split_top_level(t, '|');
+        if union.len() > 1 {
+            // `option<T>` is exported as `none | T`
+            let rest: Vec<&str> = union.iter().copied().filter(|p| *p != "none").collect();
+            return match rest.as_slice() {
+                [inner] if rest.len() < union.len() => {
+                    FieldType::Option(Box::new(self.surql_to_field_type(inner)))
+                }
+                _ => FieldType::Other(t.to_string()),
+            };
+        }
+
+        if let Some(fields) = t.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            let mut parsed = Vec::new();
+            for field in split_top_level(fields, ',')
+                .into_iter()
+                .filter(|f| !f.is_empty())
+            {
+                let Some((name, ty)) = field.split_once(':') else {
+                    return FieldType::Other(t.to_string());
+                };
+                parsed.push((
+                    name.trim().trim_matches('`').to_string(),
+                    self.surql_to_field_type(ty),
+                ));
+            }
+            return FieldType::Struct(parsed);
+        }
+
+        if let Some((outer, inner)) = t.strip_suffix('>').and_then(|s| s.split_once('<')) {
+            let args = split_top_level(inner, ',');
+            let ty = || Box::new(self.surql_to_field_type(args[0]));
+            return match (outer.trim(), args.len()) {
+                ("option", 1) => FieldType::Option(ty()),
+                ("array", 1) => FieldType::Vec(ty()),
+                ("array", 2) => match args[1].parse() {
+                    Ok(len) => FieldType::Array(ty(), len),
+                    Err(_) => FieldType::Other(t.to_string()),
+                },
+                ("set", 1) => FieldType::Set(ty()),
+                ("record", 1) => {
+                    FieldType::RecordLink(Box::new(FieldType::Other(args[0].to_string())))
+                }
+                ("geometry", 1) => match GeometryKind::from_surql_name(args[0]) {
+                    Some(kind) => FieldType::Geometry(kind),
+                    None => FieldType::Other(t.to_string()),
+                },
+                _ => FieldType::Other(t.to_string()),
+            };
+        }
+
+        if let Some(value) = parse_literal(t) {
+            return FieldType::Literal(value);
+        }
+
+        match t {
+            "string" => FieldType::String,
+            "bool" => FieldType::Bool,
+            "int" => FieldType::I64,
+            "float" => FieldType::F64,
+            "null" => FieldType::Unit,
+            "any" => FieldType::Json,
+            "bytes" => FieldType::Bytes,
+            "geometry" => FieldType::Geometry(GeometryKind::Feature),
+            "object" => FieldType::Struct(Vec::new()),
+            // A link to any table
+            "record" => FieldType::RecordLink(Box::new(FieldType::Json)),
+            _ => self
+                .registry
+                .all()
+                .iter()
+                .find(|(_, ftc)| ftc.surrealdb == t)
+                .map(|(name, _)| FieldType::Other(name.clone()))
+                .unwrap_or_else(|| FieldType::Other(t.to_string())),
         }
-        None
     }
 
     fn field_type_to_surql_inner(&self, field_type: &FieldType) -> String {
@@ -68,22 +188,31 @@ impl<'a> SurrealdbTypeMapper<'a> {
             FieldType::String => "string".to_string(),
             FieldType::Char => "string".to_string(),
             FieldType::Bool => "bool".to_string(),
-            FieldType::I8 | FieldType::I16 | FieldType::I32 | FieldType::I64 | FieldType::I128 => {
-                "int".to_string()
-            }
+            FieldType::I8 | FieldType::I16 | FieldType::I32 | FieldType::I64 => "int".to_string(),
             FieldType::Isize => "int".to_string(),
-            FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 | FieldType::U128 => {
-                "int".to_string()
-            }
+            FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 => "int".to_string(),
+            // Outside the range of both `int` (i64) and `decimal`, so 128-bit
+            // values are stored as decimal strings
+            FieldType::I128 | FieldType::U128 => "string".to_string(),
             FieldType::Usize => "int".to_string(),
             FieldType::F32 | FieldType::F64 => "float".to_string(),
             FieldType::Unit => "null".to_string(),
+            FieldType::Json => "any".to_string(),
+            FieldType::Literal(value) => value.to_string(),
+            FieldType::Bytes => "bytes".to_string(),
+            FieldType::Geometry(kind) => kind.surql_type(),
             FieldType::Option(inner) => {
                 format!("option<{}>", self.field_type_to_surql_inner(inner))
             }
             FieldType::Vec(inner) => {
                 format!("array<{}>", self.field_type_to_surql_inner(inner))
             }
+            FieldType::Set(inner) => {
+                format!("set<{}>", self.field_type_to_surql_inner(inner))
+            }
+            FieldType::Array(inner, len) => {
+                format!("array<{}, {}>", self.field_type_to_surql_inner(inner), len)
+            }
             FieldType::Tuple(_types) => {
                 // SurrealDB doesn't have tuple types, use array<any>
                 "array<any>".to_string()
@@ -92,16 +221,19 @@ impl<'a> SurrealdbTypeMapper<'a> {
             FieldType::HashMap(_, _) | FieldType::BTreeMap(_, _) => "object".to_string(),
             FieldType::RecordLink(inner) => {
                 // Try to extract the table name from the inner type
-                if let FieldType::Other(table_name) = inner.as_ref() {
+                if let FieldType::Enum(table_name)
+                | FieldType::Object(table_name)
+                | FieldType::Other(table_name) = inner.as_ref()
+                {
                     let resolved = self
                         .resolve_record_link_target(table_name)
                         .unwrap_or_else(|| table_name.clone());
-                    format!("record<{}>", resolved)
+                    format!("record<{}>", surql_ident(&resolved))
                 } else {
                     "record".to_string()
                 }
             }
-            FieldType::Other(name) => {
+            FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
                 if let Some(ftc) = self.registry.lookup(name) {
                     ftc.surrealdb.clone()
                 } else {
@@ -117,6 +249,10 @@ impl<'a> TypeMapper for SurrealdbTypeMapper<'a> {
         self.field_type_to_surql(field_type)
     }
 
+    fn parse_type(&self, native_type: &str) -> FieldType {
+        self.surql_to_field_type(native_type)
+    }
+
     fn format_value(&self, field_type: &FieldType, value: &serde_json::Value) -> String {
         to_surreal_string(field_type, value, self.registry)
     }
@@ -150,7 +286,10 @@ impl<'a> TypeMapper for SurrealdbTypeMapper<'a> {
     }
 
     fn format_array(&self, field_type: &FieldType, values: &[serde_json::Value]) -> String {
-        let inner_type = if let FieldType::Vec(inner) = field_type {
+        let inner_type = if let FieldType::Vec(inner)
+        | FieldType::Set(inner)
+        | FieldType::Array(inner, _) = field_type
+        {
             inner.as_ref()
         } else {
             &FieldType::String
@@ -187,6 +326,162 @@ mod tests {
         )))))
     }
 
+    #[test]
+    fn field_types_map_to_surql_types() {
+        let registry = ForeignTypeRegistry::default();
+        let mapper = SurrealdbTypeMapper::new(&registry);
+        let cases = [
+            (FieldType::String, "string"),
+            (FieldType::Char, "string"),
+            (FieldType::Bool, "bool"),
+            (FieldType::I32, "int"),
+            (FieldType::U64, "int"),
+            (FieldType::Usize, "int"),
+            (FieldType::I128, "string"),
+            (FieldType::F64, "float"),
+            (FieldType::Unit, "null"),
+            (FieldType::Json, "any"),
+            (FieldType::Option(Box::new(FieldType::I64)), "option<int>"),
+            (FieldType::Vec(Box::new(FieldType::String)), "array<string>"),
+            (FieldType::Set(Box::new(FieldType::Bool)), "set<bool>"),
+            (
+                FieldType::Array(Box::new(FieldType::F32), 3),
+                "array<float, 3>",
+            ),
+            (
+                FieldType::Tuple(vec![FieldType::String, FieldType::I32]),
+                "array<any>",
+            ),
+            (
+                FieldType::Struct(vec![("name".to_string(), FieldType::String)]),
+                "object",
+            ),
+            (
+                FieldType::HashMap(Box::new(FieldType::String), Box::new(FieldType::I32)),
+                "object",
+            ),
+            (
+                FieldType::RecordLink(Box::new(FieldType::Other("user".to_string()))),
+                "record<user>",
+            ),
+            (vec_record("tag"), "array<record<tag>>"),
+            (FieldType::Other("Unknown".to_string()), "Unknown"),
+        ];
+
+        for (field_type, expected) in cases {
+            assert_eq!(
+                mapper.field_type_to_surql(&field_type),
+                expected,
+                "for {field_type:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn bytes_maps_to_surql_bytes() {
+        let registry = ForeignTypeRegistry::default();
+        let mapper = SurrealdbTypeMapper::new(&registry);
+        assert_eq!(mapper.field_type_to_surql(&FieldType::Bytes), "bytes");
+        assert_eq!(
+            mapper.field_type_to_surql(&FieldType::Option(Box::new(FieldType::Bytes))),
+            "option<bytes>"
+        );
+    }
+
+    #[test]
+    fn geometry_maps_to_surql_geometry() {
+        let registry = ForeignTypeRegistry::default();
+        let mapper = SurrealdbTypeMapper::new(&registry);
+        assert_eq!(
+            mapper.field_type_to_surql(&FieldType::Geometry(GeometryKind::Polygon)),
+            "geometry<polygon>"
+        );
+        assert_eq!(
+            mapper.field_type_to_surql(&FieldType::Geometry(GeometryKind::Feature)),
+            "geometry"
+        );
+    }
+
+    #[test]
+    fn wide_integers_map_to_surql_string() {
+        let registry = ForeignTypeRegistry::default();
+        let mapper = SurrealdbTypeMapper::new(&registry);
+        assert_eq!(mapper.field_type_to_surql(&FieldType::I64), "int");
+        assert_eq!(mapper.field_type_to_surql(&FieldType::I128), "string");
+        assert_eq!(mapper.field_type_to_surql(&FieldType::U128), "string");
+        assert_eq!(
+            mapper.format_value(&FieldType::U128, &serde_json::json!(42)),
+            "'42'"
+        );
+        assert_eq!(
+            mapper.format_value(&FieldType::I128, &serde_json::json!("not a number")),
+            "'0'"
+        );
+    }
+
+    #[test]
+    fn non_finite_floats_never_reach_surql() {
+        let registry = ForeignTypeRegistry::default();
+        let mapper = SurrealdbTypeMapper::new(&registry);
+        assert_eq!(
+            mapper.format_value(&FieldType::F64, &serde_json::json!(1.5)),
+            "1.5"
+        );
+        // serde_json serializes NaN as null
+        assert_eq!(
+            mapper.format_value(&FieldType::F64, &serde_json::json!(f64::NAN)),
+            "0"
+        );
+        assert_eq!(
+            mapper.format_value(&FieldType::F32, &serde_json::json!("NaN")),
+            "0"
+        );
+        let clamped = mapper.format_value(&FieldType::F64, &serde_json::json!("-inf"));
+        assert_eq!(clamped.parse::<f64>().unwrap(), f64::MIN);
+        assert_eq!(
+            mapper
+                .format_value(&FieldType::F64, &serde_json::json!("inf"))
+                .parse::<f64>()
+                .unwrap(),
+            f64::MAX
+        );
+    }
+
+    #[tokio::test]
+    async fn u128_max_round_trips_through_generated_statements() {
+        use surrealdb::{Surreal, engine::local::Mem};
+
+        let registry = ForeignTypeRegistry::default();
+        let mapper = SurrealdbTypeMapper::new(&registry);
+        // serde_json cannot hold u128::MAX as a number, so it arrives as a string
+        let value = mapper.format_value(
+            &FieldType::U128,
+            &serde_json::Value::String(u128::MAX.to_string()),
+        );
+        assert_eq!(value, format!("'{}'", u128::MAX));
+
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        let mut response = db
+            .query(format!(
+                "DEFINE TABLE counter SCHEMAFULL; \
+                 DEFINE FIELD total ON TABLE counter TYPE {}; \
+                 UPSERT counter:1 CONTENT {{ total: {value} }}; \
+                 RETURN counter:1.total;",
+                mapper.field_type_to_surql(&FieldType::U128)
+            ))
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        let stored = response
+            .take::<surrealdb::types::Value>(3)
+            .unwrap()
+            .into_json_value();
+
+        assert_eq!(stored.as_str().unwrap().parse::<u128>().unwrap(), u128::MAX);
+    }
+
     #[test]
     fn record_link_emits_literal_when_no_registries_supplied() {
         let registry = ForeignTypeRegistry::default();
@@ -230,24 +525,15 @@ mod tests {
                 struct_name: "AliasedTable".to_string(),
                 ..StructConfig::default()
             },
-            relation: None,
-            permissions: None,
-            mock_generation_config: None,
-            events: vec![],
-            indexes: vec![],
             output_override: Some(Box::new(TableConfig {
                 table_name: "real_table".to_string(),
                 struct_config: StructConfig {
                     struct_name: "RealTable".to_string(),
                     ..StructConfig::default()
                 },
-                relation: None,
-                permissions: None,
-                mock_generation_config: None,
-                events: vec![],
-                indexes: vec![],
-                output_override: None,
+                ..Default::default()
             })),
+            ..Default::default()
         };
         let structs: BTreeMap<String, StructConfig> = BTreeMap::new();
         let mut tables = BTreeMap::new();
@@ -275,4 +561,91 @@ mod tests {
             "array<record<UnknownThing>>"
         );
     }
+
+    fn assert_round_trips(mapper: &SurrealdbTypeMapper, field_type: FieldType) {
+        let surql = mapper.field_type_to_surql(&field_type);
+        assert_eq!(mapper.parse_type(&surql), field_type, "via `{surql}`");
+    }
+
+    #[test]
+    fn composite_types_round_trip_through_surql() {
+        let registry = ForeignTypeRegistry::default();
+        let mapper = SurrealdbTypeMapper::new(&registry);
+        let record =
+            |table: &str| FieldType::RecordLink(Box::new(FieldType::Other(table.to_string())));
+
+        assert_round_trips(&mapper, FieldType::Option(Box::new(FieldType::I64)));
+        assert_round_trips(&mapper, FieldType::Vec(Box::new(record("user"))));
+        assert_round_trips(
+            &mapper,
+            FieldType::Option(Box::new(FieldType::Vec(Box::new(record("user"))))),
+        );
+        assert_round_trips(&mapper, FieldType::Set(Box::new(FieldType::String)));
+        assert_round_trips(&mapper, FieldType::Array(Box::new(FieldType::F64), 3));
+        assert_round_trips(
+            &mapper,
+            FieldType::Vec(Box::new(FieldType::Option(Box::new(FieldType::Geometry(
+                GeometryKind::Point,
+            ))))),
+        );
+        assert_round_trips(&mapper, FieldType::Geometry(GeometryKind::Feature));
+        assert_round_trips(&mapper, FieldType::Json);
+        assert_round_trips(&mapper, FieldType::Bytes);
+        assert_round_trips(&mapper, FieldType::Literal(serde_json::json!("user")));
+        assert_round_trips(&mapper, FieldType::Literal(serde_json::json!(2)));
+        assert_round_trips(&mapper, FieldType::Literal(serde_json::json!(true)));
+    }
+
+    #[test]
+    fn foreign_types_round_trip_through_surql() {
+        let foreign_types = BTreeMap::from([(
+            "DateTime".to_string(),
+            crate::config::ForeignTypeConfig {
+                surrealdb: "datetime".to_string(),
+                ..Default::default()
+            },
+        )]);
+        let registry = ForeignTypeRegistry::from_config(&foreign_types);
+        let mapper = SurrealdbTypeMapper::new(&registry);
+
+        assert_round_trips(
+            &mapper,
+            FieldType::Option(Box::new(FieldType::Other("DateTime".to_string()))),
+        );
+    }
+
+    #[test]
+    fn parse_type_reads_exported_surql() {
+        let registry = ForeignTypeRegistry::default();
+        let mapper = SurrealdbTypeMapper::new(&registry);
+
+        assert_eq!(
+            mapper.parse_type("none | array<int>"),
+            FieldType::Option(Box::new(FieldType::Vec(Box::new(FieldType::I64))))
+        );
+        assert_eq!(
+            mapper.parse_type("{ name: string, `tags`: set<string>, owner: option<record<user>> }"),
+            FieldType::Struct(vec![
+                ("name".to_string(), FieldType::String),
+                (
+                    "tags".to_string(),
+                    FieldType::Set(Box::new(FieldType::String))
+                ),
+                (
+                    "owner".to_string(),
+                    FieldType::Option(Box::new(FieldType::RecordLink(Box::new(FieldType::Other(
+                        "user".to_string()
+                    )))))
+                ),
+            ])
+        );
+        assert_eq!(
+            mapper.parse_type("'user'"),
+            FieldType::Literal(serde_json::json!("user"))
+        );
+        assert_eq!(
+            mapper.parse_type("'draft' | 'published'"),
+            FieldType::Other("'draft' | 'published'".to_string())
+        );
+    }
 }
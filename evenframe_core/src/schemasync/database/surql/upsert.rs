@@ -1,10 +1,12 @@
 use crate::{
+    error::Result,
+    schemasync::PreservationMode,
     schemasync::mockmake::{Mockmaker, field_value::FieldValueGenerator},
-    schemasync::table::TableConfig,
+    schemasync::table::{TableConfig, surql_ident},
     types::{FieldType, StructField},
 };
-use convert_case::{Case, Casing};
-use tracing::{debug, info};
+use std::ops::Range;
+use tracing::{debug, debug_span, info};
 
 /// Check if a field is nullable (wrapped in Option)
 fn is_nullable_field(field: &StructField) -> bool {
@@ -38,27 +40,36 @@ fn needs_null_preservation(field: &StructField, original_table: Option<&TableCon
 }
 
 impl Mockmaker<'_> {
+    /// Generates the `UPSERT` (or, for unpreserved tables, `CREATE`)
+    /// statements for the records of `table_name` at the indexes in
+    /// `records`. The `DELETE` that clears an unpreserved table comes with
+    /// record 0.
     pub fn generate_upsert_statements(
         &self,
         table_name: &str,
         table_config: &TableConfig,
-    ) -> String {
+        records: Range<usize>,
+    ) -> Result<String> {
+        let _span = debug_span!("gen_table", table = %table_name).entered();
         info!(table_name = %table_name, "Generating upsert statements for table");
         debug!("Table config: {:?}", table_config);
         let mut output = String::new();
-        let config = self
-            .tables
-            .get(table_name)
-            .expect("TableConfig was not found");
 
-        let n = config
-            .mock_generation_config
-            .as_ref()
-            .map(|c| c.n)
-            .unwrap_or(self.schemasync_config.mock_gen_config.default_record_count);
+        // Without preservation nothing from the old records should survive, so
+        // clear the table and CREATE fresh records instead of merging into
+        // stale ones with UPSERT
+        let recreate = self
+            .schemasync_config
+            .mock_gen_config
+            .recreate_unpreserved_tables
+            && table_config.relation.is_none()
+            && self.preservation_mode_for(table_config) == PreservationMode::None;
+        if recreate && records.start == 0 {
+            output.push_str(&format!("DELETE {};\n", surql_ident(table_name)));
+        }
 
         // Step 3: Generate UPSERT statements for each record
-        for i in 0..n {
+        for i in records {
             let mut field_assignments = Vec::new();
 
             // Determine the record ID (default from id_map)
@@ -66,10 +77,10 @@ impl Mockmaker<'_> {
                 if i < ids.len() {
                     ids[i].clone()
                 } else {
-                    format!("{}:{}", table_name.to_case(Case::Snake), i + 1)
+                    format!("{}:{}", surql_ident(table_name), i + 1)
                 }
             } else {
-                format!("{}:{}", table_name.to_case(Case::Snake), i + 1)
+                format!("{}:{}", surql_ident(table_name), i + 1)
             };
 
             // Allow plugin to override the record ID
@@ -88,7 +99,7 @@ impl Mockmaker<'_> {
                                 field_name: "id".to_string(),
                                 field_type: "EvenframeRecordId".to_string(),
                                 record_index: i,
-                                total_records: n,
+                                total_records: self.record_count_for(table_name),
                                 record_id: default_record_id.clone(),
                             };
                         match pm.generate_field_value(plugin_name, &id_input) {
@@ -109,7 +120,11 @@ impl Mockmaker<'_> {
             for table_field in &table_config.struct_config.fields {
                 if table_field.edge_config.is_none()
                     && (table_field.define_config.is_some()
-                        && !table_field.define_config.as_ref().unwrap().should_skip
+                        && !table_field
+                            .define_config
+                            .as_ref()
+                            .unwrap()
+                            .is_database_derived()
                         // Skip readonly fields
                         && table_field
                             .define_config
@@ -125,20 +140,24 @@ impl Mockmaker<'_> {
                         .table_config(table_config)
                         .registry(self.registry)
                         .build()
-                        .run();
+                        .run()?;
+                    self.check_generated_value(table_name, table_field, &field_val);
 
                     // Check if this field needs null preservation
                     let needs_conditional =
                         needs_null_preservation(table_field, self.tables.get(table_name));
 
-                    if needs_conditional {
+                    if needs_conditional && !recreate {
                         // Wrap in conditional to preserve NULL state
                         field_assignments.push(format!(
                             "{}: (IF {} != NULL THEN {} ELSE NULL END)",
-                            table_field.field_name, table_field.field_name, field_val
+                            table_field.output_name(),
+                            table_field.output_name(),
+                            field_val
                         ));
                     } else {
-                        field_assignments.push(format!("{}: {field_val}", table_field.field_name));
+                        field_assignments
+                            .push(format!("{}: {field_val}", table_field.output_name()));
                     }
                 }
             }
@@ -146,7 +165,9 @@ impl Mockmaker<'_> {
             let fields_str = field_assignments.join(", ");
 
             // Generate UPSERT statement with CONTENT for each record
-            if table_config.relation.is_some() {
+            if recreate {
+                output.push_str(&format!("CREATE {record_id} CONTENT {{ {fields_str} }};\n"));
+            } else if table_config.relation.is_some() {
                 // For relation tables, we need special handling
                 output.push_str(&format!("UPSERT {record_id} CONTENT {{ {fields_str} }};\n"));
             } else {
@@ -155,6 +176,6 @@ impl Mockmaker<'_> {
             }
         }
 
-        output
+        Ok(output)
     }
 }
@@ -25,7 +25,7 @@ pub fn to_surreal_string(
                 "false".to_string()
             }
         }
-        FieldType::Other(name) => {
+        FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
             if let Some(ftc) = registry.lookup(name) {
                 match ftc.surql_value_format.as_str() {
                     "datetime" => {
@@ -91,19 +91,20 @@ pub fn to_surreal_string(
                 to_surreal_string_inferred(value)
             }
         }
-        FieldType::F32
-        | FieldType::F64
-        | FieldType::I8
+        // 128-bit fields are `string` columns: neither `int` nor `decimal`
+        // covers their range, and serde_json only carries them as strings
+        FieldType::I128 => wide_integer_to_surreal_string::<i128>(value),
+        FieldType::U128 => wide_integer_to_surreal_string::<u128>(value),
+        FieldType::F32 | FieldType::F64 => float_to_surreal_string(value),
+        FieldType::I8
         | FieldType::I16
         | FieldType::I32
         | FieldType::I64
-        | FieldType::I128
         | FieldType::Isize
         | FieldType::U8
         | FieldType::U16
         | FieldType::U32
         | FieldType::U64
-        | FieldType::U128
         | FieldType::Usize => {
             if value.is_number() {
                 value.to_string()
@@ -112,7 +113,25 @@ pub fn to_surreal_string(
             }
         }
         FieldType::Unit => "null".to_string(),
-        FieldType::Vec(inner_type) => {
+        FieldType::Json | FieldType::Literal(_) | FieldType::Geometry(_) => {
+            to_surreal_string_inferred(value)
+        }
+        // serde serialises `Vec<u8>` as an array of numbers; emit it as a hex
+        // bytes literal so it coerces into a `bytes` field
+        FieldType::Bytes => match value {
+            Value::Array(array) => {
+                let hex: String = array
+                    .iter()
+                    .map(|byte| format!("{:02X}", byte.as_u64().unwrap_or_default() as u8))
+                    .collect();
+                format!("b\"{}\"", hex)
+            }
+            Value::String(s) => format!("<bytes>'{}'", escape_single_quotes(s)),
+            _ => "b\"\"".to_string(),
+        },
+        FieldType::Vec(inner_type)
+        | FieldType::Set(inner_type)
+        | FieldType::Array(inner_type, _) => {
             if let Some(array) = value.as_array() {
                 let items: Vec<String> = array
                     .iter()
@@ -124,8 +143,9 @@ pub fn to_surreal_string(
             }
         }
         FieldType::Option(inner_type) => {
+            // `option<T>` fields accept NONE but reject NULL
             if value.is_null() {
-                "null".to_string()
+                "NONE".to_string()
             } else {
                 to_surreal_string(inner_type, value, registry)
             }
@@ -161,7 +181,11 @@ pub fn to_surreal_string(
                 let mut pairs = Vec::new();
                 for (k, v) in obj {
                     let key_str = match &**key_type {
-                        FieldType::String | FieldType::Char | FieldType::Other(_) => {
+                        FieldType::String
+                        | FieldType::Char
+                        | FieldType::Enum(_)
+                        | FieldType::Object(_)
+                        | FieldType::Other(_) => {
                             format!("'{}'", escape_single_quotes(k))
                         }
                         _ => k.clone(),
@@ -179,7 +203,11 @@ pub fn to_surreal_string(
                 let mut pairs = Vec::new();
                 for (k, v) in obj {
                     let key_str = match &**key_type {
-                        FieldType::String | FieldType::Char | FieldType::Other(_) => {
+                        FieldType::String
+                        | FieldType::Char
+                        | FieldType::Enum(_)
+                        | FieldType::Object(_)
+                        | FieldType::Other(_) => {
                             format!("'{}'", escape_single_quotes(k))
                         }
                         _ => k.clone(),
@@ -415,6 +443,41 @@ fn to_surreal_string_inferred(value: &Value) -> String {
     }
 }
 
+/// Render a 128-bit integer as a quoted decimal string. Accepts JSON numbers
+/// and numeric strings; anything that does not parse as `T` becomes `'0'`.
+fn wide_integer_to_surreal_string<T>(value: &Value) -> String
+where
+    T: std::str::FromStr + std::fmt::Display,
+{
+    let parsed = match value {
+        Value::Number(n) => n.to_string().parse::<T>().ok(),
+        Value::String(s) => s.trim().parse::<T>().ok(),
+        _ => None,
+    };
+    match parsed {
+        Some(n) => format!("'{}'", n),
+        None => "'0'".to_string(),
+    }
+}
+
+/// Render a float, never emitting `NaN`/`Infinity` (not valid SurrealQL).
+/// serde_json turns non-finite floats into `null`, which becomes `0`; numeric
+/// strings are parsed, with infinities clamped to the finite `f64` range.
+fn float_to_surreal_string(value: &Value) -> String {
+    let parsed = match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse::<f64>().ok(),
+        _ => None,
+    };
+    match parsed {
+        Some(n) if n.is_nan() => "0".to_string(),
+        Some(n) if n.is_infinite() => format!("{:e}", n.clamp(f64::MIN, f64::MAX)),
+        Some(_) if value.is_number() => value.to_string(),
+        Some(n) => n.to_string(),
+        None => "0".to_string(),
+    }
+}
+
 /// Check if a string is an ISO 8601 datetime (e.g. "2025-05-29T23:00:00Z").
 fn is_iso8601_datetime(s: &str) -> bool {
     if s.len() < 20 {
@@ -17,6 +17,16 @@ pub trait TypeMapper: Send + Sync {
     /// - I64 -> "BIGINT" (Postgres), "BIGINT" (MySQL), "INTEGER" (SQLite), "int" (SurrealDB)
     fn field_type_to_native(&self, field_type: &FieldType) -> String;
 
+    /// Parse a native type string back into a FieldType, inverting
+    /// [`TypeMapper::field_type_to_native`] as far as the mapping allows.
+    ///
+    /// Several FieldTypes share a native type (every integer width is
+    /// `int` in SurrealDB), so the canonical widest variant is returned.
+    /// Mappers without a parser keep the string as `FieldType::Other`.
+    fn parse_type(&self, native_type: &str) -> FieldType {
+        FieldType::Other(native_type.trim().to_string())
+    }
+
     /// Format a JSON value as a string suitable for use in a query.
     ///
     /// Handles proper escaping and type-specific formatting.
@@ -177,13 +187,19 @@ pub mod defaults {
             FieldType::F64 => "DOUBLE PRECISION".to_string(),
             FieldType::Unit => "".to_string(), // Skip unit types
             FieldType::Option(inner) => default_sql_type(inner, registry), // Same type, just nullable
-            FieldType::Vec(_) => "JSON".to_string(),
+            FieldType::Vec(_) | FieldType::Set(_) | FieldType::Array(_, _) => "JSON".to_string(),
             FieldType::Tuple(_) => "JSON".to_string(),
             FieldType::Struct(_) => "JSON".to_string(),
             FieldType::HashMap(_, _) => "JSON".to_string(),
             FieldType::BTreeMap(_, _) => "JSON".to_string(),
+            FieldType::Json => "JSON".to_string(),
+            FieldType::Literal(value) => {
+                default_sql_type(&FieldType::literal_base_type(value), registry)
+            }
+            FieldType::Bytes => "BLOB".to_string(),
+            FieldType::Geometry(_) => "JSON".to_string(), // GeoJSON
             FieldType::RecordLink(_) => "TEXT".to_string(), // Foreign key reference
-            FieldType::Other(name) => {
+            FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
                 if let Some(ftc) = registry.lookup(name) {
                     // Use postgres as the default SQL type
                     ftc.postgres.clone()
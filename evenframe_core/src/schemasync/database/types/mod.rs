@@ -282,7 +282,7 @@ pub struct IndexInfo {
 }
 
 /// Relationship direction for querying edges
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RelationshipDirection {
     /// Outgoing relationships (from this record)
     Outgoing,
@@ -292,6 +292,18 @@ pub enum RelationshipDirection {
     Both,
 }
 
+impl RelationshipDirection {
+    /// The single directions a query in this direction covers, with `Both`
+    /// expanding to outgoing then incoming
+    pub fn sides(self) -> &'static [RelationshipDirection] {
+        match self {
+            Self::Outgoing => &[Self::Outgoing],
+            Self::Incoming => &[Self::Incoming],
+            Self::Both => &[Self::Outgoing, Self::Incoming],
+        }
+    }
+}
+
 /// A relationship between two records
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Relationship {
@@ -301,6 +313,10 @@ pub struct Relationship {
     pub from_id: String,
     /// Target record ID
     pub to_id: String,
+    /// Which side of the edge the queried record is on: `Outgoing` when it is
+    /// the source, `Incoming` when it is the target. Never `Both`; a
+    /// self-referencing edge is returned once in each direction.
+    pub direction: RelationshipDirection,
     /// Additional data on the relationship
     pub data: Option<serde_json::Value>,
 }
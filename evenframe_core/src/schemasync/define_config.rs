@@ -2,13 +2,17 @@ use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{LitStr, parenthesized};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct DefineConfig {
     pub select_permissions: Option<String>,
     pub update_permissions: Option<String>,
     pub create_permissions: Option<String>,
     pub data_type: Option<String>,
     pub should_skip: bool,
+    /// Assert `$value != NONE` on non-optional fields, for fields SurrealDB
+    /// would not otherwise enforce (e.g. inside a `FLEXIBLE` object)
+    #[serde(default)]
+    pub strict_required: bool,
     pub default: Option<String>,
     pub default_always: Option<String>,
     pub value: Option<String>,
@@ -54,6 +58,7 @@ impl ToTokens for DefineConfig {
         };
 
         let should_skip = self.should_skip;
+        let strict_required = self.strict_required;
 
         tokens.extend(quote! {
             ::evenframe::schemasync::DefineConfig {
@@ -62,6 +67,7 @@ impl ToTokens for DefineConfig {
                 create_permissions: #create_permissions,
                 data_type: #data_type,
                 should_skip: #should_skip,
+                strict_required: #strict_required,
                 default: #default,
                 default_always: #default_always,
                 value: #value,
@@ -76,12 +82,19 @@ impl ToTokens for DefineConfig {
 }
 
 impl DefineConfig {
+    /// Whether SurrealDB derives this field itself (`VALUE`, `COMPUTED`) or it
+    /// is skipped, in which case mock data must not write to it.
+    pub fn is_database_derived(&self) -> bool {
+        self.should_skip || self.value.is_some() || self.computed.is_some()
+    }
+
     pub fn parse(field: &syn::Field) -> syn::Result<Option<DefineConfig>> {
         let mut select_permissions: Option<String> = None;
         let mut update_permissions: Option<String> = None;
         let mut create_permissions: Option<String> = None;
         let mut data_type: Option<String> = None;
         let mut should_skip: Option<bool> = None;
+        let mut strict_required: Option<bool> = None;
         let mut default: Option<String> = None;
         let mut default_always: Option<String> = None;
         let mut value: Option<String> = None;
@@ -170,6 +183,15 @@ impl DefineConfig {
                         should_skip = Some(content.parse::<syn::LitBool>()?.value);
                         return Ok(());
                     }
+                    if meta.path.is_ident("strict_required") {
+                        let content;
+                        parenthesized!(content in meta.input);
+                        if strict_required.is_some() {
+                            return Err(meta.error("duplicate strict_required attribute"));
+                        }
+                        strict_required = Some(content.parse::<syn::LitBool>()?.value);
+                        return Ok(());
+                    }
                     if meta.path.is_ident("default") {
                         let mut content;
                         parenthesized!(content in meta.input);
@@ -238,12 +260,14 @@ impl DefineConfig {
                 })?;
 
                 let should_skip = should_skip.unwrap_or(false);
+                let strict_required = strict_required.unwrap_or(false);
                 return Ok(Some(DefineConfig {
                     select_permissions,
                     update_permissions,
                     create_permissions,
                     data_type,
                     should_skip,
+                    strict_required,
                     default,
                     default_always,
                     value,
@@ -262,6 +286,7 @@ impl DefineConfig {
             create_permissions: Some("FULL".to_string()),
             data_type: None,
             should_skip: false,
+            strict_required: false,
             default: None,
             default_always: None,
             value: None,
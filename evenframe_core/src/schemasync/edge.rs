@@ -370,7 +370,7 @@ impl FromStr for Direction {
                 Ok(Direction::To)
             }
             "both" => {
-                trace!("Parsed direction: Both (mapped to To)");
+                trace!("Parsed direction: Both");
                 Ok(Direction::Both)
             }
             _ => {
@@ -1,6 +1,51 @@
 use serde::{Deserialize, Serialize};
 
+use crate::schemasync::table::surql_ident;
+
+/// A `DEFINE EVENT` on a table, declared with
+/// `#[evenframe(event(name = "…", when = "…", then = "…"))]`. A complete
+/// statement given with `#[event("DEFINE EVENT …")]` is kept in `statement`
+/// and emitted as written instead.
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct EventConfig {
-    pub statement: String,
+    #[serde(default)]
+    pub name: String,
+    /// Condition the event fires on, e.g. `$event = "UPDATE"`
+    #[serde(default)]
+    pub when: String,
+    /// Statements run when the event fires, without the surrounding braces
+    #[serde(default)]
+    pub then: String,
+    #[serde(default)]
+    pub statement: Option<String>,
+}
+
+impl EventConfig {
+    /// An event defined by a complete `DEFINE EVENT` statement.
+    pub fn from_statement(statement: impl Into<String>) -> Self {
+        Self {
+            statement: Some(statement.into()),
+            ..Self::default()
+        }
+    }
+
+    /// The `DEFINE EVENT` statement for this event on `table_name`, ending
+    /// in `;`.
+    pub fn define_statement(&self, table_name: &str) -> String {
+        if let Some(statement) = &self.statement {
+            let statement = statement.trim();
+            return if statement.ends_with(';') {
+                statement.to_string()
+            } else {
+                format!("{statement};")
+            };
+        }
+        format!(
+            "DEFINE EVENT OVERWRITE {} ON TABLE {} WHEN {} THEN {{ {} }};",
+            self.name,
+            surql_ident(table_name),
+            self.when,
+            self.then.trim()
+        )
+    }
 }
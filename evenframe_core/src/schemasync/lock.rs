@@ -0,0 +1,219 @@
+//! Schema lock file for detecting drift between Rust types and the committed schema.
+//!
+//! The lock stores a canonical form of every generated `DEFINE` statement
+//! (trimmed, one per line, sorted) together with a blake3 hash of that form.
+//! Because the canonical form does not depend on table iteration order or
+//! whitespace, regenerating an unchanged schema always yields the same hash,
+//! while any change to a table, field, index, or event alters it.
+
+use crate::{
+    error::{EvenframeError, Result},
+    schemasync::{
+        database::surql::define::{generate_analyzer_statements, generate_define_statements},
+        table::TableConfig,
+    },
+    types::{ForeignTypeRegistry, StructConfig, TaggedUnion},
+};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use tracing::{debug, info};
+
+/// File name of the schema lock, written next to the project's config file.
+pub const LOCK_FILE_NAME: &str = "evenframe.lock";
+
+const HASH_PREFIX: &str = "# hash: ";
+
+/// Normalizes a block of define statements into one statement per line,
+/// with whitespace collapsed, blank lines dropped, and lines sorted.
+pub fn canonicalize_define_statements(statements: &str) -> String {
+    let mut canonical: Vec<String> = statements
+        .split_inclusive(";\n")
+        .map(|statement| statement.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|statement| !statement.is_empty())
+        .collect();
+    canonical.sort();
+    canonical.join("\n")
+}
+
+/// Returns the hex blake3 digest of a canonical schema.
+pub fn schema_hash(canonical: &str) -> String {
+    blake3::hash(canonical.as_bytes()).to_hex().to_string()
+}
+
+/// Generates the define statements for every table, along with the analyzers
+/// their search indexes need, and returns their canonical form.
+///
+/// Statements are always generated in non-full-refresh mode so the lock does
+/// not change with the mode a particular run happens to use.
+pub fn canonical_schema(
+    tables: &BTreeMap<String, TableConfig>,
+    objects: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &ForeignTypeRegistry,
+) -> String {
+    let statements = std::iter::once(generate_analyzer_statements(tables))
+        .chain(tables.iter().map(|(table_name, table)| {
+            generate_define_statements(
+                table_name,
+                table.effective(),
+                tables,
+                objects,
+                enums,
+                false,
+                registry,
+            )
+        }))
+        .collect::<String>();
+    canonicalize_define_statements(&statements)
+}
+
+/// Renders the contents of a lock file for a canonical schema.
+pub fn render_lock(canonical: &str) -> String {
+    format!(
+        "# Generated by evenframe. Do not edit.\n{HASH_PREFIX}{}\n\n{canonical}\n",
+        schema_hash(canonical)
+    )
+}
+
+/// Writes the lock file for a canonical schema to `path`.
+pub fn write_lock(path: &Path, canonical: &str) -> Result<()> {
+    fs::write(path, render_lock(canonical))?;
+    info!("Wrote schema lock to {}", path.display());
+    Ok(())
+}
+
+/// Compares the lock file at `path` with a canonical schema.
+///
+/// Returns an error describing the added and removed statements when the
+/// committed lock is missing or no longer matches.
+pub fn check_lock(path: &Path, canonical: &str) -> Result<()> {
+    let contents = fs::read_to_string(path).map_err(|e| {
+        EvenframeError::config(format!(
+            "Failed to read schema lock {}: {e}. Run `evenframe schemasync --write-lock` to create it",
+            path.display()
+        ))
+    })?;
+
+    let locked_hash = contents
+        .lines()
+        .find_map(|line| line.strip_prefix(HASH_PREFIX))
+        .map(str::trim);
+    let current_hash = schema_hash(canonical);
+    debug!(?locked_hash, %current_hash, "Comparing schema lock");
+    if locked_hash == Some(current_hash.as_str()) {
+        info!("Schema matches lock {}", path.display());
+        return Ok(());
+    }
+
+    let locked: BTreeSet<&str> = contents
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+    let current: BTreeSet<&str> = canonical.lines().collect();
+    let mut message = format!(
+        "Schema has drifted from lock {}. Run `evenframe schemasync --write-lock` to update it",
+        path.display()
+    );
+    for statement in current.difference(&locked) {
+        message.push_str(&format!("\n  + {statement}"));
+    }
+    for statement in locked.difference(&current) {
+        message.push_str(&format!("\n  - {statement}"));
+    }
+    Err(EvenframeError::config(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemasync::DefineConfig;
+    use crate::types::{FieldType, StructField};
+
+    fn tables(title_type: FieldType) -> BTreeMap<String, TableConfig> {
+        let table = |name: &str, struct_name: &str, fields: Vec<StructField>| TableConfig {
+            table_name: name.to_string(),
+            struct_config: StructConfig {
+                struct_name: struct_name.to_string(),
+                fields,
+                ..StructConfig::default()
+            },
+            ..Default::default()
+        };
+        let field = |name: &str, field_type: FieldType| StructField {
+            field_name: name.to_string(),
+            field_type,
+            define_config: Some(DefineConfig::default()),
+            ..StructField::default()
+        };
+
+        BTreeMap::from([
+            (
+                "post".to_string(),
+                table(
+                    "post",
+                    "Post",
+                    vec![field("title", title_type), field("views", FieldType::U32)],
+                ),
+            ),
+            (
+                "user".to_string(),
+                table("user", "User", vec![field("name", FieldType::String)]),
+            ),
+        ])
+    }
+
+    fn canonical(title_type: FieldType) -> String {
+        canonical_schema(
+            &tables(title_type),
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+            &ForeignTypeRegistry::default(),
+        )
+    }
+
+    #[test]
+    fn canonical_schema_is_stable_and_sensitive_to_field_changes() {
+        let first = canonical(FieldType::String);
+        let second = canonical(FieldType::String);
+        assert_eq!(first, second);
+        assert_eq!(schema_hash(&first), schema_hash(&second));
+        assert!(first.contains("DEFINE FIELD OVERWRITE title ON TABLE post"));
+
+        let changed = canonical(FieldType::I64);
+        assert_ne!(schema_hash(&first), schema_hash(&changed));
+    }
+
+    #[test]
+    fn canonicalization_ignores_order_and_whitespace() {
+        let a = "DEFINE TABLE b;\nDEFINE  TABLE a;\n";
+        let b = "  DEFINE TABLE a;\n\nDEFINE TABLE b;\n";
+        assert_eq!(
+            canonicalize_define_statements(a),
+            canonicalize_define_statements(b)
+        );
+    }
+
+    #[test]
+    fn check_lock_reports_drift() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOCK_FILE_NAME);
+
+        assert!(check_lock(&path, &canonical(FieldType::String)).is_err());
+
+        write_lock(&path, &canonical(FieldType::String)).unwrap();
+        check_lock(&path, &canonical(FieldType::String)).unwrap();
+
+        let err = check_lock(&path, &canonical(FieldType::I64))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("+ DEFINE FIELD OVERWRITE title ON TABLE post"),
+            "{err}"
+        );
+        assert!(
+            err.contains("- DEFINE FIELD OVERWRITE title ON TABLE post"),
+            "{err}"
+        );
+    }
+}
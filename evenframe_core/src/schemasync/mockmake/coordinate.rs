@@ -9,13 +9,13 @@ use crate::error::EvenframeError;
 #[cfg(feature = "schemasync")]
 use crate::schemasync::mockmake::field_value::FieldValueGenerator;
 #[cfg(feature = "surrealdb")]
-use crate::schemasync::mockmake::{Mockmaker, format::Format};
+use crate::schemasync::mockmake::{Mockmaker, format::Format, seeded_reference_time, seeded_rng};
 #[cfg(feature = "surrealdb")]
-use crate::types::{FieldType, StructField};
+use crate::types::{FieldType, StructConfig, StructField};
 #[cfg(feature = "surrealdb")]
 use chrono::{DateTime, Duration, NaiveDate, Utc};
 #[cfg(feature = "surrealdb")]
-use rand::RngExt;
+use rand::{RngExt, rngs::StdRng};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize, Builder)]
 pub struct CoordinationId {
@@ -25,8 +25,9 @@ pub struct CoordinationId {
 
 #[cfg(feature = "surrealdb")]
 impl CoordinationId {
-    /// Parse a field path like "recurrence_rule.recurrence_begins" into (table_name, struct_name) for Mockmaker extraction
-    fn parse_field_path(&self, mockmaker: &Mockmaker<'_>) -> (String, String) {
+    /// Resolve a field path like "recurrence_rule.recurrence_begins" to the
+    /// struct that owns its last segment, walking nested objects from the table
+    fn parent_struct<'m>(&self, mockmaker: &'m Mockmaker<'_>) -> &'m StructConfig {
         tracing::trace!("Parsing field path {}", &self.field_name);
         let table_config = mockmaker.tables.get(&self.table_name).unwrap_or_else(|| {
             tracing::trace!("{:#?}", mockmaker.tables);
@@ -38,47 +39,46 @@ impl CoordinationId {
 
         let mut obj = &table_config.struct_config;
 
-        for field_path in self.field_name.split('.') {
-            for struct_field in &obj.fields {
-                if struct_field.field_name == field_path {
-                    obj = mockmaker
-                        .objects
-                        .get(&struct_field.field_name)
-                        .unwrap_or_else(|| {
-                            tracing::trace!("{:#?}", mockmaker.objects);
-                            panic!(
-                                "App struct {} not in Mockmaker's 'objects' HashMap",
-                                struct_field.field_name
-                            )
-                        });
-                }
-            }
+        let segments: Vec<&str> = self.field_name.split('.').collect();
+        for segment in &segments[..segments.len() - 1] {
+            let struct_field = obj
+                .fields
+                .iter()
+                .find(|f| f.field_name == *segment)
+                .unwrap_or_else(|| {
+                    panic!("Field {} not found in struct {}", segment, obj.struct_name)
+                });
+            let (FieldType::Enum(type_name)
+            | FieldType::Object(type_name)
+            | FieldType::Other(type_name)) = &struct_field.field_type
+            else {
+                panic!(
+                    "Field {} in path {} is not an object",
+                    segment, self.field_name
+                )
+            };
+            obj = mockmaker.objects.get(type_name).unwrap_or_else(|| {
+                tracing::trace!("{:#?}", mockmaker.objects);
+                panic!("App struct {type_name} not in Mockmaker's 'objects' HashMap")
+            });
         }
 
-        (self.table_name.clone(), obj.struct_name.to_owned())
+        obj
     }
 
     pub fn get_field(&self, mockmaker: &Mockmaker<'_>) -> StructField {
-        let (_, object_name) = self.parse_field_path(mockmaker);
-
-        let struct_config = mockmaker.objects.get(&object_name).unwrap_or_else(|| {
-            tracing::trace!("{:#?}", mockmaker.objects);
-            panic!("App struct {object_name} not in Mockmaker's 'objects' HashMap")
-        });
-
-        for struct_field in &struct_config.fields {
-            if struct_field.field_name
-                == self
-                    .field_name
-                    .split('.')
-                    .next_back()
-                    .unwrap_or(&self.field_name)
-            {
-                return struct_field.clone();
-            }
-        }
-
-        unreachable!("Should have matched with a struct field name");
+        let field_name = self
+            .field_name
+            .split('.')
+            .next_back()
+            .unwrap_or(&self.field_name);
+
+        self.parent_struct(mockmaker)
+            .fields
+            .iter()
+            .find(|f| f.field_name == field_name)
+            .cloned()
+            .unwrap_or_else(|| unreachable!("Should have matched with a struct field name"))
     }
 }
 
@@ -99,7 +99,7 @@ pub struct CoordinationGroup {
 }
 #[cfg(feature = "schemasync")]
 impl Mockmaker<'_> {
-    pub fn generate_coordinated_values(&mut self) {
+    pub fn generate_coordinated_values(&mut self) -> crate::error::Result<()> {
         tracing::debug!("Generating coordinated values for all tables");
 
         // Build coordination groups from the tables
@@ -110,6 +110,25 @@ impl Mockmaker<'_> {
 
         // Process each coordination group
         for coordination_group in coordination_groups {
+            // Seeded groups draw from their own stream, independent of the
+            // order groups and tables are generated in
+            let seed = coordination_group
+                .tables
+                .iter()
+                .filter_map(|table_name| self.tables.get(table_name))
+                .find_map(|table_config| self.seed_for(table_config));
+            let stream = format!(
+                "coordination:{}",
+                coordination_group
+                    .tables
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(",")
+            );
+            *self.rng() = seeded_rng(seed, &stream);
+            let reference_time = seeded_reference_time(seed);
+
             // Get the maximum record count from all tables in this group
             let n = coordination_group
                 .tables
@@ -141,17 +160,20 @@ impl Mockmaker<'_> {
                                 .table_config(table_config)
                                 .registry(self.registry)
                                 .build()
-                                .run();
+                                .run()?;
 
                             for coordination_id in &coordination_pair.coordinated_fields {
                                 self.coordinated_values
-                                    .insert(coordination_id.clone(), value.clone());
+                                    .entry(coordination_id.clone())
+                                    .or_default()
+                                    .insert(index, value.clone());
                             }
                         }
 
                         Coordination::InitializeSequential {
                             field_names: _,
                             increment,
+                            start,
                         } => {
                             // Collect the fields for this coordination
                             let fields: Vec<StructField> = coordination_pair
@@ -162,8 +184,13 @@ impl Mockmaker<'_> {
                             let field_refs: Vec<&StructField> = fields.iter().collect();
 
                             // Generate sequential values using the dedicated function
-                            let values =
-                                Self::generate_sequential_values(&field_refs, index, increment);
+                            let values = Self::generate_sequential_values(
+                                &field_refs,
+                                index,
+                                increment,
+                                start.as_ref(),
+                                reference_time,
+                            );
 
                             // Store the generated values
                             for coordination_id in &coordination_pair.coordinated_fields {
@@ -175,7 +202,9 @@ impl Mockmaker<'_> {
 
                                 if let Some(value) = values.get(field_name) {
                                     self.coordinated_values
-                                        .insert(coordination_id.clone(), value.clone());
+                                        .entry(coordination_id.clone())
+                                        .or_default()
+                                        .insert(index, value.clone());
                                 }
                             }
                         }
@@ -192,7 +221,12 @@ impl Mockmaker<'_> {
                             let field_refs: Vec<&StructField> = fields.iter().collect();
 
                             // Generate sum values using the dedicated function
-                            let values = Self::generate_sum_values(&field_refs, index, *total);
+                            let values = Self::generate_sum_values(
+                                &field_refs,
+                                index,
+                                *total,
+                                &mut self.rng(),
+                            );
 
                             // Store the generated values
                             for coordination_id in &coordination_pair.coordinated_fields {
@@ -204,7 +238,9 @@ impl Mockmaker<'_> {
 
                                 if let Some(value) = values.get(field_name) {
                                     self.coordinated_values
-                                        .insert(coordination_id.clone(), value.clone());
+                                        .entry(coordination_id.clone())
+                                        .or_default()
+                                        .insert(index, value.clone());
                                 }
                             }
                         }
@@ -249,11 +285,14 @@ impl Mockmaker<'_> {
                                     .table_config(table_config)
                                     .registry(self.registry)
                                     .build()
-                                    .run();
+                                    .run()?;
 
                                 let field_name = field.field_name.clone();
                                 source_values_map.insert(field_name, value.clone());
-                                self.coordinated_values.insert(coord_id.clone(), value);
+                                self.coordinated_values
+                                    .entry(coord_id.clone())
+                                    .or_default()
+                                    .insert(index, value);
                             }
 
                             // Generate derived value using the dedicated function
@@ -270,13 +309,30 @@ impl Mockmaker<'_> {
                             if let (Some(target_id), Some(value)) =
                                 (target_coord_id, derived_values.get(target_field_name))
                             {
-                                self.coordinated_values.insert(target_id, value.clone());
+                                self.coordinated_values
+                                    .entry(target_id)
+                                    .or_default()
+                                    .insert(index, value.clone());
                             }
                         }
                         Coordination::OneToOne(_) => {
                             // OneToOne is handled directly in handle_record_id
                             // at generation time, not through pre-computed values.
                         }
+                        Coordination::InitializeWeightedChoice { choices, .. } => {
+                            let value = format!(
+                                "'{}'",
+                                Self::generate_weighted_choice(choices, &mut self.rng())
+                                    .replace('\'', "\\'")
+                            );
+
+                            for coordination_id in &coordination_pair.coordinated_fields {
+                                self.coordinated_values
+                                    .entry(coordination_id.clone())
+                                    .or_default()
+                                    .insert(index, value.clone());
+                            }
+                        }
                         Coordination::InitializeCoherent(coherent_dataset) => {
                             // Collect the fields for this coordination
                             let fields: Vec<StructField> = coordination_pair
@@ -291,6 +347,7 @@ impl Mockmaker<'_> {
                                 &field_refs,
                                 coherent_dataset,
                                 index,
+                                &mut self.rng(),
                             );
 
                             // Store the generated values
@@ -307,7 +364,9 @@ impl Mockmaker<'_> {
                                 // Try exact match first
                                 if let Some(value) = values.get(field_key) {
                                     self.coordinated_values
-                                        .insert(coordination_id.clone(), value.clone());
+                                        .entry(coordination_id.clone())
+                                        .or_default()
+                                        .insert(index, value.clone());
                                 } else {
                                     // Try to find a matching key in the values map
                                     for (key, value) in &values {
@@ -315,7 +374,9 @@ impl Mockmaker<'_> {
                                             || key == field_key
                                         {
                                             self.coordinated_values
-                                                .insert(coordination_id.clone(), value.clone());
+                                                .entry(coordination_id.clone())
+                                                .or_default()
+                                                .insert(index, value.clone());
                                             break;
                                         }
                                     }
@@ -326,97 +387,73 @@ impl Mockmaker<'_> {
                 }
             }
         }
+        Ok(())
     }
 
-    /// Generate sequential values for fields
+    /// Generate sequential values for fields. The sequence runs across
+    /// records, so record `index` continues where the previous record's last
+    /// field left off. Datetime sequences without a `start` begin at midnight
+    /// of `reference_time`'s day.
     pub fn generate_sequential_values(
         fields: &[&StructField],
-        _index: usize,
+        index: usize,
         increment: &CoordinateIncrement,
+        start: Option<&CoordinatedValue>,
+        reference_time: DateTime<Utc>,
     ) -> BTreeMap<String, String> {
-        tracing::trace!(field_count = fields.len(), "Generating sequential values");
+        tracing::trace!(
+            field_count = fields.len(),
+            index,
+            "Generating sequential values"
+        );
         let mut values = BTreeMap::new();
 
-        // Generate base value
-        let first_field = &fields[0];
+        let Some(first_field) = fields.first() else {
+            return values;
+        };
+        let start_str = match start {
+            Some(CoordinatedValue::String(s)) => Some(s.as_str()),
+            _ => None,
+        };
 
-        match &first_field.format {
-            Some(Format::DateTime) => {
-                // Generate base datetime
-                let base: DateTime<Utc> = Utc::now();
-                values.insert(first_field.field_name.clone(), base.to_rfc3339());
+        for (offset, field) in fields.iter().enumerate() {
+            let position = (index * fields.len() + offset) as i64;
 
-                // Generate subsequent values
-                for (i, field) in fields.iter().skip(1).enumerate() {
-                    let incremented = match increment {
-                        CoordinateIncrement::Days(d) => {
-                            base + Duration::days(*d as i64 * (i + 1) as i64)
-                        }
-                        CoordinateIncrement::Hours(h) => {
-                            base + Duration::hours(*h as i64 * (i + 1) as i64)
-                        }
-                        CoordinateIncrement::Minutes(m) => {
-                            base + Duration::minutes(*m as i64 * (i + 1) as i64)
-                        }
-                        _ => base, // Fallback to base if increment type doesn't match
-                    };
-                    values.insert(field.field_name.clone(), incremented.to_rfc3339());
+            let value = match (&first_field.format, time_increment(increment)) {
+                (Some(Format::Date), step) => {
+                    let base = start_str
+                        .and_then(parse_sequence_start_date)
+                        .unwrap_or_else(|| {
+                            NaiveDate::from_ymd_opt(2024, 1, 1).expect("2024-01-01 is a valid date")
+                        });
+                    let date = base + step.unwrap_or_default() * position as i32;
+                    format!("'{}'", date)
                 }
-            }
-            Some(Format::Date) => {
-                // Generate base date
-                let base = NaiveDate::from_ymd_opt(2024, 1, 1)
-                    .or_else(|| NaiveDate::from_ymd_opt(2024, 1, 2))
-                    .or_else(|| NaiveDate::from_ymd_opt(2023, 12, 31))
-                    .expect("At least one of these dates should be valid");
-                values.insert(first_field.field_name.clone(), base.to_string());
-
-                // Generate subsequent values
-                for (i, field) in fields.iter().skip(1).enumerate() {
-                    let incremented = match increment {
-                        CoordinateIncrement::Days(d) => {
-                            base + Duration::days(*d as i64 * (i + 1) as i64)
-                        }
-                        _ => base, // For dates, only day increment makes sense
-                    };
-                    values.insert(field.field_name.clone(), incremented.to_string());
+                (_, Some(step)) => {
+                    let base = start_str
+                        .and_then(parse_sequence_start_datetime)
+                        .unwrap_or_else(|| {
+                            reference_time
+                                .date_naive()
+                                .and_hms_opt(0, 0, 0)
+                                .expect("midnight is a valid time")
+                                .and_utc()
+                        });
+                    format!("d'{}'", (base + step * position as i32).to_rfc3339())
                 }
-            }
-            Some(Format::DateWithinDays(_)) => {
-                // Generate base datetime for DateWithinDays format
-                let base: DateTime<Utc> = Utc::now();
-                values.insert(first_field.field_name.clone(), base.to_rfc3339());
-
-                // Generate subsequent values
-                for (i, field) in fields.iter().skip(1).enumerate() {
-                    let incremented = match increment {
-                        CoordinateIncrement::Days(d) => {
-                            base + Duration::days(*d as i64 * (i + 1) as i64)
-                        }
-                        CoordinateIncrement::Hours(h) => {
-                            base + Duration::hours(*h as i64 * (i + 1) as i64)
-                        }
-                        CoordinateIncrement::Minutes(m) => {
-                            base + Duration::minutes(*m as i64 * (i + 1) as i64)
-                        }
-                        _ => base, // Fallback to base if increment type doesn't match
+                (_, None) => {
+                    let base = match start {
+                        Some(CoordinatedValue::F64(n)) => *n,
+                        _ => 0.0,
                     };
-                    values.insert(field.field_name.clone(), incremented.to_rfc3339());
-                }
-            }
-            _ => {
-                // Numeric sequential
-                let mut rng = rand::rng();
-                let base: f64 = rng.random_range(0.0..100.0);
-
-                for (i, field) in fields.iter().enumerate() {
-                    let value = match increment {
-                        CoordinateIncrement::Numeric(n) => base + (n * i as f64),
-                        _ => base + i as f64,
+                    let step = match increment {
+                        CoordinateIncrement::Numeric(n) => *n,
+                        _ => 1.0,
                     };
-                    values.insert(field.field_name.clone(), value.to_string());
+                    (base + step * position as f64).to_string()
                 }
-            }
+            };
+            values.insert(field.field_name.clone(), value);
         }
 
         values
@@ -427,6 +464,7 @@ impl Mockmaker<'_> {
         fields: &[&StructField],
         _index: usize,
         total: f64,
+        rng: &mut StdRng,
     ) -> BTreeMap<String, String> {
         tracing::trace!(
             field_count = fields.len(),
@@ -434,7 +472,6 @@ impl Mockmaker<'_> {
             "Generating sum values"
         );
         let mut values = BTreeMap::new();
-        let mut rng = rand::rng();
 
         if fields.is_empty() {
             return values;
@@ -485,6 +522,7 @@ impl Mockmaker<'_> {
             for (field, value) in fields.iter().zip(generated_values.iter()) {
                 let formatted_value = match &field.format {
                     Some(Format::CurrencyAmount) => format!("${:.2}", value),
+                    Some(Format::Currency(scale)) => format!("{:.*}", *scale as usize, value),
                     _ => format!("{:.2}", value),
                 };
                 values.insert(field.field_name.clone(), formatted_value);
@@ -494,6 +532,23 @@ impl Mockmaker<'_> {
         values
     }
 
+    /// Pick one of the choices with probability proportional to its weight
+    pub fn generate_weighted_choice(choices: &BTreeMap<String, f64>, rng: &mut StdRng) -> String {
+        tracing::trace!(choice_count = choices.len(), "Generating weighted choice");
+        let total: f64 = choices.values().sum();
+        let mut target = rng.random_range(0.0..1.0) * total;
+
+        for (value, weight) in choices {
+            if target < *weight {
+                return value.clone();
+            }
+            target -= weight;
+        }
+
+        // Floating point leftovers land on the last choice
+        choices.keys().next_back().cloned().unwrap_or_default()
+    }
+
     /// Generate derived values from source fields
     fn generate_derive_values(
         source_fields: &[&StructField],
@@ -588,6 +643,7 @@ impl Mockmaker<'_> {
         _fields: &[&StructField],
         dataset: &crate::schemasync::mockmake::coordinate::CoherentDataset,
         index: usize,
+        rng: &mut StdRng,
     ) -> BTreeMap<String, String> {
         tracing::trace!(index = index, "Generating coherent values");
         use crate::schemasync::mockmake::coordinate::*;
@@ -692,7 +748,6 @@ impl Mockmaker<'_> {
                 center_lng,
                 radius_km,
             } => {
-                let mut rng = rand::rng();
                 let earth_radius_km = 6371.0_f64;
 
                 // sqrt for uniform area distribution within circle
@@ -730,10 +785,17 @@ pub enum Coordination {
     /// Initialize multiple fields with the same value
     InitializeEqual(Vec<String>),
 
-    /// Initialize fields in sequence (e.g., start < end dates)
+    /// Initialize fields in sequence (e.g., start < end dates). Each field of
+    /// each record advances one `increment` past the previous one, beginning
+    /// at `start`: `F64(..)` for numbers, an RFC 3339 datetime or
+    /// `YYYY-MM-DD` date `String(..)` for time increments. Without a start,
+    /// numbers begin at 0, datetimes at today's midnight UTC and
+    /// `Format::Date` fields at 2024-01-01.
     InitializeSequential {
         field_names: Vec<String>,
         increment: CoordinateIncrement,
+        #[serde(default)]
+        start: Option<CoordinatedValue>,
     },
 
     /// Fields must sum to a total (e.g., percentage fields = 100)
@@ -752,6 +814,14 @@ pub enum Coordination {
     /// Ensure fields are from same dataset (e.g., matching city/state/zip)
     InitializeCoherent(CoherentDataset),
 
+    /// Pick a value per record according to weights (e.g., 70% "active",
+    /// 20% "pending", 10% "banned"); weights must sum to 1.0.
+    /// Written as `choices: [("active", 0.7), ("pending", 0.2), ("banned", 0.1)]`
+    InitializeWeightedChoice {
+        field_name: String,
+        choices: BTreeMap<String, f64>,
+    },
+
     /// Map a relation field 1:1 to target table records sequentially.
     /// Use on relation tables to ensure every target record gets exactly one edge.
     /// The string is the field name (e.g., "out").
@@ -801,7 +871,10 @@ impl Coordination {
                         FieldType::Struct(_nested_fields) => {
                             // For inline structs, we'd need to handle this differently
                             // For now, we'll validate against objects
-                            if let FieldType::Other(type_name) = &field.field_type {
+                            if let FieldType::Enum(type_name)
+                            | FieldType::Object(type_name)
+                            | FieldType::Other(type_name) = &field.field_type
+                            {
                                 let obj = mockmaker.objects.get(type_name).ok_or_else(|| {
                                     EvenframeError::Validation(format!(
                                         "Nested object '{}' not found for field path '{}'",
@@ -816,7 +889,9 @@ impl Coordination {
                                 )));
                             }
                         }
-                        FieldType::Other(type_name) => {
+                        FieldType::Enum(type_name)
+                        | FieldType::Object(type_name)
+                        | FieldType::Other(type_name) => {
                             let obj = mockmaker.objects.get(type_name).ok_or_else(|| {
                                 EvenframeError::Validation(format!(
                                     "Nested object '{}' not found for field path '{}'",
@@ -871,7 +946,34 @@ impl Coordination {
                 }
             }
 
-            Coordination::InitializeSequential { increment, .. } => {
+            Coordination::InitializeSequential {
+                increment, start, ..
+            } => {
+                // The start value must match the kind of increment
+                match (time_increment(increment), start) {
+                    (None, Some(CoordinatedValue::String(s))) => {
+                        return Err(EvenframeError::Validation(format!(
+                            "InitializeSequential: start '{}' must be numeric for a numeric increment",
+                            s
+                        )));
+                    }
+                    (Some(_), Some(CoordinatedValue::F64(n))) => {
+                        return Err(EvenframeError::Validation(format!(
+                            "InitializeSequential: start {} must be a date for a time increment",
+                            n
+                        )));
+                    }
+                    (Some(_), Some(CoordinatedValue::String(s)))
+                        if parse_sequence_start_datetime(s).is_none() =>
+                    {
+                        return Err(EvenframeError::Validation(format!(
+                            "InitializeSequential: start '{}' is not an RFC 3339 datetime or YYYY-MM-DD date",
+                            s
+                        )));
+                    }
+                    _ => {}
+                }
+
                 // Fields must be compatible with the increment type
                 for (coord_id, field) in &fields {
                     match increment {
@@ -881,6 +983,7 @@ impl Coordination {
                             FieldType::Other(name) if is_datetime_like(name) => {}
                             FieldType::Option(inner) if matches!(**inner, FieldType::Other(ref name) if is_datetime_like(name)) =>
                                 {}
+                            _ if matches!(field.format, Some(Format::Date)) => {}
                             _ => {
                                 return Err(EvenframeError::Validation(format!(
                                     "InitializeSequential with time increment: Field '{}' must be DateTime type, got {:?}",
@@ -1124,6 +1227,55 @@ impl Coordination {
                 }
             }
 
+            Coordination::InitializeWeightedChoice {
+                field_name,
+                choices,
+            } => {
+                let target = fields
+                    .iter()
+                    .find(|(id, _)| id.field_name.ends_with(field_name))
+                    .ok_or_else(|| {
+                        EvenframeError::Validation(format!(
+                            "InitializeWeightedChoice: Field '{}' not found in coordination fields",
+                            field_name
+                        ))
+                    })?;
+
+                if !is_string_like(&target.1.field_type)
+                    && !matches!(
+                        &target.1.field_type,
+                        FieldType::Enum(_) | FieldType::Object(_) | FieldType::Other(_)
+                    )
+                {
+                    return Err(EvenframeError::Validation(format!(
+                        "InitializeWeightedChoice: Field '{}' must be a string or enum type, got {:?}",
+                        field_name, target.1.field_type
+                    )));
+                }
+
+                if choices.is_empty() {
+                    return Err(EvenframeError::Validation(format!(
+                        "InitializeWeightedChoice: Field '{}' needs at least one choice",
+                        field_name
+                    )));
+                }
+
+                if let Some((value, weight)) = choices.iter().find(|(_, weight)| **weight < 0.0) {
+                    return Err(EvenframeError::Validation(format!(
+                        "InitializeWeightedChoice: Choice '{}' has negative weight {}",
+                        value, weight
+                    )));
+                }
+
+                let total: f64 = choices.values().sum();
+                if (total - 1.0).abs() > 0.01 {
+                    return Err(EvenframeError::Validation(format!(
+                        "InitializeWeightedChoice: Weights for field '{}' must sum to 1.0, got {}",
+                        field_name, total
+                    )));
+                }
+            }
+
             Coordination::OneToOne(field_name) => {
                 // Target field must exist and be a RecordLink
                 let target = fields
@@ -1185,6 +1337,38 @@ fn is_numeric_type(field_type: &FieldType) -> bool {
     }
 }
 
+/// The time step of a date increment, or `None` for numeric and custom increments
+#[cfg(feature = "surrealdb")]
+fn time_increment(increment: &CoordinateIncrement) -> Option<Duration> {
+    match increment {
+        CoordinateIncrement::Days(d) => Some(Duration::days(*d as i64)),
+        CoordinateIncrement::Hours(h) => Some(Duration::hours(*h as i64)),
+        CoordinateIncrement::Minutes(m) => Some(Duration::minutes(*m as i64)),
+        CoordinateIncrement::Numeric(_) | CoordinateIncrement::Custom(_) => None,
+    }
+}
+
+/// Parse a sequence start given as an RFC 3339 datetime or a `YYYY-MM-DD` date
+#[cfg(feature = "surrealdb")]
+fn parse_sequence_start_datetime(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            Some(
+                NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                    .ok()?
+                    .and_hms_opt(0, 0, 0)?
+                    .and_utc(),
+            )
+        })
+}
+
+#[cfg(feature = "surrealdb")]
+fn parse_sequence_start_date(s: &str) -> Option<NaiveDate> {
+    parse_sequence_start_datetime(s).map(|dt| dt.date_naive())
+}
+
 /// Check if a type name represents a datetime-like type (used for foreign types that replaced FieldType::DateTime)
 #[cfg(feature = "surrealdb")]
 fn is_datetime_like(name: &str) -> bool {
@@ -1716,7 +1900,8 @@ mod tests {
         };
 
         for i in 0..100 {
-            let values = Mockmaker::generate_coherent_values(&[], &dataset, i);
+            let values =
+                Mockmaker::generate_coherent_values(&[], &dataset, i, &mut rand::make_rng());
             let lat: f64 = values["lat"].parse().expect("lat should be a valid f64");
             let lng: f64 = values["lng"].parse().expect("lng should be a valid f64");
 
@@ -1746,7 +1931,8 @@ mod tests {
 
         let mut lats = std::collections::BTreeSet::new();
         for i in 0..20 {
-            let values = Mockmaker::generate_coherent_values(&[], &dataset, i);
+            let values =
+                Mockmaker::generate_coherent_values(&[], &dataset, i, &mut rand::make_rng());
             lats.insert(values["lat"].clone());
         }
         assert!(
@@ -1755,4 +1941,297 @@ mod tests {
             lats.len()
         );
     }
+
+    #[test]
+    fn test_weighted_choice_follows_weights() {
+        let choices = BTreeMap::from([
+            ("active".to_string(), 0.7),
+            ("pending".to_string(), 0.2),
+            ("banned".to_string(), 0.1),
+        ]);
+        let samples = 20_000;
+
+        let mut rng = rand::make_rng();
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for _ in 0..samples {
+            *counts
+                .entry(Mockmaker::generate_weighted_choice(&choices, &mut rng))
+                .or_default() += 1;
+        }
+
+        assert_eq!(counts.len(), choices.len(), "counts: {:?}", counts);
+        for (value, weight) in &choices {
+            let observed = counts[value] as f64 / samples as f64;
+            assert!(
+                (observed - weight).abs() < 0.02,
+                "'{}' observed {:.3}, expected {:.3}",
+                value,
+                observed,
+                weight
+            );
+        }
+    }
+
+    #[test]
+    fn test_weighted_choice_parses_from_attribute_expr() {
+        let expr: syn::Expr = syn::parse_str(
+            r#"Coordination::InitializeWeightedChoice {
+                field_name: "status",
+                choices: [("active", 0.7), ("pending", 0.2), ("banned", 0.1)]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Coordination::try_from(&expr).unwrap(),
+            Coordination::InitializeWeightedChoice {
+                field_name: "status".to_string(),
+                choices: BTreeMap::from([
+                    ("active".to_string(), 0.7),
+                    ("pending".to_string(), 0.2),
+                    ("banned".to_string(), 0.1),
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn test_weighted_choice_never_picks_zero_weight() {
+        let choices = BTreeMap::from([("on".to_string(), 1.0), ("off".to_string(), 0.0)]);
+        for _ in 0..1_000 {
+            assert_eq!(
+                Mockmaker::generate_weighted_choice(&choices, &mut rand::make_rng()),
+                "on"
+            );
+        }
+    }
+
+    fn sequential_mockmaker_fixture(
+        field_type: FieldType,
+        coordination: Coordination,
+    ) -> (
+        BTreeMap<String, crate::schemasync::TableConfig>,
+        BTreeMap<String, crate::types::StructConfig>,
+        crate::schemasync::config::SchemasyncConfig,
+    ) {
+        use crate::schemasync::config::{
+            DatabaseConfig, PerformanceConfig, SchemasyncConfig, SchemasyncMockGenConfig,
+        };
+        use crate::schemasync::mockmake::MockGenerationConfig;
+        use crate::schemasync::{PreservationMode, TableConfig};
+        use crate::types::StructConfig;
+
+        let struct_config = StructConfig {
+            struct_name: "Ticket".to_string(),
+            fields: vec![StructField {
+                field_name: "position".to_string(),
+                field_type,
+                ..StructField::default()
+            }],
+            ..StructConfig::default()
+        };
+        let table = TableConfig {
+            table_name: "ticket".to_string(),
+            struct_config: struct_config.clone(),
+            mock_generation_config: Some(MockGenerationConfig {
+                n: 5,
+                table_level_override: None,
+                coordination_rules: vec![coordination],
+                batch_size: 5,
+                regenerate_fields: vec![],
+                preservation_mode: PreservationMode::None,
+                plugin: None,
+                seed: None,
+            }),
+            ..Default::default()
+        };
+        let config = SchemasyncConfig {
+            database: DatabaseConfig::default(),
+            should_generate_mocks: true,
+            mock_gen_config: SchemasyncMockGenConfig {
+                default_record_count: 5,
+                default_preservation_mode: PreservationMode::None,
+                default_batch_size: 5,
+                coordination_groups: vec![],
+                full_refresh_mode: true,
+                confirm_destructive: false,
+                max_recursion_depth: crate::schemasync::config::default_max_recursion_depth(),
+                id_strategy: crate::schemasync::config::IdStrategy::default(),
+                regenerate_modified_fields: false,
+                option_null_probability: crate::schemasync::config::default_option_null_probability(
+                ),
+                validate_generated_data: true,
+                recreate_unpreserved_tables: false,
+                seed: None,
+            },
+            performance: PerformanceConfig::default(),
+            plugins: BTreeMap::new(),
+            dry_run: false,
+            fail_on_breaking: false,
+            skip_schema_definition: false,
+            table_naming: Default::default(),
+            connect_retries: crate::schemasync::config::default_connect_retries(),
+            connect_retry_delay_ms: crate::schemasync::config::default_connect_retry_delay_ms(),
+        };
+
+        (
+            BTreeMap::from([("ticket".to_string(), table)]),
+            BTreeMap::from([("Ticket".to_string(), struct_config)]),
+            config,
+        )
+    }
+
+    fn sequential(increment: CoordinateIncrement, start: Option<CoordinatedValue>) -> Coordination {
+        Coordination::InitializeSequential {
+            field_names: vec!["position".to_string()],
+            increment,
+            start,
+        }
+    }
+
+    #[test]
+    fn test_sequential_values_advance_across_records() {
+        use surrealdb::{Surreal, engine::remote::http::Client};
+
+        let (tables, objects, config) = sequential_mockmaker_fixture(
+            FieldType::U32,
+            sequential(
+                CoordinateIncrement::Numeric(5.0),
+                Some(CoordinatedValue::F64(10.0)),
+            ),
+        );
+        let client = Surreal::<Client>::init();
+        let (enums, registry) = (
+            BTreeMap::new(),
+            crate::types::ForeignTypeRegistry::default(),
+        );
+        let mut mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        mockmaker.generate_coordinated_values().unwrap();
+
+        let id = CoordinationId::builder()
+            .table_name("ticket".to_string())
+            .field_name("position".to_string())
+            .build();
+        let generated: Vec<&str> = mockmaker.coordinated_values[&id]
+            .values()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(generated, ["10", "15", "20", "25", "30"]);
+    }
+
+    #[test]
+    fn test_sequential_datetimes_step_from_start() {
+        let field = StructField {
+            field_name: "due".to_string(),
+            field_type: FieldType::Other("DateTime".to_string()),
+            ..StructField::default()
+        };
+        let start = CoordinatedValue::String("2025-03-01".to_string());
+
+        let generated: Vec<String> = (0..3)
+            .map(|i| {
+                Mockmaker::generate_sequential_values(
+                    &[&field],
+                    i,
+                    &CoordinateIncrement::Days(7),
+                    Some(&start),
+                    Utc::now(),
+                )["due"]
+                    .clone()
+            })
+            .collect();
+        assert_eq!(
+            generated,
+            [
+                "d'2025-03-01T00:00:00+00:00'",
+                "d'2025-03-08T00:00:00+00:00'",
+                "d'2025-03-15T00:00:00+00:00'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sequential_datetimes_without_start_use_reference_time() {
+        let field = StructField {
+            field_name: "due".to_string(),
+            field_type: FieldType::Other("DateTime".to_string()),
+            ..StructField::default()
+        };
+
+        let values = Mockmaker::generate_sequential_values(
+            &[&field],
+            1,
+            &CoordinateIncrement::Days(1),
+            None,
+            seeded_reference_time(Some(7)),
+        );
+        assert_eq!(values["due"], "d'2024-01-02T00:00:00+00:00'");
+    }
+
+    #[test]
+    fn test_sequential_parses_start_from_attribute_expr() {
+        let expr: syn::Expr = syn::parse_str(
+            r#"Coordination::InitializeSequential {
+                field_names: ["position"],
+                increment: CoordinateIncrement::Numeric(5.0),
+                start: CoordinatedValue::F64(10.0)
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Coordination::try_from(&expr).unwrap(),
+            sequential(
+                CoordinateIncrement::Numeric(5.0),
+                Some(CoordinatedValue::F64(10.0))
+            )
+        );
+    }
+
+    #[test]
+    fn test_sequential_validation_checks_field_types_and_start() {
+        use surrealdb::{Surreal, engine::remote::http::Client};
+
+        let client = Surreal::<Client>::init();
+        let (enums, registry) = (
+            BTreeMap::new(),
+            crate::types::ForeignTypeRegistry::default(),
+        );
+        let ids = [CoordinationId::builder()
+            .table_name("ticket".to_string())
+            .field_name("position".to_string())
+            .build()];
+        let validate = |field_type: FieldType, coordination: Coordination| {
+            let (tables, objects, config) =
+                sequential_mockmaker_fixture(field_type, coordination.clone());
+            let mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+            coordination.validate(&mockmaker, &ids)
+        };
+
+        let numeric = sequential(
+            CoordinateIncrement::Numeric(5.0),
+            Some(CoordinatedValue::F64(10.0)),
+        );
+        assert!(validate(FieldType::U32, numeric.clone()).is_ok());
+        assert!(validate(FieldType::String, numeric).is_err());
+
+        let datetime = FieldType::Other("DateTime".to_string());
+        let dated = |start: CoordinatedValue| sequential(CoordinateIncrement::Days(1), Some(start));
+        assert!(
+            validate(
+                datetime.clone(),
+                dated(CoordinatedValue::String("2025-03-01".into()))
+            )
+            .is_ok()
+        );
+        assert!(
+            validate(
+                datetime.clone(),
+                dated(CoordinatedValue::String("March".into()))
+            )
+            .is_err()
+        );
+        assert!(validate(datetime, dated(CoordinatedValue::F64(1.0))).is_err());
+    }
 }
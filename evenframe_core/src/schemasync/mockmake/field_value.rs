@@ -1,9 +1,12 @@
 use crate::{
+    error::{EvenframeError, Result},
     schemasync::TableConfig,
+    schemasync::find_table,
     schemasync::mockmake::Mockmaker,
     schemasync::mockmake::coordinate::CoordinationId,
     schemasync::mockmake::format::Format,
     schemasync::mockmake::validator_gen,
+    schemasync::surql_ident,
     types::{EnumRepresentation, FieldType, ForeignTypeRegistry, StructField, VariantData},
     validator::{MockValue, Validator},
 };
@@ -11,7 +14,7 @@ use bon::Builder;
 #[cfg(feature = "mockmake")]
 use chrono_tz::TZ_VARIANTS;
 use convert_case::{Case, Casing};
-use rand::{RngExt, rngs::ThreadRng, seq::IndexedRandom};
+use rand::{RngExt, rngs::StdRng, seq::IndexedRandom};
 use std::collections::BTreeSet;
 use tracing;
 
@@ -60,11 +63,22 @@ pub struct FieldValueGenerator<'a> {
 }
 
 impl<'a> FieldValueGenerator<'a> {
+    /// Generates the value for the field.
+    ///
+    /// Returns [`EvenframeError::MockGeneration`] naming the table and field
+    /// when a type, record link or record id cannot be resolved.
     // Was having stack overflow so created an iterative version
-    pub fn run(&self) -> String {
+    pub fn run(&self) -> Result<String> {
+        let _span = tracing::debug_span!(
+            "gen_field",
+            table = %self.table_config.table_name,
+            field = %self.field.field_name
+        )
+        .entered();
         let mut work_stack: Vec<WorkItem<'a>> = Vec::new();
         let mut value_stack: Vec<String> = Vec::new();
-        let mut rng = rand::rng();
+        let mut rng_guard = self.mockmaker.rng();
+        let rng: &mut StdRng = &mut rng_guard;
 
         let initial_context = Frame {
             field: self.field,
@@ -153,19 +167,24 @@ impl<'a> FieldValueGenerator<'a> {
                         );
                     }
 
-                    if let Some(coordinated_value) = self.mockmaker.coordinated_values.get(
-                        &CoordinationId::builder()
-                            .field_name(ctx.field_path.clone())
-                            .table_name(self.table_config.table_name.to_string())
-                            .build(),
-                    ) {
+                    if let Some(coordinated_value) = self
+                        .mockmaker
+                        .coordinated_values
+                        .get(
+                            &CoordinationId::builder()
+                                .field_name(ctx.field_path.clone())
+                                .table_name(self.table_config.table_name.to_string())
+                                .build(),
+                        )
+                        .and_then(|values| values.get(self.id_index))
+                    {
                         value_stack.push(coordinated_value.to_string());
                     } else if let Some(format) = &ctx.field.format {
-                        value_stack.push(self.handle_format(format));
+                        value_stack.push(self.handle_format(format, rng));
                     } else if let Some(value) = validator_gen::generate_with_validators(
                         ctx.field_type,
                         &ctx.field.validators,
-                        &mut rng,
+                        rng,
                     ) {
                         value_stack.push(value);
                     } else {
@@ -173,6 +192,7 @@ impl<'a> FieldValueGenerator<'a> {
                             FieldType::String => value_stack.push(generate_string_with_retry(
                                 &ctx.field.validators,
                                 &ctx.field_path,
+                                rng,
                             )),
                             FieldType::Char => value_stack
                                 .push(format!("'{}'", rng.random_range(32u8..=126u8) as char)),
@@ -180,34 +200,50 @@ impl<'a> FieldValueGenerator<'a> {
                                 value_stack.push(format!("{}", rng.random_bool(0.5)))
                             }
                             FieldType::Unit => value_stack.push("NONE".to_string()),
+                            FieldType::Json => value_stack.push(Mockmaker::random_json(rng)),
+                            FieldType::Literal(value) => value_stack.push(value.to_string()),
+                            FieldType::Bytes => value_stack.push(Mockmaker::random_bytes(rng)),
+                            FieldType::Geometry(kind) => {
+                                value_stack.push(Mockmaker::random_geometry(*kind, rng))
+                            }
                             FieldType::F32 | FieldType::F64 => {
                                 value_stack.push(generate_float_with_retry(
                                     &ctx.field.validators,
                                     &ctx.field_path,
-                                    &mut rng,
+                                    rng,
                                 ))
                             }
                             FieldType::I8
                             | FieldType::I16
                             | FieldType::I32
                             | FieldType::I64
-                            | FieldType::I128
                             | FieldType::Isize
                             | FieldType::U8
                             | FieldType::U16
                             | FieldType::U32
                             | FieldType::U64
-                            | FieldType::U128
-                            | FieldType::Usize => {
-                                value_stack.push(generate_integer_with_retry(
+                            | FieldType::Usize => value_stack.push(generate_integer_with_retry(
+                                &ctx.field.validators,
+                                &ctx.field_path,
+                                rng,
+                            )),
+                            // 128-bit columns are `string` typed
+                            FieldType::I128 | FieldType::U128 => value_stack.push(format!(
+                                "'{}'",
+                                generate_integer_with_retry(
                                     &ctx.field.validators,
                                     &ctx.field_path,
-                                    &mut rng,
-                                ))
-                            }
+                                    rng,
+                                )
+                            )),
                             FieldType::Option(inner_type) => {
-                                if rng.random_bool(0.5) {
-                                    value_stack.push("null".to_string());
+                                if rng.random_bool(
+                                    self.mockmaker
+                                        .schemasync_config
+                                        .mock_gen_config
+                                        .option_null_probability,
+                                ) {
+                                    value_stack.push("NONE".to_string());
                                 } else {
                                     work_stack.push(WorkItem::Generate(Frame {
                                         field_type: inner_type,
@@ -215,7 +251,7 @@ impl<'a> FieldValueGenerator<'a> {
                                     }));
                                 }
                             }
-                            FieldType::Vec(inner_type) => {
+                            FieldType::Vec(inner_type) | FieldType::Set(inner_type) => {
                                 let (lo, hi) = validator_gen::array_count_range(
                                     &ctx.field.validators,
                                     2,
@@ -234,6 +270,16 @@ impl<'a> FieldValueGenerator<'a> {
                                     }));
                                 }
                             }
+                            // A fixed-size array must hold exactly its declared length
+                            FieldType::Array(inner_type, len) => {
+                                work_stack.push(WorkItem::AssembleVec { count: *len });
+                                for _ in 0..*len {
+                                    work_stack.push(WorkItem::Generate(Frame {
+                                        field_type: inner_type,
+                                        ..ctx.clone()
+                                    }));
+                                }
+                            }
                             FieldType::Tuple(types) => {
                                 work_stack.push(WorkItem::AssembleTuple { count: types.len() });
                                 for inner_type in types.iter().rev() {
@@ -286,15 +332,17 @@ impl<'a> FieldValueGenerator<'a> {
                                         &ctx.field.field_name,
                                         &ctx.table_config.table_name,
                                         ctx.table_config,
-                                        &mut rng,
-                                    ));
+                                        rng,
+                                    )?);
                                     continue;
                                 }
 
                                 // RecordLink should ultimately reference a persistable table.
                                 // If the inner type is an enum (persistable struct union), choose a variant that maps to a table.
                                 match inner_type.as_ref() {
-                                    FieldType::Other(type_name) => {
+                                    FieldType::Enum(type_name)
+                                    | FieldType::Object(type_name)
+                                    | FieldType::Other(type_name) => {
                                         // Helper: resolve a type name to a table key in self.mockmaker.tables
                                         // Closure uses RNG; mark as mut so it implements FnMut
                                         let mut resolve_table = |name: &str,
@@ -302,9 +350,8 @@ impl<'a> FieldValueGenerator<'a> {
                                                             enums: &std::collections::BTreeMap<String, crate::types::TaggedUnion>,
                                         | -> Option<String> {
                                             // 1) Direct match: type name corresponds to a table
-                                            let snake = name.to_case(Case::Snake);
-                                            if tables.contains_key(&snake) {
-                                                return Some(snake);
+                                            if let Some((table, _)) = find_table(tables, name) {
+                                                return Some(table.clone());
                                             }
                                             // 2) Enum (persistable struct union): pick a variant that maps to a table
                                             if let Some(tagged) = enums.get(name) {
@@ -314,17 +361,15 @@ impl<'a> FieldValueGenerator<'a> {
                                                     if let Some(data) = &v.data {
                                                         match data {
                                                             crate::types::VariantData::InlineStruct(enum_struct) => {
-                                                                let t = enum_struct.struct_name.to_case(Case::Snake);
-                                                                if tables.contains_key(&t) {
-                                                                    candidates.push(t);
+                                                                if let Some((t, _)) = find_table(tables, &enum_struct.struct_name) {
+                                                                    candidates.push(t.clone());
                                                                 }
                                                             }
                                                             crate::types::VariantData::DataStructureRef(fty) => {
-                                                                if let crate::types::FieldType::Other(inner_name) = fty {
-                                                                    let t = inner_name.to_case(Case::Snake);
-                                                                    if tables.contains_key(&t) {
-                                                                        candidates.push(t);
-                                                                    }
+                                                                if let crate::types::FieldType::Enum(inner_name) | crate::types::FieldType::Object(inner_name) | crate::types::FieldType::Other(inner_name) = fty
+                                                                    && let Some((t, _)) = find_table(tables, inner_name)
+                                                                {
+                                                                    candidates.push(t.clone());
                                                                 }
                                                             }
                                                         }
@@ -356,33 +401,44 @@ impl<'a> FieldValueGenerator<'a> {
                                                     );
                                                     value_stack.push(id);
                                                 } else {
-                                                    panic!(
-                                                        "No IDs generated for table {} in RecordLink",
-                                                        table_key
-                                                    );
+                                                    return Err(EvenframeError::mock_generation(
+                                                        format!(
+                                                            "No IDs generated for table {} in RecordLink: table {}, field {}",
+                                                            table_key,
+                                                            ctx.table_config.table_name,
+                                                            ctx.field_path
+                                                        ),
+                                                    ));
                                                 }
                                             } else {
                                                 // Fallback: synthesize a plausible ID using current index
-                                                let id =
-                                                    format!("r'{}:{}'", table_key, &self.id_index);
+                                                let id = format!(
+                                                    "r'{}:{}'",
+                                                    surql_ident(&table_key),
+                                                    &self.id_index
+                                                );
                                                 value_stack.push(id);
                                             }
                                         } else {
-                                            panic!(
-                                                "RecordLink references type '{}' which does not map to a persistable table or persistable union in field {}",
-                                                type_name, ctx.field.field_name
-                                            );
+                                            return Err(EvenframeError::mock_generation(format!(
+                                                "RecordLink references type '{}' which does not map to a persistable table or persistable union: table {}, field {}",
+                                                type_name,
+                                                ctx.table_config.table_name,
+                                                ctx.field_path
+                                            )));
                                         }
                                     }
                                     _ => {
-                                        panic!(
-                                            "RecordLink contains non-Other type {:?} in field {}. RecordLink should reference a type name or a persistable union.",
-                                            inner_type, ctx.field.field_name
-                                        );
+                                        return Err(EvenframeError::mock_generation(format!(
+                                            "RecordLink contains non-Other type {:?}: table {}, field {}. RecordLink should reference a type name or a persistable union.",
+                                            inner_type, ctx.table_config.table_name, ctx.field_path
+                                        )));
                                     }
                                 }
                             }
-                            FieldType::Other(type_name) => {
+                            FieldType::Enum(type_name)
+                            | FieldType::Object(type_name)
+                            | FieldType::Other(type_name) => {
                                 // Check if this is a foreign type with a mock strategy
                                 if let Some(ftc) = self.registry.lookup(type_name) {
                                     let strategy = ftc.mock_strategy.as_str();
@@ -391,7 +447,9 @@ impl<'a> FieldValueGenerator<'a> {
                                         "datetime" => {
                                             value_stack.push(format!(
                                                 "d'{}'",
-                                                chrono::Utc::now().to_rfc3339()
+                                                self.mockmaker
+                                                    .reference_time(self.table_config)
+                                                    .to_rfc3339()
                                             ));
                                             continue;
                                         }
@@ -443,8 +501,8 @@ impl<'a> FieldValueGenerator<'a> {
                                                 &ctx.field.field_name,
                                                 &ctx.table_config.table_name,
                                                 ctx.table_config,
-                                                &mut rng,
-                                            ));
+                                                rng,
+                                            )?);
                                             continue;
                                         }
                                         "object" => {
@@ -453,7 +511,11 @@ impl<'a> FieldValueGenerator<'a> {
                                         }
                                         _ => {
                                             if let Ok(fmt) = strategy.parse::<Format>() {
-                                                let val = fmt.generate_formatted_value();
+                                                let val = fmt.generate_formatted_value_at(
+                                                    rng,
+                                                    self.mockmaker
+                                                        .reference_time(self.table_config),
+                                                );
                                                 value_stack.push(format!("'{}'", val));
                                                 continue;
                                             }
@@ -480,18 +542,19 @@ impl<'a> FieldValueGenerator<'a> {
                                     .iter()
                                     .find(|(_, tc)| &tc.table_name == type_name)
                                 {
-                                    let value = if let Some(possible_ids) =
-                                        self.mockmaker.id_map.get(table_name)
-                                    {
-                                        format!(
+                                    let value = match self.mockmaker.id_map.get(table_name) {
+                                        Some(possible_ids) if !possible_ids.is_empty() => format!(
                                             "r'{}'",
                                             possible_ids[rng.random_range(0..possible_ids.len())]
-                                        )
-                                    } else {
-                                        panic!(
-                                            "There were no id's for the table {}, field {}",
-                                            table_name, ctx.field.field_name
-                                        );
+                                        ),
+                                        _ => {
+                                            return Err(EvenframeError::mock_generation(format!(
+                                                "There were no id's for the table {}: table {}, field {}",
+                                                table_name,
+                                                ctx.table_config.table_name,
+                                                ctx.field_path
+                                            )));
+                                        }
                                     };
                                     value_stack.push(value);
                                 } else if let Some(struct_config) = self
@@ -503,7 +566,7 @@ impl<'a> FieldValueGenerator<'a> {
                                     let field_names: Vec<String> = struct_config
                                         .fields
                                         .iter()
-                                        .map(|f| f.field_name.clone())
+                                        .map(|f| f.output_name().to_string())
                                         .collect();
                                     work_stack.push(WorkItem::AssembleStruct { field_names });
 
@@ -530,7 +593,7 @@ impl<'a> FieldValueGenerator<'a> {
                                 {
                                     let variant = tagged_union
                                         .variants
-                                        .choose(&mut rng)
+                                        .choose(rng)
                                         .expect("Failed to select a random enum variant");
                                     let repr = &tagged_union.representation;
                                     if let Some(ref variant_data) = variant.data {
@@ -540,7 +603,7 @@ impl<'a> FieldValueGenerator<'a> {
                                                 let field_names: Vec<String> = struct_config
                                                     .fields
                                                     .iter()
-                                                    .map(|f| f.field_name.clone())
+                                                    .map(|f| f.output_name().to_string())
                                                     .collect();
 
                                                 match repr {
@@ -671,10 +734,10 @@ impl<'a> FieldValueGenerator<'a> {
                                         }
                                     }
                                 } else {
-                                    panic!(
+                                    return Err(EvenframeError::mock_generation(format!(
                                         "This type could not be parsed: table {}, field {}",
                                         ctx.table_config.table_name, ctx.field.field_name
-                                    );
+                                    )));
                                 }
                             }
                         }
@@ -742,11 +805,12 @@ impl<'a> FieldValueGenerator<'a> {
             1,
             "Generation ended with not exactly one value on the stack."
         );
-        value_stack.pop().unwrap()
+        Ok(value_stack.pop().unwrap())
     }
 
-    pub fn handle_format(&self, format: &Format) -> String {
-        let generated = format.generate_formatted_value();
+    pub fn handle_format(&self, format: &Format, rng: &mut StdRng) -> String {
+        let generated = format
+            .generate_formatted_value_at(rng, self.mockmaker.reference_time(self.table_config));
         match format {
             Format::Percentage
             | Format::Latitude
@@ -756,6 +820,7 @@ impl<'a> FieldValueGenerator<'a> {
             Format::DateTime | Format::AppointmentDateTime | Format::DateWithinDays(_) => {
                 format!("d'{}'", generated)
             }
+            Format::Currency(_) => format!("{}dec", generated),
             _ => format!("'{}'", generated),
         }
     }
@@ -765,8 +830,8 @@ impl<'a> FieldValueGenerator<'a> {
         field_name: &str,
         table_name: &str,
         table_config: &TableConfig,
-        rng: &mut ThreadRng,
-    ) -> String {
+        rng: &mut StdRng,
+    ) -> Result<String> {
         if let Some(relation) = &table_config.relation {
             // Check if this field has a OneToOne coordination (sequential 1:1 mapping)
             let has_one_to_one = table_config
@@ -780,28 +845,32 @@ impl<'a> FieldValueGenerator<'a> {
                 .unwrap_or(false);
 
             let id_index = *self.id_index;
-            let mut pick_relation_record = |tables: &[String], field_label: &str| -> String {
-                for candidate in tables {
-                    if let Some(ids) = self.mockmaker.id_map.get(candidate) {
-                        if ids.is_empty() {
-                            panic!(
-                                "There were no id's for the table {}, field {}",
-                                candidate, field_label
-                            );
-                        }
-                        if has_one_to_one {
-                            // Sequential 1:1 mapping: record index → target ID
-                            let idx = id_index % ids.len();
-                            return format!("r'{}'", ids[idx]);
+            let mut pick_relation_record =
+                |tables: &[String], field_label: &str| -> Result<String> {
+                    for candidate in tables {
+                        if let Some(ids) = self.mockmaker.id_map.get(candidate) {
+                            if ids.is_empty() {
+                                return Err(EvenframeError::mock_generation(format!(
+                                    "There were no id's for the table {}: table {}, field {}",
+                                    candidate, table_name, field_label
+                                )));
+                            }
+                            if has_one_to_one {
+                                // Sequential 1:1 mapping: record index → target ID
+                                let idx = id_index % ids.len();
+                                return Ok(format!("r'{}'", ids[idx]));
+                            }
+                            return Ok(format!(
+                                "r'{}'",
+                                ids[rng.random_range(0..ids.len())].clone()
+                            ));
                         }
-                        return format!("r'{}'", ids[rng.random_range(0..ids.len())].clone());
                     }
-                }
-                panic!(
-                    "There were no id's for any of the tables {:?}, field {}",
-                    tables, field_label
-                );
-            };
+                    Err(EvenframeError::mock_generation(format!(
+                        "There were no id's for any of the tables {:?}: table {}, field {}",
+                        tables, table_name, field_label
+                    )))
+                };
 
             if field_name == "in" {
                 return pick_relation_record(&relation.from, field_name);
@@ -812,12 +881,15 @@ impl<'a> FieldValueGenerator<'a> {
 
         if let Some(ids) = self.mockmaker.id_map.get(table_name) {
             if *self.id_index < ids.len() {
-                format!("r'{}'", ids[*self.id_index].clone())
+                Ok(format!("r'{}'", ids[*self.id_index].clone()))
             } else {
-                panic!("Out of bounds index for {table_name}, {field_name}")
+                Err(EvenframeError::mock_generation(format!(
+                    "Out of bounds index {} for record id: table {table_name}, field {field_name}",
+                    self.id_index
+                )))
             }
         } else {
-            format!("r'{}:{}'", table_name, &self.id_index)
+            Ok(format!("r'{}:{}'", surql_ident(table_name), &self.id_index))
         }
     }
 }
@@ -828,16 +900,20 @@ impl<'a> FieldValueGenerator<'a> {
 /// hides the constraint conflict from the user.
 const RETRY_ATTEMPTS: usize = 32;
 
-fn generate_string_with_retry(validators: &[Validator], field_path: &str) -> String {
+fn generate_string_with_retry(
+    validators: &[Validator],
+    field_path: &str,
+    rng: &mut StdRng,
+) -> String {
     if validators.is_empty() {
-        return format!("'{}'", Mockmaker::random_string(8));
+        return format!("'{}'", Mockmaker::random_string(8, rng));
     }
-    let mut last = Mockmaker::random_string(8);
+    let mut last = Mockmaker::random_string(8, rng);
     for _ in 0..RETRY_ATTEMPTS {
         if validators.iter().all(|v| v.matches(&MockValue::Str(&last))) {
             return format!("'{}'", last);
         }
-        last = Mockmaker::random_string(8);
+        last = Mockmaker::random_string(8, rng);
     }
     tracing::warn!(
         field = %field_path,
@@ -850,7 +926,7 @@ fn generate_string_with_retry(validators: &[Validator], field_path: &str) -> Str
 fn generate_float_with_retry(
     validators: &[Validator],
     field_path: &str,
-    rng: &mut ThreadRng,
+    rng: &mut StdRng,
 ) -> String {
     if validators.is_empty() {
         return format!("{:.2}f", rng.random_range(0.0..100.0));
@@ -873,7 +949,7 @@ fn generate_float_with_retry(
 fn generate_integer_with_retry(
     validators: &[Validator],
     field_path: &str,
-    rng: &mut ThreadRng,
+    rng: &mut StdRng,
 ) -> String {
     if validators.is_empty() {
         return format!("{}", rng.random_range(0..100));
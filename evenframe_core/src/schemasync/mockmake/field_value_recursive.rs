@@ -1,4 +1,5 @@
 use crate::{
+    error::{EvenframeError, Result},
     schemasync::TableConfig,
     schemasync::mockmake::Mockmaker,
     schemasync::mockmake::format::Format,
@@ -11,8 +12,8 @@ use bon::Builder;
 #[cfg(feature = "mockmake")]
 use chrono_tz::TZ_VARIANTS;
 use convert_case::{Case, Casing};
-use rand::{RngExt, rngs::ThreadRng, seq::IndexedRandom};
-use std::collections::BTreeMap;
+use rand::{RngExt, seq::IndexedRandom};
+use std::{cell::Cell, collections::BTreeMap};
 
 #[derive(Debug, Builder)]
 pub struct FieldValueGenerator<'a> {
@@ -22,70 +23,105 @@ pub struct FieldValueGenerator<'a> {
     id_index: &'a usize,
     coordinated_values: &'a BTreeMap<String, String>,
     registry: &'a ForeignTypeRegistry,
+    /// Nesting depth of the value currently being generated
+    #[builder(default)]
+    depth: Cell<usize>,
 }
 
 impl<'a> FieldValueGenerator<'a> {
-    pub fn run(&self) -> String {
+    pub fn run(&self) -> Result<String> {
         if let Some(format) = &self.field.format {
-            return self.handle_format(format);
+            return Ok(self.handle_format(format));
         }
         self.generate_field_value(&self.field.field_type)
     }
 
-    pub fn generate_field_value(&self, field_type: &FieldType) -> String {
+    pub fn generate_field_value(&self, field_type: &FieldType) -> Result<String> {
+        let depth = self.depth.get();
+        let max_depth = self
+            .mockmaker
+            .schemasync_config
+            .mock_gen_config
+            .max_recursion_depth;
+        if depth > max_depth {
+            tracing::warn!(
+                table = %self.table_config.table_name,
+                field_name = %self.field.field_name,
+                max_depth,
+                "Mock value nesting exceeded max_recursion_depth, using null"
+            );
+            return Ok("null".to_string());
+        }
+
+        self.depth.set(depth + 1);
+        let value = self.generate_nested_value(field_type);
+        self.depth.set(depth);
+        value
+    }
+
+    fn generate_nested_value(&self, field_type: &FieldType) -> Result<String> {
         tracing::trace!(
             field_name = %self.field.field_name,
             field_type = ?self.field.field_type,
             "Generating field value"
         );
-        let mut rng = rand::rng();
-        match field_type {
-            FieldType::String => format!("'{}'", Mockmaker::random_string(8)),
+        // The generator is locked per draw, since nested values lock it too
+        let rng = || self.mockmaker.rng();
+        let value = match field_type {
+            FieldType::String => format!("'{}'", Mockmaker::random_string(8, &mut rng())),
 
             FieldType::Char => {
-                let c = rng.random_range(32u8..=126u8) as char;
+                let c = rng().random_range(32u8..=126u8) as char;
                 format!("'{}'", c)
             }
-            FieldType::Bool => format!("{}", rng.random_bool(0.5)),
+            FieldType::Bool => format!("{}", rng().random_bool(0.5)),
             FieldType::Unit => "NONE".to_string(),
+            FieldType::Json => Mockmaker::random_json(&mut rng()),
+            FieldType::Literal(value) => value.to_string(),
+            FieldType::Bytes => Mockmaker::random_bytes(&mut rng()),
+            FieldType::Geometry(kind) => Mockmaker::random_geometry(*kind, &mut rng()),
             FieldType::F32 | FieldType::F64 => {
-                format!("{:.2}f", rng.random_range(0.0..100.0))
+                format!("{:.2}f", rng().random_range(0.0..100.0))
             }
             // Combine signed integer types
-            FieldType::I8
-            | FieldType::I16
-            | FieldType::I32
-            | FieldType::I64
-            | FieldType::I128
-            | FieldType::Isize => {
-                format!("{}", rng.random_range(0..100))
+            FieldType::I8 | FieldType::I16 | FieldType::I32 | FieldType::I64 | FieldType::Isize => {
+                format!("{}", rng().random_range(0..100))
             }
             // Combine unsigned integer types
-            FieldType::U8
-            | FieldType::U16
-            | FieldType::U32
-            | FieldType::U64
-            | FieldType::U128
-            | FieldType::Usize => format!("{}", rng.random_range(0..100)),
+            FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 | FieldType::Usize => {
+                format!("{}", rng().random_range(0..100))
+            }
+            // 128-bit columns are `string` typed
+            FieldType::I128 | FieldType::U128 => format!("'{}'", rng().random_range(0..100)),
 
             // For an Option, randomly decide whether to generate a value or use NULL.
-            FieldType::Option(inner_type) => self.handle_option(inner_type, &mut rng),
+            FieldType::Option(inner_type) => self.handle_option(inner_type)?,
             // For a vector, generate a dummy array with a couple of elements.
-            FieldType::Vec(inner_type) => self.handle_vec(inner_type),
+            FieldType::Vec(inner_type) | FieldType::Set(inner_type) => {
+                self.handle_vec(inner_type)?
+            }
+            // A fixed-size array must hold exactly its declared length.
+            FieldType::Array(inner_type, len) => self.handle_array(inner_type, *len)?,
             // For a tuple, recursively generate values for each component.
-            FieldType::Tuple(types) => self.handle_tuple(types),
+            FieldType::Tuple(types) => self.handle_tuple(types)?,
             // For a struct (named fields), create a JSON-like object.
-            FieldType::Struct(fields) => self.handle_struct(fields),
-            FieldType::HashMap(key, value) => self.handle_hash_map(key, value),
-            FieldType::BTreeMap(key, value) => self.handle_b_tree_map(key, value),
-            FieldType::RecordLink(inner_type) => self.generate_field_value(inner_type),
+            FieldType::Struct(fields) => self.handle_struct(fields)?,
+            FieldType::HashMap(key, value) => self.handle_hash_map(key, value)?,
+            FieldType::BTreeMap(key, value) => self.handle_b_tree_map(key, value)?,
+            FieldType::RecordLink(inner_type) => self.generate_field_value(inner_type)?,
             // For other types, try to see if the type is actually a reference to another db table/app struct, a app-only struct, or an enum.
-            FieldType::Other(type_name) => self.handle_other(type_name, &mut rng),
-        }
+            FieldType::Enum(type_name)
+            | FieldType::Object(type_name)
+            | FieldType::Other(type_name) => self.handle_other(type_name)?,
+        };
+        Ok(value)
     }
 
     pub fn handle_format(&self, format: &Format) -> String {
-        let generated = format.generate_formatted_value();
+        let generated = format.generate_formatted_value_at(
+            &mut self.mockmaker.rng(),
+            self.mockmaker.reference_time(self.table_config),
+        );
 
         // Check if format generates numeric or boolean values that shouldn't be quoted
         match format {
@@ -99,112 +135,87 @@ impl<'a> FieldValueGenerator<'a> {
             Format::DateTime | Format::AppointmentDateTime | Format::DateWithinDays(_) => {
                 format!("d'{}'", generated)
             }
+            Format::Currency(_) => format!("{}dec", generated),
 
             // Most formats generate strings, quote them
             _ => format!("'{}'", generated),
         }
     }
 
-    fn handle_record_id(
-        &self,
-        field_name: &String,
-        table_name: &String,
-        rng: &mut ThreadRng,
-    ) -> String {
-        {
-            if self.table_config.relation.is_none() && field_name == "id" {
-                match self.mockmaker.id_map.get(table_name) {
-                    Some(ids) => return format!("r'{}'", ids[*self.id_index].clone()),
-                    None => panic!(
-                        "{}",
-                        format!(
-                            "There were no ids for the table {}, field {}\ncurrent id_map: {:#?}",
-                            table_name, field_name, self.mockmaker.id_map
-                        )
-                    ),
-                };
-            } else if field_name == "in" {
-                // safe unwrap, we check if relation is some at the beginning of the function
-                let relation = self.table_config.relation.as_ref().unwrap();
-                for candidate in &relation.from {
-                    if let Some(ids) = self.mockmaker.id_map.get(candidate) {
-                        if ids.is_empty() {
-                            panic!(
-                                "{}",
-                                format!(
-                                    "There were no id's for the table {}, field {}",
-                                    candidate, field_name
-                                )
-                            )
-                        }
-                        return format!("r'{}'", ids[rng.random_range(0..ids.len())].clone());
-                    }
-                }
-                panic!(
-                    "{}",
-                    format!(
-                        "There were no id's for any of the tables {:?}, field {}",
-                        relation.from, field_name
-                    )
-                );
-            } else if field_name == "out" {
-                // safe unwrap, we check if relation is some at the beginning of the function
-                let relation = self.table_config.relation.as_ref().unwrap();
-                for candidate in &relation.to {
-                    if let Some(ids) = self.mockmaker.id_map.get(candidate) {
-                        if ids.is_empty() {
-                            panic!(
-                                "{}",
-                                format!(
-                                    "There were no id's for the table {}, field {}",
-                                    candidate, field_name
-                                )
-                            )
-                        }
-                        return format!("r'{}'", ids[rng.random_range(0..ids.len())].clone());
-                    }
+    fn handle_record_id(&self, field_name: &String, table_name: &String) -> Result<String> {
+        if self.table_config.relation.is_none() && field_name == "id" {
+            return match self.mockmaker.id_map.get(table_name) {
+                Some(ids) => Ok(format!("r'{}'", ids[*self.id_index].clone())),
+                None => Err(EvenframeError::mock_generation(format!(
+                    "There were no ids for the table {}, field {}",
+                    table_name, field_name
+                ))),
+            };
+        }
+        let candidates = match (&self.table_config.relation, field_name.as_str()) {
+            (Some(relation), "in") => &relation.from,
+            (Some(relation), "out") => &relation.to,
+            _ => {
+                return Err(EvenframeError::mock_generation(format!(
+                    "EvenframeRecordId used for field other than in, out, or id (table {}, field {}). Should use RecordLink type",
+                    table_name, field_name
+                )));
+            }
+        };
+        for candidate in candidates {
+            if let Some(ids) = self.mockmaker.id_map.get(candidate) {
+                if ids.is_empty() {
+                    return Err(EvenframeError::mock_generation(format!(
+                        "There were no id's for the table {}, field {}",
+                        candidate, field_name
+                    )));
                 }
-                panic!(
-                    "{}",
-                    format!(
-                        "There were no id's for any of the tables {:?}, field {}",
-                        relation.to, field_name
-                    )
-                );
+                return Ok(format!(
+                    "r'{}'",
+                    ids[self.mockmaker.rng().random_range(0..ids.len())].clone()
+                ));
             }
-
-            panic!(
-                "EvenframeRecordId used for field other than in, out, or id. Should use RecordLink type"
-            )
         }
+        Err(EvenframeError::mock_generation(format!(
+            "There were no id's for any of the tables {:?}, field {}",
+            candidates, field_name
+        )))
     }
 
-    fn handle_option(&self, inner_type: &FieldType, rng: &mut ThreadRng) -> String {
-        if rng.random_bool(0.5) {
-            "null".to_string()
+    fn handle_option(&self, inner_type: &FieldType) -> Result<String> {
+        let null_probability = self
+            .mockmaker
+            .schemasync_config
+            .mock_gen_config
+            .option_null_probability;
+        if self.mockmaker.rng().random_bool(null_probability) {
+            Ok("NONE".to_string())
         } else {
             self.generate_field_value(inner_type)
         }
     }
 
-    fn handle_vec(&self, inner_type: &FieldType) -> String {
-        let count = rand::rng().random_range(2..10);
+    fn handle_vec(&self, inner_type: &FieldType) -> Result<String> {
+        let count = self.mockmaker.rng().random_range(2..10);
+        self.handle_array(inner_type, count)
+    }
 
-        let items: Vec<String> = (0..count)
+    fn handle_array(&self, inner_type: &FieldType, len: usize) -> Result<String> {
+        let items = (0..len)
             .map(|_| self.generate_field_value(inner_type))
-            .collect();
-        format!("[{}]", items.join(", "))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(format!("[{}]", items.join(", ")))
     }
 
-    fn handle_tuple(&self, types: &[FieldType]) -> String {
-        let values: Vec<String> = types
+    fn handle_tuple(&self, types: &[FieldType]) -> Result<String> {
+        let values = types
             .iter()
             .map(|inner_type| self.generate_field_value(inner_type))
-            .collect();
-        format!("({})", values.join(", "))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(format!("({})", values.join(", ")))
     }
 
-    fn handle_struct(&self, fields: &[(String, FieldType)]) -> String {
+    fn handle_struct(&self, fields: &[(String, FieldType)]) -> Result<String> {
         // Build nested coordination context
         let mut nested_coordinated_values = BTreeMap::new();
         let field_prefix = format!("{}.", self.field.field_name);
@@ -217,7 +228,7 @@ impl<'a> FieldValueGenerator<'a> {
             }
         }
 
-        let field_values: Vec<String> = fields
+        let field_values = fields
             .iter()
             .map(|(fname, ftype)| {
                 // Check if we have a coordinated value for this nested field
@@ -238,69 +249,77 @@ impl<'a> FieldValueGenerator<'a> {
                         coord_value.clone()
                     }
                 } else {
-                    self.generate_field_value(ftype)
+                    self.generate_field_value(ftype)?
                 };
-                format!("{}: {}", fname, value)
+                Ok(format!("{}: {}", fname, value))
             })
-            .collect();
-        format!("{{ {} }}", field_values.join(", "))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(format!("{{ {} }}", field_values.join(", ")))
     }
 
-    fn handle_hash_map(&self, key_ft: &FieldType, value_ft: &FieldType) -> String {
-        let count = rand::rng().random_range(0..3);
-        let entries: Vec<String> = (0..count)
+    fn handle_hash_map(&self, key_ft: &FieldType, value_ft: &FieldType) -> Result<String> {
+        let count = self.mockmaker.rng().random_range(0..3);
+        let entries = (0..count)
             .map(|_| {
-                let key_string = self.generate_field_value(key_ft);
-                let value_string = self.generate_field_value(value_ft);
-                format!("{}: {}", key_string, value_string)
+                let key_string = self.generate_field_value(key_ft)?;
+                let value_string = self.generate_field_value(value_ft)?;
+                Ok(format!("{}: {}", key_string, value_string))
             })
-            .collect();
-        format!("{{ {} }}", entries.join(", "))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(format!("{{ {} }}", entries.join(", ")))
     }
 
-    fn handle_b_tree_map(&self, key_ft: &FieldType, value_ft: &FieldType) -> String {
-        let count = rand::rng().random_range(0..3);
-        let entries: Vec<String> = (0..count)
+    fn handle_b_tree_map(&self, key_ft: &FieldType, value_ft: &FieldType) -> Result<String> {
+        let count = self.mockmaker.rng().random_range(0..3);
+        let entries = (0..count)
             .map(|_| {
-                let key_string = self.generate_field_value(key_ft);
-                let value_string = self.generate_field_value(value_ft);
-                format!("{}: {}", key_string, value_string)
+                let key_string = self.generate_field_value(key_ft)?;
+                let value_string = self.generate_field_value(value_ft)?;
+                Ok(format!("{}: {}", key_string, value_string))
             })
-            .collect();
-        format!("{{ {} }}", entries.join(", "))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(format!("{{ {} }}", entries.join(", ")))
     }
 
-    fn handle_other(&self, type_name: &String, rng: &mut ThreadRng) -> String {
+    fn handle_other(&self, type_name: &String) -> Result<String> {
+        let rng = || self.mockmaker.rng();
         // Check if this is a foreign type with a mock strategy
         if let Some(ftc) = self.registry.lookup(type_name) {
             match ftc.mock_strategy.as_str() {
-                "datetime" => return format!("d'{}'", chrono::Utc::now().to_rfc3339()),
+                "datetime" => {
+                    return Ok(format!(
+                        "d'{}'",
+                        self.mockmaker
+                            .reference_time(self.table_config)
+                            .to_rfc3339()
+                    ));
+                }
                 "duration" => {
-                    return format!(
+                    return Ok(format!(
                         "duration::from_nanos({})",
-                        rng.random_range(0..86_400_000_000_000i64)
-                    );
+                        rng().random_range(0..86_400_000_000_000i64)
+                    ));
                 }
                 "timezone" => {
                     #[cfg(feature = "mockmake")]
                     {
-                        let tz = &TZ_VARIANTS[rng.random_range(0..TZ_VARIANTS.len())];
-                        return format!("'{}'", tz.name());
+                        let tz = &TZ_VARIANTS[rng().random_range(0..TZ_VARIANTS.len())];
+                        return Ok(format!("'{}'", tz.name()));
                     }
                     #[cfg(not(feature = "mockmake"))]
                     {
                         let timezones = ["UTC", "America/New_York", "Europe/London", "Asia/Tokyo"];
-                        return format!("'{}'", timezones[rng.random_range(0..timezones.len())]);
+                        return Ok(format!(
+                            "'{}'",
+                            timezones[rng().random_range(0..timezones.len())]
+                        ));
                     }
                 }
-                "decimal" => return format!("{:.3}dec", rng.random_range(0.0..100.0)),
-                "float" => return format!("{:.2}f", rng.random_range(0.0..100.0)),
+                "decimal" => return Ok(format!("{:.3}dec", rng().random_range(0.0..100.0))),
+                "float" => return Ok(format!("{:.2}f", rng().random_range(0.0..100.0))),
                 "record_id" => {
-                    return self.handle_record_id(
-                        &self.field.field_name,
-                        &self.table_config.table_name,
-                        rng,
-                    );
+                    return self
+                        .handle_record_id(&self.field.field_name, &self.table_config.table_name);
                 }
                 _ => {
                     // Fall through to existing Other logic
@@ -316,7 +335,7 @@ impl<'a> FieldValueGenerator<'a> {
             .iter()
             .find(|(_, table_config)| &table_config.table_name == type_name)
         {
-            self.handle_table(table_name, rng)
+            self.handle_table(table_name)
         } else if let Some(struct_config) = self
             .mockmaker
             .objects
@@ -325,47 +344,48 @@ impl<'a> FieldValueGenerator<'a> {
         {
             self.handle_object(struct_config)
         } else if let Some(tagged_union) = self.mockmaker.enums.get(type_name) {
-            self.handle_enum(tagged_union, rng)
+            self.handle_enum(tagged_union)
         } else {
-            panic!(
-                "{}",
-                format!(
-                    "This type could not be parsed table {}, field {}",
-                    self.table_config.table_name, self.field.field_name
-                )
-            )
+            Err(EvenframeError::mock_generation(format!(
+                "This type could not be parsed: table {}, field {}",
+                self.table_config.table_name, self.field.field_name
+            )))
         }
     }
 
-    fn handle_table(&self, table_name: &String, rng: &mut ThreadRng) -> String {
-        if let Some(possible_ids) = self.mockmaker.id_map.get(table_name) {
-            let idx = rng.random_range(0..possible_ids.len());
-            format!("r'{}'", possible_ids[idx])
-        } else {
-            // Fallback if no ids were generated for this table
-            panic!(
-                "{}",
-                format!(
-                    "There were no id's for the table {}, field {}",
-                    table_name, self.field.field_name
-                )
-            )
+    fn handle_table(&self, table_name: &String) -> Result<String> {
+        match self.mockmaker.id_map.get(table_name) {
+            Some(possible_ids) if !possible_ids.is_empty() => {
+                let idx = self.mockmaker.rng().random_range(0..possible_ids.len());
+                Ok(format!("r'{}'", possible_ids[idx]))
+            }
+            _ => Err(EvenframeError::mock_generation(format!(
+                "There were no id's for the table {}, field {}",
+                table_name, self.field.field_name
+            ))),
         }
     }
 
-    fn handle_enum(&self, tagged_union: &TaggedUnion, rng: &mut ThreadRng) -> String {
+    fn handle_enum(&self, tagged_union: &TaggedUnion) -> Result<String> {
         let variant = tagged_union
             .variants
-            .choose(rng)
-            .expect("Something went wrong selecting a random enum variant, returned None");
+            .choose(&mut *self.mockmaker.rng())
+            .ok_or_else(|| {
+                EvenframeError::mock_generation(format!(
+                    "Enum {} has no variants to generate: table {}, field {}",
+                    tagged_union.enum_name, self.table_config.table_name, self.field.field_name
+                ))
+            })?;
         if let Some(ref variant_data) = variant.data {
             let inner = match variant_data {
                 VariantData::InlineStruct(enum_struct) => {
-                    self.generate_field_value(&FieldType::Other(enum_struct.struct_name.clone()))
+                    self.generate_field_value(&FieldType::Other(enum_struct.struct_name.clone()))?
+                }
+                VariantData::DataStructureRef(field_type) => {
+                    self.generate_field_value(field_type)?
                 }
-                VariantData::DataStructureRef(field_type) => self.generate_field_value(field_type),
             };
-            match &tagged_union.representation {
+            let value = match &tagged_union.representation {
                 EnumRepresentation::ExternallyTagged => {
                     format!("{{ {}: {} }}", variant.name, inner)
                 }
@@ -387,20 +407,21 @@ impl<'a> FieldValueGenerator<'a> {
                     format!("{{ {}: '{}', {}: {} }}", tag, variant.name, content, inner)
                 }
                 EnumRepresentation::Untagged => inner,
-            }
+            };
+            Ok(value)
         } else {
             // Unit variant
-            match &tagged_union.representation {
+            Ok(match &tagged_union.representation {
                 EnumRepresentation::InternallyTagged { tag }
                 | EnumRepresentation::AdjacentlyTagged { tag, .. } => {
                     format!("{{ {}: '{}' }}", tag, variant.name)
                 }
                 _ => format!("'{}'", variant.name),
-            }
+            })
         }
     }
 
-    fn handle_object(&self, struct_config: &StructConfig) -> String {
+    fn handle_object(&self, struct_config: &StructConfig) -> Result<String> {
         let mut assignments = Vec::new();
         for struct_field in &struct_config.fields {
             let val = Self::builder()
@@ -410,12 +431,13 @@ impl<'a> FieldValueGenerator<'a> {
                 .mockmaker(self.mockmaker)
                 .table_config(self.table_config)
                 .registry(self.registry)
+                .depth(Cell::new(self.depth.get()))
                 .build()
-                .run();
+                .run()?;
 
-            assignments.push(format!("{}: {val}", struct_field.field_name));
+            assignments.push(format!("{}: {val}", struct_field.output_name()));
         }
         // Surreal accepts JSON-like objects with unquoted keys:
-        format!("{{ {} }}", assignments.join(", "))
+        Ok(format!("{{ {} }}", assignments.join(", ")))
     }
 }
@@ -1,16 +1,63 @@
-use chrono::{Datelike, Duration, Utc};
+use chrono::{DateTime, Datelike, Duration, Utc};
 use quote::{ToTokens, quote};
 use regex::Regex;
+#[cfg(feature = "schemasync")]
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+};
 use tracing;
 use try_from_expr::TryFromExpr;
 
 #[cfg(feature = "schemasync")]
 use super::regex_val_gen::RegexValGen;
+#[cfg(feature = "schemasync")]
+use rand::rngs::StdRng;
+
+/// Generator for a [`Format::Custom`] name, see [`register_custom`].
+#[cfg(feature = "schemasync")]
+pub type CustomFormatGenerator = Box<dyn Fn() -> String + Send + Sync>;
+
+/// Custom format generators keyed by their `Format::Custom` name. The map is
+/// created on first use and guarded by a `Mutex`, so formats can be
+/// registered and generated from any thread.
+#[cfg(feature = "schemasync")]
+type CustomFormatRegistry = Mutex<HashMap<String, Arc<dyn Fn() -> String + Send + Sync>>>;
+
+#[cfg(feature = "schemasync")]
+static CUSTOM_FORMATS: OnceLock<CustomFormatRegistry> = OnceLock::new();
+
+/// Registers `generator` as the value source for `Format::Custom(name)`,
+/// replacing any generator previously registered under `name`.
+///
+/// Without a registered generator, a `Custom` format's string is treated as a
+/// regex pattern and values are generated from it.
+#[cfg(feature = "schemasync")]
+pub fn register_custom(name: impl Into<String>, generator: CustomFormatGenerator) {
+    let name = name.into();
+    tracing::debug!(name = %name, "Registering custom format generator");
+    CUSTOM_FORMATS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(name, Arc::from(generator));
+}
+
+/// The generator registered under `name`, cloned out so the lock is released
+/// before it runs.
+#[cfg(feature = "schemasync")]
+fn custom_generator(name: &str) -> Option<Arc<dyn Fn() -> String + Send + Sync>> {
+    CUSTOM_FORMATS
+        .get()?
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(name)
+        .cloned()
+}
 
-/// Generate a regex pattern for dates within a specified number of days from now
-fn generate_date_range_pattern(days: i64) -> String {
+/// Generate a regex pattern for dates within a specified number of days from `now`
+fn generate_date_range_pattern(days: i64, now: DateTime<Utc>) -> String {
     tracing::trace!(days = days, "Generating date range pattern");
-    let now = Utc::now();
 
     // Collect all valid dates in the range
     let mut date_patterns = Vec::new();
@@ -33,6 +80,23 @@ fn generate_date_range_pattern(days: i64) -> String {
     pattern
 }
 
+/// Locale used by a bare `#[format(PhoneNumber)]`.
+pub const DEFAULT_PHONE_LOCALE: &str = "US";
+
+/// Phone number pattern for a locale, matching its E.164 form and its usual
+/// national form. UK and DE numbers are mobile numbers, since landline area
+/// codes vary in length.
+fn phone_number_pattern(locale: &str) -> &'static str {
+    match locale.to_ascii_uppercase().as_str() {
+        // +447700900123 or 07700 900123
+        "UK" | "GB" => r"^(\+447\d{9}|07\d{3} \d{6})$",
+        // +4915112345678 or 0151 12345678
+        "DE" => r"^(\+491[5-7]\d{9}|01[5-7]\d \d{8})$",
+        // +12025550123 or (202) 555-0123
+        _ => r"^(\+1[2-9]\d{2}[2-9]\d{6}|\([2-9]\d{2}\) [2-9]\d{2}-\d{4})$",
+    }
+}
+
 #[derive(
     Debug,
     Clone,
@@ -78,6 +142,9 @@ pub enum Format {
     Url(String), // domain
     /// Generate a random currency amount (formatted as string)
     CurrencyAmount,
+    /// Generate a decimal currency amount with the given number of
+    /// fractional digits (scale), asserted to that scale in the schema
+    Currency(u32),
     /// Generate a random percentage (0-100)
     Percentage,
     /// Generate a random latitude coordinate
@@ -118,8 +185,10 @@ pub enum Format {
     LastName,
     /// Generate a random full name
     FullName,
-    /// Generate a random phone number
-    PhoneNumber,
+    /// Generate a random phone number for a locale (`US`, `UK`/`GB` or
+    /// `DE`), in either E.164 or national format. Other locales use the US
+    /// patterns.
+    PhoneNumber { locale: String },
     /// Generate a random ISO8601 duration string (e.g., "PT1H30M")
     Iso8601DurationString,
     /// Generate a random timezone identifier (e.g., "America/New_York")
@@ -130,7 +199,8 @@ pub enum Format {
     AppointmentDateTime,
     /// Generate a set of Tailwind-style colors (main, hover, active)
     TailwindColorSet(Option<String>), // Optional color name for seeding
-    /// Custom format with a user-provided pattern
+    /// Custom format: a generator registered under this name with
+    /// [`register_custom`], otherwise a user-provided regex pattern
     Custom(String),
     /// Generate a completely random string of 8-16 characters
     Random,
@@ -141,12 +211,12 @@ pub enum Format {
 #[cfg(feature = "schemasync")]
 impl Format {
     /// Helper function to generate a value from regex pattern
-    fn generate_from_regex(&self) -> String {
+    fn generate_from_regex(&self, rng: &mut StdRng, now: DateTime<Utc>) -> String {
         tracing::trace!(format = ?self, "Generating value from regex pattern");
-        let regex: Regex = self.clone().into();
+        let regex = self.clone().into_regex_at(now);
         let pattern = regex.as_str();
 
-        let mut maker = RegexValGen::new();
+        let mut maker = RegexValGen::from_rng(rng);
 
         let result = maker
             .generate(pattern)
@@ -157,8 +227,26 @@ impl Format {
     }
 
     pub fn generate_formatted_value(&self) -> String {
+        self.generate_formatted_value_with_rng(&mut rand::make_rng())
+    }
+
+    /// Like [`Self::generate_formatted_value`], drawing from `rng`. Values of
+    /// registered custom formats come from their generator, so only those
+    /// ignore `rng`.
+    pub fn generate_formatted_value_with_rng(&self, rng: &mut StdRng) -> String {
+        self.generate_formatted_value_at(rng, Utc::now())
+    }
+
+    /// Like [`Self::generate_formatted_value_with_rng`], with date formats
+    /// such as `DateWithinDays` counted from `now` instead of the clock.
+    pub fn generate_formatted_value_at(&self, rng: &mut StdRng, now: DateTime<Utc>) -> String {
         tracing::debug!(format = ?self, "Generating formatted value");
-        self.generate_from_regex()
+        if let Format::Custom(name) = self
+            && let Some(generator) = custom_generator(name)
+        {
+            return generator();
+        }
+        self.generate_from_regex(rng, now)
     }
 }
 
@@ -168,12 +256,28 @@ impl Format {
         tracing::trace!(format = ?self, "Converting format to regex");
         self.into()
     }
-}
 
-impl From<Format> for Regex {
-    fn from(format: Format) -> Self {
-        tracing::trace!(format = ?format, "Creating regex from format");
-        let pattern = match format {
+    /// The SurrealQL condition a field with this format must satisfy, if
+    /// any; `Currency` fields may not have more fractional digits than their
+    /// scale, `Email` and `Url` fields must hold a valid address.
+    pub fn surql_assert(&self, value_var: &str) -> Option<String> {
+        match self {
+            Format::Email => Some(format!("string::is_email({value_var})")),
+            Format::Url(_) => Some(format!("string::is_url({value_var})")),
+            Format::Currency(scale) => {
+                let factor = format!("1{}", "0".repeat(*scale as usize));
+                Some(format!(
+                    "math::round({value_var} * {factor}) / {factor} = {value_var}"
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Like [`Self::into_regex`], with date ranges starting at `now`.
+    pub fn into_regex_at(self, now: DateTime<Utc>) -> Regex {
+        tracing::trace!(format = ?self, "Creating regex from format");
+        let pattern = match self {
             Format::Uuid => {
                 r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
             }
@@ -208,6 +312,11 @@ impl From<Format> for Regex {
                     .expect("Failed to create URL regex");
             }
             Format::CurrencyAmount => r"^\$\d+\.\d{2}$",
+            Format::Currency(0) => r"^[1-9]\d{0,3}$",
+            Format::Currency(scale) => {
+                return Regex::new(&format!(r"^(0|[1-9]\d{{0,3}})\.\d{{{}}}$", scale))
+                    .expect("Failed to create currency regex");
+            }
             Format::Percentage => r"^\d+(?:\.\d+)?%$",
             Format::Latitude => r"^-?\d+\.\d{6}$",
             Format::Longitude => r"^-?\d+\.\d{6}$",
@@ -244,7 +353,7 @@ impl From<Format> for Regex {
             Format::FullName => {
                 r"^(James|Mary|John|Patricia|Robert|Jennifer|Michael|Linda|William|Elizabeth|David|Barbara|Richard|Susan|Joseph|Jessica|Thomas|Sarah|Charles|Karen|Christopher|Nancy|Daniel|Lisa|Matthew|Betty|Anthony|Dorothy|Mark|Sandra|Donald|Ashley|Steven|Kimberly|Kenneth|Emily|Joshua|Michelle|Kevin|Carol|Brian|Amanda|George|Melissa|Edward|Deborah|Ronald|Stephanie|Timothy|Rebecca|Jason|Sharon|Jeffrey|Laura|Ryan|Cynthia|Jacob|Amy|Gary|Kathleen|Nicholas|Angela|Eric|Helen|Jonathan|Anna|Stephen|Brenda|Larry|Pamela|Justin|Nicole|Scott|Emma|Brandon|Samantha|Benjamin|Katherine|Samuel|Christine|Gregory|Catherine|Frank|Debra|Alexander|Rachel|Raymond|Carolyn|Patrick|Janet|Jack|Virginia|Dennis|Maria|Jerry|Heather|Tyler|Diane|Aaron|Ruth|Jose|Julie|Nathan|Olivia|Adam|Joyce|Harold|Victoria|Peter|Kelly|Henry|Christina|Zachary|Lauren|Douglas|Joan|Carl|Evelyn|Arthur|Judith|Albert|Megan|Willie|Cheryl|Austin|Martha|Jesse|Andrea|Gerald|Frances|Roger|Hannah|Keith|Jacqueline|Jeremy|Ann|Terry|Gloria|Lawrence|Jean|Sean|Kathryn|Christian|Alice|Ethan|Teresa|Bryan|Sara|Joe|Janice|Louis|Doris|Eugene|Madison|Russell|Julia|Gabriel|Grace|Bruce|Judy|Logan|Beverly|Juan|Denise|Elijah|Marilyn|Harry|Charlotte|Aaron|Marie|Willie|Abigail|Albert|Sophia|Jordan|Mia|Ralph|Isabella|Roy|Amber|Noah|Danielle|Mason|Brittany|Kyle|Rose|Francis|Diana|Russell|Natalie|Philip|Lori|Randy|Kayla|Vincent|Alexis|Billy|Lilly) (Smith|Johnson|Williams|Brown|Jones|Garcia|Miller|Davis|Rodriguez|Martinez|Hernandez|Lopez|Gonzalez|Wilson|Anderson|Thomas|Taylor|Moore|Jackson|Martin|Lee|Perez|Thompson|White|Harris|Sanchez|Clark|Ramirez|Lewis|Robinson|Walker|Young|Allen|King|Wright|Scott|Torres|Nguyen|Hill|Flores|Green|Adams|Nelson|Baker|Hall|Rivera|Campbell|Mitchell|Carter|Roberts|Gomez|Phillips|Evans|Turner|Diaz|Parker|Cruz|Edwards|Collins|Reyes|Stewart|Morris|Morales|Murphy|Cook|Rogers|Gutierrez|Ortiz|Morgan|Cooper|Peterson|Bailey|Reed|Kelly|Howard|Ramos|Kim|Cox|Ward|Richardson|Watson|Brooks|Chavez|Wood|James|Bennett|Gray|Mendoza|Ruiz|Hughes|Price|Alvarez|Castillo|Sanders|Patel|Myers|Long|Ross|Foster|Jimenez|Powell|Jenkins|Perry|Russell|Sullivan|Bell|Coleman|Butler|Henderson|Barnes|Gonzales|Fisher|Vasquez|Simmons|Romero|Jordan|Patterson|Alexander|Hamilton|Graham|Reynolds|Griffin|Wallace|Moreno|West|Cole|Hayes|Bryant|Herrera|Gibson|Ellis|Tran|Medina|Aguilar|Stevens|Murray|Ford|Castro|Marshall|Owens|Harrison|Fernandez|Mcdonald|Woods|Washington|Kennedy|Wells|Vargas|Henry|Chen|Freeman|Webb|Tucker|Guzman|Burns|Crawford|Olson|Simpson|Porter|Hunter|Gordon|Mendez|Silva|Shaw|Snyder|Mason|Dixon|Munoz|Hunt|Hicks|Holmes|Palmer|Wagner|Black|Robertson|Boyd|Rose|Stone|Salazar|Fox|Warren|Mills|Meyer|Rice|Schmidt|Garza|Daniels|Ferguson|Nichols|Stephens|Soto|Weaver|Ryan|Gardner|Payne|Grant|Dunn|Kelley|Spencer|Hawkins|Arnold|Pierce|Vazquez|Hansen|Peters|Santos|Hart|Bradley|Knight|Elliott|Cunningham|Duncan|Armstrong|Hudson|Carroll|Lane|Riley|Andrews|Alvarado|Ray|Delgado|Berry|Perkins|Hoffman|Johnston|Matthews|Pena|Richards|Contreras|Willis|Carpenter|Lawrence|Sandoval|Guerrero|George|Chapman|Rios|Estrada|Ortega|Watkins|Greene|Nunez|Wheeler|Valdez|Harper|Burke|Larson|Santiago|Maldonado|Morrison|Franklin|Carlson|Austin|Dominguez|Carr|Lawson|Jacobs|Obrien|Lynch|Singh|Vega|Bishop|Montgomery|Oliver|Jensen|Harvey|Williamson|Gilbert|Dean|Sims|Espinoza|Howell|Li|Wong|Reid|Hanson|Le|Mccoy|Garrett|Burton|Fuller|Wang|Weber|Welch|Rojas|Lucas|Marquez|Fields|Park|Yang|Little|Banks|Padilla|Day|Walsh|Bowman|Schultz|Luna|Fowler|Mejia)$"
             }
-            Format::PhoneNumber => r"^(\+\d{1,2}\s)?(\(\d{3}\)\s?|\d{3})[\.\-]?\d{3}[\.\-]?\d{4}$",
+            Format::PhoneNumber { ref locale } => phone_number_pattern(locale),
             Format::Iso8601DurationString => {
                 // ISO 8601 duration: P[nY][nM][nW][nD][T[nH][nM][nS]]
                 // Structured as alternation to guarantee at least one component.
@@ -286,13 +395,13 @@ impl From<Format> for Regex {
             }
             Format::DateWithinDays(days) => {
                 // Use the helper function to generate date range pattern
-                let date_pattern = generate_date_range_pattern(days);
+                let date_pattern = generate_date_range_pattern(days, now);
 
                 &format!(r"^{}T([01][0-9]|2[0-3]):(00|15|30|45):00Z$", date_pattern)
             }
             Format::AppointmentDateTime => {
                 // Use the helper function to generate date range pattern for next 10 days
-                let date_pattern = generate_date_range_pattern(10);
+                let date_pattern = generate_date_range_pattern(10, now);
 
                 // Create a regex pattern for appointment hours (7am-8pm)
                 &format!(r"^{}T(0[7-9]|1[0-9]|20):(00|15|30|45):00Z$", date_pattern)
@@ -320,6 +429,12 @@ impl From<Format> for Regex {
     }
 }
 
+impl From<Format> for Regex {
+    fn from(format: Format) -> Self {
+        format.into_regex_at(Utc::now())
+    }
+}
+
 impl ToTokens for Format {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let variant_tokens = match self {
@@ -363,6 +478,9 @@ impl ToTokens for Format {
             Format::CurrencyAmount => {
                 quote! { ::evenframe::schemasync::format::Format::CurrencyAmount }
             }
+            Format::Currency(scale) => {
+                quote! { ::evenframe::schemasync::format::Format::Currency(#scale) }
+            }
             Format::Percentage => {
                 quote! { ::evenframe::schemasync::format::Format::Percentage }
             }
@@ -411,8 +529,8 @@ impl ToTokens for Format {
             Format::FullName => {
                 quote! { ::evenframe::schemasync::format::Format::FullName }
             }
-            Format::PhoneNumber => {
-                quote! { ::evenframe::schemasync::format::Format::PhoneNumber }
+            Format::PhoneNumber { locale } => {
+                quote! { ::evenframe::schemasync::format::Format::PhoneNumber { locale: #locale.to_string() } }
             }
             Format::Iso8601DurationString => {
                 quote! { ::evenframe::schemasync::format::Format::Iso8601DurationString }
@@ -499,12 +617,14 @@ mod tests {
 
     #[test]
     fn test_phone_number_format() {
-        let format = Format::PhoneNumber;
+        let format = Format::PhoneNumber {
+            locale: DEFAULT_PHONE_LOCALE.to_string(),
+        };
         let value = format.generate_formatted_value();
         println!("Generated phone number: {}", value);
 
         // Phone number should match the pattern
-        let regex = format.into_regex();
+        let regex = format.clone().into_regex();
         assert!(
             regex.is_match(&value),
             "Generated phone number {} doesn't match pattern",
@@ -514,11 +634,44 @@ mod tests {
         // Generate multiple samples to see variety
         println!("Multiple phone number samples:");
         for _ in 0..10 {
-            let phone = Format::PhoneNumber.generate_formatted_value();
+            let phone = format.generate_formatted_value();
             println!("  {}", phone);
         }
     }
 
+    #[test]
+    fn test_phone_number_locales() {
+        let cases = [
+            ("US", r"^(\+1\d{10}|\(\d{3}\) \d{3}-\d{4})$"),
+            ("UK", r"^(\+447\d{9}|07\d{3} \d{6})$"),
+            ("gb", r"^(\+447\d{9}|07\d{3} \d{6})$"),
+            ("DE", r"^(\+491\d{10}|01\d{2} \d{8})$"),
+        ];
+        for (locale, expected) in cases {
+            let expected = Regex::new(expected).unwrap();
+            let format = Format::PhoneNumber {
+                locale: locale.to_string(),
+            };
+            let values: Vec<String> = (0..200)
+                .map(|_| format.generate_formatted_value())
+                .collect();
+            for value in &values {
+                assert!(
+                    expected.is_match(value),
+                    "{locale} phone number {value} doesn't match {expected}"
+                );
+            }
+            assert!(
+                values.iter().any(|v| v.starts_with('+')),
+                "{locale} never produced an E.164 number"
+            );
+            assert!(
+                values.iter().any(|v| !v.starts_with('+')),
+                "{locale} never produced a national number"
+            );
+        }
+    }
+
     #[test]
     fn test_ip_address_format() {
         let format = Format::IpAddress;
@@ -555,6 +708,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_registered_custom_format_generates_values() {
+        // Registered from another thread, generated on this one
+        std::thread::spawn(|| {
+            register_custom(
+                "test_sku",
+                Box::new(|| format!("SKU-{:04}", rand::random::<u16>() % 10_000)),
+            );
+        })
+        .join()
+        .unwrap();
+
+        let value = Format::Custom("test_sku".to_string()).generate_formatted_value();
+        assert!(
+            Regex::new(r"^SKU-\d{4}$").unwrap().is_match(&value),
+            "got {value}"
+        );
+
+        // Unregistered names keep their regex-pattern meaning
+        let value = Format::Custom("^[a-c]{3}$".to_string()).generate_formatted_value();
+        assert!(
+            Regex::new(r"^[a-c]{3}$").unwrap().is_match(&value),
+            "got {value}"
+        );
+    }
+
+    #[test]
+    fn test_currency_format_respects_scale() {
+        for scale in [0, 2, 4] {
+            let value = Format::Currency(scale).generate_formatted_value();
+            let fraction = value.split_once('.').map_or("", |(_, fraction)| fraction);
+            assert_eq!(
+                fraction.len(),
+                scale as usize,
+                "got {value} for scale {scale}"
+            );
+            assert!(value.parse::<f64>().is_ok(), "got {value}");
+        }
+
+        assert_eq!(
+            Format::Currency(3).surql_assert("$value").as_deref(),
+            Some("math::round($value * 1000) / 1000 = $value")
+        );
+        assert_eq!(Format::CurrencyAmount.surql_assert("$value"), None);
+    }
+
+    #[test]
+    fn test_email_and_url_formats_assert_their_shape() {
+        assert_eq!(
+            Format::Email.surql_assert("$value").as_deref(),
+            Some("string::is_email($value)")
+        );
+        assert_eq!(
+            Format::Url("example.com".to_string())
+                .surql_assert("$value")
+                .as_deref(),
+            Some("string::is_url($value)")
+        );
+    }
+
     #[test]
     fn test_name_formats() {
         // Test FirstName format generates real first names
@@ -14,7 +14,8 @@ pub mod validator_gen;
 
 #[cfg(feature = "surrealdb")]
 use crate::{
-    dependency::sort_tables_by_dependencies,
+    dependency::{collect_table_dependencies, sort_tables_by_dependencies},
+    error::EvenframeError,
     evenframe_log,
     schemasync::TableConfig,
     schemasync::compare::surql::SurrealdbComparator,
@@ -22,18 +23,22 @@ use crate::{
         CoherentDataset, Coordination, CoordinationGroup, CoordinationId, CoordinationPair,
     },
     schemasync::mockmake::format::Format,
-    schemasync::{PreservationMode, database::surql::access::execute_access_query},
-    types::{StructConfig, StructField, TaggedUnion},
+    schemasync::{
+        PreservationMode,
+        database::surql::access::{execute_access_query, generate_access_statements},
+        surql_ident, surql_record_id,
+    },
+    types::{GeometryKind, StructConfig, StructField, TaggedUnion},
     wrappers::EvenframeRecordId,
 };
 #[cfg(feature = "surrealdb")]
-use rand::RngExt;
+use rand::{RngExt, SeedableRng, rngs::StdRng};
 #[cfg(feature = "surrealdb")]
 use std::collections::{BTreeMap, BTreeSet};
 #[cfg(feature = "surrealdb")]
 use surrealdb::Surreal;
 #[cfg(feature = "surrealdb")]
-use surrealdb::engine::local::Db;
+use surrealdb::engine::local::{Db, Mem};
 #[cfg(feature = "surrealdb")]
 use surrealdb::engine::remote::http::Client;
 #[cfg(feature = "surrealdb")]
@@ -55,7 +60,15 @@ pub struct Mockmaker<'a> {
     pub(super) record_diffs: BTreeMap<String, i32>,
     filtered_tables: BTreeMap<String, TableConfig>,
     filtered_objects: BTreeMap<String, StructConfig>,
-    pub coordinated_values: BTreeMap<CoordinationId, String>,
+    /// Coordinated values per field, keyed by record index
+    pub coordinated_values: BTreeMap<CoordinationId, BTreeMap<usize, String>>,
+    /// Statements intercepted while `SchemasyncConfig::dry_run` is set
+    dry_run_statements: std::sync::Mutex<Vec<String>>,
+    /// Generated values that failed their field's validators
+    validation_violations: std::sync::Mutex<Vec<String>>,
+    /// Source of every random mock value, reseeded per table by
+    /// [`Self::generate_table_statements`]
+    rng: std::sync::Mutex<StdRng>,
     #[cfg(feature = "wasm-plugins")]
     pub(super) plugin_manager: Option<std::cell::RefCell<plugin::PluginManager>>,
 }
@@ -83,6 +96,9 @@ impl<'a> Mockmaker<'a> {
             filtered_tables: BTreeMap::new(),
             filtered_objects: BTreeMap::new(),
             coordinated_values: BTreeMap::new(),
+            dry_run_statements: std::sync::Mutex::new(Vec::new()),
+            validation_violations: std::sync::Mutex::new(Vec::new()),
+            rng: std::sync::Mutex::new(seeded_rng(schemasync_config.mock_gen_config.seed, "")),
             #[cfg(feature = "wasm-plugins")]
             plugin_manager: {
                 if schemasync_config.plugins.is_empty() {
@@ -122,7 +138,7 @@ impl<'a> Mockmaker<'a> {
         self.filter_changes().await?;
 
         tracing::debug!("Step 6: Generating coordinated values");
-        self.generate_coordinated_values();
+        self.generate_coordinated_values()?;
 
         tracing::debug!("Step 7: Generating mock data");
         self.generate_mock_data().await?;
@@ -139,12 +155,15 @@ impl<'a> Mockmaker<'a> {
         let mut record_diffs = BTreeMap::new();
 
         let full_refresh = self.schemasync_config.mock_gen_config.full_refresh_mode;
+        let id_strategy = self.schemasync_config.mock_gen_config.id_strategy;
 
         // Process tables sequentially to avoid reference issues
         // Since these are just SELECT queries, they should be fast enough
         for (table_name, table_config) in self.tables {
             let table_config = table_config.effective();
             tracing::trace!(table = %table_name, "Generating IDs for table");
+            let mut rng = seeded_rng(self.seed_for(table_config), &format!("{table_name}:ids"));
+            let reference_time = self.reference_time(table_config);
 
             // Determine desired count from config or default
             let desired_count =
@@ -155,11 +174,11 @@ impl<'a> Mockmaker<'a> {
                 };
 
             // In full refresh mode, all data will be deleted and recreated.
-            // Generate clean sequential IDs instead of reusing stale DB IDs,
-            // which may reference records that no longer exist after deletion.
+            // Generate clean IDs instead of reusing stale DB IDs, which may
+            // reference records that no longer exist after deletion.
             if full_refresh {
                 let ids: Vec<String> = (1..=desired_count)
-                    .map(|i| format!("{table_name}:{i}"))
+                    .map(|i| new_record_id(id_strategy, table_name, i, &mut rng, reference_time))
                     .collect();
 
                 tracing::trace!(
@@ -174,7 +193,7 @@ impl<'a> Mockmaker<'a> {
             }
 
             // Query existing IDs
-            let query = format!("SELECT id FROM {table_name};",);
+            let query = format!("SELECT id FROM {};", surql_ident(table_name));
             tracing::trace!("Querying existing IDs {query}");
             let mut response = self.db.query(query).await.expect(
                 "Something went wrong getting the ids from the db for mock data generation",
@@ -220,8 +239,7 @@ impl<'a> Mockmaker<'a> {
                 // Just use the first desired_count IDs
                 for (i, record) in existing_ids.into_iter().enumerate() {
                     if i < desired_count {
-                        let id_string = record.id.to_string();
-                        ids.push(id_string);
+                        ids.push(surql_record_id(&record.id.to_string()));
                     } else {
                         // Stop after we have enough
                         break;
@@ -231,15 +249,18 @@ impl<'a> Mockmaker<'a> {
                 // We need to use existing IDs and generate more
                 // First, use all existing IDs
                 for record in existing_ids {
-                    ids.push(record.id.to_string());
+                    ids.push(surql_record_id(&record.id.to_string()));
                 }
 
                 // Generate additional IDs
-                let mut next_id = existing_count + 1;
-                while ids.len() < desired_count {
-                    ids.push(format!("{table_name}:{next_id}"));
-                    next_id += 1;
-                }
+                allocate_record_ids(
+                    &mut ids,
+                    desired_count,
+                    id_strategy,
+                    table_name,
+                    &mut rng,
+                    reference_time,
+                );
             }
 
             // Store with both the original key and snake_case key for easier lookup
@@ -269,7 +290,7 @@ impl<'a> Mockmaker<'a> {
             tracing::info!("Full refresh mode - deleting all records from all tables");
             let mut delete_all = String::new();
             for table_name in self.tables.keys() {
-                delete_all.push_str(&format!("DELETE {};\n", table_name));
+                delete_all.push_str(&format!("DELETE {};\n", surql_ident(table_name)));
             }
 
             // Even in full refresh, we must remove fields that were deleted from
@@ -287,7 +308,9 @@ impl<'a> Mockmaker<'a> {
 
             if !delete_all.is_empty() {
                 evenframe_log!(&delete_all, "remove_statements.surql");
-                self.db.query(delete_all).await?;
+                if !self.record_dry_run(&delete_all) {
+                    self.db.query(delete_all).await?;
+                }
             }
             tracing::trace!("Full refresh data deletion complete");
             return Ok(());
@@ -305,7 +328,7 @@ impl<'a> Mockmaker<'a> {
 
         evenframe_log!(&remove_statements, "remove_statements.surql");
 
-        if !remove_statements.is_empty() {
+        if !remove_statements.is_empty() && !self.record_dry_run(&remove_statements) {
             tracing::trace!("Executing remove statements");
             self.db.query(remove_statements).await?;
         }
@@ -314,29 +337,114 @@ impl<'a> Mockmaker<'a> {
         Ok(())
     }
 
-    /// Execute access query on main database
+    /// Execute access query on main database. Outside full refresh mode only
+    /// new and changed accesses are applied; see [`generate_access_statements`].
     pub async fn execute_access(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         tracing::trace!("Executing access definitions");
         let comparator = self.comparator.as_ref().unwrap();
-        let access_query = comparator.get_access_query();
+        let access_query = match comparator.get_schema_changes() {
+            Some(schema_changes) if !self.schemasync_config.mock_gen_config.full_refresh_mode => {
+                generate_access_statements(schema_changes, comparator.get_access_query())
+            }
+            _ => comparator.get_access_query().to_string(),
+        };
 
         tracing::debug!(query_length = access_query.len(), "Executing access query");
 
-        execute_access_query(self.db, access_query).await
+        if access_query.trim().is_empty() || self.record_dry_run(&access_query) {
+            return Ok(());
+        }
+        execute_access_query(self.db, &access_query).await
+    }
+
+    /// In dry-run mode, records `statements` instead of executing them and
+    /// returns `true`; otherwise returns `false` and the caller executes.
+    pub fn record_dry_run(&self, statements: &str) -> bool {
+        if !self.schemasync_config.dry_run {
+            return false;
+        }
+        if !statements.trim().is_empty() {
+            self.dry_run_statements
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(statements.to_string());
+        }
+        true
+    }
+
+    /// Drains the statements recorded by [`Self::record_dry_run`], in the
+    /// order the pipeline would have executed them.
+    pub fn take_dry_run_statements(&self) -> Vec<String> {
+        std::mem::take(
+            &mut *self
+                .dry_run_statements
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()),
+        )
+    }
+
+    /// With `validate_generated_data` set, checks a generated SurrealQL
+    /// literal against the field's validators and records each failure for
+    /// `generate_mock_data` to report.
+    pub(crate) fn check_generated_value(
+        &self,
+        table_name: &str,
+        field: &StructField,
+        literal: &str,
+    ) {
+        if !self
+            .schemasync_config
+            .mock_gen_config
+            .validate_generated_data
+            || field.validators.is_empty()
+        {
+            return;
+        }
+        let Some(value) = literal_mock_value(&field.field_type, literal) else {
+            return;
+        };
+        let failed: Vec<String> = field
+            .validators
+            .iter()
+            .filter(|validator| !validator.matches(&value))
+            .map(|validator| {
+                format!(
+                    "{table_name}.{} = {literal} fails {validator:?}",
+                    field.field_name
+                )
+            })
+            .collect();
+        if !failed.is_empty() {
+            self.validation_violations
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .extend(failed);
+        }
+    }
+
+    /// Drains the violations recorded by [`Self::check_generated_value`].
+    pub fn take_validation_violations(&self) -> Vec<String> {
+        std::mem::take(
+            &mut *self
+                .validation_violations
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()),
+        )
     }
 
     /// Filter changed tables and objects
     pub async fn filter_changes(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         tracing::trace!("Filtering changes based on schema comparison");
-        let comparator = self.comparator.as_ref().unwrap();
-        let schema_changes = comparator.get_schema_changes().unwrap();
-
         let (filtered_tables, filtered_objects) =
-            if self.schemasync_config.mock_gen_config.full_refresh_mode {
+            if self.schemasync_config.mock_gen_config.full_refresh_mode
+                || self.schemasync_config.skip_schema_definition
+            {
                 tracing::debug!("Full refresh mode enabled - using all tables and objects");
                 (self.tables.clone(), self.objects.clone())
             } else {
                 tracing::debug!("Incremental mode - filtering changed items only");
+                let comparator = self.comparator.as_ref().unwrap();
+                let schema_changes = comparator.get_schema_changes().unwrap();
                 self.filter_changed_tables_and_objects(
                     schema_changes,
                     self.tables,
@@ -363,6 +471,15 @@ impl<'a> Mockmaker<'a> {
         Ok(())
     }
 
+    /// The whole pipeline when `skip_schema_definition` is set: read or
+    /// allocate ids, select every table and generate its mock data, without
+    /// running any schema statements.
+    pub(super) async fn generate_mocks_only(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.generate_ids().await?;
+        self.filter_changes().await?;
+        self.generate_mock_data().await
+    }
+
     pub(super) async fn generate_mock_data(&self) -> Result<(), Box<dyn std::error::Error>> {
         tracing::trace!("Starting mock data generation");
 
@@ -391,44 +508,76 @@ impl<'a> Mockmaker<'a> {
                 );
 
                 if self.schemasync_config.should_generate_mocks {
-                    let stmts = if table.relation.is_some() {
-                        tracing::trace!(table = %table_name, "Generating INSERT statements for relation");
-                        self.generate_insert_statements(table_name, table)
-                    } else {
-                        tracing::trace!(table = %table_name, "Generating UPSERT statements for table");
-                        self.generate_upsert_statements(table_name, table)
-                    };
-
-                    tracing::debug!(
-                        table = %table_name,
-                        statement_count = stmts.lines().count(),
-                        "Generated mock data statements"
-                    );
-
-                    evenframe_log!(&stmts, "all_statements.surql", true);
-
-                    // Execute and validate upsert statements
-                    use crate::schemasync::database::surql::execute::execute_and_validate;
-
-                    match execute_and_validate(self.db, &stmts, "UPSERT", table_name).await {
-                        Ok(_results) => {
-                            tracing::debug!(table = %table_name, "Mock data inserted successfully");
-                        }
-                        Err(e) => {
+                    // Each batch of records is generated, checked and executed
+                    // before the next, so a large table is never held in
+                    // memory whole. Tables run in dependency order and each
+                    // table's batches finish before the next table starts, so
+                    // relations only ever see records that already exist.
+                    *self.rng() = seeded_rng(self.seed_for(table), table_name);
+                    let record_count = self.record_count_for(table_name);
+                    let batch_size = self.batch_size_for(table);
+                    // An empty table still runs one batch for the `DELETE`
+                    // that clears an unpreserved table
+                    let batch_count = record_count.div_ceil(batch_size).max(1);
+
+                    for index in 0..batch_count {
+                        let start = index * batch_size;
+                        let records = start..(start + batch_size).min(record_count);
+                        let batch = self.generate_record_statements(table_name, table, records)?;
+
+                        tracing::debug!(
+                            table = %table_name,
+                            batch = index + 1,
+                            statement_count = batch.lines().count(),
+                            "Generated mock data statements"
+                        );
+
+                        evenframe_log!(&batch, "all_statements.surql", true);
+
+                        let violations = self.take_validation_violations();
+                        if !violations.is_empty() {
                             tracing::error!(
                                 table = %table_name,
-                                error = %e,
-                                "Failed to execute statements"
+                                violation_count = violations.len(),
+                                "Generated mock data failed field validators"
                             );
-                            #[cfg(feature = "dev-mode")]
-                            {
-                                let error_msg = format!(
-                                    "Failed to execute upsert statements for table {}: {}",
-                                    table_name, e
+                            return Err(EvenframeError::MockValidation(violations).into());
+                        }
+
+                        if batch.trim().is_empty() || self.record_dry_run(&batch) {
+                            continue;
+                        }
+
+                        match insert_mock_batch(self.db, table_name, index + 1, &batch).await {
+                            Ok(()) => {
+                                tracing::info!(
+                                    table = %table_name,
+                                    batch = index + 1,
+                                    batch_count = batch_count,
+                                    "Mock data batch inserted"
+                                );
+                            }
+                            Err(e) => {
+                                tracing::error!(
+                                    table = %table_name,
+                                    batch = index + 1,
+                                    batch_count = batch_count,
+                                    error = %e,
+                                    "Failed to execute statements"
                                 );
-                                evenframe_log!(&error_msg, "results.log", true);
+                                #[cfg(feature = "dev-mode")]
+                                {
+                                    let error_msg = format!(
+                                        "Failed to execute upsert statements for table {} (batch {}/{}): {}",
+                                        table_name,
+                                        index + 1,
+                                        batch_count,
+                                        e
+                                    );
+                                    evenframe_log!(&error_msg, "results.log", true);
+                                }
+                                return Err(e.into());
                             }
-                            return Err(e);
                         }
                     }
                 }
@@ -438,17 +587,305 @@ impl<'a> Mockmaker<'a> {
         Ok(())
     }
 
+    /// Number of mock records generated for `table_name`; the table-level
+    /// config wins over the global default.
+    pub(crate) fn record_count_for(&self, table_name: &str) -> usize {
+        self.tables
+            .get(table_name)
+            .and_then(|table| table.mock_generation_config.as_ref())
+            .map(|c| c.n)
+            .unwrap_or(self.schemasync_config.mock_gen_config.default_record_count)
+    }
+
+    /// Number of records generated and sent to the database per query for
+    /// `table`; the table-level config wins over the global default.
+    fn batch_size_for(&self, table: &TableConfig) -> usize {
+        table
+            .mock_generation_config
+            .as_ref()
+            .map(|c| c.batch_size)
+            .unwrap_or(self.schemasync_config.mock_gen_config.default_batch_size)
+            .max(1)
+    }
+
+    /// Preservation mode for `table`; the table-level config wins over the
+    /// global default.
+    pub(crate) fn preservation_mode_for(&self, table: &TableConfig) -> PreservationMode {
+        table
+            .mock_generation_config
+            .as_ref()
+            .map(|c| c.preservation_mode.clone())
+            .unwrap_or_else(|| {
+                self.schemasync_config
+                    .mock_gen_config
+                    .default_preservation_mode
+                    .clone()
+            })
+    }
+
+    /// Seed for `table`'s mock data; the table-level config wins over the
+    /// global default.
+    pub(crate) fn seed_for(&self, table: &TableConfig) -> Option<u64> {
+        table
+            .mock_generation_config
+            .as_ref()
+            .and_then(|c| c.seed)
+            .or(self.schemasync_config.mock_gen_config.seed)
+    }
+
+    /// The random generator mock values are drawn from.
+    pub(crate) fn rng(&self) -> std::sync::MutexGuard<'_, StdRng> {
+        self.rng.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Instant `datetime` mock values are generated at: now, or a fixed
+    /// instant when `table` is seeded so seeded runs stay reproducible.
+    pub(crate) fn reference_time(&self, table: &TableConfig) -> chrono::DateTime<chrono::Utc> {
+        seeded_reference_time(self.seed_for(table))
+    }
+
+    /// Generates the mock data statements for a single table: `INSERT` for
+    /// relations, `DELETE` + `CREATE` for tables with `PreservationMode::None`
+    /// and `UPSERT` for everything else.
+    ///
+    /// The random generator is reseeded from the table's seed first, so a
+    /// seeded table generates the same statements whichever tables came
+    /// before it.
+    pub fn generate_table_statements(
+        &self,
+        table_name: &str,
+        table: &TableConfig,
+    ) -> crate::error::Result<String> {
+        *self.rng() = seeded_rng(self.seed_for(table), table_name);
+        self.generate_record_statements(table_name, table, 0..self.record_count_for(table_name))
+    }
+
+    /// Generates the mock data statements for the records of a single table
+    /// at the indexes in `records`, continuing the table's random sequence:
+    /// consecutive ranges produce the same statements as one
+    /// [`Self::generate_table_statements`] call.
+    pub fn generate_record_statements(
+        &self,
+        table_name: &str,
+        table: &TableConfig,
+        records: std::ops::Range<usize>,
+    ) -> crate::error::Result<String> {
+        if table.relation.is_some() {
+            tracing::trace!(table = %table_name, "Generating INSERT statements for relation");
+            self.generate_insert_statements(table_name, table, records)
+        } else {
+            tracing::trace!(table = %table_name, "Generating UPSERT statements for table");
+            self.generate_upsert_statements(table_name, table, records)
+        }
+    }
+
+    /// Generates mock data statements for every table in dependency order
+    /// without executing them against the database.
+    pub fn generate_all_statements(&self) -> crate::error::Result<String> {
+        let sorted_table_names = sort_tables_by_dependencies(self.tables, self.objects, self.enums);
+
+        let mut output = String::new();
+        for table_name in &sorted_table_names {
+            if let Some(table) = self.tables.get(table_name) {
+                output.push_str(&self.generate_table_statements(table_name, table.effective())?);
+            }
+        }
+        Ok(output)
+    }
+
+    /// Writes the statements [`Self::generate_all_statements`] produces to
+    /// `file_path` instead of executing them.
+    pub fn export_mock_data(&self, file_path: &str) -> crate::error::Result<()> {
+        tracing::debug!(file_path = %file_path, "Exporting mock data");
+        let statements = self.generate_all_statements()?;
+
+        std::fs::write(file_path, &statements).map_err(|e| {
+            EvenframeError::export(format!(
+                "Failed to write mock data to '{}': {}",
+                file_path, e
+            ))
+        })?;
+
+        tracing::info!(
+            file_path = %file_path,
+            statement_count = statements.lines().count(),
+            "Mock data exported"
+        );
+        Ok(())
+    }
+
+    /// Generates `count` mock records for a single table and returns them as
+    /// JSON, skipping the comparison, removal and insertion steps of
+    /// [`Self::run`]. Formats, validators and coordinated values apply as usual.
+    ///
+    /// Tables this one links to must already hold records: their ids come
+    /// from the id map when [`Self::generate_ids`] has run, otherwise from
+    /// the database. The generated statements are evaluated in a scratch
+    /// in-memory database, so nothing is written to the target database.
+    pub async fn generate_for_table(
+        &self,
+        table_name: &str,
+        count: usize,
+    ) -> crate::error::Result<Vec<serde_json::Value>> {
+        let mut tables = self.tables.clone();
+        let table = tables.get_mut(table_name).ok_or_else(|| {
+            EvenframeError::mock_generation(format!("Unknown table '{table_name}'"))
+        })?;
+        match table.mock_generation_config.as_mut() {
+            Some(mock_config) => mock_config.n = count,
+            None => {
+                table.mock_generation_config = Some(MockGenerationConfig {
+                    n: count,
+                    table_level_override: None,
+                    coordination_rules: vec![],
+                    batch_size: self.schemasync_config.mock_gen_config.default_batch_size,
+                    regenerate_fields: vec![],
+                    preservation_mode: PreservationMode::None,
+                    plugin: None,
+                    seed: None,
+                })
+            }
+        }
+
+        let mut scratch = Mockmaker::new(
+            self.db,
+            &tables,
+            self.objects,
+            self.enums,
+            self.schemasync_config,
+            self.registry,
+        );
+        scratch.coordinated_values = self.coordinated_values.clone();
+
+        let dependencies = collect_table_dependencies(
+            table_name,
+            self.tables,
+            self.objects,
+            self.enums,
+            &mut BTreeSet::new(),
+        );
+        for dependency in dependencies {
+            if dependency == table_name {
+                continue;
+            }
+            let ids = match self.id_map.get(&dependency) {
+                Some(ids) => ids.clone(),
+                None => self.existing_ids(&dependency).await?,
+            };
+            if ids.is_empty() {
+                return Err(EvenframeError::mock_generation(format!(
+                    "Table '{table_name}' links to '{dependency}', which has no records"
+                )));
+            }
+            scratch.id_map.insert(dependency, ids);
+        }
+        scratch.id_map.insert(
+            table_name.to_string(),
+            (1..=count)
+                .map(|i| format!("{}:{i}", surql_ident(table_name)))
+                .collect(),
+        );
+
+        let table = tables[table_name].effective();
+        let statements = scratch.generate_table_statements(table_name, table)?;
+
+        let evaluator = Surreal::new::<Mem>(()).await.map_err(|e| {
+            EvenframeError::database(format!("Failed to start in-memory database: {e}"))
+        })?;
+        evaluator.use_ns("mock").use_db("mock").await.map_err(|e| {
+            EvenframeError::database(format!("Failed to select in-memory namespace: {e}"))
+        })?;
+        let mut response = evaluator.query(statements).await.map_err(|e| {
+            EvenframeError::mock_generation(format!(
+                "Failed to evaluate mock statements for table '{table_name}': {e}"
+            ))
+        })?;
+
+        let mut records = Vec::with_capacity(count);
+        for index in 0..response.num_statements() {
+            let created: surrealdb::types::Value = response.take(index).map_err(|e| {
+                EvenframeError::mock_generation(format!(
+                    "Invalid mock record for table '{table_name}': {e}"
+                ))
+            })?;
+            match created.into_json_value() {
+                serde_json::Value::Array(created) => records.extend(created),
+                created => records.push(created),
+            }
+        }
+        Ok(records)
+    }
+
+    /// Ids of the records currently stored in `table_name`.
+    async fn existing_ids(&self, table_name: &str) -> crate::error::Result<Vec<String>> {
+        let read_error = |e: surrealdb::Error| {
+            EvenframeError::database(format!(
+                "Failed to read record ids from '{table_name}': {e}"
+            ))
+        };
+        let mut response = self
+            .db
+            .query(format!("SELECT id FROM {};", surql_ident(table_name)))
+            .await
+            .map_err(read_error)?;
+        let rows: Vec<serde_json::Value> = response.take(0).map_err(read_error)?;
+        Ok(rows
+            .iter()
+            .filter_map(|row| row.get("id").and_then(|id| id.as_str()))
+            .map(|id| surql_record_id(&EvenframeRecordId::from(id.to_string()).to_string()))
+            .collect())
+    }
+
     // Getter for new_schema so Schemasync can access it
     pub fn get_new_schema(&self) -> Option<&Surreal<Db>> {
         self.comparator.as_ref()?.get_new_schema()
     }
 
-    pub fn random_string(len: usize) -> String {
+    pub fn random_string(len: usize, rng: &mut StdRng) -> String {
         use rand::distr::Alphanumeric;
-        let mut rng = rand::rng();
         (0..len).map(|_| rng.sample(Alphanumeric) as char).collect()
     }
 
+    /// Small random JSON object literal for `FieldType::Json` fields, mixing
+    /// scalar, array and nested object values.
+    pub fn random_json(rng: &mut StdRng) -> String {
+        let entries: Vec<String> = (0..rng.random_range(1..=3))
+            .map(|i| {
+                let value = match rng.random_range(0..5) {
+                    0 => format!("'{}'", Self::random_string(6, rng)),
+                    1 => format!("{}", rng.random_range(0..1000)),
+                    2 => format!("{}", rng.random_bool(0.5)),
+                    3 => format!(
+                        "['{}', '{}']",
+                        Self::random_string(4, rng),
+                        Self::random_string(4, rng)
+                    ),
+                    _ => format!("{{ value: {} }}", rng.random_range(0..100)),
+                };
+                format!("key_{}: {}", i, value)
+            })
+            .collect();
+        format!("{{ {} }}", entries.join(", "))
+    }
+
+    /// Random hex bytes literal (`b"..."`) for `FieldType::Bytes` fields.
+    pub fn random_bytes(rng: &mut StdRng) -> String {
+        let hex: String = (0..rng.random_range(4..=32))
+            .map(|_| format!("{:02X}", rng.random::<u8>()))
+            .collect();
+        format!("b\"{}\"", hex)
+    }
+
+    /// GeoJSON literal of the given kind with random WGS84 positions for
+    /// `FieldType::Geometry` fields.
+    pub fn random_geometry(kind: GeometryKind, rng: &mut StdRng) -> String {
+        kind.geojson(&mut || {
+            let lon: f64 = rng.random_range(-180.0..180.0);
+            let lat: f64 = rng.random_range(-90.0..90.0);
+            ((lon * 1e6).round() / 1e6, (lat * 1e6).round() / 1e6)
+        })
+    }
+
     /// Builds coordination groups from the provided table configs
     pub fn build_coordination_groups(&mut self) -> Vec<CoordinationGroup> {
         let mut coordination_groups = Vec::new();
@@ -476,6 +913,9 @@ impl<'a> Mockmaker<'a> {
                             all_fields
                         }
                         Coordination::OneToOne(field_name) => vec![field_name.clone()],
+                        Coordination::InitializeWeightedChoice { field_name, .. } => {
+                            vec![field_name.clone()]
+                        }
                         Coordination::InitializeCoherent(dataset) => match dataset {
                             CoherentDataset::Address {
                                 city,
@@ -554,6 +994,7 @@ impl<'a> Mockmaker<'a> {
                     Coordination::InitializeSum { .. } => "sum",
                     Coordination::InitializeDerive { .. } => "derive",
                     Coordination::OneToOne(_) => "one_to_one",
+                    Coordination::InitializeWeightedChoice { .. } => "weighted_choice",
                     Coordination::InitializeCoherent(_) => "coherent",
                 };
 
@@ -594,6 +1035,9 @@ impl<'a> Mockmaker<'a> {
                             all_fields
                         }
                         Coordination::OneToOne(field_name) => vec![field_name.clone()],
+                        Coordination::InitializeWeightedChoice { field_name, .. } => {
+                            vec![field_name.clone()]
+                        }
                         Coordination::InitializeCoherent(dataset) => match dataset {
                             CoherentDataset::Address {
                                 city,
@@ -662,6 +1106,9 @@ impl<'a> Mockmaker<'a> {
                                     all
                                 }
                                 Coordination::OneToOne(f) => vec![f.clone()],
+                                Coordination::InitializeWeightedChoice {
+                                    field_name: f, ..
+                                } => vec![f.clone()],
                                 Coordination::InitializeCoherent(d) => match d {
                                     CoherentDataset::Address {
                                         city,
@@ -763,6 +1210,154 @@ impl<'a> Mockmaker<'a> {
     }
 }
 
+/// Random generator for one stream of mock data (`stream` is usually a
+/// table name): derived from `seed` and `stream` when seeded, so each stream
+/// is reproducible on its own, otherwise seeded from the OS.
+#[cfg(feature = "surrealdb")]
+pub(crate) fn seeded_rng(seed: Option<u64>, stream: &str) -> StdRng {
+    match seed {
+        Some(seed) => {
+            StdRng::from_seed(*blake3::hash(format!("{seed}:{stream}").as_bytes()).as_bytes())
+        }
+        None => rand::make_rng(),
+    }
+}
+
+/// Instant time-based mock values are generated relative to: now, or a
+/// fixed instant when `seed` is set so seeded runs stay reproducible.
+#[cfg(feature = "surrealdb")]
+pub(crate) fn seeded_reference_time(seed: Option<u64>) -> chrono::DateTime<chrono::Utc> {
+    match seed {
+        Some(_) => chrono::DateTime::from_timestamp(1_704_067_200, 0)
+            .expect("2024-01-01T00:00:00Z is a valid timestamp"),
+        None => chrono::Utc::now(),
+    }
+}
+
+/// Builds the id of the `index`th (1-based) new mock record of `table_name`.
+/// UUID keys are bracketed since their hyphens are not valid in a bare key.
+/// The random bits of UUIDs and ULIDs come from `rng` and a ULID's
+/// timestamp is `reference_time` (see [`seeded_reference_time`]).
+#[cfg(feature = "surrealdb")]
+pub(crate) fn new_record_id(
+    strategy: crate::schemasync::config::IdStrategy,
+    table_name: &str,
+    index: usize,
+    rng: &mut StdRng,
+    reference_time: chrono::DateTime<chrono::Utc>,
+) -> String {
+    use crate::schemasync::config::IdStrategy;
+
+    let table_name = surql_ident(table_name);
+    match strategy {
+        IdStrategy::Sequential => format!("{table_name}:{index}"),
+        IdStrategy::Uuid => format!(
+            "{table_name}:⟨{}⟩",
+            uuid::Builder::from_random_bytes(rng.random()).into_uuid()
+        ),
+        IdStrategy::Ulid => format!(
+            "{table_name}:{}",
+            ulid::Ulid::from_parts(reference_time.timestamp_millis() as u64, rng.random())
+        ),
+    }
+}
+
+/// Tops `ids` up to `desired_count` with new record IDs. Sequential IDs
+/// scan upward from 1 past any already taken, so gaps left by deleted records
+/// are filled instead of colliding with the records after them.
+#[cfg(feature = "surrealdb")]
+pub(crate) fn allocate_record_ids(
+    ids: &mut Vec<String>,
+    desired_count: usize,
+    strategy: crate::schemasync::config::IdStrategy,
+    table_name: &str,
+    rng: &mut StdRng,
+    reference_time: chrono::DateTime<chrono::Utc>,
+) {
+    let mut taken: std::collections::HashSet<String> = ids.iter().cloned().collect();
+    let mut next_index = 1;
+    while ids.len() < desired_count {
+        let id = new_record_id(strategy, table_name, next_index, rng, reference_time);
+        next_index += 1;
+        if taken.insert(id.clone()) {
+            ids.push(id);
+        }
+    }
+}
+
+/// The value of a generated SurrealQL literal as validators see it, for the
+/// string and number literals the generators emit; `None` for anything else,
+/// including `NONE` options.
+#[cfg(feature = "surrealdb")]
+fn literal_mock_value<'l>(
+    field_type: &crate::types::FieldType,
+    literal: &'l str,
+) -> Option<crate::validator::MockValue<'l>> {
+    use crate::types::FieldType;
+    use crate::validator::MockValue;
+
+    match field_type {
+        FieldType::Option(inner) if literal != "NONE" => literal_mock_value(inner, literal),
+        FieldType::String | FieldType::Char => literal
+            .strip_prefix('\'')
+            .and_then(|s| s.strip_suffix('\''))
+            .map(MockValue::Str),
+        FieldType::F32 | FieldType::F64 => literal
+            .trim_end_matches('f')
+            .parse()
+            .ok()
+            .map(MockValue::Num),
+        FieldType::I8
+        | FieldType::I16
+        | FieldType::I32
+        | FieldType::I64
+        | FieldType::Isize
+        | FieldType::U8
+        | FieldType::U16
+        | FieldType::U32
+        | FieldType::U64
+        | FieldType::Usize => literal.parse().ok().map(MockValue::Num),
+        _ => None,
+    }
+}
+
+/// Executes one batch of mock data statements, tagging failures with the
+/// table, the 1-based batch number and the first rejected statement.
+#[cfg(feature = "surrealdb")]
+async fn insert_mock_batch<C>(
+    db: &Surreal<C>,
+    table_name: &str,
+    batch_number: usize,
+    batch: &str,
+) -> Result<(), EvenframeError>
+where
+    C: surrealdb::Connection,
+{
+    use crate::schemasync::database::surql::execute::{
+        QueryValidationErrors, execute_and_validate,
+    };
+
+    execute_and_validate(db, batch, "UPSERT", table_name)
+        .await
+        .map(|_| ())
+        .map_err(|e| {
+            // Errors raised before validation (e.g. a parse error in the
+            // batch) are not tied to a single statement
+            let failed = e
+                .downcast_ref::<QueryValidationErrors>()
+                .and_then(|validation| validation.errors.first());
+            match failed {
+                Some(failed) => EvenframeError::mock_insertion(
+                    table_name,
+                    batch_number,
+                    failed.statement.as_deref().unwrap_or(batch),
+                    failed,
+                ),
+                None => EvenframeError::mock_insertion(table_name, batch_number, batch, e),
+            }
+        })
+}
+
 // Import for MockGenerationConfig (always available, but avoid duplicates with surrealdb imports)
 #[cfg(not(feature = "surrealdb"))]
 use crate::schemasync::PreservationMode;
@@ -780,11 +1375,17 @@ pub struct MockGenerationConfig {
     pub table_level_override: Option<std::collections::HashMap<StructField, Format>>,
     pub coordination_rules: Vec<crate::schemasync::mockmake::coordinate::Coordination>,
     pub batch_size: usize,
+    /// Field names to regenerate on preserved records; `*`, `prefix_*` and
+    /// `*_suffix` globs are accepted.
     pub regenerate_fields: Vec<String>,
     pub preservation_mode: PreservationMode,
     /// Name of the WASM plugin to use for table-level mock generation.
     #[serde(default)]
     pub plugin: Option<String>,
+    /// Seed for this table's mock data; wins over
+    /// `SchemasyncMockGenConfig::seed`.
+    #[serde(default)]
+    pub seed: Option<u64>,
 }
 
 impl Default for MockGenerationConfig {
@@ -810,6 +1411,7 @@ impl Default for MockGenerationConfig {
             regenerate_fields: vec![],
             preservation_mode,
             plugin: None,
+            seed: None,
         }
     }
 }
@@ -839,6 +1441,9 @@ impl quote::ToTokens for MockGenerationConfig {
             PreservationMode::Full => {
                 quote::quote! { ::evenframe::schemasync::PreservationMode::Full }
             }
+            PreservationMode::Merge => {
+                quote::quote! { ::evenframe::schemasync::PreservationMode::Merge }
+            }
             PreservationMode::None => {
                 quote::quote! { ::evenframe::schemasync::PreservationMode::None }
             }
@@ -849,6 +1454,10 @@ impl quote::ToTokens for MockGenerationConfig {
             Some(name) => quote::quote! { Some(#name.to_string()) },
             None => quote::quote! { None },
         };
+        let seed_tokens = match self.seed {
+            Some(seed) => quote::quote! { Some(#seed) },
+            None => quote::quote! { None },
+        };
 
         let config_tokens = quote::quote! {
             MockGenerationConfig {
@@ -859,9 +1468,1188 @@ impl quote::ToTokens for MockGenerationConfig {
                 regenerate_fields: vec![#(#regenerate_fields.to_string()),*],
                 preservation_mode: #preservation_mode_tokens,
                 plugin: #plugin_tokens,
+                seed: #seed_tokens,
             }
         };
 
         tokens.extend(config_tokens);
     }
 }
+
+/// Fixtures shared by the `Mockmaker` test modules below.
+#[cfg(all(test, feature = "schemasync"))]
+mod test_support {
+    use super::*;
+    use crate::schemasync::config::{
+        DatabaseConfig, PerformanceConfig, SchemasyncConfig, SchemasyncMockGenConfig,
+    };
+    use crate::types::FieldType;
+
+    pub(super) fn config(dry_run: bool) -> SchemasyncConfig {
+        SchemasyncConfig {
+            database: DatabaseConfig::default(),
+            should_generate_mocks: true,
+            mock_gen_config: SchemasyncMockGenConfig {
+                default_record_count: 2,
+                default_preservation_mode: PreservationMode::None,
+                default_batch_size: 2,
+                coordination_groups: vec![],
+                full_refresh_mode: true,
+                confirm_destructive: false,
+                max_recursion_depth: crate::schemasync::config::default_max_recursion_depth(),
+                id_strategy: crate::schemasync::config::IdStrategy::default(),
+                regenerate_modified_fields: false,
+                option_null_probability: crate::schemasync::config::default_option_null_probability(
+                ),
+                validate_generated_data: true,
+                recreate_unpreserved_tables: false,
+                seed: None,
+            },
+            performance: PerformanceConfig::default(),
+            plugins: BTreeMap::new(),
+            dry_run,
+            fail_on_breaking: false,
+            skip_schema_definition: false,
+            table_naming: Default::default(),
+            connect_retries: crate::schemasync::config::default_connect_retries(),
+            connect_retry_delay_ms: crate::schemasync::config::default_connect_retry_delay_ms(),
+        }
+    }
+
+    pub(super) fn tables() -> BTreeMap<String, TableConfig> {
+        let table = TableConfig {
+            table_name: "post".to_string(),
+            struct_config: StructConfig {
+                struct_name: "Post".to_string(),
+                fields: vec![StructField {
+                    field_name: "title".to_string(),
+                    field_type: FieldType::String,
+                    ..StructField::default()
+                }],
+                ..StructConfig::default()
+            },
+            ..Default::default()
+        };
+        BTreeMap::from([("post".to_string(), table)])
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod export_mock_data_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::types::ForeignTypeRegistry;
+
+    #[test]
+    fn export_mock_data_writes_upsert_statements() {
+        let client = Surreal::<Client>::init();
+        let config = config(false);
+        let tables = tables();
+        let objects = BTreeMap::new();
+        let enums = BTreeMap::new();
+        let registry = ForeignTypeRegistry::default();
+        let mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mock.surql");
+        mockmaker
+            .export_mock_data(path.to_str().unwrap())
+            .expect("export should succeed");
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let upserts = contents
+            .lines()
+            .filter(|line| line.starts_with("UPSERT post:"))
+            .count();
+        assert_eq!(
+            upserts, 2,
+            "expected one UPSERT per record; got:\n{contents}"
+        );
+    }
+
+    #[test]
+    fn export_mock_data_errors_on_unwritable_path() {
+        let client = Surreal::<Client>::init();
+        let config = config(false);
+        let tables = BTreeMap::new();
+        let objects = BTreeMap::new();
+        let enums = BTreeMap::new();
+        let registry = ForeignTypeRegistry::default();
+        let mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        let result = mockmaker.export_mock_data("/nonexistent-evenframe-dir/mock.surql");
+        assert!(matches!(result, Err(EvenframeError::Export(_))));
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod seed_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::types::{FieldType, ForeignTypeRegistry};
+
+    #[tokio::test]
+    async fn seeded_generation_is_reproducible() {
+        let client = Surreal::<Client>::init();
+        let mut tables = tables();
+        tables.get_mut("post").unwrap().struct_config.fields = [
+            ("title", FieldType::String, None),
+            ("views", FieldType::U32, None),
+            ("tags", FieldType::Vec(Box::new(FieldType::String)), None),
+            ("author_email", FieldType::String, Some(Format::Email)),
+            ("due", FieldType::String, Some(Format::DateWithinDays(30))),
+        ]
+        .into_iter()
+        .map(|(name, field_type, format)| StructField {
+            field_name: name.to_string(),
+            field_type,
+            format,
+            define_config: Some(crate::schemasync::DefineConfig::default()),
+            ..StructField::default()
+        })
+        .collect();
+        let objects = BTreeMap::new();
+        let enums = BTreeMap::new();
+        let registry = ForeignTypeRegistry::default();
+        // ULID ids and date formats are time-based, so they must come from
+        // the seeded reference time rather than the clock
+        let generate = async |seed: Option<u64>| {
+            let mut config = config(false);
+            config.mock_gen_config.seed = seed;
+            config.mock_gen_config.id_strategy = crate::schemasync::config::IdStrategy::Ulid;
+            let mut mockmaker =
+                Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+            mockmaker.generate_ids().await.unwrap();
+            mockmaker.generate_all_statements().unwrap()
+        };
+
+        let first = generate(Some(7)).await;
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        assert_eq!(first, generate(Some(7)).await);
+        assert!(first.contains("2024-01-"), "{first}");
+        assert_ne!(first, generate(Some(8)).await);
+        assert_ne!(generate(None).await, generate(None).await);
+    }
+
+    #[test]
+    fn seeded_record_ids_are_reproducible() {
+        use crate::schemasync::config::IdStrategy;
+
+        for strategy in [IdStrategy::Uuid, IdStrategy::Ulid] {
+            let id = |seed| {
+                new_record_id(
+                    strategy,
+                    "post",
+                    1,
+                    &mut seeded_rng(seed, "post"),
+                    seeded_reference_time(seed),
+                )
+            };
+            assert_eq!(id(Some(7)), id(Some(7)));
+            assert_ne!(id(Some(7)), id(Some(8)));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod dry_run_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::types::ForeignTypeRegistry;
+
+    #[test]
+    fn record_dry_run_is_noop_outside_dry_run() {
+        let client = Surreal::<Client>::init();
+        let config = config(false);
+        let tables = tables();
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        assert!(!mockmaker.record_dry_run("DELETE post;"));
+        assert!(mockmaker.take_dry_run_statements().is_empty());
+    }
+
+    // The client is never connected, so any statement that reached the
+    // database would fail these tests.
+    #[tokio::test]
+    async fn dry_run_collects_statements_instead_of_executing() {
+        let client = Surreal::<Client>::init();
+        let config = config(true);
+        let tables = tables();
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mut mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        mockmaker.generate_ids().await.unwrap();
+        mockmaker.remove_old_data().await.unwrap();
+        mockmaker.filtered_tables = tables.clone();
+        mockmaker.generate_mock_data().await.unwrap();
+
+        let statements = mockmaker.take_dry_run_statements();
+        assert_eq!(statements.len(), 2, "got: {statements:#?}");
+        assert_eq!(statements[0].trim(), "DELETE post;");
+        assert_eq!(
+            statements[1]
+                .lines()
+                .filter(|line| line.starts_with("UPSERT post:"))
+                .count(),
+            2
+        );
+        assert!(mockmaker.take_dry_run_statements().is_empty());
+    }
+
+    #[tokio::test]
+    async fn mocks_only_runs_no_schema_statements() {
+        let client = Surreal::<Client>::init();
+        let mut config = config(true);
+        config.skip_schema_definition = true;
+        let tables = tables();
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mut mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        // No comparator has run, so filtering must not need schema changes
+        mockmaker.generate_mocks_only().await.unwrap();
+
+        let statements = mockmaker.take_dry_run_statements();
+        assert!(
+            statements
+                .iter()
+                .flat_map(|s| s.lines())
+                .all(|line| !line.trim_start().starts_with("DEFINE")
+                    && !line.trim_start().starts_with("REMOVE")),
+            "got: {statements:#?}"
+        );
+        assert_eq!(
+            statements
+                .iter()
+                .flat_map(|s| s.lines())
+                .filter(|line| line.starts_with("UPSERT post:"))
+                .count(),
+            2
+        );
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod table_statement_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::types::{FieldType, ForeignTypeRegistry};
+
+    #[test]
+    fn unpreserved_tables_are_deleted_then_created() {
+        let client = Surreal::<Client>::init();
+        let mut config = config(false);
+        config.mock_gen_config.recreate_unpreserved_tables = true;
+        let mut tables = tables();
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+
+        let mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+        let stmts = mockmaker
+            .generate_table_statements("post", &tables["post"])
+            .unwrap();
+        let lines: Vec<&str> = stmts.lines().collect();
+        assert_eq!(lines.len(), 3, "got: {stmts}");
+        assert_eq!(lines[0], "DELETE post;");
+        assert!(
+            lines[1..]
+                .iter()
+                .all(|line| line.starts_with("CREATE post:")),
+            "got: {stmts}"
+        );
+
+        // A table that preserves its data keeps using UPSERT
+        tables.get_mut("post").unwrap().mock_generation_config = Some(MockGenerationConfig {
+            n: 2,
+            table_level_override: None,
+            coordination_rules: vec![],
+            batch_size: 2,
+            regenerate_fields: vec![],
+            preservation_mode: PreservationMode::Smart,
+            plugin: None,
+            seed: None,
+        });
+        let mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+        let stmts = mockmaker
+            .generate_table_statements("post", &tables["post"])
+            .unwrap();
+        assert!(!stmts.contains("DELETE"), "got: {stmts}");
+        assert_eq!(
+            stmts
+                .lines()
+                .filter(|l| l.starts_with("UPSERT post:"))
+                .count(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn value_defined_fields_are_left_to_the_database() {
+        let client = Surreal::<Client>::init();
+        let config = config(true);
+        let mut tables = tables();
+        let post = tables.get_mut("post").unwrap();
+        post.struct_config.fields = [("title", None), ("slug", Some("string::lowercase(title)"))]
+            .into_iter()
+            .map(|(name, value)| StructField {
+                field_name: name.to_string(),
+                field_type: FieldType::String,
+                define_config: Some(crate::schemasync::DefineConfig {
+                    value: value.map(str::to_string),
+                    ..Default::default()
+                }),
+                ..StructField::default()
+            })
+            .collect();
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mut mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        mockmaker.generate_ids().await.unwrap();
+        mockmaker.filtered_tables = tables.clone();
+        mockmaker.generate_mock_data().await.unwrap();
+
+        let statements = mockmaker.take_dry_run_statements().join("\n");
+        let upserts: Vec<&str> = statements
+            .lines()
+            .filter(|line| line.starts_with("UPSERT post:"))
+            .collect();
+        assert_eq!(upserts.len(), 2, "got: {statements}");
+        for upsert in upserts {
+            assert!(upsert.contains("title"), "missing title: {upsert}");
+            assert!(!upsert.contains("slug"), "slug should be omitted: {upsert}");
+        }
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod record_id_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::types::{FieldType, ForeignTypeRegistry};
+
+    #[test]
+    fn allocated_ids_skip_existing_sequential_ids() {
+        use crate::schemasync::config::IdStrategy;
+
+        let mut rng = rand::make_rng();
+        // Existing records with a gap left by a deleted `post:2`
+        let mut ids = vec!["post:1".to_string(), "post:3".to_string()];
+        let now = chrono::Utc::now();
+        allocate_record_ids(&mut ids, 5, IdStrategy::Sequential, "post", &mut rng, now);
+        assert_eq!(ids, ["post:1", "post:3", "post:2", "post:4", "post:5"]);
+
+        let mut ids = vec!["post:1".to_string(), "post:3".to_string()];
+        allocate_record_ids(&mut ids, 2, IdStrategy::Sequential, "post", &mut rng, now);
+        assert_eq!(ids, ["post:1", "post:3"]);
+    }
+
+    #[test]
+    fn new_record_id_follows_strategy() {
+        use crate::schemasync::config::IdStrategy;
+
+        let mut rng = rand::make_rng();
+        let now = chrono::Utc::now();
+        assert_eq!(
+            new_record_id(IdStrategy::Sequential, "post", 3, &mut rng, now),
+            "post:3"
+        );
+
+        let id = new_record_id(IdStrategy::Uuid, "post", 3, &mut rng, now);
+        let key = id
+            .strip_prefix("post:⟨")
+            .and_then(|rest| rest.strip_suffix('⟩'))
+            .unwrap_or_else(|| panic!("unexpected uuid id: {id}"));
+        assert!(uuid::Uuid::parse_str(key).is_ok(), "invalid uuid: {id}");
+
+        let id = new_record_id(IdStrategy::Ulid, "post", 3, &mut rng, now);
+        let key = id.strip_prefix("post:").unwrap();
+        let ulid = ulid::Ulid::from_string(key).unwrap_or_else(|_| panic!("invalid ulid: {id}"));
+        assert_eq!(ulid.timestamp_ms(), now.timestamp_millis() as u64);
+        assert_ne!(
+            id,
+            new_record_id(IdStrategy::Ulid, "post", 3, &mut rng, now)
+        );
+    }
+
+    // Ids must be accepted by SurrealDB both as a statement target and inside
+    // the `r'...'` literal used for record links.
+    #[tokio::test]
+    async fn generated_ids_are_valid_record_ids() {
+        use crate::schemasync::config::IdStrategy;
+
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        let mut rng = rand::make_rng();
+        for strategy in [IdStrategy::Sequential, IdStrategy::Uuid, IdStrategy::Ulid] {
+            let id = new_record_id(strategy, "post", 1, &mut rng, chrono::Utc::now());
+            let response = db
+                .query(format!("UPSERT {id} CONTENT {{ link: r'{id}' }};"))
+                .await
+                .unwrap();
+            if let Err(e) = response.check() {
+                panic!("{strategy:?} id `{id}` was rejected: {e}");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn full_refresh_ids_use_configured_strategy() {
+        let client = Surreal::<Client>::init();
+        let mut config = config(true);
+        config.mock_gen_config.id_strategy = crate::schemasync::config::IdStrategy::Ulid;
+        let tables = tables();
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mut mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        mockmaker.generate_ids().await.unwrap();
+
+        let ids = &mockmaker.id_map["post"];
+        assert_eq!(ids.len(), 2);
+        for id in ids {
+            let key = id.strip_prefix("post:").unwrap();
+            assert!(ulid::Ulid::from_string(key).is_ok(), "not a ulid: {id}");
+        }
+    }
+
+    // Kebab-case table names are escaped everywhere they reach SurrealQL:
+    // the schema, record ids and record links.
+    #[tokio::test]
+    async fn kebab_case_tables_are_defined_and_populated() {
+        use crate::schemasync::database::surql::define::generate_define_statements;
+        use crate::schemasync::{DefineConfig, TableCase};
+
+        let mut config = config(false);
+        config.table_naming = TableCase::Kebab;
+        let table = |struct_name: &str, field: StructField| {
+            let table_name = TableCase::Kebab.apply(struct_name);
+            let config = TableConfig {
+                table_name: table_name.clone(),
+                struct_config: StructConfig {
+                    struct_name: struct_name.to_string(),
+                    fields: vec![StructField {
+                        define_config: Some(DefineConfig::default()),
+                        ..field
+                    }],
+                    ..StructConfig::default()
+                },
+                ..Default::default()
+            };
+            (table_name, config)
+        };
+        let tables = BTreeMap::from([
+            table(
+                "UserProfile",
+                StructField {
+                    field_name: "name".to_string(),
+                    field_type: FieldType::String,
+                    ..StructField::default()
+                },
+            ),
+            table(
+                "BlogPost",
+                StructField {
+                    field_name: "author".to_string(),
+                    field_type: FieldType::RecordLink(Box::new(FieldType::Other(
+                        "UserProfile".to_string(),
+                    ))),
+                    ..StructField::default()
+                },
+            ),
+        ]);
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+
+        let mut schema = String::new();
+        for (name, table) in &tables {
+            schema.push_str(&generate_define_statements(
+                name, table, &tables, &objects, &enums, false, &registry,
+            ));
+        }
+        assert!(
+            schema.contains("author ON TABLE `blog-post` TYPE record<`user-profile`>"),
+            "{schema}"
+        );
+
+        let client = Surreal::<Client>::init();
+        let mut mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+        mockmaker.generate_ids().await.unwrap();
+        let statements = mockmaker.generate_all_statements().unwrap();
+
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        for query in [&schema, &statements] {
+            if let Err(e) = db.query(query.as_str()).await.unwrap().check() {
+                panic!("rejected: {e}\n{query}");
+            }
+        }
+        let mut response = db
+            .query("SELECT VALUE author.name FROM `blog-post`")
+            .await
+            .unwrap();
+        let names: Vec<Option<String>> = response.take(0).unwrap();
+        assert_eq!(names.len(), 2);
+        assert!(names.iter().all(Option::is_some), "{names:?}");
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod batch_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::types::ForeignTypeRegistry;
+
+    #[tokio::test]
+    async fn failed_batch_reports_table_and_statement() {
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        db.query("DEFINE TABLE post SCHEMAFULL; DEFINE FIELD title ON post TYPE string;")
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+
+        let batch =
+            "UPSERT post:1 CONTENT { title: 'ok' };\nUPSERT post:2 CONTENT { title: 42 };\n";
+        let err = insert_mock_batch(&db, "post", 2, batch).await.unwrap_err();
+
+        let EvenframeError::MockInsertion {
+            table,
+            batch,
+            snippet,
+            ..
+        } = &err
+        else {
+            panic!("expected MockInsertion, got {err:?}");
+        };
+        assert_eq!(table, "post");
+        assert_eq!(*batch, 2);
+        assert_eq!(snippet, "UPSERT post:2 CONTENT { title: 42 }");
+        let message = err.to_string();
+        assert!(message.contains("table `post`"), "got: {message}");
+        assert!(message.contains("Statement 1:"), "got: {message}");
+    }
+
+    #[tokio::test]
+    async fn mock_data_is_executed_in_batches() {
+        let client = Surreal::<Client>::init();
+        let config = config(true);
+        let mut tables = tables();
+        let post = tables.get_mut("post").unwrap();
+        post.struct_config.fields[0].define_config =
+            Some(crate::schemasync::DefineConfig::default());
+        post.mock_generation_config = Some(MockGenerationConfig {
+            n: 5,
+            table_level_override: None,
+            coordination_rules: vec![],
+            batch_size: 2,
+            regenerate_fields: vec![],
+            preservation_mode: PreservationMode::None,
+            plugin: None,
+            seed: Some(7),
+        });
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mut mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        mockmaker.generate_ids().await.unwrap();
+        mockmaker.filtered_tables = tables.clone();
+        mockmaker.generate_mock_data().await.unwrap();
+
+        let batches = mockmaker.take_dry_run_statements();
+        let sizes: Vec<usize> = batches.iter().map(|batch| batch.lines().count()).collect();
+        assert_eq!(sizes, vec![2, 2, 1], "got: {batches:#?}");
+        assert!(batches[0].starts_with("UPSERT post:1 "));
+        assert!(batches[2].starts_with("UPSERT post:5 "));
+
+        // Generating batch by batch continues the table's random sequence
+        let whole = mockmaker
+            .generate_table_statements("post", &tables["post"])
+            .unwrap();
+        assert_eq!(batches.concat(), whole);
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod generate_for_table_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::types::{FieldType, ForeignTypeRegistry};
+
+    #[tokio::test]
+    async fn generate_for_table_returns_requested_records() {
+        let client = Surreal::<Client>::init();
+        let config = config(false);
+        let mut tables = tables();
+        let post = tables.get_mut("post").unwrap();
+        post.struct_config.fields = ["title", "body"]
+            .into_iter()
+            .map(|name| StructField {
+                field_name: name.to_string(),
+                field_type: FieldType::String,
+                define_config: Some(crate::schemasync::DefineConfig::default()),
+                ..StructField::default()
+            })
+            .collect();
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        let records = mockmaker.generate_for_table("post", 5).await.unwrap();
+
+        assert_eq!(records.len(), 5);
+        for record in &records {
+            assert!(record.get("id").is_some(), "missing id: {record}");
+            assert!(record["title"].is_string(), "missing title: {record}");
+            assert!(record["body"].is_string(), "missing body: {record}");
+        }
+    }
+
+    #[tokio::test]
+    async fn generate_for_table_rejects_unknown_table() {
+        let client = Surreal::<Client>::init();
+        let config = config(false);
+        let tables = tables();
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        let err = mockmaker
+            .generate_for_table("comment", 1)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Unknown table 'comment'"),
+            "got: {err}"
+        );
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod literal_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::types::{FieldType, ForeignTypeRegistry};
+
+    #[tokio::test]
+    async fn literal_fields_always_generate_their_value() {
+        let client = Surreal::<Client>::init();
+        let config = config(false);
+        let mut tables = tables();
+        let post = tables.get_mut("post").unwrap();
+        post.struct_config.fields = [
+            ("kind", FieldType::Literal(serde_json::json!("user"))),
+            ("version", FieldType::Literal(serde_json::json!(2))),
+            (
+                "tags",
+                FieldType::Vec(Box::new(FieldType::Literal(serde_json::json!("pinned")))),
+            ),
+        ]
+        .into_iter()
+        .map(|(name, field_type)| StructField {
+            field_name: name.to_string(),
+            field_type,
+            define_config: Some(crate::schemasync::DefineConfig::default()),
+            ..StructField::default()
+        })
+        .collect();
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        let records = mockmaker.generate_for_table("post", 10).await.unwrap();
+
+        assert_eq!(records.len(), 10);
+        for record in &records {
+            assert_eq!(record["kind"], "user", "{record}");
+            assert_eq!(record["version"], 2, "{record}");
+            let tags = record["tags"].as_array().expect("tags array");
+            assert!(tags.iter().all(|tag| tag == "pinned"), "{record}");
+        }
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod recursion_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::schemasync::mockmake::field_value_recursive::FieldValueGenerator;
+    use crate::types::{FieldType, ForeignTypeRegistry};
+
+    #[test]
+    fn recursive_objects_stop_at_max_recursion_depth() {
+        let client = Surreal::<Client>::init();
+        let mut config = config(false);
+        config.mock_gen_config.max_recursion_depth = 6;
+        let tables = tables();
+        let children = StructField {
+            field_name: "children".to_string(),
+            field_type: FieldType::Vec(Box::new(FieldType::Other("Node".to_string()))),
+            ..StructField::default()
+        };
+        let objects = BTreeMap::from([(
+            "Node".to_string(),
+            StructConfig {
+                struct_name: "Node".to_string(),
+                fields: vec![children.clone()],
+                ..StructConfig::default()
+            },
+        )]);
+        let enums = BTreeMap::new();
+        let registry = ForeignTypeRegistry::default();
+        let mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+        let coordinated_values = BTreeMap::new();
+
+        let value = FieldValueGenerator::builder()
+            .mockmaker(&mockmaker)
+            .table_config(&tables["post"])
+            .field(&children)
+            .id_index(&0)
+            .coordinated_values(&coordinated_values)
+            .registry(&registry)
+            .build()
+            .run()
+            .unwrap();
+
+        assert!(value.starts_with('['), "got: {value}");
+        assert!(value.contains("null"), "got: {value}");
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod array_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::schemasync::mockmake::field_value_recursive::FieldValueGenerator;
+    use crate::types::{FieldType, ForeignTypeRegistry};
+
+    #[test]
+    fn fixed_arrays_generate_exactly_their_length() {
+        let client = Surreal::<Client>::init();
+        let config = config(false);
+        let tables = tables();
+        let checksum = StructField {
+            field_name: "checksum".to_string(),
+            field_type: FieldType::Array(Box::new(FieldType::U8), 16),
+            ..StructField::default()
+        };
+        let objects = BTreeMap::new();
+        let enums = BTreeMap::new();
+        let registry = ForeignTypeRegistry::default();
+        let mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+        let coordinated_values = BTreeMap::new();
+
+        let value = FieldValueGenerator::builder()
+            .mockmaker(&mockmaker)
+            .table_config(&tables["post"])
+            .field(&checksum)
+            .id_index(&0)
+            .coordinated_values(&coordinated_values)
+            .registry(&registry)
+            .build()
+            .run()
+            .unwrap();
+
+        let elements = value
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split(',')
+            .count();
+        assert_eq!(elements, 16, "got: {value}");
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod option_null_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::types::{FieldType, ForeignTypeRegistry};
+
+    #[test]
+    fn option_null_rate_follows_configured_probability() {
+        let client = Surreal::<Client>::init();
+        let mut config = config(false);
+        config.mock_gen_config.option_null_probability = 0.1;
+        let tables = tables();
+        let nickname = StructField {
+            field_name: "nickname".to_string(),
+            field_type: FieldType::Option(Box::new(FieldType::String)),
+            ..StructField::default()
+        };
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        const SAMPLES: usize = 10_000;
+        let nulls = (0..SAMPLES)
+            .filter(|index| {
+                crate::schemasync::mockmake::field_value::FieldValueGenerator::builder()
+                    .mockmaker(&mockmaker)
+                    .table_config(&tables["post"])
+                    .field(&nickname)
+                    .id_index(index)
+                    .registry(&registry)
+                    .build()
+                    .run()
+                    .unwrap()
+                    == "NONE"
+            })
+            .count();
+
+        // Ten standard deviations around the expected 1000 nulls
+        let rate = nulls as f64 / SAMPLES as f64;
+        assert!((0.07..=0.13).contains(&rate), "null rate {rate}");
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod record_link_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::types::{FieldType, ForeignTypeRegistry};
+
+    #[tokio::test]
+    async fn optional_record_links_are_record_ids_or_none() {
+        let client = Surreal::<Client>::init();
+        let mut config = config(false);
+        config.mock_gen_config.option_null_probability = 0.5;
+        let mut tables = tables();
+        let mut user = tables["post"].clone();
+        user.table_name = "user".to_string();
+        user.struct_config.struct_name = "User".to_string();
+        tables.insert("user".to_string(), user);
+        let reviewer = StructField {
+            field_name: "reviewer".to_string(),
+            field_type: FieldType::Option(Box::new(FieldType::RecordLink(Box::new(
+                FieldType::Other("User".to_string()),
+            )))),
+            ..StructField::default()
+        };
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mut mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+        mockmaker.generate_ids().await.unwrap();
+
+        let values: Vec<String> = (0..200)
+            .map(|index| {
+                crate::schemasync::mockmake::field_value::FieldValueGenerator::builder()
+                    .mockmaker(&mockmaker)
+                    .table_config(&tables["post"])
+                    .field(&reviewer)
+                    .id_index(&index)
+                    .registry(&registry)
+                    .build()
+                    .run()
+                    .unwrap()
+            })
+            .collect();
+
+        let user_ids = &mockmaker.id_map["user"];
+        for value in &values {
+            assert!(
+                value == "NONE" || user_ids.iter().any(|id| *value == format!("r'{id}'")),
+                "unexpected value {value}"
+            );
+        }
+        assert!(values.iter().any(|v| v == "NONE"));
+        assert!(values.iter().any(|v| v != "NONE"));
+    }
+
+    #[tokio::test]
+    async fn record_links_reference_generated_ids() {
+        let client = Surreal::<Client>::init();
+        let config = config(false);
+        let mut tables = tables();
+        let mut user = tables["post"].clone();
+        user.table_name = "user".to_string();
+        user.struct_config.struct_name = "User".to_string();
+        tables.insert("user".to_string(), user);
+        let author = StructField {
+            field_name: "author".to_string(),
+            field_type: FieldType::RecordLink(Box::new(FieldType::Other("User".to_string()))),
+            ..StructField::default()
+        };
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mut mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+        mockmaker.generate_ids().await.unwrap();
+
+        let user_ids = &mockmaker.id_map["user"];
+        assert!(!user_ids.is_empty());
+        for index in 0..50 {
+            let value = crate::schemasync::mockmake::field_value::FieldValueGenerator::builder()
+                .mockmaker(&mockmaker)
+                .table_config(&tables["post"])
+                .field(&author)
+                .id_index(&index)
+                .registry(&registry)
+                .build()
+                .run()
+                .unwrap();
+            assert!(
+                user_ids.iter().any(|id| value == format!("r'{id}'")),
+                "link {value} does not reference a generated user"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn unresolvable_record_link_error_names_the_field() {
+        let client = Surreal::<Client>::init();
+        let config = config(false);
+        let mut tables = tables();
+        let mut user = tables["post"].clone();
+        user.table_name = "user".to_string();
+        user.struct_config.struct_name = "User".to_string();
+        tables.insert("user".to_string(), user);
+        let author = StructField {
+            field_name: "author".to_string(),
+            field_type: FieldType::RecordLink(Box::new(FieldType::Other("User".to_string()))),
+            ..StructField::default()
+        };
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mut mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+        mockmaker.id_map.insert("user".to_string(), Vec::new());
+
+        let err = crate::schemasync::mockmake::field_value::FieldValueGenerator::builder()
+            .mockmaker(&mockmaker)
+            .table_config(&tables["post"])
+            .field(&author)
+            .id_index(&0)
+            .registry(&registry)
+            .build()
+            .run()
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("table post, field author"), "{err}");
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod currency_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::types::{FieldType, ForeignTypeRegistry};
+
+    #[tokio::test]
+    async fn currency_values_are_generated_and_asserted_at_their_scale() {
+        use crate::config::ForeignTypeConfig;
+        use crate::schemasync::mockmake::format::Format;
+        use surrealdb::engine::local::Mem;
+
+        let client = Surreal::<Client>::init();
+        let config = config(true);
+        let mut tables = tables();
+        let price = StructField {
+            field_name: "price".to_string(),
+            field_type: FieldType::Other("Decimal".to_string()),
+            format: Some(Format::Currency(2)),
+            define_config: Some(crate::schemasync::DefineConfig::default()),
+            ..StructField::default()
+        };
+        tables.get_mut("post").unwrap().struct_config.fields = vec![price.clone()];
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::from_config(&BTreeMap::from([(
+            "Decimal".to_string(),
+            ForeignTypeConfig {
+                rust_type_names: vec!["Decimal".to_string()],
+                surrealdb: "decimal".to_string(),
+                mock_strategy: "decimal".to_string(),
+                default_value_surql: "0dec".to_string(),
+                ..Default::default()
+            },
+        )]));
+        let mut mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        mockmaker.generate_ids().await.unwrap();
+        mockmaker.filtered_tables = tables.clone();
+        mockmaker.generate_mock_data().await.unwrap();
+        let statements = mockmaker.take_dry_run_statements().join("\n");
+
+        let prices: Vec<&str> = statements
+            .split("price: ")
+            .skip(1)
+            .map(|rest| rest.split([',', ' ', '}']).next().unwrap())
+            .collect();
+        assert_eq!(prices.len(), 2, "got: {statements}");
+        for price in &prices {
+            let digits = price
+                .strip_suffix("dec")
+                .unwrap_or_else(|| panic!("{price}"));
+            let (_, fraction) = digits.split_once('.').unwrap_or_else(|| panic!("{price}"));
+            assert_eq!(fraction.len(), 2, "got: {price}");
+        }
+
+        let define = price
+            .generate_define_statement(
+                enums.clone(),
+                objects.clone(),
+                tables.clone(),
+                &"post".to_string(),
+                &registry,
+            )
+            .unwrap();
+        assert!(
+            define.contains(
+                " TYPE decimal DEFAULT 0dec ASSERT math::round($value * 100) / 100 = $value"
+            ),
+            "got: {define}"
+        );
+
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        db.query(format!("DEFINE TABLE post SCHEMAFULL; {define}"))
+            .await
+            .unwrap()
+            .check()
+            .unwrap();
+        db.query(statements.as_str())
+            .await
+            .unwrap()
+            .check()
+            .unwrap_or_else(|e| panic!("generated prices were rejected: {e}"));
+        let rejected = db
+            .query("CREATE post SET price = 1.234dec;")
+            .await
+            .unwrap()
+            .check();
+        assert!(rejected.is_err());
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod email_url_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::types::{FieldType, ForeignTypeRegistry};
+
+    #[tokio::test]
+    async fn email_and_url_values_are_generated_and_asserted() {
+        use crate::schemasync::mockmake::format::Format;
+        use surrealdb::engine::local::Mem;
+
+        let client = Surreal::<Client>::init();
+        let config = config(true);
+        let mut tables = tables();
+        let field = |name: &str, format: Format| StructField {
+            field_name: name.to_string(),
+            field_type: FieldType::String,
+            format: Some(format),
+            define_config: Some(crate::schemasync::DefineConfig::default()),
+            ..StructField::default()
+        };
+        let fields = vec![
+            field("contact", Format::Email),
+            field("homepage", Format::Url("example.com".to_string())),
+        ];
+        tables.get_mut("post").unwrap().struct_config.fields = fields.clone();
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mut mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        mockmaker.generate_ids().await.unwrap();
+        mockmaker.filtered_tables = tables.clone();
+        mockmaker.generate_mock_data().await.unwrap();
+        let statements = mockmaker.take_dry_run_statements().join("\n");
+        assert_eq!(
+            statements.matches("contact: ").count(),
+            2,
+            "got: {statements}"
+        );
+        assert_eq!(
+            statements.matches("homepage: ").count(),
+            2,
+            "got: {statements}"
+        );
+
+        let mut defines = String::from("DEFINE TABLE post SCHEMAFULL;");
+        for field in &fields {
+            let define = field
+                .generate_define_statement(
+                    enums.clone(),
+                    objects.clone(),
+                    tables.clone(),
+                    &"post".to_string(),
+                    &registry,
+                )
+                .unwrap();
+            defines.push_str(&define);
+        }
+        assert!(
+            defines.contains("ASSERT string::is_email($value)"),
+            "got: {defines}"
+        );
+        assert!(
+            defines.contains("ASSERT string::is_url($value)"),
+            "got: {defines}"
+        );
+
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        db.query(defines).await.unwrap().check().unwrap();
+        db.query(statements.as_str())
+            .await
+            .unwrap()
+            .check()
+            .unwrap_or_else(|e| panic!("generated emails or urls were rejected: {e}"));
+        let rejected = db
+            .query("CREATE post SET contact = 'nobody', homepage = 'not a url';")
+            .await
+            .unwrap()
+            .check();
+        assert!(rejected.is_err());
+    }
+}
+
+#[cfg(all(test, feature = "schemasync"))]
+mod validator_tests {
+    use super::test_support::{config, tables};
+    use super::*;
+    use crate::types::{FieldType, ForeignTypeRegistry};
+
+    #[tokio::test]
+    async fn generated_values_failing_validators_are_reported() {
+        use crate::schemasync::mockmake::format::Format;
+        use crate::validator::{StringValidator, Validator};
+
+        let client = Surreal::<Client>::init();
+        let mut config = config(true);
+        config.mock_gen_config.validate_generated_data = true;
+        let mut tables = tables();
+        let post = tables.get_mut("post").unwrap();
+        post.struct_config.fields = vec![StructField {
+            field_name: "email".to_string(),
+            field_type: FieldType::String,
+            // A name can never pass as an email address
+            format: Some(Format::FullName),
+            validators: vec![Validator::StringValidator(StringValidator::Email)],
+            define_config: Some(crate::schemasync::DefineConfig::default()),
+            ..StructField::default()
+        }];
+        let (objects, enums) = (BTreeMap::new(), BTreeMap::new());
+        let registry = ForeignTypeRegistry::default();
+        let mut mockmaker = Mockmaker::new(&client, &tables, &objects, &enums, &config, &registry);
+
+        mockmaker.generate_ids().await.unwrap();
+        mockmaker.filtered_tables = tables.clone();
+        let error = mockmaker.generate_mock_data().await.unwrap_err();
+
+        let Some(EvenframeError::MockValidation(violations)) = error.downcast_ref() else {
+            panic!("expected a validation error, got: {error}");
+        };
+        assert_eq!(violations.len(), 2, "got: {violations:#?}");
+        for violation in violations {
+            assert!(violation.starts_with("post.email = '"), "got: {violation}");
+            assert!(
+                violation.ends_with("fails StringValidator(Email)"),
+                "got: {violation}"
+            );
+        }
+        assert!(mockmaker.take_dry_run_statements().is_empty());
+    }
+}
@@ -12,7 +12,7 @@
 //! // Might generate: "abc12"
 //! ```
 
-use rand::RngExt;
+use rand::{RngExt, SeedableRng, rngs::StdRng};
 use std::error::Error;
 use std::fmt;
 use tracing;
@@ -103,16 +103,31 @@ pub enum RegexComponent {
 }
 
 /// A regex pattern generator that creates random strings matching regex patterns
-#[derive(Default)]
 pub struct RegexValGen {
-    rng: rand::rngs::ThreadRng,
+    rng: StdRng,
+}
+
+impl Default for RegexValGen {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl RegexValGen {
     /// Creates a new RegexValGen instance
     pub fn new() -> Self {
         tracing::trace!("Creating new RegexValGen instance");
-        Self { rng: rand::rng() }
+        Self {
+            rng: rand::make_rng(),
+        }
+    }
+
+    /// Creates a RegexValGen whose output is determined by `rng`, so seeded
+    /// callers get reproducible strings
+    pub fn from_rng(rng: &mut StdRng) -> Self {
+        Self {
+            rng: StdRng::from_rng(rng),
+        }
     }
 
     /// Generates a random string matching the given regex pattern
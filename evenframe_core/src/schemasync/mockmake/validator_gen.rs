@@ -17,7 +17,7 @@ use crate::types::FieldType;
 use crate::validator::{
     ArrayValidator, MockValue, NumberValidator, StringValidator, Validator,
 };
-use rand::{RngExt, rngs::ThreadRng};
+use rand::{RngExt, rngs::StdRng};
 
 /// Cap on how many times we'll regenerate a string when it doesn't satisfy
 /// every validator on the first try (e.g. a regex-driven candidate that
@@ -30,7 +30,7 @@ const STRING_GEN_ATTEMPTS: usize = 16;
 pub fn generate_with_validators(
     field_type: &FieldType,
     validators: &[Validator],
-    rng: &mut ThreadRng,
+    rng: &mut StdRng,
 ) -> Option<String> {
     if validators.is_empty() {
         return None;
@@ -57,6 +57,40 @@ pub fn generate_with_validators(
     }
 }
 
+/// JSON counterpart of [`generate_with_validators`] for callers that build
+/// `serde_json::Value` records (e.g. the preservation merger) instead of
+/// SurrealQL literals. Covers the same primitive field types.
+pub fn generate_json_with_validators(
+    field_type: &FieldType,
+    validators: &[Validator],
+    rng: &mut StdRng,
+) -> Option<serde_json::Value> {
+    if validators.is_empty() {
+        return None;
+    }
+    match field_type {
+        FieldType::String => generate_string_value(validators, rng).map(serde_json::Value::from),
+        FieldType::F32 | FieldType::F64 => {
+            generate_float_value(validators, rng).map(serde_json::Value::from)
+        }
+        FieldType::I8
+        | FieldType::I16
+        | FieldType::I32
+        | FieldType::I64
+        | FieldType::I128
+        | FieldType::Isize
+        | FieldType::U8
+        | FieldType::U16
+        | FieldType::U32
+        | FieldType::U64
+        | FieldType::U128
+        | FieldType::Usize => generate_integer_value(field_type, validators, rng)
+            .and_then(|v| i64::try_from(v).ok())
+            .map(serde_json::Value::from),
+        _ => None,
+    }
+}
+
 /// Pick the element-count for a `Vec<_>` field given the field's array
 /// validators. `default_lo`/`default_hi` mirror the existing
 /// `rng.random_range(2..10)` defaults from `field_value.rs`.
@@ -223,16 +257,22 @@ fn collect_string_constraints(validators: &[Validator]) -> StringConstraints {
     c
 }
 
-fn generate_string(validators: &[Validator], rng: &mut ThreadRng) -> Option<String> {
+fn generate_string(validators: &[Validator], rng: &mut StdRng) -> Option<String> {
+    let value = generate_string_value(validators, rng)?;
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('\'');
+    quoted.push_str(&escape_surql_string(&value));
+    quoted.push('\'');
+    Some(quoted)
+}
+
+/// Unquoted string satisfying `validators`.
+fn generate_string_value(validators: &[Validator], rng: &mut StdRng) -> Option<String> {
     let c = collect_string_constraints(validators);
 
     // A literal pin overrides everything.
-    if let Some(ref lit) = c.literal {
-        let mut quoted = String::with_capacity(lit.len() + 2);
-        quoted.push('\'');
-        quoted.push_str(&escape_surql_string(lit));
-        quoted.push('\'');
-        return Some(quoted);
+    if let Some(lit) = c.literal {
+        return Some(lit);
     }
 
     for _ in 0..STRING_GEN_ATTEMPTS {
@@ -243,29 +283,25 @@ fn generate_string(validators: &[Validator], rng: &mut ThreadRng) -> Option<Stri
             .iter()
             .all(|v| v.matches(&MockValue::Str(&candidate)))
         {
-            let mut quoted = String::with_capacity(candidate.len() + 2);
-            quoted.push('\'');
-            quoted.push_str(&escape_surql_string(&candidate));
-            quoted.push('\'');
-            return Some(quoted);
+            return Some(candidate);
         }
     }
     None
 }
 
-fn build_string_candidate(c: &StringConstraints, rng: &mut ThreadRng) -> Option<String> {
+fn build_string_candidate(c: &StringConstraints, rng: &mut StdRng) -> Option<String> {
     let target_len = pick_target_len(c, rng);
 
     // 1. Seed value from the shape/regex/length pipeline.
     let mut s = if let Some(fmt) = &c.regex_format {
-        let mut maker = RegexValGen::new();
+        let mut maker = RegexValGen::from_rng(rng);
         maker
             .generate(fmt.clone().into_regex().as_str())
             .ok()?
     } else if let Some(shape) = c.shape {
         gen_shape(shape, target_len, rng)?
     } else {
-        Mockmaker::random_string(target_len.unwrap_or(8))
+        Mockmaker::random_string(target_len.unwrap_or(8), rng)
     };
 
     // 2. Splice in starts_with / ends_with / includes literals.
@@ -307,7 +343,7 @@ fn build_string_candidate(c: &StringConstraints, rng: &mut ThreadRng) -> Option<
             // by appended ASCII). If the shape is structural (uuid, email,
             // semver), the post-validation `matches` loop will catch the
             // mismatch and trigger another attempt.
-            s.push_str(&Mockmaker::random_string(min - cur));
+            s.push_str(&Mockmaker::random_string(min - cur, rng));
         }
     }
 
@@ -338,7 +374,7 @@ fn build_string_candidate(c: &StringConstraints, rng: &mut ThreadRng) -> Option<
     Some(s)
 }
 
-fn pick_target_len(c: &StringConstraints, rng: &mut ThreadRng) -> Option<usize> {
+fn pick_target_len(c: &StringConstraints, rng: &mut StdRng) -> Option<usize> {
     if let Some(n) = c.exact_len {
         return Some(n);
     }
@@ -367,17 +403,17 @@ fn effective_max_len(c: &StringConstraints) -> Option<usize> {
     }
 }
 
-fn gen_shape(shape: StringShape, target_len: Option<usize>, rng: &mut ThreadRng) -> Option<String> {
+fn gen_shape(shape: StringShape, target_len: Option<usize>, rng: &mut StdRng) -> Option<String> {
     use crate::schemasync::mockmake::format::Format;
-    let format_via = |f: Format| -> Option<String> {
-        let mut maker = RegexValGen::new();
+    let format_via = |f: Format, rng: &mut StdRng| -> Option<String> {
+        let mut maker = RegexValGen::from_rng(rng);
         maker.generate(f.into_regex().as_str()).ok()
     };
     match shape {
-        StringShape::Email => format_via(Format::Email),
-        StringShape::Uuid => format_via(Format::Uuid),
-        StringShape::Url => format_via(Format::Url("example.com".to_string())),
-        StringShape::Ip | StringShape::IpV4 => format_via(Format::IpAddress),
+        StringShape::Email => format_via(Format::Email, rng),
+        StringShape::Uuid => format_via(Format::Uuid, rng),
+        StringShape::Url => format_via(Format::Url("example.com".to_string()), rng),
+        StringShape::Ip | StringShape::IpV4 => format_via(Format::IpAddress, rng),
         StringShape::IpV6 => {
             // No Format::IpV6 today; build one inline.
             let mut parts = Vec::with_capacity(8);
@@ -388,7 +424,7 @@ fn gen_shape(shape: StringShape, target_len: Option<usize>, rng: &mut ThreadRng)
         }
         StringShape::Hex => {
             let len = target_len.unwrap_or(16).max(1);
-            format_via(Format::HexString(len))
+            format_via(Format::HexString(len), rng)
         }
         StringShape::Alpha => Some(random_from_alphabet(target_len.unwrap_or(8), ALPHA, rng)),
         StringShape::Alphanumeric => Some(random_from_alphabet(
@@ -403,15 +439,15 @@ fn gen_shape(shape: StringShape, target_len: Option<usize>, rng: &mut ThreadRng)
             Some(format!("{}.{:03}", int_part, frac))
         }
         StringShape::Integer => Some(format!("{}", rng.random_range(-1_000_000i64..=1_000_000))),
-        StringShape::CreditCard => format_via(Format::CreditCardNumber),
+        StringShape::CreditCard => format_via(Format::CreditCardNumber, rng),
         StringShape::Semver => Some(format!(
             "{}.{}.{}",
             rng.random_range(0u32..=20),
             rng.random_range(0u32..=20),
             rng.random_range(0u32..=99)
         )),
-        StringShape::DateIso => format_via(Format::DateTime),
-        StringShape::DateYmd => format_via(Format::Date),
+        StringShape::DateIso => format_via(Format::DateTime, rng),
+        StringShape::DateYmd => format_via(Format::Date, rng),
         StringShape::DateEpoch => Some(format!("{}", rng.random_range(0i64..=2_000_000_000))),
     }
 }
@@ -420,7 +456,7 @@ const ALPHA: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
 const ALPHANUM: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
 const DIGITS: &str = "0123456789";
 
-fn random_from_alphabet(len: usize, alphabet: &str, rng: &mut ThreadRng) -> String {
+fn random_from_alphabet(len: usize, alphabet: &str, rng: &mut StdRng) -> String {
     let chars: Vec<char> = alphabet.chars().collect();
     (0..len)
         .map(|_| chars[rng.random_range(0..chars.len())])
@@ -525,8 +561,16 @@ fn integer_field_default_range(field_type: &FieldType) -> (f64, f64) {
 fn generate_integer(
     field_type: &FieldType,
     validators: &[Validator],
-    rng: &mut ThreadRng,
+    rng: &mut StdRng,
 ) -> Option<String> {
+    generate_integer_value(field_type, validators, rng).map(|v| format!("{}", v))
+}
+
+fn generate_integer_value(
+    field_type: &FieldType,
+    validators: &[Validator],
+    rng: &mut StdRng,
+) -> Option<i128> {
     let mut r = collect_numeric_range(validators);
     r.require_int = true;
     let (default_lo, default_hi) = integer_field_default_range(field_type);
@@ -545,10 +589,15 @@ fn generate_integer(
     {
         return None;
     }
-    Some(format!("{}", int_value))
+    Some(int_value)
+}
+
+fn generate_float(validators: &[Validator], rng: &mut StdRng) -> Option<String> {
+    generate_float_value(validators, rng).map(|v| format!("{:.2}f", v))
 }
 
-fn generate_float(validators: &[Validator], rng: &mut ThreadRng) -> Option<String> {
+/// Float satisfying `validators`, rounded to the two decimals used in literals.
+fn generate_float_value(validators: &[Validator], rng: &mut StdRng) -> Option<f64> {
     let mut r = collect_numeric_range(validators);
     if r.lo == f64::NEG_INFINITY {
         r.lo = 0.0;
@@ -566,10 +615,10 @@ fn generate_float(validators: &[Validator], rng: &mut ThreadRng) -> Option<Strin
     {
         return None;
     }
-    Some(format!("{:.2}f", rounded))
+    Some(rounded)
 }
 
-fn sample_numeric(r: &NumericRange, rng: &mut ThreadRng) -> Option<f64> {
+fn sample_numeric(r: &NumericRange, rng: &mut StdRng) -> Option<f64> {
     if r.lo > r.hi {
         return None;
     }
@@ -634,7 +683,7 @@ mod tests {
             Validator::StringValidator(StringValidator::MinLength(8)),
             Validator::StringValidator(StringValidator::MaxLength(12)),
         ];
-        let mut rng = rand::rng();
+        let mut rng: StdRng = rand::make_rng();
         for _ in 0..50 {
             let lit = generate_with_validators(&FieldType::String, &validators, &mut rng)
                 .expect("should produce a value");
@@ -655,7 +704,7 @@ mod tests {
     #[test]
     fn string_email_shape_satisfies_email_validator() {
         let validators = vec![Validator::StringValidator(StringValidator::Email)];
-        let mut rng = rand::rng();
+        let mut rng: StdRng = rand::make_rng();
         for _ in 0..50 {
             let lit = generate_with_validators(&FieldType::String, &validators, &mut rng)
                 .expect("should produce an email");
@@ -674,7 +723,7 @@ mod tests {
             Validator::StringValidator(StringValidator::StartsWith("ID-".into())),
             Validator::StringValidator(StringValidator::MinLength(6)),
         ];
-        let mut rng = rand::rng();
+        let mut rng: StdRng = rand::make_rng();
         for _ in 0..50 {
             let lit = generate_with_validators(&FieldType::String, &validators, &mut rng)
                 .expect("should produce a value");
@@ -692,7 +741,7 @@ mod tests {
             Validator::StringValidator(StringValidator::Lowercased),
             Validator::StringValidator(StringValidator::MinLength(4)),
         ];
-        let mut rng = rand::rng();
+        let mut rng: StdRng = rand::make_rng();
         for _ in 0..50 {
             let lit = generate_with_validators(&FieldType::String, &validators, &mut rng)
                 .expect("should produce a value");
@@ -709,7 +758,7 @@ mod tests {
             OrderedFloat(10.0),
             OrderedFloat(20.0),
         ))];
-        let mut rng = rand::rng();
+        let mut rng: StdRng = rand::make_rng();
         for _ in 0..50 {
             let lit = generate_with_validators(&FieldType::I32, &validators, &mut rng)
                 .expect("should produce an integer");
@@ -724,7 +773,7 @@ mod tests {
             Validator::NumberValidator(NumberValidator::Positive),
             Validator::NumberValidator(NumberValidator::MultipleOf(OrderedFloat(5.0))),
         ];
-        let mut rng = rand::rng();
+        let mut rng: StdRng = rand::make_rng();
         for _ in 0..50 {
             let lit = generate_with_validators(&FieldType::I64, &validators, &mut rng)
                 .expect("should produce an integer");
@@ -743,7 +792,7 @@ mod tests {
         let validators = vec![Validator::NumberValidator(
             NumberValidator::GreaterThanOrEqualTo(OrderedFloat(50.0)),
         )];
-        let mut rng = rand::rng();
+        let mut rng: StdRng = rand::make_rng();
         for _ in 0..50 {
             let lit = generate_with_validators(&FieldType::F64, &validators, &mut rng)
                 .expect("should produce a float");
@@ -772,7 +821,7 @@ mod tests {
 
     #[test]
     fn empty_validators_yields_none() {
-        let mut rng = rand::rng();
+        let mut rng: StdRng = rand::make_rng();
         assert!(generate_with_validators(&FieldType::String, &[], &mut rng).is_none());
         assert!(generate_with_validators(&FieldType::I32, &[], &mut rng).is_none());
     }
@@ -780,7 +829,7 @@ mod tests {
     #[test]
     fn unsupported_type_yields_none() {
         let validators = vec![Validator::NumberValidator(NumberValidator::Positive)];
-        let mut rng = rand::rng();
+        let mut rng: StdRng = rand::make_rng();
         // Bool is not handled by validator_gen — caller falls back to default.
         assert!(generate_with_validators(&FieldType::Bool, &validators, &mut rng).is_none());
     }
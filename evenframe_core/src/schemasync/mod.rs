@@ -9,17 +9,22 @@ pub mod database;
 pub mod define_config;
 pub mod edge;
 pub mod event;
+#[cfg(feature = "surrealdb")]
+pub mod lock;
 pub mod mockmake;
+#[cfg(feature = "surrealdb")]
+pub mod overview;
 pub mod permissions;
 pub mod table;
 
 // Re-export commonly used types (always available)
+pub use config::TableCase;
 pub use define_config::DefineConfig;
 pub use edge::{Direction, EdgeConfig, Subquery};
 pub use event::EventConfig;
 pub use mockmake::{coordinate, format};
 pub use permissions::PermissionsConfig;
-pub use table::{IndexConfig, TableConfig};
+pub use table::{IndexConfig, TableConfig, find_table, surql_ident, surql_record_id};
 
 // PreservationMode - always available (used by MockGenerationConfig data type)
 #[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -31,6 +36,9 @@ pub enum PreservationMode {
     Smart,
     /// Full preservation - preserve all existing data, only generate for new fields
     Full,
+    /// Merge preservation - like `Full`, but also generates fields added
+    /// inside existing nested objects
+    Merge,
 }
 
 impl quote::ToTokens for PreservationMode {
@@ -45,6 +53,9 @@ impl quote::ToTokens for PreservationMode {
             PreservationMode::Full => {
                 quote::quote! { ::evenframe::schemasync::PreservationMode::Full }
             }
+            PreservationMode::Merge => {
+                quote::quote! { ::evenframe::schemasync::PreservationMode::Merge }
+            }
         };
         tokens.extend(variant_tokens);
     }
@@ -57,7 +68,8 @@ use crate::{
     error::{EvenframeError, Result},
     schemasync::compare::SchemaChanges,
     schemasync::database::surql::{
-        define::generate_define_statements, execute::execute_and_validate,
+        define::{generate_analyzer_statements, generate_define_statements},
+        execute::execute_and_validate,
     },
 };
 #[cfg(feature = "surrealdb")]
@@ -87,6 +99,12 @@ pub struct Schemasync<'a> {
     objects: Option<&'a BTreeMap<String, StructConfig>>,
     enums: Option<&'a BTreeMap<String, TaggedUnion>>,
     registry: Option<&'a crate::types::ForeignTypeRegistry>,
+    /// Overrides `SchemasyncConfig::dry_run` when set
+    dry_run: Option<bool>,
+    /// Overrides `SchemasyncConfig::skip_schema_definition` when set
+    skip_schema_definition: Option<bool>,
+    /// Only the given tables are synced; other tables in the database are left untouched
+    partial: bool,
 
     // Internal state - initialized automatically
     db: Option<Surreal<Client>>,
@@ -152,6 +170,9 @@ impl<'a> Schemasync<'a> {
             objects: None,
             enums: None,
             registry: None,
+            dry_run: None,
+            skip_schema_definition: None,
+            partial: false,
             db: None,
             schemasync_config: None,
             owned_registry: None,
@@ -186,6 +207,43 @@ impl<'a> Schemasync<'a> {
         self
     }
 
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        debug!("Configuring Schemasync with dry_run: {}", dry_run);
+        self.dry_run = Some(dry_run);
+        self
+    }
+
+    pub fn with_skip_schema_definition(mut self, skip: bool) -> Self {
+        debug!(
+            "Configuring Schemasync with skip_schema_definition: {}",
+            skip
+        );
+        self.skip_schema_definition = Some(skip);
+        self
+    }
+
+    /// Sync only the tables passed to `with_tables`. Tables that exist in the
+    /// database but not in that set are excluded from the comparison, so they
+    /// are never reported as removed or dropped.
+    pub fn with_partial_sync(mut self, partial: bool) -> Self {
+        debug!("Configuring Schemasync with partial sync: {}", partial);
+        self.partial = partial;
+        self
+    }
+
+    /// Restricts the comparator to the configured tables for partial syncs
+    fn scope_comparator(
+        &self,
+        mockmaker: &mut Mockmaker<'_>,
+        tables: &BTreeMap<String, TableConfig>,
+    ) {
+        if self.partial
+            && let Some(ref mut comparator) = mockmaker.comparator
+        {
+            comparator.restrict_to_tables(tables.keys().cloned().collect());
+        }
+    }
+
     /// Initialize database connection and config from environment
     async fn initialize(&mut self) -> Result<()> {
         info!("Initializing Schemasync database connection and configuration");
@@ -198,33 +256,50 @@ impl<'a> Schemasync<'a> {
         );
         trace!("Database name: {}", config.schemasync.database.database);
 
-        let db = Surreal::new::<Http>(&config.schemasync.database.url)
-            .await
-            .map_err(|e| {
-                EvenframeError::database(format!(
-                    "There was a problem creating the HTTP surrealdb client: {e}"
-                ))
-            })
-            .unwrap();
-        debug!("Created SurrealDB connection");
-
         let username = std::env::var("SURREALDB_USER")
             .map_err(|_| EvenframeError::EnvVarNotSet("SURREALDB_USER".to_string()))?;
         let password = std::env::var("SURREALDB_PASSWORD")
             .map_err(|_| EvenframeError::EnvVarNotSet("SURREALDB_PASSWORD".to_string()))?;
         debug!("Retrieved database credentials from environment");
 
-        db.signin(Root { username, password }).await.map_err(|e| {
-            EvenframeError::database(format!("There was a problem signing in as root: {e}"))
-        })?;
-        debug!("Successfully signed in to SurrealDB");
-
-        db.use_ns(&config.schemasync.database.namespace)
-            .use_db(&config.schemasync.database.database)
-            .await
-            .map_err(|e| {
-                EvenframeError::database(format!("There was a problem using to the namespace: {e}"))
-            })?;
+        let database = &config.schemasync.database;
+        let db = crate::schemasync::database::surql::retry_with_backoff(
+            "SurrealDB connection",
+            config.schemasync.connect_retries,
+            config.schemasync.connect_retry_delay_ms,
+            || async {
+                let db = Surreal::new::<Http>(&database.url).await.map_err(|e| {
+                    EvenframeError::database(format!(
+                        "There was a problem creating the HTTP surrealdb client: {e}"
+                    ))
+                })?;
+                debug!("Created SurrealDB connection");
+
+                db.signin(Root {
+                    username: username.clone(),
+                    password: password.clone(),
+                })
+                .await
+                .map_err(|e| {
+                    crate::schemasync::database::surql::signin_error(
+                        "There was a problem signing in as root",
+                        e,
+                    )
+                })?;
+                debug!("Successfully signed in to SurrealDB");
+
+                db.use_ns(&database.namespace)
+                    .use_db(&database.database)
+                    .await
+                    .map_err(|e| {
+                        EvenframeError::database(format!(
+                            "There was a problem using to the namespace: {e}"
+                        ))
+                    })?;
+                Ok(db)
+            },
+        )
+        .await?;
         info!(
             "Connected to database namespace '{}' and database '{}'",
             config.schemasync.database.namespace, config.schemasync.database.database
@@ -238,7 +313,14 @@ impl<'a> Schemasync<'a> {
             debug!("Built ForeignTypeRegistry from EvenframeConfig foreign_types");
             self.owned_registry = Some(registry);
         }
-        self.schemasync_config = Some(config.schemasync);
+        let mut schemasync_config = config.schemasync;
+        if let Some(dry_run) = self.dry_run {
+            schemasync_config.dry_run = dry_run;
+        }
+        if let Some(skip) = self.skip_schema_definition {
+            schemasync_config.skip_schema_definition = skip;
+        }
+        self.schemasync_config = Some(schemasync_config);
         debug!("Schemasync initialization completed successfully");
 
         Ok(())
@@ -319,9 +401,10 @@ impl<'a> Schemasync<'a> {
             );
         }
 
-        let define_statements_string = define_statements
-            .values()
-            .map(|s| s.as_str())
+        let analyzer_statements = generate_analyzer_statements(tables);
+        let define_statements_string = std::iter::once(analyzer_statements.as_str())
+            .filter(|s| !s.is_empty())
+            .chain(define_statements.values().map(|s| s.as_str()))
             .collect::<Vec<_>>()
             .join(" ");
 
@@ -349,6 +432,7 @@ impl<'a> Schemasync<'a> {
         );
 
         let mut mockmaker = Mockmaker::new(&db, tables, objects, enums, &config, registry);
+        self.scope_comparator(&mut mockmaker, tables);
         mockmaker.generate_ids().await?;
 
         if let Some(ref mut comparator) = mockmaker.comparator {
@@ -428,8 +512,12 @@ impl<'a> Schemasync<'a> {
         Ok(())
     }
 
-    /// Run the complete schemasync pipeline
-    pub async fn run(mut self) -> Result<()> {
+    /// Run the complete schemasync pipeline.
+    ///
+    /// In dry-run mode nothing is written to the database; the returned
+    /// statements are the ones that would have been executed, in order.
+    /// Otherwise the returned list is empty.
+    pub async fn run(mut self) -> Result<Vec<String>> {
         info!("Starting Schemasync pipeline execution");
         self.initialize().await?;
 
@@ -460,8 +548,18 @@ impl<'a> Schemasync<'a> {
         // Create Mockmaker instance (which contains Comparator)
         info!("Creating Mockmaker instance for data generation and comparison");
         let mut mockmaker = Mockmaker::new(&db, tables, objects, enums, &config, registry);
+        self.scope_comparator(&mut mockmaker, tables);
         debug!("Mockmaker instance created successfully");
 
+        if config.skip_schema_definition {
+            info!("Skipping schema definition - generating mock data only");
+            mockmaker.generate_mocks_only().await.map_err(|e| {
+                error!("Failed to generate mock data: {}", e);
+                e
+            })?;
+            return Ok(mockmaker.take_dry_run_statements());
+        }
+
         // Run initial ID generation and comparator setup
         info!("Generating IDs for mock data");
         mockmaker.generate_ids().await?;
@@ -474,6 +572,19 @@ impl<'a> Schemasync<'a> {
         }
         debug!("Schema comparison completed");
 
+        if config.fail_on_breaking
+            && let Some(changes) = mockmaker
+                .comparator
+                .as_ref()
+                .and_then(|c| c.get_schema_changes())
+        {
+            let breaking = changes.breaking_changes();
+            if !breaking.is_empty() {
+                error!(count = breaking.len(), "Breaking schema changes detected");
+                return Err(EvenframeError::BreakingChanges(breaking));
+            }
+        }
+
         // Continue with the rest of the mockmaker pipeline
         info!("Removing old data from database");
         mockmaker.remove_old_data().await.map_err(|e| {
@@ -504,6 +615,7 @@ impl<'a> Schemasync<'a> {
         info!("Defining database tables and schema");
         self.define_tables(
             &db,
+            &mockmaker,
             define_statements,
             schema_changes,
             config.mock_gen_config.full_refresh_mode,
@@ -516,7 +628,9 @@ impl<'a> Schemasync<'a> {
         debug!("Table definitions completed successfully");
 
         info!("Executing function definitions");
-        self.execute_functions(&db, &config).await.map_err(|e| {
+        self.execute_functions(&db, &mockmaker, &config)
+            .await
+            .map_err(|e| {
             error!("Failed to execute functions: {}", e);
             e
         })?;
@@ -539,14 +653,23 @@ impl<'a> Schemasync<'a> {
 
         debug!("Mock data generation completed");
 
-        info!("Schemasync pipeline execution completed successfully");
-        Ok(())
+        let dry_run_statements = mockmaker.take_dry_run_statements();
+        if config.dry_run {
+            info!(
+                "Dry run completed - {} statement blocks collected, nothing executed",
+                dry_run_statements.len()
+            );
+        } else {
+            info!("Schemasync pipeline execution completed successfully");
+        }
+        Ok(dry_run_statements)
     }
 
     /// Define tables in both schemas (this stays in Schemasync)
     async fn define_tables(
         &self,
         db: &Surreal<Client>,
+        mockmaker: &Mockmaker<'_>,
         define_statments: BTreeMap<&String, String>,
         schema_changes: &SchemaChanges,
         full_refresh_mode: bool,
@@ -559,6 +682,9 @@ impl<'a> Schemasync<'a> {
 
         // Validates individual TABLE/FIELD statements (safe to split by ';')
         let execute = async |name, stmt: &str| -> Result<()> {
+            if mockmaker.record_dry_run(stmt) {
+                return Ok(());
+            }
             let define_result = execute_and_validate(db, stmt, "define", name).await;
             match define_result {
                 Ok(_) => {
@@ -586,6 +712,9 @@ impl<'a> Schemasync<'a> {
         // expected results. Send event blocks directly via db.query() instead.
         let execute_events = async |table_name: &str, event_block: &str| -> Result<()> {
             debug!("Executing event definitions for table: {}", table_name);
+            if mockmaker.record_dry_run(event_block) {
+                return Ok(());
+            }
             db.query(event_block).await.map_err(|e| {
                 let error_msg = format!(
                     "Failed to execute event definitions for table {}:\n{}\n{}",
@@ -606,6 +735,13 @@ impl<'a> Schemasync<'a> {
             Ok(())
         };
 
+        // Analyzers must exist before the search indexes that use them
+        for stmt in generate_analyzer_statements(mockmaker.tables).split_inclusive(';') {
+            if stmt.trim_start().starts_with("DEFINE ANALYZER") {
+                execute("analyzer", stmt).await?;
+            }
+        }
+
         // In full refresh mode, define ALL tables regardless of schema changes
         if full_refresh_mode {
             info!(
@@ -753,34 +889,7 @@ impl<'a> Schemasync<'a> {
             }
         }
 
-        // Process new accesses if any
-        if !schema_changes.new_accesses.is_empty() {
-            info!(
-                "Defining {} new accesses",
-                schema_changes.new_accesses.len()
-            );
-            // Access definitions would be handled separately if needed
-        }
-
-        // Process modified accesses that need recreation
-        if !schema_changes.modified_accesses.is_empty() {
-            for access_change in &schema_changes.modified_accesses {
-                // Check if all changes are ignorable
-                let only_ignorable_changes = access_change
-                    .changes
-                    .iter()
-                    .all(|change| change.is_ignorable());
-
-                if !only_ignorable_changes {
-                    debug!(
-                        "Access {} has non-ignorable changes, needs recreation",
-                        access_change.access_name
-                    );
-                    // Access recreation would be handled here if needed
-                }
-            }
-        }
-
+        // New and modified accesses are applied by `Mockmaker::execute_access`
         Ok(())
     }
 
@@ -788,6 +897,7 @@ impl<'a> Schemasync<'a> {
     async fn execute_functions(
         &self,
         db: &Surreal<Client>,
+        mockmaker: &Mockmaker<'_>,
         config: &crate::schemasync::config::SchemasyncConfig,
     ) -> Result<()> {
         if let Some(ref functions_surql) = config.database.resolved.functions_surql
@@ -795,6 +905,9 @@ impl<'a> Schemasync<'a> {
         {
             info!("Executing function definitions from surql");
             evenframe_log!(functions_surql, "function_definitions.surql");
+            if mockmaker.record_dry_run(functions_surql) {
+                return Ok(());
+            }
 
             let result = execute_and_validate(db, functions_surql, "define", "functions").await;
             match result {
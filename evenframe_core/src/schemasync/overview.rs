@@ -0,0 +1,214 @@
+//! Offline overview of the tables schemasync would define.
+//!
+//! Resolves every field to the SurrealDB type its `DEFINE FIELD` statement
+//! would declare, without connecting to a database.
+
+use crate::{
+    schemasync::table::TableConfig,
+    types::{ForeignTypeRegistry, StructConfig, TaggedUnion},
+};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tracing::warn;
+
+/// A table and the SurrealDB types of its fields.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TableOverview {
+    pub name: String,
+    pub relation: Option<RelationOverview>,
+    pub fields: Vec<FieldOverview>,
+}
+
+/// The tables a relation table connects.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RelationOverview {
+    pub from: Vec<String>,
+    pub to: Vec<String>,
+}
+
+/// A field's output name and resolved SurrealDB type.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldOverview {
+    pub name: String,
+    pub surql_type: String,
+    /// Edge fields are traversed through a relation table rather than stored.
+    pub edge: bool,
+}
+
+/// Builds the overview of every table. Fields whose type cannot be resolved
+/// are reported as `any`, the type their define statement falls back to.
+pub fn schema_overview(
+    tables: &BTreeMap<String, TableConfig>,
+    objects: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &ForeignTypeRegistry,
+) -> Vec<TableOverview> {
+    tables
+        .iter()
+        .map(|(table_name, table)| {
+            let table = table.effective();
+            let fields = table
+                .struct_config
+                .fields
+                .iter()
+                .filter(|field| !matches!(field.field_name.as_str(), "id" | "in" | "out"))
+                .map(|field| {
+                    let surql_type = field
+                        .surql_type(enums, objects, tables, table_name, registry)
+                        .unwrap_or_else(|e| {
+                            warn!(
+                                table = %table_name,
+                                field = %field.field_name,
+                                error = %e,
+                                "Could not resolve field type"
+                            );
+                            "any".to_string()
+                        });
+                    FieldOverview {
+                        name: field.output_name().to_string(),
+                        surql_type,
+                        edge: field.edge_config.is_some(),
+                    }
+                })
+                .collect();
+            TableOverview {
+                name: table_name.clone(),
+                relation: table.relation.as_ref().map(|relation| RelationOverview {
+                    from: relation.from.clone(),
+                    to: relation.to.clone(),
+                }),
+                fields,
+            }
+        })
+        .collect()
+}
+
+/// Renders the overview as an indented tree, one table per block.
+pub fn render_overview(overview: &[TableOverview]) -> String {
+    let mut output = String::new();
+    for table in overview {
+        output.push_str(&table.name);
+        if let Some(relation) = &table.relation {
+            output.push_str(&format!(
+                " (relation {} -> {})",
+                relation.from.join(" | "),
+                relation.to.join(" | ")
+            ));
+        }
+        output.push('\n');
+        for (index, field) in table.fields.iter().enumerate() {
+            let branch = if index + 1 == table.fields.len() {
+                "└──"
+            } else {
+                "├──"
+            };
+            let edge = if field.edge { " (edge)" } else { "" };
+            output.push_str(&format!(
+                "  {branch} {}: {}{edge}\n",
+                field.name, field.surql_type
+            ));
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemasync::{Direction, EdgeConfig};
+    use crate::types::{FieldType, StructField};
+
+    fn tables() -> BTreeMap<String, TableConfig> {
+        let table = |name: &str, struct_name: &str, fields: Vec<StructField>| TableConfig {
+            table_name: name.to_string(),
+            struct_config: StructConfig {
+                struct_name: struct_name.to_string(),
+                fields,
+                ..StructConfig::default()
+            },
+            ..Default::default()
+        };
+        let field = |name: &str, field_type: FieldType| StructField {
+            field_name: name.to_string(),
+            field_type,
+            ..StructField::default()
+        };
+
+        BTreeMap::from([
+            (
+                "post".to_string(),
+                table(
+                    "post",
+                    "Post",
+                    vec![
+                        field("id", FieldType::String),
+                        field("title", FieldType::String),
+                        field(
+                            "author",
+                            FieldType::RecordLink(Box::new(FieldType::Other("User".to_string()))),
+                        ),
+                        field("views", FieldType::Option(Box::new(FieldType::U32))),
+                        StructField {
+                            edge_config: Some(EdgeConfig {
+                                edge_name: "likes".to_string(),
+                                from: vec!["user".to_string()],
+                                to: vec!["post".to_string()],
+                                direction: Some(Direction::To),
+                            }),
+                            ..field(
+                                "liked_by",
+                                FieldType::Vec(Box::new(FieldType::RecordLink(Box::new(
+                                    FieldType::Other("User".to_string()),
+                                )))),
+                            )
+                        },
+                    ],
+                ),
+            ),
+            (
+                "user".to_string(),
+                table("user", "User", vec![field("name", FieldType::String)]),
+            ),
+        ])
+    }
+
+    #[test]
+    fn overview_lists_fields_with_surrealdb_types() {
+        let overview = schema_overview(
+            &tables(),
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+            &ForeignTypeRegistry::default(),
+        );
+
+        let post = overview.iter().find(|t| t.name == "post").unwrap();
+        let types: Vec<(&str, &str, bool)> = post
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.surql_type.as_str(), f.edge))
+            .collect();
+        assert_eq!(
+            types,
+            [
+                ("title", "string", false),
+                ("author", "record<user>", false),
+                ("views", "option<int>", false),
+                ("liked_by", "array<record<user>>", true),
+            ]
+        );
+
+        let rendered = render_overview(&overview);
+        assert!(
+            rendered.contains("post\n  ├── title: string\n"),
+            "{rendered}"
+        );
+        assert!(
+            rendered.contains("  └── liked_by: array<record<user>> (edge)\n"),
+            "{rendered}"
+        );
+        assert!(
+            rendered.contains("user\n  └── name: string\n"),
+            "{rendered}"
+        );
+    }
+}
@@ -3,6 +3,9 @@ use quote::{ToTokens, quote};
 use syn::parenthesized;
 use tracing::{debug, info, trace, warn};
 
+/// Condition used by the presets for operations open to any signed-in user
+const AUTHENTICATED: &str = "WHERE $auth.id != NONE";
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct PermissionsConfig {
     pub all_permissions: Option<String>,
@@ -53,6 +56,43 @@ impl ToTokens for PermissionsConfig {
 }
 
 impl PermissionsConfig {
+    /// Anyone may read; only signed-in users may write.
+    pub fn public_read() -> Self {
+        let authenticated = Some(AUTHENTICATED.to_string());
+        Self {
+            all_permissions: None,
+            select_permissions: Some("FULL".to_string()),
+            update_permissions: authenticated.clone(),
+            delete_permissions: authenticated.clone(),
+            create_permissions: authenticated,
+        }
+    }
+
+    /// Every operation requires a signed-in user.
+    pub fn authenticated_only() -> Self {
+        let authenticated = Some(AUTHENTICATED.to_string());
+        Self {
+            all_permissions: None,
+            select_permissions: authenticated.clone(),
+            update_permissions: authenticated.clone(),
+            delete_permissions: authenticated.clone(),
+            create_permissions: authenticated,
+        }
+    }
+
+    /// Every operation is limited to records whose `owner_field` links to
+    /// the signed-in user, so users can only create records they own.
+    pub fn owner_based(owner_field: &str) -> Self {
+        let owned = Some(format!("WHERE {owner_field} = $auth.id"));
+        Self {
+            all_permissions: None,
+            select_permissions: owned.clone(),
+            update_permissions: owned.clone(),
+            delete_permissions: owned.clone(),
+            create_permissions: owned,
+        }
+    }
+
     pub fn parse(attrs: &[syn::Attribute]) -> syn::Result<Option<PermissionsConfig>> {
         debug!(
             "Parsing permissions configuration from {} attributes",
@@ -63,6 +103,7 @@ impl PermissionsConfig {
         let mut update_permissions: Option<String> = None;
         let mut delete_permissions: Option<String> = None;
         let mut create_permissions: Option<String> = None;
+        let mut preset: Option<PermissionsConfig> = None;
 
         for (i, attr) in attrs.iter().enumerate() {
             trace!("Processing attribute {} of {}", i + 1, attrs.len());
@@ -135,17 +176,46 @@ impl PermissionsConfig {
                         return Ok(());
                     }
 
+                    let is_preset = ["public_read", "authenticated_only", "owner_based"]
+                        .iter()
+                        .any(|name| meta.path.is_ident(name));
+                    if is_preset {
+                        if preset.is_some() {
+                            return Err(meta.error("only one permissions preset may be given"));
+                        }
+                        preset = Some(if meta.path.is_ident("owner_based") {
+                            let content;
+                            parenthesized!(content in meta.input);
+                            let owner_field = content.parse::<syn::LitStr>()?.value();
+                            trace!("Parsed owner_based preset on field {}", owner_field);
+                            PermissionsConfig::owner_based(&owner_field)
+                        } else if meta.path.is_ident("public_read") {
+                            PermissionsConfig::public_read()
+                        } else {
+                            PermissionsConfig::authenticated_only()
+                        });
+                        return Ok(());
+                    }
+
                     let path = meta.path.to_token_stream().to_string();
                     warn!("Unrecognized permission type: {}", path);
                     Err(meta.error("unrecognized permission type"))
                 })?;
 
+                // Operations given explicitly override the preset
+                let preset = preset.clone().unwrap_or(PermissionsConfig {
+                    all_permissions: None,
+                    select_permissions: None,
+                    update_permissions: None,
+                    delete_permissions: None,
+                    create_permissions: None,
+                });
                 let permissions_config = PermissionsConfig {
                     all_permissions: all_permissions.clone(),
-                    select_permissions: select_permissions.clone(),
-                    update_permissions: update_permissions.clone(),
-                    delete_permissions: delete_permissions.clone(),
-                    create_permissions: create_permissions.clone(),
+                    select_permissions: select_permissions.clone().or(preset.select_permissions),
+                    update_permissions: update_permissions.clone().or(preset.update_permissions),
+                    delete_permissions: delete_permissions.clone().or(preset.delete_permissions),
+                    create_permissions: create_permissions.clone().or(preset.create_permissions),
                 };
 
                 info!("Successfully parsed permissions configuration");
@@ -166,3 +236,34 @@ impl PermissionsConfig {
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_expands_presets_and_keeps_explicit_operations() {
+        let attrs: Vec<syn::Attribute> = vec![syn::parse_quote! {
+            #[permissions(owner_based("author"), select("FULL"))]
+        }];
+        let config = PermissionsConfig::parse(&attrs).unwrap().unwrap();
+        assert_eq!(config.select_permissions.as_deref(), Some("FULL"));
+        assert_eq!(
+            config.update_permissions.as_deref(),
+            Some("WHERE author = $auth.id")
+        );
+
+        let attrs: Vec<syn::Attribute> = vec![syn::parse_quote! {
+            #[permissions(public_read)]
+        }];
+        assert_eq!(
+            PermissionsConfig::parse(&attrs).unwrap(),
+            Some(PermissionsConfig::public_read())
+        );
+
+        let attrs: Vec<syn::Attribute> = vec![syn::parse_quote! {
+            #[permissions(public_read, authenticated_only)]
+        }];
+        assert!(PermissionsConfig::parse(&attrs).is_err());
+    }
+}
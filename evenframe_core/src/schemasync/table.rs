@@ -1,8 +1,52 @@
 use crate::schemasync::mockmake::MockGenerationConfig;
 use crate::schemasync::{edge::EdgeConfig, event::EventConfig, permissions::PermissionsConfig};
 use crate::types::StructConfig;
+use convert_case::{Case, Casing};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// Change feed retention of tables without `#[evenframe(changefeed = "…")]`.
+pub const DEFAULT_CHANGEFEED: &str = "3d";
+
+/// Parses a SurrealQL duration such as `3d` or `1h30m` into nanoseconds, so
+/// durations SurrealDB prints in a different unit still compare equal.
+pub fn duration_nanos(duration: &str) -> Option<u128> {
+    const UNITS: &[(&str, u128)] = &[
+        ("ns", 1),
+        ("us", 1_000),
+        ("µs", 1_000),
+        ("ms", 1_000_000),
+        ("s", 1_000_000_000),
+        ("m", 60 * 1_000_000_000),
+        ("h", 60 * 60 * 1_000_000_000),
+        ("d", 24 * 60 * 60 * 1_000_000_000),
+        ("w", 7 * 24 * 60 * 60 * 1_000_000_000),
+        ("y", 365 * 24 * 60 * 60 * 1_000_000_000),
+    ];
+
+    let mut rest = duration.trim();
+    if rest.is_empty() {
+        return None;
+    }
+    let mut total = 0u128;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let amount: u128 = rest[..digits].parse().ok()?;
+        rest = &rest[digits..];
+        // Longest unit first so `ms` is not read as `m`
+        let (unit, nanos) = UNITS
+            .iter()
+            .filter(|(unit, _)| rest.starts_with(unit))
+            .max_by_key(|(unit, _)| unit.len())?;
+        total = total.checked_add(amount.checked_mul(*nanos)?)?;
+        rest = &rest[unit.len()..];
+    }
+    Some(total)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct TableConfig {
     pub table_name: String,
     pub struct_config: StructConfig,
@@ -15,6 +59,14 @@ pub struct TableConfig {
     pub indexes: Vec<IndexConfig>,
     #[serde(default)]
     pub output_override: Option<Box<TableConfig>>,
+    /// Set by `#[evenframe(no_overwrite)]`: fields are defined with
+    /// `IF NOT EXISTS` so manual changes made in the database are kept.
+    #[serde(default)]
+    pub no_overwrite: bool,
+    /// Set by `#[evenframe(changefeed = "…")]`: how long the table's change
+    /// feed keeps changes. Tables without it use [`DEFAULT_CHANGEFEED`].
+    #[serde(default)]
+    pub changefeed: Option<String>,
 }
 
 impl TableConfig {
@@ -26,6 +78,71 @@ impl TableConfig {
             .as_deref()
             .map_or(self, Self::effective)
     }
+
+    /// Retention of the table's `CHANGEFEED` clause.
+    pub fn changefeed(&self) -> &str {
+        self.changefeed.as_deref().unwrap_or(DEFAULT_CHANGEFEED)
+    }
+
+    /// The clause following `DEFINE FIELD` for this table's fields.
+    pub fn define_field_mode(&self) -> &'static str {
+        if self.no_overwrite {
+            "IF NOT EXISTS"
+        } else {
+            "OVERWRITE"
+        }
+    }
+}
+
+/// Finds the table `name` refers to, whether it is a table name or the name
+/// of the table's struct. Table keys already carry the configured
+/// [`TableCase`](crate::schemasync::TableCase), so no casing is applied here;
+/// names that differ from a key or struct name only in casing still match.
+pub fn find_table<'a>(
+    tables: &'a BTreeMap<String, TableConfig>,
+    name: &str,
+) -> Option<(&'a String, &'a TableConfig)> {
+    if let Some(entry) = tables.get_key_value(name) {
+        return Some(entry);
+    }
+    if let Some(entry) = tables
+        .iter()
+        .find(|(_, table)| table.struct_config.struct_name == name)
+    {
+        return Some(entry);
+    }
+    let wanted = name.to_case(Case::Snake);
+    tables.iter().find(|(key, table)| {
+        key.to_case(Case::Snake) == wanted
+            || table.struct_config.struct_name.to_case(Case::Snake) == wanted
+    })
+}
+
+/// Writes a table or index name as a SurrealQL identifier. Plain identifiers
+/// pass through unchanged; anything else, such as the names
+/// [`TableCase::Kebab`](crate::schemasync::TableCase::Kebab) produces, is
+/// escaped in backticks.
+pub fn surql_ident(name: &str) -> Cow<'_, str> {
+    let plain = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain || name.starts_with('`') {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(format!("`{}`", name.replace('`', "\\`")))
+    }
+}
+
+/// Writes the record id `table:key`, escaping the table with [`surql_ident`].
+/// Ids read back from the database (which drop the escaping) pass through
+/// again safely.
+pub fn surql_record_id(id: &str) -> String {
+    match id.split_once(':') {
+        Some((table, key)) => format!("{}:{}", surql_ident(table), key),
+        None => id.to_string(),
+    }
 }
 
 /// A struct-level composite (or single-column) index declared via
@@ -35,3 +152,49 @@ pub struct IndexConfig {
     pub fields: Vec<String>,
     pub unique: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_nanos_normalizes_units() {
+        assert_eq!(duration_nanos("3d"), duration_nanos("72h"));
+        assert_eq!(duration_nanos("1h30m"), duration_nanos("90m"));
+        assert_eq!(duration_nanos("1ms"), Some(1_000_000));
+        assert_eq!(duration_nanos("3"), None);
+        assert_eq!(duration_nanos("3 days"), None);
+        assert_eq!(duration_nanos(""), None);
+    }
+
+    #[test]
+    fn find_table_matches_table_and_struct_names() {
+        let table = |name: &str| TableConfig {
+            table_name: name.to_string(),
+            struct_config: StructConfig {
+                struct_name: "UserProfile".to_string(),
+                ..StructConfig::default()
+            },
+            ..Default::default()
+        };
+        for name in ["user_profile", "UserProfile"] {
+            let tables = BTreeMap::from([(name.to_string(), table(name))]);
+            for lookup in ["user_profile", "UserProfile", "userProfile"] {
+                let (key, _) = find_table(&tables, lookup).expect(lookup);
+                assert_eq!(key, name);
+            }
+            assert!(find_table(&tables, "Profile").is_none());
+        }
+    }
+
+    #[test]
+    fn surql_ident_escapes_only_non_plain_names() {
+        assert_eq!(surql_ident("user_profile"), "user_profile");
+        assert_eq!(surql_ident("UserProfile"), "UserProfile");
+        assert_eq!(surql_ident("user-profile"), "`user-profile`");
+        assert_eq!(surql_ident("`user-profile`"), "`user-profile`");
+        assert_eq!(surql_record_id("user-profile:1"), "`user-profile`:1");
+        assert_eq!(surql_record_id("`user-profile`:1"), "`user-profile`:1");
+        assert_eq!(surql_record_id("user:⟨a-b⟩"), "user:⟨a-b⟩");
+    }
+}
@@ -1,7 +1,8 @@
 //! Build-time configuration for type generation.
 
-use crate::config::ForeignTypeConfig;
+use crate::config::{EvenframeConfig, ForeignTypeConfig, PROFILE_ENV_VAR};
 use crate::error::EvenframeError;
+use crate::schemasync::config::TableCase;
 use crate::typesync::config::{
     ArrayStyle, CollisionStrategy, FileNamingConvention, OutputConfig, OutputMode,
 };
@@ -34,12 +35,18 @@ pub struct BuildConfig {
     /// Generate Macroforge types.
     pub macroforge: bool,
 
+    /// Generate Zod schemas.
+    pub zod: bool,
+
     /// Generate FlatBuffers schema.
     pub flatbuffers: bool,
 
     /// Generate Protocol Buffers schema.
     pub protobuf: bool,
 
+    /// Generate a JSON Schema document.
+    pub json_schema: bool,
+
     /// FlatBuffers namespace (e.g., "com.example.app").
     pub flatbuffers_namespace: Option<String>,
 
@@ -64,6 +71,9 @@ pub struct BuildConfig {
     /// Synthetic-item WASM plugin configurations. These plugins add new
     /// structs/enums/tables derived from the scanner results.
     pub synthetic_item_plugins: BTreeMap<String, crate::config::SyntheticItemPluginConfig>,
+
+    /// Casing used to derive table names from struct names.
+    pub table_naming: TableCase,
 }
 
 impl Default for BuildConfig {
@@ -76,8 +86,10 @@ impl Default for BuildConfig {
             arktype: true,
             effect: false,
             macroforge: false,
+            zod: false,
             flatbuffers: false,
             protobuf: false,
+            json_schema: false,
             flatbuffers_namespace: None,
             protobuf_package: None,
             protobuf_import_validate: false,
@@ -86,6 +98,7 @@ impl Default for BuildConfig {
             foreign_types: BTreeMap::new(),
             output_rule_plugins: BTreeMap::new(),
             synthetic_item_plugins: BTreeMap::new(),
+            table_naming: TableCase::default(),
         }
     }
 }
@@ -100,6 +113,8 @@ impl BuildConfig {
     ///
     /// Searches for evenframe.toml starting from `CARGO_MANIFEST_DIR` (if set)
     /// or the current directory, walking upward to the filesystem root.
+    /// When `EVENFRAME_PROFILE` is set, the matching `[profiles.<name>]`
+    /// table is merged over the base config, as for `EvenframeConfig::new`.
     ///
     /// # Errors
     ///
@@ -117,8 +132,11 @@ impl BuildConfig {
     pub fn from_toml_path(path: impl AsRef<Path>) -> Result<Self, EvenframeError> {
         let path = path.as_ref();
         let content = fs::read_to_string(path)?;
+        let profile = env::var(PROFILE_ENV_VAR)
+            .ok()
+            .filter(|profile| !profile.is_empty());
 
-        Self::parse_toml(&content, path)
+        Self::parse_toml(&content, path, profile.as_deref())
     }
 
     /// Searches for `.evenframe/config.toml` (preferred) or `evenframe.toml` (fallback)
@@ -147,10 +165,17 @@ impl BuildConfig {
         }
     }
 
-    /// Parses TOML content into BuildConfig.
-    fn parse_toml(content: &str, path: &Path) -> Result<Self, EvenframeError> {
-        let value: toml::Value =
+    /// Parses TOML content into BuildConfig, applying the `[profiles.<profile>]`
+    /// overrides when a profile is given.
+    fn parse_toml(
+        content: &str,
+        path: &Path,
+        profile: Option<&str>,
+    ) -> Result<Self, EvenframeError> {
+        let mut table: toml::Table =
             toml::from_str(content).map_err(|e| EvenframeError::config_error(e.to_string()))?;
+        EvenframeConfig::apply_profile(&mut table, profile)?;
+        let value = toml::Value::Table(table);
 
         let mut config = Self::default();
 
@@ -196,6 +221,10 @@ impl BuildConfig {
                 config.macroforge = v.as_bool().unwrap_or(false);
             }
 
+            if let Some(v) = typesync.get("should_generate_zod_types") {
+                config.zod = v.as_bool().unwrap_or(false);
+            }
+
             if let Some(v) = typesync.get("should_generate_flatbuffers_types") {
                 config.flatbuffers = v.as_bool().unwrap_or(false);
             }
@@ -204,6 +233,10 @@ impl BuildConfig {
                 config.protobuf = v.as_bool().unwrap_or(false);
             }
 
+            if let Some(v) = typesync.get("should_generate_json_schema_types") {
+                config.json_schema = v.as_bool().unwrap_or(false);
+            }
+
             if let Some(ns) = typesync
                 .get("flatbuffers_namespace")
                 .and_then(|v| v.as_str())
@@ -251,9 +284,24 @@ impl BuildConfig {
                         _ => ArrayStyle::Shorthand,
                     };
                 }
+                if let Some(v) = output_table
+                    .get("branded_record_ids")
+                    .and_then(|v| v.as_bool())
+                {
+                    config.output.branded_record_ids = v;
+                }
             }
         }
 
+        // Parse [schemasync] table_naming
+        if let Some(naming) = value.get("schemasync").and_then(|v| v.get("table_naming")) {
+            config.table_naming = naming.clone().try_into().map_err(|e| {
+                EvenframeError::config_error(format!(
+                    "Failed to parse [schemasync] table_naming: {e}"
+                ))
+            })?;
+        }
+
         // Set scan_path to the project root
         config.scan_path = project_root.to_path_buf();
 
@@ -346,6 +394,18 @@ impl BuildConfigBuilder {
         self
     }
 
+    /// Enables Zod schema generation.
+    pub fn enable_zod(mut self) -> Self {
+        self.config.zod = true;
+        self
+    }
+
+    /// Disables Zod schema generation.
+    pub fn disable_zod(mut self) -> Self {
+        self.config.zod = false;
+        self
+    }
+
     /// Enables FlatBuffers schema generation with optional namespace.
     pub fn enable_flatbuffers(mut self, namespace: Option<String>) -> Self {
         self.config.flatbuffers = true;
@@ -373,6 +433,18 @@ impl BuildConfigBuilder {
         self
     }
 
+    /// Enables JSON Schema generation.
+    pub fn enable_json_schema(mut self) -> Self {
+        self.config.json_schema = true;
+        self
+    }
+
+    /// Disables JSON Schema generation.
+    pub fn disable_json_schema(mut self) -> Self {
+        self.config.json_schema = false;
+        self
+    }
+
     /// Sets the output mode (single file or per-file).
     pub fn output_mode(mut self, mode: OutputMode) -> Self {
         self.config.output.mode = mode;
@@ -397,6 +469,18 @@ impl BuildConfigBuilder {
         self
     }
 
+    /// Enables or disables branded record-id aliases for `RecordLink` fields.
+    pub fn branded_record_ids(mut self, enabled: bool) -> Self {
+        self.config.output.branded_record_ids = enabled;
+        self
+    }
+
+    /// Sets the casing used to derive table names from struct names.
+    pub fn table_naming(mut self, case: TableCase) -> Self {
+        self.config.table_naming = case;
+        self
+    }
+
     /// Sets foreign type configurations.
     pub fn foreign_types(mut self, foreign_types: BTreeMap<String, ForeignTypeConfig>) -> Self {
         self.config.foreign_types = foreign_types;
@@ -408,8 +492,10 @@ impl BuildConfigBuilder {
         self.config.arktype = true;
         self.config.effect = true;
         self.config.macroforge = true;
+        self.config.zod = true;
         self.config.flatbuffers = true;
         self.config.protobuf = true;
+        self.config.json_schema = true;
         self
     }
 
@@ -418,8 +504,10 @@ impl BuildConfigBuilder {
         self.config.arktype = false;
         self.config.effect = false;
         self.config.macroforge = false;
+        self.config.zod = false;
         self.config.flatbuffers = false;
         self.config.protobuf = false;
+        self.config.json_schema = false;
         self
     }
 
@@ -439,8 +527,10 @@ mod tests {
         assert!(config.arktype);
         assert!(!config.effect);
         assert!(!config.macroforge);
+        assert!(!config.zod);
         assert!(!config.flatbuffers);
         assert!(!config.protobuf);
+        assert!(!config.json_schema);
     }
 
     #[test]
@@ -455,8 +545,10 @@ mod tests {
         assert!(config.arktype);
         assert!(config.effect);
         assert!(config.macroforge);
+        assert!(config.zod);
         assert!(config.flatbuffers);
         assert!(config.protobuf);
+        assert!(config.json_schema);
     }
 
     #[test]
@@ -494,11 +586,43 @@ should_generate_arktype_types = true
 should_generate_effect_types = true
 "#;
 
-        let config = BuildConfig::parse_toml(toml_content, Path::new("/test/evenframe.toml"))
+        let config = BuildConfig::parse_toml(toml_content, Path::new("/test/evenframe.toml"), None)
             .expect("Should parse successfully");
 
         assert!(config.arktype);
         assert!(config.effect);
         assert_eq!(config.apply_aliases, vec!["MyMacro".to_string()]);
     }
+
+    #[test]
+    fn test_parse_toml_applies_profile() {
+        let toml_content = r#"
+[typesync]
+should_generate_effect_types = false
+
+[schemasync]
+table_naming = "snake"
+
+[profiles.legacy.typesync]
+should_generate_effect_types = true
+
+[profiles.legacy.schemasync]
+table_naming = "verbatim"
+"#;
+        let path = Path::new("/test/evenframe.toml");
+
+        let base = BuildConfig::parse_toml(toml_content, path, None).unwrap();
+        assert!(!base.effect);
+        assert_eq!(base.table_naming, TableCase::Snake);
+
+        let legacy = BuildConfig::parse_toml(toml_content, path, Some("legacy")).unwrap();
+        assert!(legacy.effect);
+        assert_eq!(legacy.table_naming, TableCase::Verbatim);
+
+        let err = BuildConfig::parse_toml(toml_content, path, Some("prod")).unwrap_err();
+        assert!(
+            err.to_string().contains("Unknown config profile 'prod'"),
+            "{err}"
+        );
+    }
 }
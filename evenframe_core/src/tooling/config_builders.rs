@@ -1,25 +1,31 @@
 //! Configuration builders for processing Evenframe types.
 
 use super::{BuildConfig, EvenframeType, WorkspaceScanner};
-use crate::error::Result;
+use crate::error::{EvenframeError, Result};
 use crate::{
+    dependency::collect_table_dependencies,
     derive::{
         attributes::{
-            parse_annotation_attributes, parse_doccom_attribute, parse_event_attributes,
-            parse_format_attribute_bin, parse_index_attributes, parse_macroforge_derive_attribute,
-            parse_mock_data_attribute, parse_relation_attribute, parse_rust_derives,
-            parse_table_validators,
+            apply_evenframe_default, parse_annotation_attributes, parse_doc_comment,
+            parse_doccom_attribute, parse_evenframe_changefeed_attribute,
+            parse_evenframe_default_attribute, parse_evenframe_index_attribute,
+            parse_evenframe_literal_attribute, parse_evenframe_no_overwrite_attribute,
+            parse_evenframe_rename_attribute, parse_evenframe_search_attribute,
+            parse_evenframe_skip_attribute, parse_event_attributes, parse_format_attribute_bin,
+            parse_index_attributes, parse_macroforge_derive_attribute, parse_mock_data_attribute,
+            parse_relation_attribute, parse_rust_derives, parse_table_validators,
         },
         validator_parser::parse_field_validators_as_enums,
     },
+    schemasync::TableCase,
     schemasync::table::TableConfig,
-    schemasync::{DefineConfig, EdgeConfig, EventConfig, PermissionsConfig},
+    schemasync::{DefineConfig, EdgeConfig, PermissionsConfig},
     types::{FieldType, StructConfig, StructField, TaggedUnion, Variant, VariantData},
     typesync::config::CollisionStrategy,
     validator::{StringValidator, Validator},
 };
 use convert_case::{Case, Casing};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::Path;
 use syn::{Fields, FieldsNamed, Item, ItemEnum, ItemStruct, parse_file};
@@ -57,6 +63,7 @@ pub fn build_all_configs(config: &BuildConfig) -> Result<AllConfigs> {
         &mut table_configs,
         &mut struct_configs,
         config.collision_strategy,
+        config.table_naming,
     )?;
 
     info!(
@@ -66,7 +73,7 @@ pub fn build_all_configs(config: &BuildConfig) -> Result<AllConfigs> {
         table_configs.len()
     );
 
-    resolve_relation_endpoints(&mut table_configs, &enum_configs);
+    resolve_relation_endpoints(&mut table_configs, &enum_configs, config.table_naming);
 
     // Apply output rule plugins to enrich configs with convention-based defaults
     #[cfg(feature = "wasm-plugins")]
@@ -105,7 +112,8 @@ pub fn build_all_configs(config: &BuildConfig) -> Result<AllConfigs> {
         )?;
         // Re-run relation endpoint resolution so any synthetic relation
         // tables get their from/to resolved from their `in`/`out` fields.
-        resolve_relation_endpoints(&mut table_configs, &enum_configs);
+        resolve_relation_endpoints(&mut table_configs, &enum_configs, config.table_naming);
+        resolve_named_field_types(&mut enum_configs, &mut table_configs, &mut struct_configs);
     }
 
     Ok((enum_configs, table_configs, struct_configs))
@@ -154,6 +162,7 @@ pub fn build_all_configs_default() -> AllConfigs {
         &mut table_configs,
         &mut struct_configs,
         CollisionStrategy::Error,
+        config.table_naming,
     ) {
         warn!("Error processing types: {}", e);
         return (BTreeMap::new(), BTreeMap::new(), BTreeMap::new());
@@ -166,7 +175,7 @@ pub fn build_all_configs_default() -> AllConfigs {
         table_configs.len()
     );
 
-    resolve_relation_endpoints(&mut table_configs, &enum_configs);
+    resolve_relation_endpoints(&mut table_configs, &enum_configs, config.table_naming);
 
     (enum_configs, table_configs, struct_configs)
 }
@@ -179,6 +188,7 @@ pub fn build_all_configs_default() -> AllConfigs {
 fn resolve_relation_endpoints(
     table_configs: &mut BTreeMap<String, TableConfig>,
     enum_configs: &BTreeMap<String, TaggedUnion>,
+    table_naming: TableCase,
 ) {
     // Snapshot table names to avoid borrow conflicts
     let known_tables: std::collections::BTreeSet<String> = table_configs.keys().cloned().collect();
@@ -200,6 +210,7 @@ fn resolve_relation_endpoints(
                 "in",
                 enum_configs,
                 &known_tables,
+                table_naming,
             )
         {
             debug!(
@@ -214,6 +225,7 @@ fn resolve_relation_endpoints(
                 "out",
                 enum_configs,
                 &known_tables,
+                table_naming,
             )
         {
             debug!(
@@ -231,6 +243,7 @@ fn resolve_field_to_tables(
     field_name: &str,
     enum_configs: &BTreeMap<String, TaggedUnion>,
     known_tables: &std::collections::BTreeSet<String>,
+    table_naming: TableCase,
 ) -> Option<Vec<String>> {
     let field = struct_config
         .fields
@@ -240,16 +253,18 @@ fn resolve_field_to_tables(
     // Extract the inner type name from RecordLink<T>
     let inner_type_name = match &field.field_type {
         FieldType::RecordLink(inner) => match inner.as_ref() {
-            FieldType::Other(name) => name.clone(),
+            FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
+                name.clone()
+            }
             _ => return None,
         },
         _ => return None,
     };
 
     // Try direct table match
-    let snake = inner_type_name.to_case(Case::Snake);
-    if known_tables.contains(&snake) {
-        return Some(vec![snake]);
+    let table = table_naming.apply(&inner_type_name);
+    if known_tables.contains(&table) {
+        return Some(vec![table]);
     }
 
     // Try enum variant resolution
@@ -259,10 +274,12 @@ fn resolve_field_to_tables(
             if let Some(data) = &variant.data {
                 let struct_name = match data {
                     VariantData::InlineStruct(s) => &s.struct_name,
-                    VariantData::DataStructureRef(FieldType::Other(name)) => name,
+                    VariantData::DataStructureRef(
+                        FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name),
+                    ) => name,
                     _ => continue,
                 };
-                let t = struct_name.to_case(Case::Snake);
+                let t = table_naming.apply(struct_name);
                 if known_tables.contains(&t) {
                     tables.push(t);
                 }
@@ -301,6 +318,7 @@ fn process_types(
     table_configs: &mut BTreeMap<String, TableConfig>,
     struct_configs: &mut BTreeMap<String, StructConfig>,
     collision_strategy: CollisionStrategy,
+    table_naming: TableCase,
 ) -> Result<()> {
     debug!("Grouping types by file");
     let mut types_by_file: BTreeMap<String, Vec<_>> = BTreeMap::new();
@@ -345,7 +363,14 @@ fn process_types(
                         file_types.iter().find(|&t| item_struct.ident == t.name)
                     {
                         debug!("Found Evenframe struct: {:?}", item_struct.ident);
-                        if let Some(mut struct_config) = parse_struct_config(&item_struct) {
+                        if let Some(mut struct_config) =
+                            parse_struct_config(&item_struct).map_err(|e| {
+                                crate::error::EvenframeError::Config(format!(
+                                    "Failed to parse struct '{}' in '{}': {}",
+                                    item_struct.ident, file_path, e
+                                ))
+                            })?
+                        {
                             struct_config.pipeline = evenframe_type.pipeline;
                             // Check for name collision
                             if let Some(existing_file) =
@@ -388,9 +413,9 @@ fn process_types(
                                 .insert(struct_config.struct_name.clone(), struct_config.clone());
 
                             if evenframe_type.has_id_field {
-                                let table_name = struct_config.struct_name.to_case(Case::Snake);
+                                let table_name = table_naming.apply(&struct_config.struct_name);
                                 debug!(
-                                    "Building table config for: {} (snake_case: {})",
+                                    "Building table config for: {} (table: {})",
                                     struct_config.struct_name, &table_name
                                 );
 
@@ -429,6 +454,24 @@ fn process_types(
                                         struct_config.struct_name, file_path, e
                                     ))
                                 })?;
+                                let no_overwrite = parse_evenframe_no_overwrite_attribute(
+                                    &item_struct.attrs,
+                                )
+                                .map_err(|e| {
+                                    crate::error::EvenframeError::Config(format!(
+                                        "Failed to parse #[evenframe(...)] on struct '{}' in '{}': {}",
+                                        struct_config.struct_name, file_path, e
+                                    ))
+                                })?;
+                                let changefeed = parse_evenframe_changefeed_attribute(
+                                    &item_struct.attrs,
+                                )
+                                .map_err(|e| {
+                                    crate::error::EvenframeError::Config(format!(
+                                        "Failed to parse #[evenframe(...)] on struct '{}' in '{}': {}",
+                                        struct_config.struct_name, file_path, e
+                                    ))
+                                })?;
 
                                 let table_config = TableConfig {
                                     table_name: table_name.clone(),
@@ -440,12 +483,11 @@ fn process_types(
                                         .ok()
                                         .flatten(),
                                     mock_generation_config,
-                                    events: events
-                                        .into_iter()
-                                        .map(|statement| EventConfig { statement })
-                                        .collect(),
+                                    events,
                                     indexes,
                                     output_override: None,
+                                    no_overwrite,
+                                    changefeed,
                                 };
                                 trace!(
                                     "Inserting table config {:?}: {:#?}",
@@ -461,7 +503,14 @@ fn process_types(
                         file_types.iter().find(|&t| item_enum.ident == t.name)
                     {
                         debug!("Found Evenframe enum: {}", item_enum.ident);
-                        if let Some(mut tagged_union) = parse_enum_config(&item_enum) {
+                        if let Some(mut tagged_union) =
+                            parse_enum_config(&item_enum).map_err(|e| {
+                                crate::error::EvenframeError::Config(format!(
+                                    "Failed to parse enum '{}' in '{}': {}",
+                                    item_enum.ident, file_path, e
+                                ))
+                            })?
+                        {
                             tagged_union.pipeline = evenframe_type.pipeline;
                             // Check for name collision
                             if let Some(existing_file) = enum_origins.get(&tagged_union.enum_name) {
@@ -536,18 +585,71 @@ fn process_types(
         }
     }
 
+    resolve_named_field_types(enum_configs, table_configs, struct_configs);
+
     Ok(())
 }
 
+/// Marks field references to scanned enums and structs as `FieldType::Enum`
+/// and `FieldType::Object`, leaving foreign and unknown types as `Other`.
+fn resolve_named_field_types(
+    enum_configs: &mut BTreeMap<String, TaggedUnion>,
+    table_configs: &mut BTreeMap<String, TableConfig>,
+    struct_configs: &mut BTreeMap<String, StructConfig>,
+) {
+    let enum_names: BTreeSet<String> = enum_configs.keys().cloned().collect();
+    let object_names: BTreeSet<String> = struct_configs
+        .keys()
+        .cloned()
+        .chain(
+            table_configs
+                .values()
+                .map(|table| table.struct_config.struct_name.clone()),
+        )
+        .collect();
+
+    let resolve_fields = |fields: &mut Vec<StructField>| {
+        for field in fields {
+            field
+                .field_type
+                .resolve_named_types(&enum_names, &object_names);
+        }
+    };
+
+    for struct_config in struct_configs.values_mut() {
+        resolve_fields(&mut struct_config.fields);
+    }
+    for table_config in table_configs.values_mut() {
+        resolve_fields(&mut table_config.struct_config.fields);
+    }
+    for tagged_union in enum_configs.values_mut() {
+        for variant in &mut tagged_union.variants {
+            match &mut variant.data {
+                Some(VariantData::DataStructureRef(field_type)) => {
+                    field_type.resolve_named_types(&enum_names, &object_names)
+                }
+                Some(VariantData::InlineStruct(enum_struct)) => {
+                    resolve_fields(&mut enum_struct.fields)
+                }
+                None => {}
+            }
+        }
+    }
+}
+
 /// Recursively updates type references that were renamed due to collisions.
 fn rename_field_type(field_type: &mut FieldType, renames: &BTreeMap<String, String>) {
     match field_type {
-        FieldType::Other(name) => {
+        FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
             if let Some(new_name) = renames.get(name.as_str()) {
                 *name = new_name.clone();
             }
         }
-        FieldType::Option(inner) | FieldType::Vec(inner) | FieldType::RecordLink(inner) => {
+        FieldType::Option(inner)
+        | FieldType::Vec(inner)
+        | FieldType::Set(inner)
+        | FieldType::Array(inner, _)
+        | FieldType::RecordLink(inner) => {
             rename_field_type(inner, renames);
         }
         FieldType::HashMap(k, v) | FieldType::BTreeMap(k, v) => {
@@ -568,7 +670,7 @@ fn rename_field_type(field_type: &mut FieldType, renames: &BTreeMap<String, Stri
     }
 }
 
-fn parse_struct_config(item_struct: &ItemStruct) -> Option<StructConfig> {
+fn parse_struct_config(item_struct: &ItemStruct) -> syn::Result<Option<StructConfig>> {
     let struct_name = item_struct.ident.to_string();
     trace!("Parsing struct config for: {}", struct_name);
     let mut fields = Vec::new();
@@ -579,7 +681,7 @@ fn parse_struct_config(item_struct: &ItemStruct) -> Option<StructConfig> {
             fields_named.named.len(),
             struct_name
         );
-        fields = process_struct_fields(fields_named);
+        fields = process_struct_fields(fields_named)?;
     }
 
     let table_validators = parse_table_validators(&item_struct.attrs)
@@ -587,6 +689,7 @@ fn parse_struct_config(item_struct: &ItemStruct) -> Option<StructConfig> {
         .unwrap_or_default();
 
     let doccom = parse_doccom_attribute(&item_struct.attrs).ok().flatten();
+    let doc = parse_doc_comment(&item_struct.attrs);
     let macroforge_derives = parse_macroforge_derive_attribute(&item_struct.attrs)
         .ok()
         .unwrap_or_default();
@@ -596,7 +699,7 @@ fn parse_struct_config(item_struct: &ItemStruct) -> Option<StructConfig> {
     let rust_derives = parse_rust_derives(&item_struct.attrs);
     let raw_attributes = collect_raw_attributes(&item_struct.attrs);
 
-    Some(StructConfig {
+    Ok(Some(StructConfig {
         struct_name,
         fields,
         validators: table_validators
@@ -604,16 +707,17 @@ fn parse_struct_config(item_struct: &ItemStruct) -> Option<StructConfig> {
             .map(|v| Validator::StringValidator(StringValidator::StringEmbedded(v)))
             .collect(),
         doccom,
+        doc,
         macroforge_derives,
         annotations,
         pipeline: crate::types::Pipeline::default(),
         rust_derives,
         output_override: None,
         raw_attributes,
-    })
+    }))
 }
 
-fn parse_enum_config(item_enum: &ItemEnum) -> Option<TaggedUnion> {
+fn parse_enum_config(item_enum: &ItemEnum) -> syn::Result<Option<TaggedUnion>> {
     let enum_name = item_enum.ident.to_string();
     trace!("Parsing enum config for: {}", enum_name);
     let mut variants = Vec::new();
@@ -667,7 +771,7 @@ fn parse_enum_config(item_enum: &ItemEnum) -> Option<TaggedUnion> {
                     fields_named.named.len(),
                     variant_name
                 );
-                let struct_fields = process_struct_fields(fields_named);
+                let struct_fields = process_struct_fields(fields_named)?;
 
                 Some(VariantData::InlineStruct(StructConfig {
                     struct_name: variant_name.clone(),
@@ -697,7 +801,7 @@ fn parse_enum_config(item_enum: &ItemEnum) -> Option<TaggedUnion> {
 
     let enum_raw_attributes = collect_raw_attributes(&item_enum.attrs);
 
-    Some(TaggedUnion {
+    Ok(Some(TaggedUnion {
         enum_name,
         variants,
         representation,
@@ -708,12 +812,15 @@ fn parse_enum_config(item_enum: &ItemEnum) -> Option<TaggedUnion> {
         rust_derives: enum_rust_derives,
         output_override: None,
         raw_attributes: enum_raw_attributes,
-    })
+    }))
 }
 
-fn process_struct_fields(fields_named: &FieldsNamed) -> Vec<StructField> {
+fn process_struct_fields(fields_named: &FieldsNamed) -> syn::Result<Vec<StructField>> {
     let mut struct_fields = Vec::new();
     for field in &fields_named.named {
+        if parse_evenframe_skip_attribute(&field.attrs).unwrap_or(false) {
+            continue;
+        }
         let field_name = field
             .ident
             .as_ref()
@@ -721,16 +828,32 @@ fn process_struct_fields(fields_named: &FieldsNamed) -> Vec<StructField> {
             .to_string();
         let field_name = field_name.trim_start_matches("r#").to_string();
 
-        let field_type = FieldType::parse_syn_ty(&field.ty);
+        let field_type = match parse_evenframe_literal_attribute(&field.attrs)
+            .map_err(|e| syn::Error::new(e.span(), format!("field '{}': {}", field_name, e)))?
+        {
+            Some(value) => FieldType::Literal(value),
+            None => FieldType::parse_syn_ty(&field.ty),
+        };
 
         let edge_config = EdgeConfig::parse(field).ok().flatten();
-        let define_config = DefineConfig::parse(field).ok().flatten();
+        let mut define_config = DefineConfig::parse(field).ok().flatten();
+        if let Some(lit) = parse_evenframe_default_attribute(&field.attrs)? {
+            apply_evenframe_default(&mut define_config, &lit, &field_type)
+                .map_err(|e| syn::Error::new(e.span(), format!("field '{}': {}", field_name, e)))?;
+        }
         let format = parse_format_attribute_bin(&field.attrs).ok().flatten();
         let validators = parse_field_validators_as_enums(&field.attrs);
         let doccom = parse_doccom_attribute(&field.attrs).ok().flatten();
+        let doc = parse_doc_comment(&field.attrs);
         let annotations = parse_annotation_attributes(&field.attrs)
             .ok()
             .unwrap_or_default();
+        let rename = parse_evenframe_rename_attribute(&field.attrs)
+            .ok()
+            .flatten();
+        let index = parse_evenframe_index_attribute(&field.attrs).ok().flatten();
+        let search_analyzer = parse_evenframe_search_attribute(&field.attrs)
+            .map_err(|e| syn::Error::new(e.span(), format!("field '{}': {}", field_name, e)))?;
 
         let field_raw_attributes = collect_raw_attributes(&field.attrs);
 
@@ -743,14 +866,18 @@ fn process_struct_fields(fields_named: &FieldsNamed) -> Vec<StructField> {
             validators,
             always_regenerate: false,
             doccom,
+            doc,
             annotations,
-            unique: false,
+            unique: index == Some(true),
+            indexed: index == Some(false),
+            search_analyzer,
             mock_plugin: None,
+            rename,
             output_override: None,
             raw_attributes: field_raw_attributes,
         });
     }
-    struct_fields
+    Ok(struct_fields)
 }
 
 /// Attributes that evenframe's own attribute parsers handle. Everything
@@ -763,6 +890,7 @@ const KNOWN_ATTRS: &[&str] = &[
     "doc",
     "doccom",
     "edge",
+    "evenframe",
     "event",
     "fetch",
     "format",
@@ -901,6 +1029,49 @@ pub fn filter_for_schemasync(
     )
 }
 
+/// Restrict `tables` to the named tables plus every table they reach through
+/// record links, relations and nested objects.
+///
+/// Errors with the list of valid table names if any requested name is unknown.
+pub fn filter_tables_with_dependencies(
+    tables: BTreeMap<String, TableConfig>,
+    names: &[String],
+    objects: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+) -> Result<BTreeMap<String, TableConfig>> {
+    let unknown: Vec<&str> = names
+        .iter()
+        .map(String::as_str)
+        .filter(|name| !tables.contains_key(*name))
+        .collect();
+    if !unknown.is_empty() {
+        return Err(EvenframeError::config(format!(
+            "Unknown table(s): {}. Valid tables: {}",
+            unknown.join(", "),
+            tables.keys().cloned().collect::<Vec<_>>().join(", ")
+        )));
+    }
+
+    let mut selected = BTreeSet::new();
+    let mut pending = names.to_vec();
+    while let Some(name) = pending.pop() {
+        if selected.insert(name.clone()) {
+            pending.extend(collect_table_dependencies(
+                &name,
+                &tables,
+                objects,
+                enums,
+                &mut BTreeSet::new(),
+            ));
+        }
+    }
+
+    Ok(tables
+        .into_iter()
+        .filter(|(name, _)| selected.contains(name))
+        .collect())
+}
+
 // ============================================================
 // Rule plugin application
 // ============================================================
@@ -992,9 +1163,10 @@ fn apply_rule_plugins(
                         });
                     }
                     for event in &to.events {
-                        tc.events.push(crate::schemasync::EventConfig {
-                            statement: event.statement.clone(),
-                        });
+                        tc.events
+                            .push(crate::schemasync::EventConfig::from_statement(
+                                event.statement.clone(),
+                            ));
                     }
                 }
                 Err(e) => {
@@ -1352,3 +1524,316 @@ fn merge_synthetic_output(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tooling::TypeKind;
+
+    fn table(name: &str, fields: Vec<StructField>) -> (String, TableConfig) {
+        (
+            name.to_string(),
+            TableConfig {
+                table_name: name.to_string(),
+                struct_config: StructConfig {
+                    struct_name: name.to_case(Case::Pascal),
+                    fields,
+                    ..StructConfig::default()
+                },
+                ..Default::default()
+            },
+        )
+    }
+
+    fn link(field_name: &str, target: &str) -> StructField {
+        StructField {
+            field_name: field_name.to_string(),
+            field_type: FieldType::RecordLink(Box::new(FieldType::Other(target.to_string()))),
+            ..StructField::default()
+        }
+    }
+
+    fn tables() -> BTreeMap<String, TableConfig> {
+        BTreeMap::from([
+            table("user", vec![]),
+            table("post", vec![link("author", "User")]),
+            table("comment", vec![link("post", "Post")]),
+            table("tag", vec![]),
+        ])
+    }
+
+    #[test]
+    fn filter_tables_keeps_transitive_dependencies() {
+        let filtered = filter_tables_with_dependencies(
+            tables(),
+            &["comment".to_string()],
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            filtered.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec!["comment", "post", "user"]
+        );
+    }
+
+    #[test]
+    fn filter_tables_rejects_unknown_names() {
+        let err = filter_tables_with_dependencies(
+            tables(),
+            &["post".to_string(), "widget".to_string()],
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+        )
+        .unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("Unknown table(s): widget"), "{message}");
+        assert!(
+            message.contains("Valid tables: comment, post, tag, user"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn parse_struct_config_drops_skipped_fields() {
+        let item: ItemStruct = syn::parse_quote! {
+            pub struct Session {
+                pub id: String,
+                pub token: String,
+                #[evenframe(skip)]
+                pub cached_user: Option<String>,
+            }
+        };
+
+        let config = parse_struct_config(&item).unwrap().unwrap();
+
+        let names: Vec<_> = config
+            .fields
+            .iter()
+            .map(|f| f.field_name.as_str())
+            .collect();
+        assert_eq!(names, ["id", "token"]);
+    }
+
+    #[test]
+    fn parse_struct_config_reads_literal_fields() {
+        let item: ItemStruct = syn::parse_quote! {
+            pub struct Account {
+                pub id: String,
+                #[evenframe(literal = "user")]
+                pub kind: String,
+            }
+        };
+
+        let config = parse_struct_config(&item).unwrap().unwrap();
+        assert_eq!(
+            config.fields[1].field_type,
+            FieldType::Literal(serde_json::json!("user"))
+        );
+
+        let item: ItemStruct = syn::parse_quote! {
+            pub struct Account {
+                pub id: String,
+                #[evenframe(literal = b'u')]
+                pub kind: u8,
+            }
+        };
+        let err = parse_struct_config(&item).unwrap_err().to_string();
+        assert!(err.contains("field 'kind'"), "{err}");
+    }
+
+    #[test]
+    fn parse_struct_config_rejects_invalid_defaults() {
+        let item: ItemStruct = syn::parse_quote! {
+            pub struct Job {
+                pub id: String,
+                #[evenframe(default = "300")]
+                pub retries: u8,
+            }
+        };
+
+        let err = parse_struct_config(&item).unwrap_err().to_string();
+        assert!(err.contains("field 'retries'"), "{err}");
+        assert!(err.contains("invalid default \"300\""), "{err}");
+
+        let item: ItemStruct = syn::parse_quote! {
+            pub struct Job {
+                pub id: String,
+                #[evenframe(default = "None")]
+                pub retries: Option<u8>,
+            }
+        };
+        let config = parse_struct_config(&item).unwrap().unwrap();
+        let define = config.fields[1].define_config.as_ref().unwrap();
+        assert_eq!(define.default.as_deref(), Some("NONE"));
+    }
+
+    #[test]
+    fn parse_struct_config_rejects_unknown_search_analyzers() {
+        let item: ItemStruct = syn::parse_quote! {
+            pub struct Article {
+                pub id: String,
+                #[evenframe(search(analyzer = "klingon"))]
+                pub body: String,
+            }
+        };
+
+        let err = parse_struct_config(&item).unwrap_err().to_string();
+        assert!(err.contains("field 'body'"), "{err}");
+        assert!(err.contains("Unknown search analyzer 'klingon'"), "{err}");
+    }
+
+    #[test]
+    fn process_types_resolves_enum_and_object_references() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let file_path = dir.path().join("models.rs");
+        fs::write(
+            &file_path,
+            r#"
+            #[derive(Evenframe)]
+            pub enum Status { Active, Inactive }
+
+            #[derive(Evenframe)]
+            pub struct Address { pub city: String }
+
+            #[derive(Evenframe)]
+            pub struct User {
+                pub id: String,
+                pub status: Status,
+                pub addresses: Vec<Address>,
+                pub created_at: DateTime<Utc>,
+            }
+            "#,
+        )
+        .unwrap();
+        let file_path = file_path.to_string_lossy().to_string();
+        let found = |name: &str, kind: TypeKind, has_id_field: bool| EvenframeType {
+            name: name.to_string(),
+            module_path: "models".to_string(),
+            file_path: file_path.clone(),
+            kind,
+            has_id_field,
+            pipeline: crate::types::Pipeline::default(),
+        };
+        let types = vec![
+            found("Status", TypeKind::Enum, false),
+            found("Address", TypeKind::Struct, false),
+            found("User", TypeKind::Struct, true),
+        ];
+
+        let (mut enums, mut tables, mut objects) =
+            (BTreeMap::new(), BTreeMap::new(), BTreeMap::new());
+        process_types(
+            &types,
+            &mut enums,
+            &mut tables,
+            &mut objects,
+            CollisionStrategy::Error,
+            TableCase::default(),
+        )
+        .unwrap();
+
+        let user = &tables["user"].struct_config;
+        let field_type = |name: &str| {
+            user.fields
+                .iter()
+                .find(|f| f.field_name == name)
+                .unwrap()
+                .field_type
+                .clone()
+        };
+        assert_eq!(field_type("status"), FieldType::Enum("Status".to_string()));
+        assert_eq!(
+            field_type("addresses"),
+            FieldType::Vec(Box::new(FieldType::Object("Address".to_string())))
+        );
+        assert!(matches!(field_type("created_at"), FieldType::Other(_)));
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn table_naming_is_consistent_across_tables_and_record_links() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let file_path = dir.path().join("models.rs");
+        fs::write(
+            &file_path,
+            r#"
+            #[derive(Evenframe)]
+            pub struct UserProfile { pub id: String }
+
+            #[derive(Evenframe)]
+            pub struct BlogPost {
+                pub id: String,
+                pub author: RecordLink<UserProfile>,
+            }
+            "#,
+        )
+        .unwrap();
+        let file_path = file_path.to_string_lossy().to_string();
+        let found = |name: &str| EvenframeType {
+            name: name.to_string(),
+            module_path: "models".to_string(),
+            file_path: file_path.clone(),
+            kind: TypeKind::Struct,
+            has_id_field: true,
+            pipeline: crate::types::Pipeline::default(),
+        };
+        let types = vec![found("UserProfile"), found("BlogPost")];
+
+        let generate = |case: TableCase| {
+            let (mut enums, mut tables, mut objects) =
+                (BTreeMap::new(), BTreeMap::new(), BTreeMap::new());
+            process_types(
+                &types,
+                &mut enums,
+                &mut tables,
+                &mut objects,
+                CollisionStrategy::Error,
+                case,
+            )
+            .unwrap();
+
+            let registry = crate::types::ForeignTypeRegistry::default();
+            let mapper = crate::schemasync::database::surql::SurrealdbTypeMapper::new(&registry)
+                .with_struct_table_registries(&objects, &tables);
+            let post = tables
+                .values()
+                .find(|t| t.struct_config.struct_name == "BlogPost")
+                .unwrap();
+            let author = post
+                .struct_config
+                .fields
+                .iter()
+                .find(|f| f.field_name == "author")
+                .unwrap();
+            (
+                tables.keys().cloned().collect::<Vec<_>>(),
+                mapper.field_type_to_surql(&author.field_type),
+            )
+        };
+
+        assert_eq!(
+            generate(TableCase::Snake),
+            (
+                vec!["blog_post".to_string(), "user_profile".to_string()],
+                "record<user_profile>".to_string()
+            )
+        );
+        assert_eq!(
+            generate(TableCase::Verbatim),
+            (
+                vec!["BlogPost".to_string(), "UserProfile".to_string()],
+                "record<UserProfile>".to_string()
+            )
+        );
+        assert_eq!(
+            generate(TableCase::Kebab),
+            (
+                vec!["blog-post".to_string(), "user-profile".to_string()],
+                "record<`user-profile`>".to_string()
+            )
+        );
+    }
+}
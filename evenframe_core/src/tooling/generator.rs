@@ -11,6 +11,7 @@ use crate::typesync::macroforge::generate_macroforge_type_string;
 use crate::typesync::protobuf::generate_protobuf_schema_string;
 use crate::typesync::{
     arktype::generate_arktype_type_string, effect::generate_effect_schema_string,
+    json_schema::generate_json_schema_string, zod::generate_zod_schema_string,
 };
 use std::collections::BTreeMap;
 use std::fs;
@@ -29,10 +30,14 @@ pub enum GeneratorType {
     Effect,
     /// Macroforge type generator.
     Macroforge,
+    /// Zod schema generator.
+    Zod,
     /// FlatBuffers schema generator.
     FlatBuffers,
     /// Protocol Buffers schema generator.
     Protobuf,
+    /// JSON Schema document generator.
+    JsonSchema,
 }
 
 impl GeneratorType {
@@ -42,8 +47,10 @@ impl GeneratorType {
             GeneratorType::ArkType => "arktype.ts",
             GeneratorType::Effect => "bindings.ts",
             GeneratorType::Macroforge => "macroforge.ts",
+            GeneratorType::Zod => "zod.ts",
             GeneratorType::FlatBuffers => "schema.fbs",
             GeneratorType::Protobuf => "schema.proto",
+            GeneratorType::JsonSchema => "schema.json",
         }
     }
 }
@@ -153,6 +160,11 @@ impl TypeGenerator {
             report.add_file(file);
         }
 
+        if self.config.zod {
+            let file = self.generate_zod_internal(&structs, &enums, &registry)?;
+            report.add_file(file);
+        }
+
         #[cfg(feature = "flatbuffers")]
         if self.config.flatbuffers {
             let file = self.generate_flatbuffers_internal(&structs, &enums, &registry)?;
@@ -165,6 +177,11 @@ impl TypeGenerator {
             report.add_file(file);
         }
 
+        if self.config.json_schema {
+            let file = self.generate_json_schema_internal(&structs, &enums, &registry)?;
+            report.add_file(file);
+        }
+
         info!(
             "Generation complete. Generated {} files",
             report.files.len()
@@ -198,6 +215,22 @@ impl TypeGenerator {
         self.generate_macroforge_internal(&structs, &enums, &registry)
     }
 
+    /// Generates only Zod schemas.
+    pub fn generate_zod(&self) -> Result<GeneratedFile, EvenframeError> {
+        let (enums, structs) = self.build_typesync_configs()?;
+        let registry = ForeignTypeRegistry::from_config(&self.config.foreign_types);
+        fs::create_dir_all(&self.config.output_path)?;
+        self.generate_zod_internal(&structs, &enums, &registry)
+    }
+
+    /// Generates only the JSON Schema document.
+    pub fn generate_json_schema(&self) -> Result<GeneratedFile, EvenframeError> {
+        let (enums, structs) = self.build_typesync_configs()?;
+        let registry = ForeignTypeRegistry::from_config(&self.config.foreign_types);
+        fs::create_dir_all(&self.config.output_path)?;
+        self.generate_json_schema_internal(&structs, &enums, &registry)
+    }
+
     /// Generates only FlatBuffers schema.
     #[cfg(feature = "flatbuffers")]
     pub fn generate_flatbuffers(&self) -> Result<GeneratedFile, EvenframeError> {
@@ -277,6 +310,61 @@ impl TypeGenerator {
         })
     }
 
+    fn generate_zod_internal(
+        &self,
+        structs: &BTreeMap<String, StructConfig>,
+        enums: &BTreeMap<String, TaggedUnion>,
+        registry: &ForeignTypeRegistry,
+    ) -> Result<GeneratedFile, EvenframeError> {
+        info!("Generating Zod schemas");
+
+        let content = generate_zod_schema_string(structs, enums, true, registry);
+        let full_content = format!("import {{ z }} from \"zod\";\n\n{}", content);
+
+        let path = self
+            .config
+            .output_path
+            .join(GeneratorType::Zod.default_filename());
+
+        let bytes_written = full_content.len();
+        fs::write(&path, &full_content)?;
+
+        info!("Zod schemas written to {:?}", path);
+
+        Ok(GeneratedFile {
+            path,
+            bytes_written,
+            generator_type: GeneratorType::Zod,
+        })
+    }
+
+    fn generate_json_schema_internal(
+        &self,
+        structs: &BTreeMap<String, StructConfig>,
+        enums: &BTreeMap<String, TaggedUnion>,
+        registry: &ForeignTypeRegistry,
+    ) -> Result<GeneratedFile, EvenframeError> {
+        info!("Generating JSON Schema");
+
+        let content = generate_json_schema_string(structs, enums, registry);
+
+        let path = self
+            .config
+            .output_path
+            .join(GeneratorType::JsonSchema.default_filename());
+
+        let bytes_written = content.len();
+        fs::write(&path, &content)?;
+
+        info!("JSON Schema written to {:?}", path);
+
+        Ok(GeneratedFile {
+            path,
+            bytes_written,
+            generator_type: GeneratorType::JsonSchema,
+        })
+    }
+
     #[cfg(feature = "macroforge")]
     fn generate_macroforge_internal(
         &self,
@@ -291,6 +379,8 @@ impl TypeGenerator {
             enums,
             false,
             self.config.output.array_style,
+            self.config.output.branded_record_ids,
+            self.config.table_naming,
             registry,
         );
 
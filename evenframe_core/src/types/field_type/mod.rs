@@ -1,6 +1,7 @@
 use core::fmt;
 use quote::{ToTokens, quote};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use syn::Type as SynType;
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -28,12 +29,165 @@ pub enum FieldType {
     Struct(Vec<(String, FieldType)>),
     Option(Box<FieldType>),
     Vec(Box<FieldType>),
+    Set(Box<FieldType>),
+    /// Fixed-size array (`[T; N]`), stored as SurrealDB `array<T, N>`.
+    Array(Box<FieldType>, usize),
     HashMap(Box<FieldType>, Box<FieldType>),
     BTreeMap(Box<FieldType>, Box<FieldType>),
     RecordLink(Box<FieldType>),
+    /// Arbitrary JSON passthrough (`serde_json::Value`).
+    Json,
+    /// A field that always holds exactly this value, stored as a SurrealDB
+    /// literal type (`"user"`, `42`, `true`).
+    Literal(serde_json::Value),
+    /// Binary blob (`Vec<u8>`, `&[u8]`), stored as SurrealDB `bytes`.
+    Bytes,
+    /// GeoJSON geometry, stored as SurrealDB `geometry<kind>`.
+    Geometry(GeometryKind),
+    /// A named enum, resolved against the known enums by
+    /// [`FieldType::resolve_named_types`].
+    Enum(String),
+    /// A named struct, resolved against the known structs by
+    /// [`FieldType::resolve_named_types`].
+    Object(String),
+    /// A named type that has not been resolved: foreign types, types unknown
+    /// to evenframe, and references seen by a derive, which only sees its own
+    /// item.
     Other(String),
 }
 
+/// The geometry kinds SurrealDB accepts in `geometry<...>`, plus `Feature`
+/// for any geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GeometryKind {
+    Point,
+    Line,
+    Polygon,
+    MultiPoint,
+    MultiLine,
+    MultiPolygon,
+    Collection,
+    /// Any geometry (a bare `geometry` column).
+    Feature,
+}
+
+impl GeometryKind {
+    /// Maps a Rust type name (as used by `geo`/`geojson`) to a geometry kind.
+    pub fn from_type_name(name: &str) -> Option<Self> {
+        match name {
+            "Point" => Some(Self::Point),
+            "LineString" | "Line" => Some(Self::Line),
+            "Polygon" => Some(Self::Polygon),
+            "MultiPoint" => Some(Self::MultiPoint),
+            "MultiLineString" | "MultiLine" => Some(Self::MultiLine),
+            "MultiPolygon" => Some(Self::MultiPolygon),
+            "GeometryCollection" => Some(Self::Collection),
+            "Geometry" => Some(Self::Feature),
+            _ => None,
+        }
+    }
+
+    /// The kind as written inside SurrealDB's `geometry<...>`.
+    pub fn surql_name(&self) -> &'static str {
+        match self {
+            Self::Point => "point",
+            Self::Line => "line",
+            Self::Polygon => "polygon",
+            Self::MultiPoint => "multipoint",
+            Self::MultiLine => "multiline",
+            Self::MultiPolygon => "multipolygon",
+            Self::Collection => "collection",
+            Self::Feature => "feature",
+        }
+    }
+
+    /// Inverse of [`GeometryKind::surql_name`].
+    pub fn from_surql_name(name: &str) -> Option<Self> {
+        match name {
+            "point" => Some(Self::Point),
+            "line" => Some(Self::Line),
+            "polygon" => Some(Self::Polygon),
+            "multipoint" => Some(Self::MultiPoint),
+            "multiline" => Some(Self::MultiLine),
+            "multipolygon" => Some(Self::MultiPolygon),
+            "collection" => Some(Self::Collection),
+            "feature" => Some(Self::Feature),
+            _ => None,
+        }
+    }
+
+    /// The SurrealDB column type; `Feature` accepts any geometry, which
+    /// SurrealDB spells as a bare `geometry`.
+    pub fn surql_type(&self) -> String {
+        match self {
+            Self::Feature => "geometry".to_string(),
+            _ => format!("geometry<{}>", self.surql_name()),
+        }
+    }
+
+    /// The GeoJSON `type` member for this kind; `Feature` is written as a point.
+    pub fn geojson_type(&self) -> &'static str {
+        match self {
+            Self::Point | Self::Feature => "Point",
+            Self::Line => "LineString",
+            Self::Polygon => "Polygon",
+            Self::MultiPoint => "MultiPoint",
+            Self::MultiLine => "MultiLineString",
+            Self::MultiPolygon => "MultiPolygon",
+            Self::Collection => "GeometryCollection",
+        }
+    }
+
+    /// A GeoJSON object of this kind with positions drawn from `position`
+    /// (`(longitude, latitude)`). Polygon rings are closed, and collections
+    /// hold a point and a line.
+    pub fn geojson(&self, position: &mut impl FnMut() -> (f64, f64)) -> String {
+        let mut pos = || {
+            let (lon, lat) = position();
+            format!("[{}, {}]", lon, lat)
+        };
+        let coordinates = match self {
+            Self::Point | Self::Feature => pos(),
+            Self::Line | Self::MultiPoint => format!("[{}, {}]", pos(), pos()),
+            Self::Polygon | Self::MultiPolygon => {
+                let first = pos();
+                let ring = format!("[[{}, {}, {}, {}]]", first, pos(), pos(), first);
+                if *self == Self::Polygon {
+                    ring
+                } else {
+                    format!("[{}]", ring)
+                }
+            }
+            Self::MultiLine => format!("[[{}, {}], [{}, {}]]", pos(), pos(), pos(), pos()),
+            Self::Collection => {
+                return format!(
+                    r#"{{ "type": "GeometryCollection", "geometries": [{}, {}] }}"#,
+                    Self::Point.geojson(position),
+                    Self::Line.geojson(position)
+                );
+            }
+        };
+        format!(
+            r#"{{ "type": "{}", "coordinates": {} }}"#,
+            self.geojson_type(),
+            coordinates
+        )
+    }
+}
+
+impl ToTokens for GeometryKind {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let variant = syn::Ident::new(&format!("{:?}", self), proc_macro2::Span::call_site());
+        tokens.extend(quote! { ::evenframe::types::GeometryKind::#variant });
+    }
+}
+
+impl fmt::Display for GeometryKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.surql_name())
+    }
+}
+
 impl ToTokens for FieldType {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         match self {
@@ -55,6 +209,26 @@ impl ToTokens for FieldType {
             FieldType::U128 => tokens.extend(quote! { FieldType::U128 }),
             FieldType::Usize => tokens.extend(quote! { FieldType::Usize }),
             FieldType::Unit => tokens.extend(quote! { FieldType::Unit }),
+            FieldType::Json => tokens.extend(quote! { FieldType::Json }),
+            FieldType::Literal(value) => {
+                let json = syn::LitStr::new(&value.to_string(), proc_macro2::Span::call_site());
+                tokens.extend(quote! {
+                    FieldType::Literal(
+                        ::evenframe::prelude::serde_json::from_str(#json)
+                            .expect("literal field type is valid JSON")
+                    )
+                });
+            }
+            FieldType::Bytes => tokens.extend(quote! { FieldType::Bytes }),
+            FieldType::Geometry(kind) => tokens.extend(quote! { FieldType::Geometry(#kind) }),
+            FieldType::Enum(s) => {
+                let lit = syn::LitStr::new(s, proc_macro2::Span::call_site());
+                tokens.extend(quote! { FieldType::Enum(#lit.to_string()) });
+            }
+            FieldType::Object(s) => {
+                let lit = syn::LitStr::new(s, proc_macro2::Span::call_site());
+                tokens.extend(quote! { FieldType::Object(#lit.to_string()) });
+            }
             FieldType::Other(s) => {
                 let lit = syn::LitStr::new(s, proc_macro2::Span::call_site());
                 tokens.extend(quote! { FieldType::Other(#lit.to_string()) });
@@ -69,6 +243,16 @@ impl ToTokens for FieldType {
                     FieldType::Vec(Box::new(#inner))
                 });
             }
+            FieldType::Set(inner) => {
+                tokens.extend(quote! {
+                    FieldType::Set(Box::new(#inner))
+                });
+            }
+            FieldType::Array(inner, len) => {
+                tokens.extend(quote! {
+                    FieldType::Array(Box::new(#inner), #len)
+                });
+            }
             FieldType::Tuple(types) => {
                 tokens.extend(quote! {
                     FieldType::Tuple(vec![#(#types),*])
@@ -101,8 +285,8 @@ impl FieldType {
         let result = match ty {
             SynType::Path(tp) => Self::handle_type_path(tp),
             SynType::Tuple(t) => Self::handle_tuple(t),
-            SynType::Slice(s) => FieldType::Vec(Box::new(Self::parse_syn_ty(&s.elem))),
-            SynType::Array(arr) => FieldType::Vec(Box::new(Self::parse_syn_ty(&arr.elem))),
+            SynType::Slice(s) => Self::vec_of(Self::parse_syn_ty(&s.elem)),
+            SynType::Array(arr) => Self::handle_array(arr),
             SynType::Reference(r) => Self::parse_syn_ty(&r.elem),
             SynType::Ptr(p) => Self::parse_syn_ty(&p.elem),
             SynType::Paren(p) => Self::parse_syn_ty(&p.elem),
@@ -145,6 +329,31 @@ impl FieldType {
         result
     }
 
+    /// `Vec<u8>` and `[u8]` are byte blobs; any other element type is a list.
+    fn vec_of(inner: FieldType) -> FieldType {
+        if inner == FieldType::U8 {
+            FieldType::Bytes
+        } else {
+            FieldType::Vec(Box::new(inner))
+        }
+    }
+
+    /// `[T; N]` keeps its length when `N` is an integer literal; a length
+    /// given by a const or expression falls back to a `Vec`.
+    fn handle_array(arr: &syn::TypeArray) -> FieldType {
+        let inner = Self::parse_syn_ty(&arr.elem);
+        match &arr.len {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(len),
+                ..
+            }) => match len.base10_parse::<usize>() {
+                Ok(len) => FieldType::Array(Box::new(inner), len),
+                Err(_) => FieldType::Vec(Box::new(inner)),
+            },
+            _ => FieldType::Vec(Box::new(inner)),
+        }
+    }
+
     fn handle_tuple(t: &syn::TypeTuple) -> FieldType {
         if t.elems.is_empty() {
             FieldType::Unit
@@ -168,6 +377,11 @@ impl FieldType {
 
         let ident = last.ident.to_string();
 
+        // Geometry types may carry a coordinate parameter (`Point<f64>`)
+        if let Some(kind) = Self::geometry_kind_for_path(&tp.path) {
+            return FieldType::Geometry(kind);
+        }
+
         // Handle generic types with angle brackets
         if let syn::PathArguments::AngleBracketed(args) = &last.arguments {
             let type_args: Vec<_> = args
@@ -184,9 +398,20 @@ impl FieldType {
                     return FieldType::Option(Box::new(Self::parse_syn_ty(type_args[0])));
                 }
                 "Vec" if type_args.len() == 1 => {
-                    return FieldType::Vec(Box::new(Self::parse_syn_ty(type_args[0])));
+                    return Self::vec_of(Self::parse_syn_ty(type_args[0]));
+                }
+                "HashSet" | "BTreeSet" if type_args.len() == 1 => {
+                    return FieldType::Set(Box::new(Self::parse_syn_ty(type_args[0])));
                 }
-                "Box" if type_args.len() == 1 => {
+                // Lifetimes are filtered out above, so `Cow<'a, str>` has a
+                // single type argument
+                "Box" | "Arc" | "Rc" | "RefCell" | "Cell" | "Cow" | "Mutex" | "RwLock"
+                    if type_args.len() == 1 =>
+                {
+                    return Self::parse_syn_ty(type_args[0]);
+                }
+                // `ordered_float` wrappers serialize as the bare float
+                "OrderedFloat" | "NotNan" if type_args.len() == 1 => {
                     return Self::parse_syn_ty(type_args[0]);
                 }
                 "HashMap" if type_args.len() == 2 => {
@@ -231,6 +456,7 @@ impl FieldType {
             "u64" => FieldType::U64,
             "u128" => FieldType::U128,
             "usize" => FieldType::Usize,
+            "Value" if Self::is_json_value_path(&tp.path) => FieldType::Json,
             _ => {
                 // Unknown type - store as Other (use only the last segment identifier,
                 // not the full path, so `crate::module::Foo` becomes just `Foo`)
@@ -241,6 +467,33 @@ impl FieldType {
         }
     }
 
+    /// `Value` is treated as `serde_json::Value` when written bare or with a
+    /// `serde_json::` prefix; other paths ending in `Value` stay `Other`.
+    pub(crate) fn is_json_value_path(path: &syn::Path) -> bool {
+        match path.segments.len() {
+            1 => true,
+            2 => path.segments[0].ident == "serde_json",
+            _ => false,
+        }
+    }
+
+    /// Geometry type names are recognised bare or under a `geo`, `geo_types`
+    /// or `geojson` prefix; other paths with the same name stay `Other`.
+    pub(crate) fn geometry_kind_for_path(path: &syn::Path) -> Option<GeometryKind> {
+        let kind = GeometryKind::from_type_name(&path.segments.last()?.ident.to_string())?;
+        match path.segments.len() {
+            1 => Some(kind),
+            2 if matches!(
+                path.segments[0].ident.to_string().as_str(),
+                "geo" | "geo_types" | "geojson"
+            ) =>
+            {
+                Some(kind)
+            }
+            _ => None,
+        }
+    }
+
     pub fn parse_type_str(type_str: &str) -> FieldType {
         let clean_str = type_str
             .chars()
@@ -266,23 +519,45 @@ impl FieldType {
             "u128" => FieldType::U128,
             "usize" => FieldType::Usize,
             "()" => FieldType::Unit,
+            "Value" | "serde_json::Value" => FieldType::Json,
+            _ if let Some(kind) = GeometryKind::from_type_name(&clean_str) => {
+                FieldType::Geometry(kind)
+            }
             _ => {
-                // Check for generic types like Option<T> or Vec<T>
-                if let Some(start) = clean_str.find('<') {
+                // Check for generic types like Option<T> or Vec<T>; tuples
+                // such as `(u8,Option<i64>)` are split further down
+                if !clean_str.starts_with('(')
+                    && let Some(start) = clean_str.find('<')
+                {
                     if let Some(end) = clean_str.rfind('>') {
                         let outer = &clean_str[..start];
                         let inner = &clean_str[start + 1..end];
+                        let args = Self::split_type_args(inner);
+                        let base = outer.rsplit("::").next().unwrap_or(outer);
 
-                        match outer {
+                        match base {
+                            "HashMap" if args.len() == 2 => FieldType::HashMap(
+                                Box::new(Self::parse_type_str(args[0])),
+                                Box::new(Self::parse_type_str(args[1])),
+                            ),
+                            "BTreeMap" if args.len() == 2 => FieldType::BTreeMap(
+                                Box::new(Self::parse_type_str(args[0])),
+                                Box::new(Self::parse_type_str(args[1])),
+                            ),
+                            "RecordLink" if args.len() == 1 => {
+                                FieldType::RecordLink(Box::new(Self::parse_type_str(inner)))
+                            }
                             "Option" => {
                                 let inner_type = Self::parse_type_str(inner);
                                 FieldType::Option(Box::new(inner_type))
                             }
-                            "Vec" => {
+                            "Vec" => Self::vec_of(Self::parse_type_str(inner)),
+                            "HashSet" | "BTreeSet" => {
                                 let inner_type = Self::parse_type_str(inner);
-                                FieldType::Vec(Box::new(inner_type))
+                                FieldType::Set(Box::new(inner_type))
                             }
-                            "Box" => Self::parse_type_str(inner),
+                            "Box" | "Arc" | "Rc" | "RefCell" | "Cell" | "OrderedFloat"
+                            | "NotNan" => Self::parse_type_str(inner),
                             // For any generic type (e.g., DateTime<Utc>), store just the base name
                             _ => FieldType::Other(outer.to_string()),
                         }
@@ -291,34 +566,10 @@ impl FieldType {
                     }
                 } else if clean_str.starts_with('(') && clean_str.ends_with(')') {
                     let inner = &clean_str[1..clean_str.len() - 1];
-
-                    let mut elements = Vec::new();
-                    let mut current = String::new();
-                    let mut depth = 0;
-
-                    for c in inner.chars() {
-                        match c {
-                            '<' => {
-                                depth += 1;
-                                current.push(c);
-                            }
-                            '>' => {
-                                depth -= 1;
-                                current.push(c);
-                            }
-                            ',' if depth == 0 => {
-                                if !current.is_empty() {
-                                    elements.push(Self::parse_type_str(&current));
-                                    current.clear();
-                                }
-                            }
-                            _ => current.push(c),
-                        }
-                    }
-
-                    if !current.is_empty() {
-                        elements.push(Self::parse_type_str(&current));
-                    }
+                    let elements = Self::split_type_args(inner)
+                        .into_iter()
+                        .map(Self::parse_type_str)
+                        .collect();
 
                     FieldType::Tuple(elements)
                 } else {
@@ -328,9 +579,85 @@ impl FieldType {
             }
         }
     }
+
+    /// Splits a whitespace-free generic or tuple argument list on its
+    /// top-level commas, so `String,HashMap<i32,(u8,u8)>` yields two
+    /// arguments. Empty arguments (e.g. from a trailing comma) are dropped.
+    fn split_type_args(args: &str) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut depth = 0usize;
+        let mut start = 0;
+        for (i, c) in args.char_indices() {
+            match c {
+                '<' | '(' | '[' => depth += 1,
+                '>' | ')' | ']' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    parts.push(&args[start..i]);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        parts.push(&args[start..]);
+        parts.retain(|part| !part.is_empty());
+        parts
+    }
 }
 
 impl FieldType {
+    /// Rewrites `Other` references to known enums and structs into `Enum` and
+    /// `Object`, recursing into container types. Names in neither set stay
+    /// `Other`.
+    pub fn resolve_named_types(
+        &mut self,
+        enum_names: &BTreeSet<String>,
+        object_names: &BTreeSet<String>,
+    ) {
+        match self {
+            FieldType::Other(name) => {
+                if enum_names.contains(name.as_str()) {
+                    *self = FieldType::Enum(std::mem::take(name));
+                } else if object_names.contains(name.as_str()) {
+                    *self = FieldType::Object(std::mem::take(name));
+                }
+            }
+            FieldType::Option(inner)
+            | FieldType::Vec(inner)
+            | FieldType::Set(inner)
+            | FieldType::Array(inner, _)
+            | FieldType::RecordLink(inner) => inner.resolve_named_types(enum_names, object_names),
+            FieldType::HashMap(key, value) | FieldType::BTreeMap(key, value) => {
+                key.resolve_named_types(enum_names, object_names);
+                value.resolve_named_types(enum_names, object_names);
+            }
+            FieldType::Tuple(types) => {
+                for t in types {
+                    t.resolve_named_types(enum_names, object_names);
+                }
+            }
+            FieldType::Struct(fields) => {
+                for (_, ft) in fields {
+                    ft.resolve_named_types(enum_names, object_names);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The type a literal's value belongs to, for targets without literal
+    /// types: strings are `String`, integers `I64`, other numbers `F64`,
+    /// booleans `Bool`, `null` is `Unit`, and arrays and objects are `Json`.
+    pub fn literal_base_type(value: &serde_json::Value) -> FieldType {
+        match value {
+            serde_json::Value::String(_) => FieldType::String,
+            serde_json::Value::Number(n) if n.is_i64() || n.is_u64() => FieldType::I64,
+            serde_json::Value::Number(_) => FieldType::F64,
+            serde_json::Value::Bool(_) => FieldType::Bool,
+            serde_json::Value::Null => FieldType::Unit,
+            serde_json::Value::Array(_) | serde_json::Value::Object(_) => FieldType::Json,
+        }
+    }
+
     /// Returns a human-readable canonical name using Rust-like syntax.
     ///
     /// Examples: `"String"`, `"Decimal"`, `"Option<DateTime>"`, `"Vec<i32>"`, `"HashMap<String, i64>"`
@@ -367,6 +694,8 @@ impl FieldType {
             }
             FieldType::Option(inner) => format!("Option<{}>", inner.canonical_name()),
             FieldType::Vec(inner) => format!("Vec<{}>", inner.canonical_name()),
+            FieldType::Set(inner) => format!("HashSet<{}>", inner.canonical_name()),
+            FieldType::Array(inner, len) => format!("[{}; {}]", inner.canonical_name(), len),
             FieldType::HashMap(k, v) => {
                 format!("HashMap<{}, {}>", k.canonical_name(), v.canonical_name())
             }
@@ -374,7 +703,13 @@ impl FieldType {
                 format!("BTreeMap<{}, {}>", k.canonical_name(), v.canonical_name())
             }
             FieldType::RecordLink(inner) => format!("RecordLink<{}>", inner.canonical_name()),
-            FieldType::Other(name) => name.clone(),
+            FieldType::Json => "serde_json::Value".to_string(),
+            FieldType::Literal(value) => value.to_string(),
+            FieldType::Bytes => "Vec<u8>".to_string(),
+            FieldType::Geometry(kind) => format!("Geometry<{}>", kind),
+            FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
+                name.clone()
+            }
         }
     }
 }
@@ -426,10 +761,299 @@ impl fmt::Display for FieldType {
             }
             FieldType::Option(inner) => write!(f, "Option({})", inner),
             FieldType::Vec(inner) => write!(f, "Vec({})", inner),
+            FieldType::Set(inner) => write!(f, "Set({})", inner),
+            FieldType::Array(inner, len) => write!(f, "Array({}, {})", inner, len),
             FieldType::HashMap(key, value) => write!(f, "HashMap({}, {})", key, value),
             FieldType::BTreeMap(key, value) => write!(f, "BTreeMap({}, {})", key, value),
             FieldType::RecordLink(inner) => write!(f, "RecordLink({})", inner),
-            FieldType::Other(name) => write!(f, "{}", name),
+            FieldType::Json => write!(f, "Json"),
+            FieldType::Literal(value) => write!(f, "Literal({})", value),
+            FieldType::Bytes => write!(f, "Bytes"),
+            FieldType::Geometry(kind) => write!(f, "Geometry({})", kind),
+            FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
+                write!(f, "{}", name)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(ty: &str) -> FieldType {
+        FieldType::parse_syn_ty(&syn::parse_str::<SynType>(ty).unwrap())
+    }
+
+    #[test]
+    fn resolve_named_types_distinguishes_enums_and_objects() {
+        let enums = BTreeSet::from(["Status".to_string()]);
+        let objects = BTreeSet::from(["Address".to_string(), "User".to_string()]);
+
+        let mut field_type = parse("HashMap<String, (Status, Option<Address>, DateTime<Utc>)>");
+        field_type.resolve_named_types(&enums, &objects);
+        assert_eq!(
+            field_type,
+            FieldType::HashMap(
+                Box::new(FieldType::String),
+                Box::new(FieldType::Tuple(vec![
+                    FieldType::Enum("Status".to_string()),
+                    FieldType::Option(Box::new(FieldType::Object("Address".to_string()))),
+                    parse("DateTime<Utc>"),
+                ])),
+            )
+        );
+
+        let mut link = parse("RecordLink<User>");
+        link.resolve_named_types(&enums, &objects);
+        assert_eq!(
+            link,
+            FieldType::RecordLink(Box::new(FieldType::Object("User".to_string())))
+        );
+    }
+
+    #[test]
+    fn enum_and_object_round_trip_through_tokens() {
+        for field_type in [
+            FieldType::Enum("Status".to_string()),
+            FieldType::Object("Address".to_string()),
+        ] {
+            let tokens = field_type.to_token_stream().to_string();
+            let name = field_type.canonical_name();
+            assert!(tokens.contains(&format!("\"{name}\"")), "{tokens}");
+            assert_eq!(field_type.to_string(), name);
         }
+        assert!(
+            FieldType::Enum("Status".to_string())
+                .to_token_stream()
+                .to_string()
+                .starts_with("FieldType :: Enum")
+        );
+    }
+
+    #[test]
+    fn test_parse_syn_ty_fixed_array_keeps_length() {
+        assert_eq!(
+            parse("[u8; 16]"),
+            FieldType::Array(Box::new(FieldType::U8), 16)
+        );
+        assert_eq!(parse("[u8; LEN]"), FieldType::Vec(Box::new(FieldType::U8)));
+
+        let array = parse("[u8; 16]");
+        assert_eq!(array.canonical_name(), "[u8; 16]");
+        assert!(
+            array
+                .to_token_stream()
+                .to_string()
+                .contains("FieldType :: Array")
+        );
+    }
+
+    #[test]
+    fn test_parse_syn_ty_unwraps_cow_and_locks() {
+        assert_eq!(parse("Cow<'a, str>"), FieldType::String);
+        assert_eq!(parse("std::borrow::Cow<'static, str>"), FieldType::String);
+        assert_eq!(parse("Cow<'a, [u8]>"), FieldType::Bytes);
+        assert_eq!(
+            parse("Cow<'a, [String]>"),
+            FieldType::Vec(Box::new(FieldType::String))
+        );
+        assert_eq!(
+            parse("Option<Cow<'a, str>>"),
+            FieldType::Option(Box::new(FieldType::String))
+        );
+        assert_eq!(parse("Mutex<u32>"), FieldType::U32);
+        assert_eq!(parse("Arc<RwLock<bool>>"), FieldType::Bool);
+    }
+
+    #[test]
+    fn test_ordered_float_wrappers_parse_as_floats() {
+        assert_eq!(parse("OrderedFloat<f64>"), FieldType::F64);
+        assert_eq!(parse("ordered_float::NotNan<f32>"), FieldType::F32);
+        assert_eq!(
+            parse("Option<OrderedFloat<f64>>"),
+            FieldType::Option(Box::new(FieldType::F64))
+        );
+        assert_eq!(
+            FieldType::parse_type_str("OrderedFloat<f64>"),
+            FieldType::F64
+        );
+    }
+
+    #[test]
+    fn test_parse_syn_ty_hash_set() {
+        assert_eq!(
+            parse("HashSet<String>"),
+            FieldType::Set(Box::new(FieldType::String))
+        );
+    }
+
+    #[test]
+    fn test_parse_syn_ty_btree_set_of_struct() {
+        assert_eq!(
+            parse("std::collections::BTreeSet<Tag>"),
+            FieldType::Set(Box::new(FieldType::Other("Tag".to_string())))
+        );
+    }
+
+    #[test]
+    fn test_parse_type_str_sets() {
+        assert_eq!(
+            FieldType::parse_type_str("HashSet<i32>"),
+            FieldType::Set(Box::new(FieldType::I32))
+        );
+        assert_eq!(
+            FieldType::parse_type_str("Option<BTreeSet<String>>"),
+            FieldType::Option(Box::new(FieldType::Set(Box::new(FieldType::String))))
+        );
+    }
+
+    #[test]
+    fn test_parse_type_str_matches_parse_syn_ty_for_generics() {
+        for ty in [
+            "HashMap<String, i32>",
+            "std::collections::BTreeMap<String, Vec<(u8, Option<i64>)>>",
+            "RecordLink<User>",
+            "Vec<Option<i32>>",
+            "HashMap<String, HashMap<u8, RecordLink<Team>>>",
+            "(String, BTreeMap<i32, bool>)",
+        ] {
+            assert_eq!(FieldType::parse_type_str(ty), parse(ty), "{ty}");
+        }
+        assert_eq!(
+            FieldType::parse_type_str("HashMap<String, i32>"),
+            FieldType::HashMap(Box::new(FieldType::String), Box::new(FieldType::I32))
+        );
+        assert_eq!(
+            FieldType::parse_type_str("RecordLink<User>"),
+            FieldType::RecordLink(Box::new(FieldType::Other("User".to_string())))
+        );
+        assert_eq!(
+            FieldType::parse_type_str("Vec<Option<i32>>"),
+            FieldType::Vec(Box::new(FieldType::Option(Box::new(FieldType::I32))))
+        );
+    }
+
+    #[test]
+    fn test_parse_syn_ty_json_value() {
+        assert_eq!(parse("serde_json::Value"), FieldType::Json);
+        assert_eq!(parse("Value"), FieldType::Json);
+        assert_eq!(
+            parse("Option<serde_json::Value>"),
+            FieldType::Option(Box::new(FieldType::Json))
+        );
+        assert_eq!(parse("toml::Value"), FieldType::Other("Value".to_string()));
+    }
+
+    #[test]
+    fn test_parse_type_str_json_value() {
+        assert_eq!(
+            FieldType::parse_type_str("serde_json::Value"),
+            FieldType::Json
+        );
+        assert_eq!(
+            FieldType::parse_type_str("Vec<Value>"),
+            FieldType::Vec(Box::new(FieldType::Json))
+        );
+    }
+
+    #[test]
+    fn test_parse_syn_ty_bytes() {
+        assert_eq!(parse("Vec<u8>"), FieldType::Bytes);
+        assert_eq!(parse("&[u8]"), FieldType::Bytes);
+        assert_eq!(
+            parse("Option<Vec<u8>>"),
+            FieldType::Option(Box::new(FieldType::Bytes))
+        );
+        assert_eq!(parse("Vec<u16>"), FieldType::Vec(Box::new(FieldType::U16)));
+    }
+
+    #[test]
+    fn test_parse_syn_ty_smart_pointers() {
+        assert_eq!(parse("Arc<str>"), FieldType::String);
+        assert_eq!(
+            parse("Arc<CustomStruct>"),
+            FieldType::Other("CustomStruct".to_string())
+        );
+        assert_eq!(
+            parse("Rc<Vec<i32>>"),
+            FieldType::Vec(Box::new(FieldType::I32))
+        );
+        assert_eq!(parse("RefCell<u64>"), FieldType::U64);
+        assert_eq!(parse("std::sync::Arc<bool>"), FieldType::Bool);
+        assert_eq!(
+            FieldType::parse_type_str("Rc<Vec<i32>>"),
+            FieldType::Vec(Box::new(FieldType::I32))
+        );
+    }
+
+    #[test]
+    fn test_parse_syn_ty_geometry() {
+        assert_eq!(parse("Point"), FieldType::Geometry(GeometryKind::Point));
+        assert_eq!(
+            parse("geo_types::Polygon"),
+            FieldType::Geometry(GeometryKind::Polygon)
+        );
+        assert_eq!(
+            parse("geo_types::Point<f64>"),
+            FieldType::Geometry(GeometryKind::Point)
+        );
+        assert_eq!(
+            parse("geojson::Geometry"),
+            FieldType::Geometry(GeometryKind::Feature)
+        );
+        assert_eq!(
+            parse("Option<LineString>"),
+            FieldType::Option(Box::new(FieldType::Geometry(GeometryKind::Line)))
+        );
+        assert_eq!(
+            parse("my_crate::shapes::Point"),
+            FieldType::Other("Point".to_string())
+        );
+    }
+
+    #[test]
+    fn test_geometry_display_and_tokens() {
+        let field_type = FieldType::Geometry(GeometryKind::MultiPolygon);
+        assert_eq!(field_type.to_string(), "Geometry(multipolygon)");
+        assert_eq!(
+            field_type.to_token_stream().to_string(),
+            quote! { FieldType::Geometry(::evenframe::types::GeometryKind::MultiPolygon) }
+                .to_string()
+        );
+        assert_eq!(
+            FieldType::parse_type_str("Vec<MultiPoint>"),
+            FieldType::Vec(Box::new(FieldType::Geometry(GeometryKind::MultiPoint)))
+        );
+    }
+
+    #[test]
+    fn test_literal_display_and_tokens() {
+        let field_type = FieldType::Literal(serde_json::json!("user"));
+        assert_eq!(field_type.to_string(), "Literal(\"user\")");
+        assert_eq!(field_type.canonical_name(), "\"user\"");
+        assert_eq!(
+            field_type.to_token_stream().to_string(),
+            quote! {
+                FieldType::Literal(
+                    ::evenframe::prelude::serde_json::from_str("\"user\"")
+                        .expect("literal field type is valid JSON")
+                )
+            }
+            .to_string()
+        );
+        assert_eq!(
+            FieldType::literal_base_type(&serde_json::json!(1.5)),
+            FieldType::F64
+        );
+    }
+
+    #[test]
+    fn test_parse_type_str_bytes() {
+        assert_eq!(FieldType::parse_type_str("Vec<u8>"), FieldType::Bytes);
+        assert_eq!(
+            FieldType::parse_type_str("Option<Vec<u8>>"),
+            FieldType::Option(Box::new(FieldType::Bytes))
+        );
     }
 }
@@ -1,9 +1,14 @@
 mod field_type;
 pub mod foreign_type_registry;
 
-pub use crate::types::field_type::FieldType;
+pub use crate::types::field_type::{FieldType, GeometryKind};
 #[cfg(feature = "surrealdb")]
-use crate::{EvenframeError, Result, evenframe_log, schemasync::TableConfig};
+use crate::{
+    EvenframeError, Result, evenframe_log,
+    schemasync::{
+        TableCase, TableConfig, database::surql::define::comment_clause, find_table, surql_ident,
+    },
+};
 use crate::{
     schemasync::mockmake::format::Format,
     schemasync::{DefineConfig, EdgeConfig},
@@ -11,8 +16,6 @@ use crate::{
     validator::Validator,
     wrappers::EvenframeRecordId,
 };
-#[cfg(feature = "surrealdb")]
-use convert_case::{Case, Casing};
 pub use foreign_type_registry::ForeignTypeRegistry;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
@@ -163,6 +166,28 @@ pub enum VariantData {
     DataStructureRef(FieldType),
 }
 
+/// Analyzer of a `#[evenframe(search)]` index that names none. Schemasync
+/// defines it once whenever a field uses it.
+pub const DEFAULT_SEARCH_ANALYZER: &str = "evenframe_search";
+
+/// Analyzers a `#[evenframe(search(analyzer = "..."))]` index may name, with
+/// the tokenizers and filters schemasync defines each one with.
+pub const SEARCH_ANALYZERS: &[(&str, &str)] = &[
+    (
+        DEFAULT_SEARCH_ANALYZER,
+        "TOKENIZERS class FILTERS lowercase, ascii",
+    ),
+    ("ascii", "TOKENIZERS blank, class FILTERS lowercase, ascii"),
+    (
+        "english",
+        "TOKENIZERS blank, class FILTERS lowercase, ascii, snowball(english)",
+    ),
+    (
+        "autocomplete",
+        "TOKENIZERS blank, class FILTERS lowercase, ascii, edgengram(2, 10)",
+    ),
+];
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StructField {
     pub field_name: String,
@@ -174,13 +199,25 @@ pub struct StructField {
     pub always_regenerate: bool,
     #[serde(default)]
     pub doccom: Option<String>,
+    /// Text of the field's `///` doc comment, emitted as its SurrealDB COMMENT.
+    #[serde(default)]
+    pub doc: Option<String>,
     #[serde(default)]
     pub annotations: Vec<String>,
     #[serde(default)]
     pub unique: bool,
+    /// Non-unique index from `#[evenframe(index)]`.
+    #[serde(default)]
+    pub indexed: bool,
+    /// Analyzer of the full-text search index from `#[evenframe(search)]`.
+    #[serde(default)]
+    pub search_analyzer: Option<String>,
     /// Name of the WASM plugin to use for mock data generation (if any).
     #[serde(default)]
     pub mock_plugin: Option<String>,
+    /// Output name override from `#[evenframe(rename = "…")]`.
+    #[serde(default)]
+    pub rename: Option<String>,
     #[serde(default)]
     pub output_override: Option<Box<Self>>,
     #[serde(default)]
@@ -200,9 +237,13 @@ impl std::hash::Hash for StructField {
         self.validators.hash(state);
         self.always_regenerate.hash(state);
         self.doccom.hash(state);
+        self.doc.hash(state);
         self.annotations.hash(state);
         self.unique.hash(state);
+        self.indexed.hash(state);
+        self.search_analyzer.hash(state);
         self.mock_plugin.hash(state);
+        self.rename.hash(state);
         self.output_override.hash(state);
     }
 }
@@ -232,25 +273,88 @@ impl StructField {
             .as_deref()
             .map_or(self, Self::effective)
     }
+
+    /// Name the field is emitted under in database output: the `rename`
+    /// override when present, otherwise `field_name`.
+    pub fn output_name(&self) -> &str {
+        self.rename.as_deref().unwrap_or(&self.field_name)
+    }
+
+    /// COMMENT of the field's define statement: the explicit `comment` from
+    /// its define config when present, otherwise its doc comment.
+    pub fn comment(&self) -> Option<&str> {
+        self.define_config
+            .as_ref()
+            .and_then(|def| def.comment.as_deref())
+            .or(self.doc.as_deref())
+    }
+
+    /// Name the field is emitted under in TypeScript output: the `rename`
+    /// override verbatim when present, otherwise `field_name` in camelCase.
+    pub fn ts_name(&self) -> String {
+        match &self.rename {
+            Some(rename) => rename.clone(),
+            None => convert_case::Casing::to_case(&self.field_name, convert_case::Case::Camel),
+        }
+    }
+
+    /// Combined `ASSERT` expression for this field: the explicit
+    /// `define_config.assert` and the clauses derived from `validators`, joined
+    /// with `AND`. Optional fields skip validator clauses when the value is `NONE`;
+    /// non-optional fields with `strict_required` also assert `$value != NONE`.
     #[cfg(feature = "surrealdb")]
-    pub fn generate_define_statement(
+    pub fn assert_clause(&self) -> Option<String> {
+        use crate::schemasync::database::surql::assert::generate_assert_from_validators;
+
+        let explicit = self.define_config.as_ref().and_then(|d| d.assert.clone());
+        let mut validator_assert = generate_assert_from_validators(&self.validators, "$value");
+        if let Some(format_assert) = self
+            .format
+            .as_ref()
+            .and_then(|format| format.surql_assert("$value"))
+        {
+            validator_assert = if validator_assert.is_empty() {
+                format_assert
+            } else {
+                format!("{} AND {}", validator_assert, format_assert)
+            };
+        }
+        if !validator_assert.is_empty() && matches!(self.field_type, FieldType::Option(_)) {
+            validator_assert = format!("$value = NONE OR ({})", validator_assert);
+        }
+
+        let assert = match (explicit, validator_assert.is_empty()) {
+            (Some(explicit), true) => Some(explicit),
+            (Some(explicit), false) => Some(format!("({}) AND ({})", explicit, validator_assert)),
+            (None, false) => Some(validator_assert),
+            (None, true) => None,
+        };
+
+        let strict_required = self
+            .define_config
+            .as_ref()
+            .is_some_and(|d| d.strict_required)
+            && !matches!(self.field_type, FieldType::Option(_));
+        match (strict_required, assert) {
+            (true, Some(assert)) => Some(format!("$value != NONE AND ({})", assert)),
+            (true, None) => Some("$value != NONE".to_string()),
+            (false, assert) => assert,
+        }
+    }
+
+    /// Converts `start_field_type` to the SurrealDB type used in this field's
+    /// `DEFINE FIELD` statement, along with whether it needs an array wildcard
+    /// definition and that definition's type.
+    #[cfg(feature = "surrealdb")]
+    fn convert_type_iteratively(
         &self,
-        enums: BTreeMap<String, TaggedUnion>,
-        app_structs: BTreeMap<String, StructConfig>,
-        persistable_structs: BTreeMap<String, TableConfig>,
-        table_name: &String,
+        start_field_type: &FieldType,
+        enums: &BTreeMap<String, TaggedUnion>,
+        app_structs: &BTreeMap<String, StructConfig>,
+        persistable_structs: &BTreeMap<String, TableConfig>,
+        table_name: &str,
         registry: &ForeignTypeRegistry,
-    ) -> Result<String> {
-        evenframe_log!(
-            format!(
-                "Generating define statements for:\nEnums: {:#?}\nApp structs: {:#?}\nTables: {:#?}",
-                enums.keys(),
-                app_structs.keys(),
-                persistable_structs.keys()
-            ),
-            "define_generation.log"
-        );
-
+    ) -> Result<(String, bool, Option<String>)> {
         /* --- Start of Iterative Type Conversion Logic --- */
 
         #[derive(Debug)]
@@ -259,6 +363,8 @@ impl StructField {
             PushString(String),
             AssembleOption,
             AssembleVec,
+            AssembleSet,
+            AssembleArray { len: usize },
             AssembleMap,
             AssembleTuple { count: usize },
             AssembleStruct { count: usize, names: Vec<String> },
@@ -268,434 +374,627 @@ impl StructField {
             LeaveStructScope { name: String },
         }
 
-        let convert_type_iteratively =
-            |start_field_type: &FieldType| -> Result<(String, bool, Option<String>)> {
-                let mut work_stack: Vec<WorkItem> = vec![WorkItem::Process(start_field_type)];
-                let mut value_stack: Vec<(String, bool, Option<String>)> = Vec::new();
-                let mut visited_types = HashSet::new();
-
-                while let Some(item) = work_stack.pop() {
-                    match item {
-                        WorkItem::Process(field_type) => {
-                            match field_type {
-                                FieldType::String | FieldType::Char => {
-                                    value_stack.push(("string".to_string(), false, None))
-                                }
-                                FieldType::Bool => {
-                                    value_stack.push(("bool".to_string(), false, None))
-                                }
-                                FieldType::F32 | FieldType::F64 => {
-                                    value_stack.push(("float".to_string(), false, None))
-                                }
-                                FieldType::I8
-                                | FieldType::I16
-                                | FieldType::I32
-                                | FieldType::I64
-                                | FieldType::I128
-                                | FieldType::Isize
-                                | FieldType::U8
-                                | FieldType::U16
-                                | FieldType::U32
-                                | FieldType::U64
-                                | FieldType::U128
-                                | FieldType::Usize => {
-                                    value_stack.push(("int".to_string(), false, None))
-                                }
-                                FieldType::Unit => {
-                                    value_stack.push(("any".to_string(), false, None))
-                                }
-                                FieldType::Option(inner) => {
-                                    work_stack.push(WorkItem::AssembleOption);
-                                    work_stack.push(WorkItem::Process(inner));
-                                }
-                                FieldType::Vec(inner) => {
-                                    work_stack.push(WorkItem::AssembleVec);
-                                    work_stack.push(WorkItem::Process(inner));
-                                }
-                                FieldType::HashMap(_, value) | FieldType::BTreeMap(_, value) => {
-                                    work_stack.push(WorkItem::AssembleMap);
-                                    work_stack.push(WorkItem::Process(value));
-                                }
-                                FieldType::RecordLink(inner) => {
-                                    if let FieldType::Other(type_name) = inner.as_ref() {
-                                        // Resolve `output_override` so a synthetic
-                                        // projection (e.g. PartialUser → User) emits
-                                        // the underlying table name. Falls back to
-                                        // the literal name when no registered struct
-                                        // or table matches.
-                                        let resolved = if let Some(sc) = app_structs.get(type_name) {
-                                            sc.effective().struct_name.to_case(Case::Snake)
-                                        } else if let Some(tc) =
-                                            persistable_structs.get(&type_name.to_case(Case::Snake))
-                                        {
-                                            tc.effective().table_name.clone()
-                                        } else {
-                                            type_name.to_case(Case::Snake)
-                                        };
-                                        value_stack.push((format!("record<{}>", resolved), false, None));
-                                    } else {
-                                        work_stack.push(WorkItem::Process(inner));
-                                    }
-                                }
-                                FieldType::Tuple(types) => {
-                                    work_stack.push(WorkItem::AssembleTuple { count: types.len() });
-                                    for t in types.iter().rev() {
-                                        work_stack.push(WorkItem::Process(t));
-                                    }
-                                }
-                                FieldType::Struct(fields) => {
-                                    let names =
-                                        fields.iter().map(|(name, _)| name.clone()).collect();
-                                    work_stack.push(WorkItem::AssembleStruct {
-                                        count: fields.len(),
-                                        names,
+        let mut work_stack: Vec<WorkItem> = vec![WorkItem::Process(start_field_type)];
+        let mut value_stack: Vec<(String, bool, Option<String>)> = Vec::new();
+        let mut visited_types = HashSet::new();
+
+        while let Some(item) = work_stack.pop() {
+            match item {
+                WorkItem::Process(field_type) => {
+                    match field_type {
+                        FieldType::String | FieldType::Char => {
+                            value_stack.push(("string".to_string(), false, None))
+                        }
+                        FieldType::Bool => value_stack.push(("bool".to_string(), false, None)),
+                        FieldType::F32 | FieldType::F64 => {
+                            value_stack.push(("float".to_string(), false, None))
+                        }
+                        FieldType::I8
+                        | FieldType::I16
+                        | FieldType::I32
+                        | FieldType::I64
+                        | FieldType::Isize
+                        | FieldType::U8
+                        | FieldType::U16
+                        | FieldType::U32
+                        | FieldType::U64
+                        | FieldType::Usize => value_stack.push(("int".to_string(), false, None)),
+                        // Wider than `int`, so stored as decimal strings
+                        FieldType::I128 | FieldType::U128 => {
+                            value_stack.push(("string".to_string(), false, None))
+                        }
+                        // `any` is the only type that accepts nested object keys in
+                        // a SCHEMAFULL table without per-path field definitions
+                        FieldType::Unit | FieldType::Json => {
+                            value_stack.push(("any".to_string(), false, None))
+                        }
+                        FieldType::Literal(value) => {
+                            value_stack.push((value.to_string(), false, None))
+                        }
+                        FieldType::Bytes => value_stack.push(("bytes".to_string(), false, None)),
+                        FieldType::Geometry(kind) => {
+                            value_stack.push((kind.surql_type(), false, None))
+                        }
+                        FieldType::Option(inner) => {
+                            work_stack.push(WorkItem::AssembleOption);
+                            work_stack.push(WorkItem::Process(inner));
+                        }
+                        FieldType::Vec(inner) => {
+                            work_stack.push(WorkItem::AssembleVec);
+                            work_stack.push(WorkItem::Process(inner));
+                        }
+                        FieldType::Set(inner) => {
+                            work_stack.push(WorkItem::AssembleSet);
+                            work_stack.push(WorkItem::Process(inner));
+                        }
+                        FieldType::Array(inner, len) => {
+                            work_stack.push(WorkItem::AssembleArray { len: *len });
+                            work_stack.push(WorkItem::Process(inner));
+                        }
+                        FieldType::HashMap(_, value) | FieldType::BTreeMap(_, value) => {
+                            work_stack.push(WorkItem::AssembleMap);
+                            work_stack.push(WorkItem::Process(value));
+                        }
+                        FieldType::RecordLink(inner) => {
+                            if let FieldType::Enum(type_name)
+                            | FieldType::Object(type_name)
+                            | FieldType::Other(type_name) = inner.as_ref()
+                            {
+                                // Resolve `output_override` so a synthetic
+                                // projection (e.g. PartialUser → User) emits
+                                // the underlying table name. Targets that
+                                // aren't tables get the default casing.
+                                let target =
+                                    app_structs.get(type_name).map_or(type_name.as_str(), |sc| {
+                                        sc.effective().struct_name.as_str()
                                     });
-                                    for (_, ftype) in fields.iter().rev() {
-                                        work_stack.push(WorkItem::Process(ftype));
-                                    }
-                                }
-                                FieldType::Other(name) => {
-                                    // Check foreign type registry first
-                                    if let Some(ftc) = registry.lookup(name) {
-                                        let type_str = if self.field_name == "id" {
-                                            ftc.surrealdb_id_format
-                                                .as_ref()
-                                                .map(|fmt| fmt.replace("{table_name}", table_name))
-                                                .unwrap_or_else(|| ftc.surrealdb.clone())
-                                        } else {
-                                            ftc.surrealdb_non_id_format
-                                                .as_ref()
-                                                .cloned()
-                                                .unwrap_or_else(|| ftc.surrealdb.clone())
-                                        };
-                                        value_stack.push((type_str, false, None));
-                                    } else if let Some(enum_def) = enums.get(name) {
-                                        let enum_def = enum_def.effective();
-                                        let total_variants = enum_def.variants.len();
-                                        work_stack.push(WorkItem::AssembleEnum {
-                                            count: total_variants,
-                                        });
-
-                                        for variant in enum_def.variants.iter().rev() {
-                                            let variant = variant.effective();
-                                            if let Some(data) = &variant.data {
-                                                match data {
-                                                    VariantData::InlineStruct(s) => {
-                                                        let struct_config = app_structs.get(&s.struct_name)
-                                                        .ok_or_else(|| EvenframeError::FieldDefinition {
-                                                            message: format!("Inline enum struct '{}' should have corresponding object definition", s.struct_name),
-                                                            work_stack: format!("{:#?}", work_stack),
-                                                            value_stack: format!("{:#?}", value_stack),
-                                                            item: format!("{:#?}", item),
-                                                            visited_types: format!("{:#?}", visited_types),
-                                                        })?
-                                                        .effective();
-                                                        match &enum_def.representation {
-                                                        EnumRepresentation::ExternallyTagged => {
-                                                            // { VariantName: { fields } }
-                                                            work_stack.push(WorkItem::WrapInVariantKey { variant_name: variant.name.clone() });
-                                                            let names = struct_config.fields.iter().map(|f| f.effective().field_name.clone()).collect();
-                                                            work_stack.push(WorkItem::AssembleStruct { count: struct_config.fields.len(), names });
-                                                            for field in struct_config.fields.iter().rev() {
-                                                                work_stack.push(WorkItem::Process(&field.effective().field_type));
-                                                            }
-                                                        }
-                                                        EnumRepresentation::InternallyTagged { tag } => {
-                                                            // { tag: "VariantName", field1: type1, ... }
-                                                            let mut names = vec![tag.clone()];
-                                                            names.extend(struct_config.fields.iter().map(|f| f.effective().field_name.clone()));
-                                                            work_stack.push(WorkItem::AssembleStruct { count: struct_config.fields.len() + 1, names });
-                                                            for field in struct_config.fields.iter().rev() {
-                                                                work_stack.push(WorkItem::Process(&field.effective().field_type));
-                                                            }
-                                                            work_stack.push(WorkItem::PushString(format!("\"{}\"", variant.name)));
-                                                        }
-                                                        EnumRepresentation::AdjacentlyTagged { tag, content } => {
-                                                            // { tag: "VariantName", content: { fields } }
-                                                            let names = struct_config.fields.iter().map(|f| f.effective().field_name.clone()).collect();
-                                                            work_stack.push(WorkItem::AssembleStruct {
-                                                                count: 2,
-                                                                names: vec![tag.clone(), content.clone()],
-                                                            });
-                                                            // content value (inner struct)
-                                                            work_stack.push(WorkItem::AssembleStruct { count: struct_config.fields.len(), names });
-                                                            for field in struct_config.fields.iter().rev() {
-                                                                work_stack.push(WorkItem::Process(&field.effective().field_type));
-                                                            }
-                                                            // tag value
-                                                            work_stack.push(WorkItem::PushString(format!("\"{}\"", variant.name)));
-                                                        }
-                                                        EnumRepresentation::Untagged => {
-                                                            // { fields } (no wrapping)
-                                                            let names = struct_config.fields.iter().map(|f| f.effective().field_name.clone()).collect();
-                                                            work_stack.push(WorkItem::AssembleStruct { count: struct_config.fields.len(), names });
-                                                            for field in struct_config.fields.iter().rev() {
-                                                                work_stack.push(WorkItem::Process(&field.effective().field_type));
-                                                            }
-                                                        }
-                                                    }
-                                                    }
-                                                    VariantData::DataStructureRef(ft) => {
-                                                        match &enum_def.representation {
-                                                        EnumRepresentation::ExternallyTagged => {
-                                                            // { VariantName: value }
-                                                            work_stack.push(WorkItem::WrapInVariantKey { variant_name: variant.name.clone() });
-                                                            work_stack.push(WorkItem::Process(ft));
-                                                        }
-                                                        EnumRepresentation::AdjacentlyTagged { tag, content } => {
-                                                            // { tag: "VariantName", content: value }
-                                                            work_stack.push(WorkItem::AssembleStruct {
-                                                                count: 2,
-                                                                names: vec![tag.clone(), content.clone()],
-                                                            });
-                                                            work_stack.push(WorkItem::Process(ft));
-                                                            work_stack.push(WorkItem::PushString(format!("\"{}\"", variant.name)));
-                                                        }
-                                                        EnumRepresentation::Untagged => {
-                                                            // value (no wrapping)
-                                                            work_stack.push(WorkItem::Process(ft));
-                                                        }
-                                                        EnumRepresentation::InternallyTagged { .. } => {
-                                                            // serde does not support tuple variants with internal tagging;
-                                                            // fall back to externally tagged
-                                                            work_stack.push(WorkItem::WrapInVariantKey { variant_name: variant.name.clone() });
-                                                            work_stack.push(WorkItem::Process(ft));
+                                let resolved = find_table(persistable_structs, target).map_or_else(
+                                    || TableCase::default().apply(target),
+                                    |(_, tc)| tc.effective().table_name.clone(),
+                                );
+                                value_stack.push((
+                                    format!("record<{}>", surql_ident(&resolved)),
+                                    false,
+                                    None,
+                                ));
+                            } else {
+                                work_stack.push(WorkItem::Process(inner));
+                            }
+                        }
+                        FieldType::Tuple(types) => {
+                            work_stack.push(WorkItem::AssembleTuple { count: types.len() });
+                            for t in types.iter().rev() {
+                                work_stack.push(WorkItem::Process(t));
+                            }
+                        }
+                        FieldType::Struct(fields) => {
+                            let names = fields.iter().map(|(name, _)| name.clone()).collect();
+                            work_stack.push(WorkItem::AssembleStruct {
+                                count: fields.len(),
+                                names,
+                            });
+                            for (_, ftype) in fields.iter().rev() {
+                                work_stack.push(WorkItem::Process(ftype));
+                            }
+                        }
+                        FieldType::Enum(name)
+                        | FieldType::Object(name)
+                        | FieldType::Other(name) => {
+                            // Check foreign type registry first
+                            if let Some(ftc) = registry.lookup(name) {
+                                let type_str = if self.field_name == "id" {
+                                    ftc.surrealdb_id_format
+                                        .as_ref()
+                                        .map(|fmt| fmt.replace("{table_name}", table_name))
+                                        .unwrap_or_else(|| ftc.surrealdb.clone())
+                                } else {
+                                    ftc.surrealdb_non_id_format
+                                        .as_ref()
+                                        .cloned()
+                                        .unwrap_or_else(|| ftc.surrealdb.clone())
+                                };
+                                value_stack.push((type_str, false, None));
+                            } else if let Some(enum_def) = enums.get(name) {
+                                let enum_def = enum_def.effective();
+                                let total_variants = enum_def.variants.len();
+                                work_stack.push(WorkItem::AssembleEnum {
+                                    count: total_variants,
+                                });
+
+                                for variant in enum_def.variants.iter().rev() {
+                                    let variant = variant.effective();
+                                    if let Some(data) = &variant.data {
+                                        match data {
+                                            VariantData::InlineStruct(s) => {
+                                                let struct_config = app_structs.get(&s.struct_name)
+                                                .ok_or_else(|| EvenframeError::FieldDefinition {
+                                                    message: format!("Inline enum struct '{}' should have corresponding object definition", s.struct_name),
+                                                    work_stack: format!("{:#?}", work_stack),
+                                                    value_stack: format!("{:#?}", value_stack),
+                                                    item: format!("{:#?}", item),
+                                                    visited_types: format!("{:#?}", visited_types),
+                                                })?
+                                                .effective();
+                                                match &enum_def.representation {
+                                                    EnumRepresentation::ExternallyTagged => {
+                                                        // { VariantName: { fields } }
+                                                        work_stack.push(
+                                                            WorkItem::WrapInVariantKey {
+                                                                variant_name: variant.name.clone(),
+                                                            },
+                                                        );
+                                                        let names = struct_config
+                                                            .fields
+                                                            .iter()
+                                                            .map(|f| {
+                                                                f.effective()
+                                                                    .output_name()
+                                                                    .to_string()
+                                                            })
+                                                            .collect();
+                                                        work_stack.push(WorkItem::AssembleStruct {
+                                                            count: struct_config.fields.len(),
+                                                            names,
+                                                        });
+                                                        for field in
+                                                            struct_config.fields.iter().rev()
+                                                        {
+                                                            work_stack.push(WorkItem::Process(
+                                                                &field.effective().field_type,
+                                                            ));
                                                         }
                                                     }
-                                                    }
-                                                }
-                                            } else {
-                                                // Unit variant
-                                                match &enum_def.representation {
                                                     EnumRepresentation::InternallyTagged {
                                                         tag,
                                                     } => {
-                                                        // { tag: "VariantName" }
+                                                        // { tag: "VariantName", field1: type1, ... }
+                                                        let mut names = vec![tag.clone()];
+                                                        names.extend(
+                                                            struct_config.fields.iter().map(|f| {
+                                                                f.effective()
+                                                                    .output_name()
+                                                                    .to_string()
+                                                            }),
+                                                        );
                                                         work_stack.push(WorkItem::AssembleStruct {
-                                                            count: 1,
-                                                            names: vec![tag.clone()],
+                                                            count: struct_config.fields.len() + 1,
+                                                            names,
                                                         });
+                                                        for field in
+                                                            struct_config.fields.iter().rev()
+                                                        {
+                                                            work_stack.push(WorkItem::Process(
+                                                                &field.effective().field_type,
+                                                            ));
+                                                        }
                                                         work_stack.push(WorkItem::PushString(
                                                             format!("\"{}\"", variant.name),
                                                         ));
                                                     }
                                                     EnumRepresentation::AdjacentlyTagged {
                                                         tag,
-                                                        ..
+                                                        content,
                                                     } => {
-                                                        // { tag: "VariantName" }
+                                                        // { tag: "VariantName", content: { fields } }
+                                                        let names = struct_config
+                                                            .fields
+                                                            .iter()
+                                                            .map(|f| {
+                                                                f.effective()
+                                                                    .output_name()
+                                                                    .to_string()
+                                                            })
+                                                            .collect();
+                                                        work_stack.push(WorkItem::AssembleStruct {
+                                                            count: 2,
+                                                            names: vec![
+                                                                tag.clone(),
+                                                                content.clone(),
+                                                            ],
+                                                        });
+                                                        // content value (inner struct)
                                                         work_stack.push(WorkItem::AssembleStruct {
-                                                            count: 1,
-                                                            names: vec![tag.clone()],
+                                                            count: struct_config.fields.len(),
+                                                            names,
                                                         });
+                                                        for field in
+                                                            struct_config.fields.iter().rev()
+                                                        {
+                                                            work_stack.push(WorkItem::Process(
+                                                                &field.effective().field_type,
+                                                            ));
+                                                        }
+                                                        // tag value
                                                         work_stack.push(WorkItem::PushString(
                                                             format!("\"{}\"", variant.name),
                                                         ));
                                                     }
-                                                    _ => {
-                                                        // ExternallyTagged / Untagged: "VariantName"
+                                                    EnumRepresentation::Untagged => {
+                                                        // { fields } (no wrapping)
+                                                        let names = struct_config
+                                                            .fields
+                                                            .iter()
+                                                            .map(|f| {
+                                                                f.effective()
+                                                                    .output_name()
+                                                                    .to_string()
+                                                            })
+                                                            .collect();
+                                                        work_stack.push(WorkItem::AssembleStruct {
+                                                            count: struct_config.fields.len(),
+                                                            names,
+                                                        });
+                                                        for field in
+                                                            struct_config.fields.iter().rev()
+                                                        {
+                                                            work_stack.push(WorkItem::Process(
+                                                                &field.effective().field_type,
+                                                            ));
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                            VariantData::DataStructureRef(ft) => {
+                                                match &enum_def.representation {
+                                                    EnumRepresentation::ExternallyTagged => {
+                                                        // { VariantName: value }
+                                                        work_stack.push(
+                                                            WorkItem::WrapInVariantKey {
+                                                                variant_name: variant.name.clone(),
+                                                            },
+                                                        );
+                                                        work_stack.push(WorkItem::Process(ft));
+                                                    }
+                                                    EnumRepresentation::AdjacentlyTagged {
+                                                        tag,
+                                                        content,
+                                                    } => {
+                                                        // { tag: "VariantName", content: value }
+                                                        work_stack.push(WorkItem::AssembleStruct {
+                                                            count: 2,
+                                                            names: vec![
+                                                                tag.clone(),
+                                                                content.clone(),
+                                                            ],
+                                                        });
+                                                        work_stack.push(WorkItem::Process(ft));
                                                         work_stack.push(WorkItem::PushString(
                                                             format!("\"{}\"", variant.name),
                                                         ));
                                                     }
+                                                    EnumRepresentation::Untagged => {
+                                                        // value (no wrapping)
+                                                        work_stack.push(WorkItem::Process(ft));
+                                                    }
+                                                    EnumRepresentation::InternallyTagged {
+                                                        ..
+                                                    } => {
+                                                        // serde does not support tuple variants with internal tagging;
+                                                        // fall back to externally tagged
+                                                        work_stack.push(
+                                                            WorkItem::WrapInVariantKey {
+                                                                variant_name: variant.name.clone(),
+                                                            },
+                                                        );
+                                                        work_stack.push(WorkItem::Process(ft));
+                                                    }
                                                 }
                                             }
                                         }
-                                    } else if let Some(app_struct) = app_structs.get(name) {
-                                        let app_struct = app_struct.effective();
-                                        // If the effective struct is itself a registered
-                                        // table (e.g. a synthetic projection that
-                                        // overrides to the underlying table), emit a
-                                        // record<> reference instead of inlining the
-                                        // struct's fields.
-                                        let effective_snake =
-                                            app_struct.struct_name.to_case(Case::Snake);
-                                        if persistable_structs.contains_key(&effective_snake) {
-                                            let resolved = persistable_structs
-                                                .get(&effective_snake)
-                                                .map(|tc| tc.effective().table_name.clone())
-                                                .unwrap_or(effective_snake);
-                                            value_stack.push((
-                                                format!("record<{}>", resolved),
-                                                false,
-                                                None,
-                                            ));
-                                        } else {
-                                            if visited_types.contains(name) {
-                                                value_stack.push(("object".to_string(), false, None));
-                                                continue;
+                                    } else {
+                                        // Unit variant
+                                        match &enum_def.representation {
+                                            EnumRepresentation::InternallyTagged { tag } => {
+                                                // { tag: "VariantName" }
+                                                work_stack.push(WorkItem::AssembleStruct {
+                                                    count: 1,
+                                                    names: vec![tag.clone()],
+                                                });
+                                                work_stack.push(WorkItem::PushString(format!(
+                                                    "\"{}\"",
+                                                    variant.name
+                                                )));
+                                            }
+                                            EnumRepresentation::AdjacentlyTagged {
+                                                tag, ..
+                                            } => {
+                                                // { tag: "VariantName" }
+                                                work_stack.push(WorkItem::AssembleStruct {
+                                                    count: 1,
+                                                    names: vec![tag.clone()],
+                                                });
+                                                work_stack.push(WorkItem::PushString(format!(
+                                                    "\"{}\"",
+                                                    variant.name
+                                                )));
                                             }
-                                            work_stack.push(WorkItem::LeaveStructScope {
-                                                name: name.clone(),
-                                            });
-                                            let names = app_struct
-                                                .fields
-                                                .iter()
-                                                .map(|f| f.effective().field_name.clone())
-                                                .collect();
-                                            work_stack.push(WorkItem::AssembleStruct {
-                                                count: app_struct.fields.len(),
-                                                names,
-                                            });
-                                            for field in app_struct.fields.iter().rev() {
-                                                work_stack
-                                                    .push(WorkItem::Process(&field.effective().field_type));
+                                            _ => {
+                                                // ExternallyTagged / Untagged: "VariantName"
+                                                work_stack.push(WorkItem::PushString(format!(
+                                                    "\"{}\"",
+                                                    variant.name
+                                                )));
                                             }
-                                            work_stack.push(WorkItem::EnterStructScope {
-                                                name: name.clone(),
-                                            });
                                         }
-                                    } else if let Some(tc) =
-                                        persistable_structs.get(&name.to_case(Case::Snake))
-                                    {
-                                        let resolved = tc.effective().table_name.clone();
-                                        value_stack.push((
-                                            format!("record<{}>", resolved),
-                                            false,
-                                            None,
-                                        ));
-                                    } else {
-                                        value_stack.push((name.clone(), false, None));
                                     }
                                 }
+                            } else if let Some(app_struct) = app_structs.get(name) {
+                                let app_struct = app_struct.effective();
+                                // If the effective struct is itself a registered
+                                // table (e.g. a synthetic projection that
+                                // overrides to the underlying table), emit a
+                                // record<> reference instead of inlining the
+                                // struct's fields.
+                                if let Some((_, tc)) =
+                                    find_table(persistable_structs, &app_struct.struct_name)
+                                {
+                                    let resolved = tc.effective().table_name.clone();
+                                    value_stack.push((
+                                        format!("record<{}>", surql_ident(&resolved)),
+                                        false,
+                                        None,
+                                    ));
+                                } else {
+                                    if visited_types.contains(name) {
+                                        value_stack.push(("object".to_string(), false, None));
+                                        continue;
+                                    }
+                                    work_stack
+                                        .push(WorkItem::LeaveStructScope { name: name.clone() });
+                                    let names = app_struct
+                                        .fields
+                                        .iter()
+                                        .map(|f| f.effective().output_name().to_string())
+                                        .collect();
+                                    work_stack.push(WorkItem::AssembleStruct {
+                                        count: app_struct.fields.len(),
+                                        names,
+                                    });
+                                    for field in app_struct.fields.iter().rev() {
+                                        work_stack
+                                            .push(WorkItem::Process(&field.effective().field_type));
+                                    }
+                                    work_stack
+                                        .push(WorkItem::EnterStructScope { name: name.clone() });
+                                }
+                            } else if let Some((_, tc)) = find_table(persistable_structs, name) {
+                                let resolved = tc.effective().table_name.clone();
+                                value_stack.push((
+                                    format!("record<{}>", surql_ident(&resolved)),
+                                    false,
+                                    None,
+                                ));
+                            } else {
+                                value_stack.push((name.clone(), false, None));
                             }
                         }
-                        WorkItem::PushString(s) => {
-                            value_stack.push((s, false, None));
-                        }
-                        WorkItem::AssembleOption => {
-                            let (inner_type, needs_wildcard, wildcard_type) = value_stack
+                    }
+                }
+                WorkItem::PushString(s) => {
+                    value_stack.push((s, false, None));
+                }
+                WorkItem::AssembleOption => {
+                    let (inner_type, needs_wildcard, wildcard_type) = value_stack
+                        .pop()
+                        .ok_or_else(|| EvenframeError::FieldDefinition {
+                            message: "Stack underflow in AssembleOption".to_string(),
+                            work_stack: format!("{:#?}", work_stack),
+                            value_stack: format!("{:#?}", value_stack),
+                            item: "AssembleOption".to_string(),
+                            visited_types: format!("{:#?}", visited_types),
+                        })?;
+                    value_stack.push((
+                        format!("option<{}>", inner_type),
+                        needs_wildcard,
+                        wildcard_type,
+                    ));
+                }
+                WorkItem::AssembleVec => {
+                    let (inner_type, _, _) =
+                        value_stack
+                            .pop()
+                            .ok_or_else(|| EvenframeError::FieldDefinition {
+                                message: "Stack underflow in AssembleVec".to_string(),
+                                work_stack: format!("{:#?}", work_stack),
+                                value_stack: format!("{:#?}", value_stack),
+                                item: "AssembleVec".to_string(),
+                                visited_types: format!("{:#?}", visited_types),
+                            })?;
+                    value_stack.push((format!("array<{}>", inner_type), false, None));
+                }
+                WorkItem::AssembleSet => {
+                    let (inner_type, _, _) =
+                        value_stack
+                            .pop()
+                            .ok_or_else(|| EvenframeError::FieldDefinition {
+                                message: "Stack underflow in AssembleSet".to_string(),
+                                work_stack: format!("{:#?}", work_stack),
+                                value_stack: format!("{:#?}", value_stack),
+                                item: "AssembleSet".to_string(),
+                                visited_types: format!("{:#?}", visited_types),
+                            })?;
+                    value_stack.push((format!("set<{}>", inner_type), false, None));
+                }
+                WorkItem::AssembleArray { len } => {
+                    let (inner_type, _, _) =
+                        value_stack
+                            .pop()
+                            .ok_or_else(|| EvenframeError::FieldDefinition {
+                                message: "Stack underflow in AssembleArray".to_string(),
+                                work_stack: format!("{:#?}", work_stack),
+                                value_stack: format!("{:#?}", value_stack),
+                                item: "AssembleArray".to_string(),
+                                visited_types: format!("{:#?}", visited_types),
+                            })?;
+                    value_stack.push((format!("array<{}, {}>", inner_type, len), false, None));
+                }
+                WorkItem::AssembleMap => {
+                    let (value_type, _, _) =
+                        value_stack
+                            .pop()
+                            .ok_or_else(|| EvenframeError::FieldDefinition {
+                                message: "Stack underflow in AssembleMap".to_string(),
+                                work_stack: format!("{:#?}", work_stack),
+                                value_stack: format!("{:#?}", value_stack),
+                                item: "AssembleMap".to_string(),
+                                visited_types: format!("{:#?}", visited_types),
+                            })?;
+                    value_stack.push(("object".to_string(), true, Some(value_type)));
+                }
+                WorkItem::AssembleTuple { count } => {
+                    let mut items = Vec::with_capacity(count);
+                    for _ in 0..count {
+                        items.push(
+                            value_stack
                                 .pop()
                                 .ok_or_else(|| EvenframeError::FieldDefinition {
-                                    message: "Stack underflow in AssembleOption".to_string(),
+                                    message: "Stack underflow in AssembleTuple".to_string(),
                                     work_stack: format!("{:#?}", work_stack),
                                     value_stack: format!("{:#?}", value_stack),
-                                    item: "AssembleOption".to_string(),
+                                    item: "AssembleTuple".to_string(),
                                     visited_types: format!("{:#?}", visited_types),
-                                })?;
-                            value_stack.push((
-                                format!("null | {}", inner_type),
-                                needs_wildcard,
-                                wildcard_type,
-                            ));
-                        }
-                        WorkItem::AssembleVec => {
-                            let (inner_type, _, _) = value_stack.pop().ok_or_else(|| {
-                                EvenframeError::FieldDefinition {
-                                    message: "Stack underflow in AssembleVec".to_string(),
-                                    work_stack: format!("{:#?}", work_stack),
-                                    value_stack: format!("{:#?}", value_stack),
-                                    item: "AssembleVec".to_string(),
-                                    visited_types: format!("{:#?}", visited_types),
-                                }
-                            })?;
-                            value_stack.push((format!("array<{}>", inner_type), false, None));
-                        }
-                        WorkItem::AssembleMap => {
-                            let (value_type, _, _) = value_stack.pop().ok_or_else(|| {
-                                EvenframeError::FieldDefinition {
-                                    message: "Stack underflow in AssembleMap".to_string(),
+                                })?
+                                .0,
+                        );
+                    }
+                    items.reverse();
+                    value_stack.push((format!("[{}]", items.join(", ")), false, None));
+                }
+                WorkItem::AssembleStruct { count, names } => {
+                    let mut items = Vec::with_capacity(count);
+                    for i in 0..count {
+                        let (field_type, _, _) =
+                            value_stack
+                                .pop()
+                                .ok_or_else(|| EvenframeError::FieldDefinition {
+                                    message: "Stack underflow in AssembleStruct".to_string(),
                                     work_stack: format!("{:#?}", work_stack),
                                     value_stack: format!("{:#?}", value_stack),
-                                    item: "AssembleMap".to_string(),
+                                    item: "AssembleStruct".to_string(),
                                     visited_types: format!("{:#?}", visited_types),
-                                }
-                            })?;
-                            value_stack.push(("object".to_string(), true, Some(value_type)));
-                        }
-                        WorkItem::AssembleTuple { count } => {
-                            let mut items = Vec::with_capacity(count);
-                            for _ in 0..count {
-                                items.push(
-                                    value_stack
-                                        .pop()
-                                        .ok_or_else(|| EvenframeError::FieldDefinition {
-                                            message: "Stack underflow in AssembleTuple".to_string(),
-                                            work_stack: format!("{:#?}", work_stack),
-                                            value_stack: format!("{:#?}", value_stack),
-                                            item: "AssembleTuple".to_string(),
-                                            visited_types: format!("{:#?}", visited_types),
-                                        })?
-                                        .0,
-                                );
-                            }
-                            items.reverse();
-                            value_stack.push((format!("[{}]", items.join(", ")), false, None));
-                        }
-                        WorkItem::AssembleStruct { count, names } => {
-                            let mut items = Vec::with_capacity(count);
-                            for i in 0..count {
-                                let (field_type, _, _) = value_stack.pop().ok_or_else(|| {
-                                    EvenframeError::FieldDefinition {
-                                        message: "Stack underflow in AssembleStruct".to_string(),
-                                        work_stack: format!("{:#?}", work_stack),
-                                        value_stack: format!("{:#?}", value_stack),
-                                        item: "AssembleStruct".to_string(),
-                                        visited_types: format!("{:#?}", visited_types),
-                                    }
                                 })?;
-                                items.push(format!("{}: {}", names[count - 1 - i], field_type));
-                            }
-                            items.reverse();
-                            value_stack.push((format!("{{ {} }}", items.join(", ")), false, None));
-                        }
-                        WorkItem::AssembleEnum { count } => {
-                            let mut variants = Vec::with_capacity(count);
-                            for _ in 0..count {
-                                variants.push(
-                                    value_stack
-                                        .pop()
-                                        .ok_or_else(|| EvenframeError::FieldDefinition {
-                                            message: "Stack underflow in AssembleEnum".to_string(),
-                                            work_stack: format!("{:#?}", work_stack),
-                                            value_stack: format!("{:#?}", value_stack),
-                                            item: "AssembleEnum".to_string(),
-                                            visited_types: format!("{:#?}", visited_types),
-                                        })?
-                                        .0,
-                                );
-                            }
-                            variants.reverse();
-                            value_stack.push((variants.join(" | "), false, None));
-                        }
-                        WorkItem::WrapInVariantKey { variant_name } => {
-                            let (inner, _, _) = value_stack.pop().ok_or_else(|| {
-                                EvenframeError::FieldDefinition {
-                                    message: "Stack underflow in WrapInVariantKey".to_string(),
+                        items.push(format!("{}: {}", names[count - 1 - i], field_type));
+                    }
+                    items.reverse();
+                    value_stack.push((format!("{{ {} }}", items.join(", ")), false, None));
+                }
+                WorkItem::AssembleEnum { count } => {
+                    let mut variants = Vec::with_capacity(count);
+                    for _ in 0..count {
+                        variants.push(
+                            value_stack
+                                .pop()
+                                .ok_or_else(|| EvenframeError::FieldDefinition {
+                                    message: "Stack underflow in AssembleEnum".to_string(),
                                     work_stack: format!("{:#?}", work_stack),
                                     value_stack: format!("{:#?}", value_stack),
-                                    item: "WrapInVariantKey".to_string(),
+                                    item: "AssembleEnum".to_string(),
                                     visited_types: format!("{:#?}", visited_types),
-                                }
-                            })?;
-                            value_stack.push((
-                                format!("{{ {}: {} }}", variant_name, inner),
-                                false,
-                                None,
-                            ));
-                        }
-                        WorkItem::EnterStructScope { name } => {
-                            visited_types.insert(name);
-                        }
-                        WorkItem::LeaveStructScope { name } => {
-                            visited_types.remove(&name);
-                        }
+                                })?
+                                .0,
+                        );
                     }
+                    variants.reverse();
+                    value_stack.push((variants.join(" | "), false, None));
                 }
-                value_stack
-                    .pop()
-                    .ok_or_else(|| EvenframeError::FieldDefinition {
-                        message: "Final stack underflow".to_string(),
-                        work_stack: format!("{:#?}", work_stack),
-                        value_stack: format!("{:#?}", value_stack),
-                        item: "(item out of scope)".to_string(),
-                        visited_types: format!("{:#?}", visited_types),
-                    })
-            };
+                WorkItem::WrapInVariantKey { variant_name } => {
+                    let (inner, _, _) =
+                        value_stack
+                            .pop()
+                            .ok_or_else(|| EvenframeError::FieldDefinition {
+                                message: "Stack underflow in WrapInVariantKey".to_string(),
+                                work_stack: format!("{:#?}", work_stack),
+                                value_stack: format!("{:#?}", value_stack),
+                                item: "WrapInVariantKey".to_string(),
+                                visited_types: format!("{:#?}", visited_types),
+                            })?;
+                    value_stack.push((format!("{{ {}: {} }}", variant_name, inner), false, None));
+                }
+                WorkItem::EnterStructScope { name } => {
+                    visited_types.insert(name);
+                }
+                WorkItem::LeaveStructScope { name } => {
+                    visited_types.remove(&name);
+                }
+            }
+        }
+        value_stack
+            .pop()
+            .ok_or_else(|| EvenframeError::FieldDefinition {
+                message: "Final stack underflow".to_string(),
+                work_stack: format!("{:#?}", work_stack),
+                value_stack: format!("{:#?}", value_stack),
+                item: "(item out of scope)".to_string(),
+                visited_types: format!("{:#?}", visited_types),
+            })
+    }
+
+    /// The SurrealDB type this field's `DEFINE FIELD` statement declares: the
+    /// explicit `data_type` when one is set, otherwise the converted type.
+    #[cfg(feature = "surrealdb")]
+    pub fn surql_type(
+        &self,
+        enums: &BTreeMap<String, TaggedUnion>,
+        app_structs: &BTreeMap<String, StructConfig>,
+        persistable_structs: &BTreeMap<String, TableConfig>,
+        table_name: &str,
+        registry: &ForeignTypeRegistry,
+    ) -> Result<String> {
+        if let Some(data_type) = self
+            .define_config
+            .as_ref()
+            .and_then(|def| def.data_type.as_ref())
+        {
+            return Ok(data_type.clone());
+        }
+        let (type_str, _, _) = self.convert_type_iteratively(
+            &self.field_type,
+            enums,
+            app_structs,
+            persistable_structs,
+            table_name,
+            registry,
+        )?;
+        Ok(type_str)
+    }
+
+    #[cfg(feature = "surrealdb")]
+    pub fn generate_define_statement(
+        &self,
+        enums: BTreeMap<String, TaggedUnion>,
+        app_structs: BTreeMap<String, StructConfig>,
+        persistable_structs: BTreeMap<String, TableConfig>,
+        table_name: &String,
+        registry: &ForeignTypeRegistry,
+    ) -> Result<String> {
+        evenframe_log!(
+            format!(
+                "Generating define statements for:\nEnums: {:#?}\nApp structs: {:#?}\nTables: {:#?}",
+                enums.keys(),
+                app_structs.keys(),
+                persistable_structs.keys()
+            ),
+            "define_generation.log"
+        );
 
+        let convert_type_iteratively = |field_type: &FieldType| {
+            self.convert_type_iteratively(
+                field_type,
+                &enums,
+                &app_structs,
+                &persistable_structs,
+                table_name,
+                registry,
+            )
+        };
+
+        let define_mode = persistable_structs
+            .get(table_name)
+            .map_or("OVERWRITE", |table| table.effective().define_field_mode());
         let mut stmt = format!(
-            "DEFINE FIELD OVERWRITE {} ON TABLE {}",
-            self.field_name, table_name
+            "DEFINE FIELD {define_mode} {} ON TABLE {}",
+            self.output_name(),
+            surql_ident(table_name)
         );
 
         // Handle computed fields (SurrealDB 3.0 COMPUTED syntax)
@@ -735,8 +1034,8 @@ impl StructField {
                 stmt.push_str(&format!(" PERMISSIONS {}", permissions.join(" ")));
             }
 
-            if let Some(ref comment_str) = def.comment {
-                stmt.push_str(&format!(" COMMENT '{}'", comment_str.replace('\'', "\\'")));
+            if let Some(comment) = self.comment() {
+                stmt.push_str(&comment_clause(comment));
             }
 
             stmt.push_str(";\n");
@@ -796,10 +1095,10 @@ impl StructField {
             if let Some(ref val) = def.value {
                 stmt.push_str(&format!(" VALUE {}", val));
             }
+        }
 
-            if let Some(ref assert_val) = def.assert {
-                stmt.push_str(&format!(" ASSERT {}", assert_val));
-            }
+        if let Some(assert_val) = self.assert_clause() {
+            stmt.push_str(&format!(" ASSERT {}", assert_val));
         }
 
         if let Some(ref def) = self.define_config {
@@ -819,9 +1118,10 @@ impl StructField {
                 stmt.push_str(&format!(" PERMISSIONS {}", permissions.join(" ")));
             }
 
-            if let Some(ref comment_str) = def.comment {
-                stmt.push_str(&format!(" COMMENT '{}'", comment_str.replace('\'', "\\'")));
-            }
+        }
+
+        if let Some(comment) = self.comment() {
+            stmt.push_str(&comment_clause(comment));
         }
 
         stmt.push_str(";\n");
@@ -830,8 +1130,10 @@ impl StructField {
             && needs_wildcard
         {
             stmt.push_str(&format!(
-                "DEFINE FIELD OVERWRITE {}.* ON TABLE {} TYPE {};\n",
-                self.field_name, table_name, wildcard_value_type
+                "DEFINE FIELD {define_mode} {}.* ON TABLE {} TYPE {};\n",
+                self.output_name(),
+                surql_ident(table_name),
+                wildcard_value_type
             ));
         }
 
@@ -846,6 +1148,9 @@ pub struct StructConfig {
     pub validators: Vec<Validator>,
     #[serde(default)]
     pub doccom: Option<String>,
+    /// Text of the struct's `///` doc comment, emitted as its table's COMMENT.
+    #[serde(default)]
+    pub doc: Option<String>,
     #[serde(default)]
     pub macroforge_derives: Vec<String>,
     #[serde(default)]
@@ -1068,6 +1373,7 @@ mod tests {
             fields: vec![],
             validators: vec![],
             doccom: None,
+            doc: None,
             macroforge_derives: vec![],
             annotations: vec![],
             pipeline: Pipeline::default(),
@@ -1104,6 +1410,7 @@ mod tests {
             fields: vec![],
             validators: vec![],
             doccom: None,
+            doc: None,
             macroforge_derives: vec![],
             annotations: vec![],
             pipeline: Pipeline::default(),
@@ -1148,9 +1455,13 @@ mod tests {
             validators: vec![],
             always_regenerate: false,
             doccom: None,
+            doc: None,
             annotations: vec![],
             unique: false,
+            indexed: false,
+            search_analyzer: None,
             mock_plugin: None,
+            rename: None,
             output_override: None,
             raw_attributes: BTreeMap::new(),
         };
@@ -1167,6 +1478,7 @@ mod tests {
             fields: vec![],
             validators: vec![],
             doccom: None,
+            doc: None,
             macroforge_derives: vec![],
             annotations: vec![],
             pipeline: Pipeline::default(),
@@ -1191,9 +1503,13 @@ mod tests {
                     validators: vec![],
                     always_regenerate: false,
                     doccom: None,
+                    doc: None,
                     annotations: vec![],
                     unique: false,
+                    indexed: false,
+                    search_analyzer: None,
                     mock_plugin: None,
+                    rename: None,
                     output_override: None,
                     raw_attributes: BTreeMap::new(),
                 },
@@ -1206,15 +1522,20 @@ mod tests {
                     validators: vec![],
                     always_regenerate: false,
                     doccom: None,
+                    doc: None,
                     annotations: vec![],
                     unique: false,
+                    indexed: false,
+                    search_analyzer: None,
                     mock_plugin: None,
+                    rename: None,
                     output_override: None,
                     raw_attributes: BTreeMap::new(),
                 },
             ],
             validators: vec![],
             doccom: None,
+            doc: None,
             macroforge_derives: vec![],
             annotations: vec![],
             pipeline: Pipeline::default(),
@@ -1232,6 +1553,7 @@ mod tests {
             fields: vec![],
             validators: vec![],
             doccom: None,
+            doc: None,
             macroforge_derives: vec![],
             annotations: vec![],
             pipeline: Pipeline::default(),
@@ -1361,6 +1683,7 @@ mod tests {
             fields: vec![],
             validators: vec![],
             doccom: None,
+            doc: None,
             macroforge_derives: vec![],
             annotations: vec![],
             pipeline: Pipeline::default(),
@@ -1392,9 +1715,13 @@ mod tests {
             validators: vec![Validator::StringValidator(StringValidator::Email)],
             always_regenerate: false,
             doccom: None,
+            doc: None,
             annotations: vec![],
             unique: false,
+            indexed: false,
+            search_analyzer: None,
             mock_plugin: None,
+            rename: None,
             output_override: None,
             raw_attributes: BTreeMap::new(),
         };
@@ -1531,24 +1858,20 @@ mod tests {
                 select_permissions: Some("FULL".to_string()),
                 update_permissions: Some("FULL".to_string()),
                 create_permissions: Some("FULL".to_string()),
-                data_type: None,
-                should_skip: false,
-                default: None,
-                default_always: None,
-                value: None,
-                assert: None,
-                readonly: None,
                 flexible: Some(false),
-                computed: None,
-                comment: None,
+                ..Default::default()
             }),
             format: None,
             validators: vec![],
             always_regenerate: false,
             doccom: None,
+            doc: None,
             annotations: vec![],
             unique: false,
+            indexed: false,
+            search_analyzer: None,
             mock_plugin: None,
+            rename: None,
             output_override: None,
             raw_attributes: BTreeMap::new(),
         };
@@ -1559,6 +1882,7 @@ mod tests {
             fields: vec![],
             validators: vec![],
             doccom: None,
+            doc: None,
             macroforge_derives: vec![],
             annotations: vec![],
             pipeline: Pipeline::Typesync,
@@ -1577,12 +1901,7 @@ mod tests {
                 struct_name: "User".to_string(),
                 ..StructConfig::default()
             },
-            relation: None,
-            permissions: None,
-            mock_generation_config: None,
-            events: vec![],
-            indexes: vec![],
-            output_override: None,
+            ..Default::default()
         };
 
         let mut app_structs = BTreeMap::new();
@@ -1628,24 +1947,20 @@ mod tests {
                 select_permissions: Some("FULL".to_string()),
                 update_permissions: Some("FULL".to_string()),
                 create_permissions: Some("FULL".to_string()),
-                data_type: None,
-                should_skip: false,
-                default: None,
-                default_always: None,
-                value: None,
-                assert: None,
-                readonly: None,
                 flexible: Some(false),
-                computed: None,
-                comment: None,
+                ..Default::default()
             }),
             format: None,
             validators: vec![],
             always_regenerate: false,
             doccom: None,
+            doc: None,
             annotations: vec![],
             unique: false,
+            indexed: false,
+            search_analyzer: None,
             mock_plugin: None,
+            rename: None,
             output_override: None,
             raw_attributes: BTreeMap::new(),
         };
@@ -1665,4 +1980,451 @@ mod tests {
             "without override, expected literal `record<partial_user>`; got: {stmt}"
         );
     }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_emits_set_type() {
+        let field = StructField {
+            field_name: "tags".to_string(),
+            field_type: FieldType::Set(Box::new(FieldType::String)),
+            ..StructField::default()
+        };
+
+        let stmt = field
+            .generate_define_statement(
+                BTreeMap::new(),
+                BTreeMap::new(),
+                BTreeMap::new(),
+                &"post".to_string(),
+                &ForeignTypeRegistry::default(),
+            )
+            .expect("generate_define_statement should succeed");
+
+        assert!(
+            stmt.starts_with("DEFINE FIELD OVERWRITE tags ON TABLE post TYPE set<string>"),
+            "expected `set<string>` for a HashSet<String> field; got: {stmt}"
+        );
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_emits_any_for_json() {
+        let field = StructField {
+            field_name: "metadata".to_string(),
+            field_type: FieldType::Json,
+            ..StructField::default()
+        };
+
+        let stmt = field
+            .generate_define_statement(
+                BTreeMap::new(),
+                BTreeMap::new(),
+                BTreeMap::new(),
+                &"post".to_string(),
+                &ForeignTypeRegistry::default(),
+            )
+            .expect("generate_define_statement should succeed");
+
+        assert!(
+            stmt.starts_with("DEFINE FIELD OVERWRITE metadata ON TABLE post TYPE any;"),
+            "expected `any` for a serde_json::Value field; got: {stmt}"
+        );
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_emits_bytes() {
+        let define = |field_type: FieldType| {
+            StructField {
+                field_name: "avatar".to_string(),
+                field_type,
+                ..StructField::default()
+            }
+            .generate_define_statement(
+                BTreeMap::new(),
+                BTreeMap::new(),
+                BTreeMap::new(),
+                &"user".to_string(),
+                &ForeignTypeRegistry::default(),
+            )
+            .expect("generate_define_statement should succeed")
+        };
+
+        let stmt = define(FieldType::Bytes);
+        assert!(
+            stmt.starts_with("DEFINE FIELD OVERWRITE avatar ON TABLE user TYPE bytes;"),
+            "expected `bytes` for a Vec<u8> field; got: {stmt}"
+        );
+
+        let stmt = define(FieldType::Option(Box::new(FieldType::Bytes)));
+        assert!(
+            stmt.contains("TYPE option<bytes>;"),
+            "expected optional `bytes` for an Option<Vec<u8>> field; got: {stmt}"
+        );
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_emits_option_array() {
+        let field = StructField {
+            field_name: "scores".to_string(),
+            field_type: FieldType::Option(Box::new(FieldType::Vec(Box::new(FieldType::I32)))),
+            ..StructField::default()
+        };
+
+        assert_eq!(
+            define(&field),
+            "DEFINE FIELD OVERWRITE scores ON TABLE user TYPE option<array<int>>;\n"
+        );
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_emits_option_record_link() {
+        let field = StructField {
+            field_name: "reviewer".to_string(),
+            field_type: FieldType::Option(Box::new(FieldType::RecordLink(Box::new(
+                FieldType::Other("User".to_string()),
+            )))),
+            ..StructField::default()
+        };
+
+        assert_eq!(
+            define(&field),
+            "DEFINE FIELD OVERWRITE reviewer ON TABLE user TYPE option<record<user>>;\n"
+        );
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_emits_option_map_with_wildcard() {
+        let field = StructField {
+            field_name: "scores".to_string(),
+            field_type: FieldType::Option(Box::new(FieldType::HashMap(
+                Box::new(FieldType::String),
+                Box::new(FieldType::I32),
+            ))),
+            ..StructField::default()
+        };
+
+        assert_eq!(
+            define(&field),
+            "DEFINE FIELD OVERWRITE scores ON TABLE user TYPE option<object>;\n\
+             DEFINE FIELD OVERWRITE scores.* ON TABLE user TYPE int;\n"
+        );
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_emits_fixed_array_length() {
+        let field = StructField {
+            field_name: "checksum".to_string(),
+            field_type: FieldType::parse_syn_ty(&syn::parse_quote!([u8; 16])),
+            ..StructField::default()
+        };
+
+        assert_eq!(
+            define(&field),
+            "DEFINE FIELD OVERWRITE checksum ON TABLE user TYPE array<int, 16>;\n"
+        );
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[tokio::test]
+    async fn test_optional_define_statements_accept_none_in_surrealdb() {
+        use surrealdb::{Surreal, engine::local::Mem};
+
+        let optional = |field_name: &str, inner: FieldType| StructField {
+            field_name: field_name.to_string(),
+            field_type: FieldType::Option(Box::new(inner)),
+            ..StructField::default()
+        };
+        let list = optional("list", FieldType::Vec(Box::new(FieldType::I32)));
+        let map = optional(
+            "map",
+            FieldType::HashMap(Box::new(FieldType::String), Box::new(FieldType::I32)),
+        );
+
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        db.query(format!(
+            "DEFINE TABLE user SCHEMAFULL; {}{}",
+            define(&list),
+            define(&map)
+        ))
+        .await
+        .unwrap()
+        .check()
+        .unwrap();
+
+        for content in [
+            "{ list: NONE, map: NONE }",
+            "{ list: [1, 2], map: { a: 1 } }",
+        ] {
+            db.query(format!("CREATE user CONTENT {content};"))
+                .await
+                .unwrap()
+                .check()
+                .unwrap_or_else(|e| panic!("{content} was rejected: {e}"));
+        }
+
+        // The wildcard definition still types the map values
+        let rejected = db
+            .query("CREATE user CONTENT { map: { a: 'one' } };")
+            .await
+            .unwrap()
+            .check();
+        assert!(rejected.is_err());
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_emits_geometry_point() {
+        let field = StructField {
+            field_name: "location".to_string(),
+            field_type: FieldType::Geometry(GeometryKind::Point),
+            ..StructField::default()
+        };
+
+        let stmt = field
+            .generate_define_statement(
+                BTreeMap::new(),
+                BTreeMap::new(),
+                BTreeMap::new(),
+                &"venue".to_string(),
+                &ForeignTypeRegistry::default(),
+            )
+            .expect("generate_define_statement should succeed");
+
+        assert!(
+            stmt.starts_with(
+                "DEFINE FIELD OVERWRITE location ON TABLE venue TYPE geometry<point>;"
+            ),
+            "expected `geometry<point>` for a Point field; got: {stmt}"
+        );
+    }
+
+    #[cfg(feature = "surrealdb")]
+    fn string_length_field(define_config: Option<crate::schemasync::DefineConfig>) -> StructField {
+        use crate::validator::{StringValidator, Validator};
+
+        StructField {
+            field_name: "username".to_string(),
+            field_type: FieldType::String,
+            define_config,
+            validators: vec![
+                Validator::StringValidator(StringValidator::MinLength(3)),
+                Validator::StringValidator(StringValidator::MaxLength(20)),
+            ],
+            ..StructField::default()
+        }
+    }
+
+    #[cfg(feature = "surrealdb")]
+    fn define(field: &StructField) -> String {
+        field
+            .generate_define_statement(
+                BTreeMap::new(),
+                BTreeMap::new(),
+                BTreeMap::new(),
+                &"user".to_string(),
+                &ForeignTypeRegistry::default(),
+            )
+            .expect("generate_define_statement should succeed")
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_asserts_string_length_validators() {
+        let stmt = define(&string_length_field(None));
+
+        assert_eq!(
+            stmt,
+            "DEFINE FIELD OVERWRITE username ON TABLE user TYPE string \
+             ASSERT string::len($value) >= 3 AND string::len($value) <= 20;\n"
+        );
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_combines_validators_with_explicit_assert() {
+        let stmt = define(&string_length_field(Some(
+            crate::schemasync::DefineConfig {
+                assert: Some("$value != 'admin'".to_string()),
+                ..Default::default()
+            },
+        )));
+
+        assert!(
+            stmt.contains(
+                " ASSERT ($value != 'admin') AND \
+                 (string::len($value) >= 3 AND string::len($value) <= 20);"
+            ),
+            "got: {stmt}"
+        );
+    }
+
+    #[cfg(feature = "surrealdb")]
+    fn strict_required_config(strict_required: bool) -> crate::schemasync::DefineConfig {
+        crate::schemasync::DefineConfig {
+            strict_required,
+            ..Default::default()
+        }
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_strict_required_asserts_presence() {
+        let strict = define(&string_length_field(Some(strict_required_config(true))));
+        assert!(
+            strict.contains(
+                " ASSERT $value != NONE AND \
+                 (string::len($value) >= 3 AND string::len($value) <= 20);"
+            ),
+            "got: {strict}"
+        );
+
+        let lenient = define(&string_length_field(Some(strict_required_config(false))));
+        assert!(
+            lenient.contains(" ASSERT string::len($value) >= 3 AND string::len($value) <= 20;"),
+            "got: {lenient}"
+        );
+        assert!(!lenient.contains("NONE"), "got: {lenient}");
+
+        let bare = StructField {
+            field_name: "title".to_string(),
+            field_type: FieldType::String,
+            define_config: Some(strict_required_config(true)),
+            ..StructField::default()
+        };
+        assert_eq!(bare.assert_clause().as_deref(), Some("$value != NONE"));
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_strict_required_skips_optional_fields() {
+        let field = StructField {
+            field_name: "nickname".to_string(),
+            field_type: FieldType::Option(Box::new(FieldType::String)),
+            define_config: Some(strict_required_config(true)),
+            ..StructField::default()
+        };
+
+        assert_eq!(field.assert_clause(), None);
+        assert!(!define(&field).contains("ASSERT"));
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_asserts_number_range() {
+        use crate::validator::{NumberValidator, Validator};
+        use ordered_float::OrderedFloat;
+
+        let field = StructField {
+            field_name: "score".to_string(),
+            field_type: FieldType::I32,
+            validators: vec![Validator::NumberValidator(NumberValidator::Between(
+                OrderedFloat(1.0),
+                OrderedFloat(10.0),
+            ))],
+            ..StructField::default()
+        };
+
+        assert_eq!(
+            define(&field),
+            "DEFINE FIELD OVERWRITE score ON TABLE user TYPE int \
+             ASSERT $value >= 1 AND $value <= 10;\n"
+        );
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_generate_define_statement_optional_validators_allow_none() {
+        let mut field = string_length_field(None);
+        field.field_type = FieldType::Option(Box::new(FieldType::String));
+
+        let stmt = define(&field);
+
+        assert!(
+            stmt.contains(
+                " ASSERT $value = NONE OR \
+                 (string::len($value) >= 3 AND string::len($value) <= 20);"
+            ),
+            "got: {stmt}"
+        );
+    }
+
+    #[cfg(feature = "surrealdb")]
+    fn shape_surql_type(representation: EnumRepresentation) -> String {
+        let circle = StructConfig {
+            struct_name: "Circle".to_string(),
+            fields: vec![StructField {
+                field_name: "radius".to_string(),
+                field_type: FieldType::F64,
+                ..StructField::default()
+            }],
+            ..StructConfig::default()
+        };
+        let shape = TaggedUnion {
+            enum_name: "Shape".to_string(),
+            variants: vec![
+                Variant {
+                    name: "Empty".to_string(),
+                    data: None,
+                    doccom: None,
+                    annotations: vec![],
+                    output_override: None,
+                    raw_attributes: BTreeMap::new(),
+                    is_default: false,
+                },
+                Variant {
+                    name: "Circle".to_string(),
+                    data: Some(VariantData::InlineStruct(circle.clone())),
+                    doccom: None,
+                    annotations: vec![],
+                    output_override: None,
+                    raw_attributes: BTreeMap::new(),
+                    is_default: false,
+                },
+            ],
+            representation,
+            doccom: None,
+            macroforge_derives: vec![],
+            annotations: vec![],
+            pipeline: Pipeline::Both,
+            rust_derives: vec![],
+            output_override: None,
+            raw_attributes: BTreeMap::new(),
+        };
+        let field = StructField {
+            field_name: "shape".to_string(),
+            field_type: FieldType::Other("Shape".to_string()),
+            ..StructField::default()
+        };
+
+        field
+            .surql_type(
+                &BTreeMap::from([("Shape".to_string(), shape)]),
+                &BTreeMap::from([("Circle".to_string(), circle)]),
+                &BTreeMap::new(),
+                "drawing",
+                &ForeignTypeRegistry::default(),
+            )
+            .expect("enum field type should resolve")
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[test]
+    fn test_surql_type_follows_enum_representation() {
+        assert_eq!(
+            shape_surql_type(EnumRepresentation::ExternallyTagged),
+            "\"Empty\" | { Circle: { radius: float } }"
+        );
+        assert_eq!(
+            shape_surql_type(EnumRepresentation::InternallyTagged {
+                tag: "type".to_string(),
+            }),
+            "{ type: \"Empty\" } | { type: \"Circle\", radius: float }"
+        );
+    }
 }
@@ -42,7 +42,7 @@ fn variant_to_arktype(
                         let mut fields_parts: Vec<String> =
                             vec![format!("{}: ['===', '{}']", tag, variant.name)];
                         for field in &enum_struct.fields {
-                            let field_name = field.field_name.to_case(Case::Camel);
+                            let field_name = field.ts_name();
                             fields_parts.push(format!(
                                 "{}: {}",
                                 field_name,
@@ -117,19 +117,19 @@ pub fn field_type_to_arktype(
         FieldType::Char => "'string'".to_string(),
         FieldType::Bool => "'boolean'".to_string(),
         FieldType::Unit => "'null'".to_string(),
+        FieldType::Json => "'unknown'".to_string(),
+        FieldType::Literal(value) => format!("['===', {}]", value),
+        FieldType::Bytes => "['number', '[]']".to_string(),
+        FieldType::Geometry(_) => "'object'".to_string(),
         FieldType::F32 | FieldType::F64 => "'number'".to_string(),
-        FieldType::I8
-        | FieldType::I16
-        | FieldType::I32
-        | FieldType::I64
-        | FieldType::I128
-        | FieldType::Isize => "'number'".to_string(),
-        FieldType::U8
-        | FieldType::U16
-        | FieldType::U32
-        | FieldType::U64
-        | FieldType::U128
-        | FieldType::Usize => "'number'".to_string(),
+        FieldType::I8 | FieldType::I16 | FieldType::I32 | FieldType::I64 | FieldType::Isize => {
+            "'number'".to_string()
+        }
+        FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 | FieldType::Usize => {
+            "'number'".to_string()
+        }
+        // Stored as decimal strings, a JS number cannot hold the full range
+        FieldType::I128 | FieldType::U128 => "'string'".to_string(),
 
         FieldType::Tuple(types) => {
             let types_str = types
@@ -162,7 +162,7 @@ pub fn field_type_to_arktype(
             )
         }
 
-        FieldType::Vec(inner) => {
+        FieldType::Vec(inner) | FieldType::Set(inner) | FieldType::Array(inner, _) => {
             format!(
                 "[{}, '[]']",
                 field_type_to_arktype(inner, structs, enums, registry)
@@ -189,7 +189,7 @@ pub fn field_type_to_arktype(
             field_type_to_arktype(inner, structs, enums, registry)
         ),
 
-        FieldType::Other(type_name) => {
+        FieldType::Enum(type_name) | FieldType::Object(type_name) | FieldType::Other(type_name) => {
             // Check foreign type registry first
             if let Some(ftc) = registry.lookup(type_name)
                 && !ftc.arktype.is_empty()
@@ -322,7 +322,7 @@ pub fn generate_arktype_type_string(
         ));
 
         for field in &struct_config.fields {
-            let field_name = field.field_name.to_case(Case::Camel);
+            let field_name = field.ts_name();
 
             // Write field doc comment if present
             if let Some(ref doc) = field.doccom {
@@ -69,6 +69,10 @@ pub struct OutputConfig {
     /// Set to `generic` for `Array<Type>` syntax.
     #[serde(default)]
     pub array_style: ArrayStyle,
+    /// Emit a branded id alias per table (e.g. `type UserId = string & { __brand: 'user' }`)
+    /// and use it for `RecordLink` fields pointing at that table.
+    #[serde(default)]
+    pub branded_record_ids: bool,
 }
 
 fn default_file_extension() -> String {
@@ -85,6 +89,9 @@ pub struct TypesyncConfig {
     /// Whether to generate Macroforge TypeScript interfaces with JSDoc annotations
     #[serde(default)]
     pub should_generate_macroforge_types: bool,
+    /// Whether to generate Zod schemas
+    #[serde(default)]
+    pub should_generate_zod_types: bool,
     /// Whether to generate FlatBuffers schema files (.fbs)
     #[serde(default)]
     pub should_generate_flatbuffers_types: bool,
@@ -100,6 +107,9 @@ pub struct TypesyncConfig {
     /// Whether to import validate.proto for validation rules in Protocol Buffers
     #[serde(default)]
     pub protobuf_import_validate: bool,
+    /// Whether to generate a JSON Schema document (schema.json)
+    #[serde(default)]
+    pub should_generate_json_schema_types: bool,
     /// Whether to generate SurrealDB schema types
     pub should_generate_surrealdb_schemas: bool,
     /// Output path for generated type files
@@ -124,7 +124,7 @@ pub fn generate_effect_schema_string(
                     let schema = to_schema(&f.field_type, &name, &processed);
                     let schema_with_validators =
                         apply_validators_to_schema(schema, &f.validators, &f.field_name);
-                    let field_name_camel = f.field_name.to_case(Case::Camel);
+                    let field_name_camel = f.ts_name();
                     let field_name_title = f.field_name.to_case(Case::Title);
 
                     let is_optional = matches!(f.field_type, FieldType::Option(_));
@@ -197,7 +197,7 @@ fn encoded_interface_for_struct(
         .map(|f| {
             format!(
                 "  readonly {}: {};",
-                f.field_name.to_case(Case::Camel),
+                f.ts_name(),
                 field_type_to_ts_encoded(&f.field_type, registry)
             )
         })
@@ -399,6 +399,16 @@ fn enum_variant_to_encoded(
 
 // ----- Schema and Type Conversion Logic ------------------------------------
 
+/// The Effect schema for a literal field. `Schema.Literal` only takes
+/// primitives, so array and object literals fall back to `Schema.Unknown`.
+pub(crate) fn literal_schema(value: &serde_json::Value) -> String {
+    if value.is_array() || value.is_object() {
+        "Schema.Unknown".to_string()
+    } else {
+        format!("Schema.Literal({})", value)
+    }
+}
+
 /// Converts a `FieldType` into its corresponding Effect `Schema` representation.
 fn field_type_to_effect_schema(
     field_type: &FieldType,
@@ -437,24 +447,28 @@ fn field_type_to_effect_schema(
                 }
                 FieldType::Bool => value_stack.push("Schema.Boolean".to_string()),
                 FieldType::Unit => value_stack.push("Schema.Null".to_string()),
+                FieldType::Json => value_stack.push("Schema.Unknown".to_string()),
+                FieldType::Literal(value) => value_stack.push(literal_schema(value)),
+                FieldType::Bytes => value_stack.push("Schema.Array(Schema.Number)".to_string()),
+                FieldType::Geometry(_) => value_stack.push("Schema.Object".to_string()),
                 FieldType::F32 | FieldType::F64 => value_stack.push("Schema.Number".to_string()),
                 FieldType::I8
                 | FieldType::I16
                 | FieldType::I32
                 | FieldType::I64
-                | FieldType::I128
                 | FieldType::Isize => value_stack.push("Schema.Number".to_string()),
                 FieldType::U8
                 | FieldType::U16
                 | FieldType::U32
                 | FieldType::U64
-                | FieldType::U128
                 | FieldType::Usize => value_stack.push("Schema.Number".to_string()),
+                // Stored as decimal strings, a JS number cannot hold the full range
+                FieldType::I128 | FieldType::U128 => value_stack.push("Schema.String".to_string()),
                 FieldType::Option(i) => {
                     work_stack.push(WorkItem::AssembleOption);
                     work_stack.push(WorkItem::Generate(i));
                 }
-                FieldType::Vec(i) => {
+                FieldType::Vec(i) | FieldType::Set(i) | FieldType::Array(i, _) => {
                     work_stack.push(WorkItem::AssembleVec);
                     work_stack.push(WorkItem::Generate(i));
                 }
@@ -481,7 +495,7 @@ fn field_type_to_effect_schema(
                     work_stack.push(WorkItem::Generate(v));
                     work_stack.push(WorkItem::Generate(k));
                 }
-                FieldType::Other(name) => {
+                FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
                     // Check foreign type registry first
                     if let Some(ftc) = registry.lookup(name)
                         && !ftc.effect_schema.is_empty() {
@@ -590,27 +604,31 @@ fn field_type_to_ts_encoded(
                     FieldType::String | FieldType::Char => value_stack.push("string".to_string()),
                     FieldType::Bool => value_stack.push("boolean".to_string()),
                     FieldType::Unit => value_stack.push("null".to_string()),
+                    FieldType::Json => value_stack.push("unknown".to_string()),
+                    // JSON text is also a TypeScript literal type
+                    FieldType::Literal(value) => value_stack.push(value.to_string()),
+                    FieldType::Bytes => value_stack.push("ReadonlyArray<number>".to_string()),
+                    FieldType::Geometry(_) => value_stack.push("object".to_string()),
                     FieldType::F32
                     | FieldType::F64
                     | FieldType::I8
                     | FieldType::I16
                     | FieldType::I32
                     | FieldType::I64
-                    | FieldType::I128
                     | FieldType::Isize
                     | FieldType::U8
                     | FieldType::U16
                     | FieldType::U32
                     | FieldType::U64
-                    | FieldType::U128
                     | FieldType::Usize => value_stack.push("number".to_string()),
+                    FieldType::I128 | FieldType::U128 => value_stack.push("string".to_string()),
 
                     // Containers
                     FieldType::Option(inner) => {
                         work_stack.push(WorkItem::AssembleOption);
                         work_stack.push(WorkItem::Generate(inner));
                     }
-                    FieldType::Vec(inner) => {
+                    FieldType::Vec(inner) | FieldType::Set(inner) | FieldType::Array(inner, _) => {
                         work_stack.push(WorkItem::AssembleVec);
                         work_stack.push(WorkItem::Generate(inner));
                     }
@@ -639,7 +657,7 @@ fn field_type_to_ts_encoded(
                     }
 
                     // User-defined types
-                    FieldType::Other(name) => {
+                    FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
                         // Check foreign type registry first
                         if let Some(ftc) = registry.lookup(name)
                             && !ftc.effect_encoded.is_empty()
@@ -795,7 +813,7 @@ pub fn generate_effect_schema_for_types(
                     let schema = to_schema(&f.field_type, &name, &processed);
                     let schema_with_validators =
                         apply_validators_to_schema(schema, &f.validators, &f.field_name);
-                    let field_name_camel = f.field_name.to_case(Case::Camel);
+                    let field_name_camel = f.ts_name();
                     let field_name_title = f.field_name.to_case(Case::Title);
 
                     let is_optional = matches!(f.field_type, FieldType::Option(_));
@@ -7,6 +7,7 @@ use crate::dependency::{analyse_recursion, deps_of};
 use crate::types::{
     EnumRepresentation, FieldType, ForeignTypeRegistry, StructConfig, TaggedUnion, VariantData,
 };
+use crate::typesync::effect::literal_schema;
 use crate::validator::{
     ArrayValidator, BigDecimalValidator, BigIntValidator, DateValidator, DurationValidator,
     NumberValidator, StringValidator, Validator,
@@ -114,9 +115,9 @@ pub fn generate_effect_schema_string(
                             {$let schema = field_type_to_effect_schema(&field.field_type, structs, name, &recursion_info, &processed, registry)}
                             {$let schema_validated = apply_validators_to_schema(schema, &field.validators, &field.field_name)}
                             {#if matches!(field.field_type, FieldType::Option(_))}
-                                @{field.field_name.to_case(Case::Camel)}: @{schema_validated}
+                                @{field.ts_name()}: @{schema_validated}
                             {:else}
-                                @{field.field_name.to_case(Case::Camel)}: Schema.propertySignature(@{schema_validated}).annotations({ missingMessage: () => "'^@{field.field_name.to_case(Case::Title)}' is required^'" })
+                                @{field.ts_name()}: Schema.propertySignature(@{schema_validated}).annotations({ missingMessage: () => "'^@{field.field_name.to_case(Case::Title)}' is required^'" })
                             {/if}
                             {#if index + 1 != field_count},{/if}
                         {/for}
@@ -124,7 +125,7 @@ pub fn generate_effect_schema_string(
 
                     export interface {|@{name}Encoded|} {
                         {#for field in &struct_config.fields}
-                            readonly @{field.field_name.to_case(Case::Camel)}: @{field_type_to_ts_encoded(&field.field_type, registry)};
+                            readonly @{field.ts_name()}: @{field_type_to_ts_encoded(&field.field_type, registry)};
                         {/for}
                     }
                     export type {|@{name}Type|} = typeof @{name}.Type;
@@ -326,7 +327,8 @@ fn field_type_to_effect_schema(
     };
 
     // Check for foreign type in Other variant before using ts_template
-    if let FieldType::Other(type_name) = field_type
+    if let FieldType::Enum(type_name) | FieldType::Object(type_name) | FieldType::Other(type_name) =
+        field_type
         && let Some(ftc) = registry.lookup(type_name)
         && !ftc.effect_schema.is_empty()
     {
@@ -343,15 +345,25 @@ fn field_type_to_effect_schema(
                 Schema.Boolean
             {:case FieldType::Unit}
                 Schema.Null
+            {:case FieldType::Json}
+                Schema.Unknown
+            {:case FieldType::Literal(value)}
+                @{literal_schema(value)}
+            {:case FieldType::Bytes}
+                Schema.Array(Schema.Number)
+            {:case FieldType::Geometry(_)}
+                Schema.Object
             {:case FieldType::F32 | FieldType::F64}
                 Schema.Number
-            {:case FieldType::I8 | FieldType::I16 | FieldType::I32 | FieldType::I64 | FieldType::I128 | FieldType::Isize}
+            {:case FieldType::I8 | FieldType::I16 | FieldType::I32 | FieldType::I64 | FieldType::Isize}
                 Schema.Number
-            {:case FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 | FieldType::U128 | FieldType::Usize}
+            {:case FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 | FieldType::Usize}
                 Schema.Number
+            {:case FieldType::I128 | FieldType::U128}
+                Schema.String
             {:case FieldType::Option(inner_type)}
                 Schema.OptionFromNullishOr(@{recurse(inner_type)}, null)
-            {:case FieldType::Vec(inner_type)}
+            {:case FieldType::Vec(inner_type) | FieldType::Set(inner_type) | FieldType::Array(inner_type, _)}
                 Schema.Array(@{recurse(inner_type)})
             {:case FieldType::Tuple(tuple_items)}
                 Schema.Tuple(
@@ -369,7 +381,7 @@ fn field_type_to_effect_schema(
                 Schema.Union(Schema.String.pipe(Schema.nonEmptyString()), @{recurse(inner_type)}).annotations({ message: () => ({ message: "Please enter a valid value", override: true }) })
             {:case FieldType::HashMap(key_type, val_type) | FieldType::BTreeMap(key_type, val_type)}
                 Schema.Record({ key: @{recurse(key_type)}, value: @{recurse(val_type)} })
-            {:case FieldType::Other(type_name)}
+            {:case FieldType::Enum(type_name) | FieldType::Object(type_name) | FieldType::Other(type_name)}
                 {$let pascal_name = type_name.to_case(Case::Pascal)}
                 {#if recursion_info.is_recursive_pair(current_type, &pascal_name) && !processed.contains(&pascal_name)}
                     {#if structs.values().any(|struct_config| struct_config.struct_name.to_case(Case::Pascal) == pascal_name)}
@@ -386,7 +398,8 @@ fn field_type_to_effect_schema(
 
 fn field_type_to_ts_encoded(field_type: &FieldType, registry: &ForeignTypeRegistry) -> String {
     // Check for foreign type in Other variant before using ts_template
-    if let FieldType::Other(type_name) = field_type
+    if let FieldType::Enum(type_name) | FieldType::Object(type_name) | FieldType::Other(type_name) =
+        field_type
         && let Some(ftc) = registry.lookup(type_name)
         && !ftc.effect_encoded.is_empty()
     {
@@ -401,15 +414,25 @@ fn field_type_to_ts_encoded(field_type: &FieldType, registry: &ForeignTypeRegist
                 boolean
             {:case FieldType::Unit}
                 null
+            {:case FieldType::Json}
+                unknown
+            {:case FieldType::Literal(value)}
+                @{value.to_string()}
+            {:case FieldType::Bytes}
+                ReadonlyArray<number>
+            {:case FieldType::Geometry(_)}
+                object
             {:case FieldType::F32 | FieldType::F64}
                 number
-            {:case FieldType::I8 | FieldType::I16 | FieldType::I32 | FieldType::I64 | FieldType::I128 | FieldType::Isize}
+            {:case FieldType::I8 | FieldType::I16 | FieldType::I32 | FieldType::I64 | FieldType::Isize}
                 number
-            {:case FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 | FieldType::U128 | FieldType::Usize}
+            {:case FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 | FieldType::Usize}
                 number
+            {:case FieldType::I128 | FieldType::U128}
+                string
             {:case FieldType::Option(inner_type)}
                 @{field_type_to_ts_encoded(inner_type, registry)} | null | undefined
-            {:case FieldType::Vec(inner_type)}
+            {:case FieldType::Vec(inner_type) | FieldType::Set(inner_type) | FieldType::Array(inner_type, _)}
                 ReadonlyArray<@{field_type_to_ts_encoded(inner_type, registry)}>
             {:case FieldType::Tuple(tuple_items)}
                readonly [
@@ -428,7 +451,7 @@ fn field_type_to_ts_encoded(field_type: &FieldType, registry: &ForeignTypeRegist
                 Record<@{field_type_to_ts_encoded(key_type, registry)}, @{field_type_to_ts_encoded(val_type, registry)}>
             {:case FieldType::RecordLink(inner_type)}
                 string | @{field_type_to_ts_encoded(inner_type, registry)}
-            {:case FieldType::Other(type_name)}
+            {:case FieldType::Enum(type_name) | FieldType::Object(type_name) | FieldType::Other(type_name)}
                 {|@{type_name.to_case(Case::Pascal)}Encoded|}
         {/match}
     }.source().to_string()
@@ -646,9 +669,13 @@ mod tests {
                         format: None,
                         always_regenerate: false,
                         doccom: None,
+                        doc: None,
                         annotations: vec![],
                         unique: false,
+                        indexed: false,
+                        search_analyzer: None,
                         mock_plugin: None,
+                        rename: None,
                         output_override: None,
                         raw_attributes: std::collections::BTreeMap::new(),
                     },
@@ -661,9 +688,13 @@ mod tests {
                         format: None,
                         always_regenerate: false,
                         doccom: None,
+                        doc: None,
                         annotations: vec![],
                         unique: false,
+                        indexed: false,
+                        search_analyzer: None,
                         mock_plugin: None,
+                        rename: None,
                         output_override: None,
                         raw_attributes: std::collections::BTreeMap::new(),
                     },
@@ -676,15 +707,20 @@ mod tests {
                         format: None,
                         always_regenerate: false,
                         doccom: None,
+                        doc: None,
                         annotations: vec![],
                         unique: false,
+                        indexed: false,
+                        search_analyzer: None,
                         mock_plugin: None,
+                        rename: None,
                         output_override: None,
                         raw_attributes: std::collections::BTreeMap::new(),
                     },
                 ],
                 validators: vec![],
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: crate::types::Pipeline::default(),
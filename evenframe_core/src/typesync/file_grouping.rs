@@ -245,6 +245,7 @@ mod tests {
                 .collect(),
             validators: vec![],
             doccom: None,
+            doc: None,
             macroforge_derives: vec![],
             annotations: vec![],
             pipeline: crate::types::Pipeline::default(),
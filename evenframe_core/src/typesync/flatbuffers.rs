@@ -171,6 +171,12 @@ fn field_type_to_flatbuffers(
         FieldType::String | FieldType::Char => "string".to_string(),
         FieldType::Bool => "bool".to_string(),
         FieldType::Unit => "bool".to_string(), // Placeholder for unit type
+        FieldType::Json => "string".to_string(), // Serialized JSON text
+        FieldType::Literal(value) => {
+            field_type_to_flatbuffers(&FieldType::literal_base_type(value), registry)
+        }
+        FieldType::Bytes => "[uint8]".to_string(),
+        FieldType::Geometry(_) => "string".to_string(), // Serialized GeoJSON
         FieldType::F32 => "float".to_string(),
         FieldType::F64 => "double".to_string(),
         FieldType::I8 => "int8".to_string(),
@@ -190,7 +196,7 @@ fn field_type_to_flatbuffers(
             field_type_to_flatbuffers(inner, registry)
         }
 
-        FieldType::Vec(inner) => {
+        FieldType::Vec(inner) | FieldType::Set(inner) | FieldType::Array(inner, _) => {
             format!("[{}]", field_type_to_flatbuffers(inner, registry))
         }
 
@@ -221,14 +227,17 @@ fn field_type_to_flatbuffers(
 
         FieldType::RecordLink(inner) => {
             // For record links, we just use the inner type name
-            if let FieldType::Other(type_name) = inner.as_ref() {
+            if let FieldType::Enum(type_name)
+            | FieldType::Object(type_name)
+            | FieldType::Other(type_name) = inner.as_ref()
+            {
                 type_name.to_case(Case::Pascal)
             } else {
                 field_type_to_flatbuffers(inner, registry)
             }
         }
 
-        FieldType::Other(type_name) => {
+        FieldType::Enum(type_name) | FieldType::Object(type_name) | FieldType::Other(type_name) => {
             // Check foreign type registry first
             if let Some(ftc) = registry.lookup(type_name)
                 && !ftc.flatbuffers.is_empty()
@@ -748,6 +757,7 @@ mod tests {
                 ],
                 validators: vec![],
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: crate::types::Pipeline::default(),
@@ -891,6 +901,7 @@ mod tests {
                 ],
                 validators: vec![],
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: crate::types::Pipeline::default(),
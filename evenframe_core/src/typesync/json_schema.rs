@@ -0,0 +1,507 @@
+use crate::types::StructConfig;
+use crate::types::{EnumRepresentation, FieldType, StructField, TaggedUnion, Variant, VariantData};
+use convert_case::{Case, Casing};
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+use tracing;
+
+/// Dialect declared by generated documents.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Name a struct or enum is stored under in the document's `$defs`.
+fn definition_name(type_name: &str) -> String {
+    type_name.to_case(Case::Pascal)
+}
+
+/// Reference to a struct or enum in the document's `$defs`.
+fn definition_ref(type_name: &str) -> Value {
+    json!({ "$ref": format!("#/$defs/{}", definition_name(type_name)) })
+}
+
+/// Adds the doc comment as `description`, when there is one.
+fn with_description(mut schema: Value, doccom: Option<&String>) -> Value {
+    if let (Some(doc), Value::Object(object)) = (doccom, &mut schema) {
+        object.insert(
+            "description".to_string(),
+            Value::String(doc.trim().to_string()),
+        );
+    }
+    schema
+}
+
+/// Builds an object schema. Optional fields are left out of `required`.
+fn object_schema<'a>(
+    fields: impl IntoIterator<Item = (String, Value, bool)>,
+    extra: impl IntoIterator<Item = (&'a str, Value)>,
+) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for (name, schema, is_required) in extra
+        .into_iter()
+        .map(|(name, schema)| (name.to_string(), schema, true))
+        .chain(fields)
+    {
+        if is_required {
+            required.push(Value::String(name.clone()));
+        }
+        properties.insert(name, schema);
+    }
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+fn struct_fields(
+    fields: &[StructField],
+    structs: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> Vec<(String, Value, bool)> {
+    fields
+        .iter()
+        .map(|field| {
+            let field = field.effective();
+            let schema = field_type_to_json_schema(&field.field_type, structs, enums, registry);
+            (
+                field.ts_name(),
+                with_description(schema, field.doccom.as_ref()),
+                !matches!(field.field_type, FieldType::Option(_)),
+            )
+        })
+        .collect()
+}
+
+/// Converts a single enum variant into its JSON Schema,
+/// respecting the serde enum representation strategy.
+fn variant_to_json_schema(
+    variant: &Variant,
+    representation: &EnumRepresentation,
+    structs: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> Value {
+    let data_to_schema = |variant_data: &VariantData| match variant_data {
+        VariantData::InlineStruct(enum_struct) => object_schema(
+            struct_fields(&enum_struct.fields, structs, enums, registry),
+            [],
+        ),
+        VariantData::DataStructureRef(field_type) => {
+            field_type_to_json_schema(field_type, structs, enums, registry)
+        }
+    };
+    let tag_value = || json!({ "const": variant.name });
+
+    let schema = match representation {
+        EnumRepresentation::ExternallyTagged => match &variant.data {
+            Some(variant_data) => {
+                object_schema([], [(variant.name.as_str(), data_to_schema(variant_data))])
+            }
+            None => tag_value(),
+        },
+        EnumRepresentation::InternallyTagged { tag } => match &variant.data {
+            Some(VariantData::InlineStruct(enum_struct)) => object_schema(
+                struct_fields(&enum_struct.fields, structs, enums, registry),
+                [(tag.as_str(), tag_value())],
+            ),
+            // Internally tagged doesn't work with non-struct data;
+            // fall back to externally tagged wrapping.
+            Some(variant_data) => {
+                object_schema([], [(variant.name.as_str(), data_to_schema(variant_data))])
+            }
+            None => object_schema([], [(tag.as_str(), tag_value())]),
+        },
+        EnumRepresentation::AdjacentlyTagged { tag, content } => match &variant.data {
+            Some(variant_data) => object_schema(
+                [],
+                [
+                    (tag.as_str(), tag_value()),
+                    (content.as_str(), data_to_schema(variant_data)),
+                ],
+            ),
+            None => object_schema([], [(tag.as_str(), tag_value())]),
+        },
+        EnumRepresentation::Untagged => match &variant.data {
+            Some(variant_data) => data_to_schema(variant_data),
+            None => tag_value(),
+        },
+    };
+    with_description(schema, variant.doccom.as_ref())
+}
+
+/// Converts an enum into its JSON Schema. Enums whose variants all serialize
+/// as bare strings become a string `enum`; everything else is a `oneOf`.
+pub fn enum_to_json_schema(
+    schema_enum: &TaggedUnion,
+    structs: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> Value {
+    let schema_enum = schema_enum.effective();
+    let string_literals = matches!(
+        schema_enum.representation,
+        EnumRepresentation::ExternallyTagged | EnumRepresentation::Untagged
+    ) && schema_enum.variants.iter().all(|v| v.data.is_none());
+
+    let schema = if string_literals && !schema_enum.variants.is_empty() {
+        json!({
+            "type": "string",
+            "enum": schema_enum.variants.iter().map(|v| v.name.clone()).collect::<Vec<_>>(),
+        })
+    } else {
+        json!({
+            "oneOf": schema_enum
+                .variants
+                .iter()
+                .map(|variant| {
+                    variant_to_json_schema(
+                        variant.effective(),
+                        &schema_enum.representation,
+                        structs,
+                        enums,
+                        registry,
+                    )
+                })
+                .collect::<Vec<_>>(),
+        })
+    };
+    with_description(schema, schema_enum.doccom.as_ref())
+}
+
+/// Converts a struct into an object schema.
+pub fn struct_to_json_schema(
+    struct_config: &StructConfig,
+    structs: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> Value {
+    let struct_config = struct_config.effective();
+    with_description(
+        object_schema(
+            struct_fields(&struct_config.fields, structs, enums, registry),
+            [],
+        ),
+        struct_config.doccom.as_ref(),
+    )
+}
+
+pub fn field_type_to_json_schema(
+    field_type: &FieldType,
+    structs: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> Value {
+    tracing::trace!(field_type = ?field_type, "Converting field type to JSON Schema");
+    match field_type {
+        FieldType::String | FieldType::Char => json!({ "type": "string" }),
+        FieldType::Bool => json!({ "type": "boolean" }),
+        FieldType::Unit => json!({ "type": "null" }),
+        FieldType::Json => json!({}),
+        FieldType::Literal(value) => json!({ "const": value }),
+        FieldType::Bytes => json!({
+            "type": "array",
+            "items": { "type": "integer", "minimum": 0, "maximum": 255 },
+        }),
+        FieldType::Geometry(_) => json!({ "type": "object" }),
+        FieldType::F32 | FieldType::F64 => json!({ "type": "number" }),
+        FieldType::I8 | FieldType::I16 | FieldType::I32 | FieldType::I64 | FieldType::Isize => {
+            json!({ "type": "integer" })
+        }
+        FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 | FieldType::Usize => {
+            json!({ "type": "integer", "minimum": 0 })
+        }
+        // Stored as decimal strings, JSON numbers lose precision past 2^53
+        FieldType::I128 | FieldType::U128 => json!({ "type": "string" }),
+
+        FieldType::Tuple(types) => json!({
+            "type": "array",
+            "prefixItems": types
+                .iter()
+                .map(|t| field_type_to_json_schema(t, structs, enums, registry))
+                .collect::<Vec<_>>(),
+            "items": false,
+            "minItems": types.len(),
+            "maxItems": types.len(),
+        }),
+
+        FieldType::Struct(fields) => object_schema(
+            fields.iter().map(|(name, field_type)| {
+                (
+                    name.clone(),
+                    field_type_to_json_schema(field_type, structs, enums, registry),
+                    !matches!(field_type, FieldType::Option(_)),
+                )
+            }),
+            [],
+        ),
+
+        // The field itself is left out of `required` by the enclosing object.
+        FieldType::Option(inner) => json!({
+            "anyOf": [
+                field_type_to_json_schema(inner, structs, enums, registry),
+                { "type": "null" },
+            ],
+        }),
+
+        FieldType::Vec(inner) => json!({
+            "type": "array",
+            "items": field_type_to_json_schema(inner, structs, enums, registry),
+        }),
+        FieldType::Set(inner) => json!({
+            "type": "array",
+            "items": field_type_to_json_schema(inner, structs, enums, registry),
+            "uniqueItems": true,
+        }),
+        FieldType::Array(inner, len) => json!({
+            "type": "array",
+            "items": field_type_to_json_schema(inner, structs, enums, registry),
+            "minItems": len,
+            "maxItems": len,
+        }),
+
+        // JSON object keys are always strings, whatever the Rust key type.
+        FieldType::HashMap(_, value) | FieldType::BTreeMap(_, value) => json!({
+            "type": "object",
+            "additionalProperties": field_type_to_json_schema(value, structs, enums, registry),
+        }),
+
+        FieldType::RecordLink(_) => json!({ "type": "string", "format": "record-id" }),
+
+        FieldType::Enum(type_name) | FieldType::Object(type_name) | FieldType::Other(type_name) => {
+            // Check foreign type registry first
+            if let Some(ftc) = registry.lookup(type_name)
+                && !ftc.json_schema.is_empty()
+            {
+                match serde_json::from_str(&ftc.json_schema) {
+                    Ok(schema) => return schema,
+                    Err(e) => tracing::warn!(
+                        type_name = %type_name,
+                        error = %e,
+                        "Invalid json_schema mapping for foreign type, allowing any value"
+                    ),
+                }
+            }
+
+            let is_known = structs.values().any(|s| s.struct_name == *type_name)
+                || enums.values().any(|e| e.enum_name == *type_name);
+            if is_known {
+                return definition_ref(type_name);
+            }
+
+            tracing::warn!(type_name = %type_name, "No JSON Schema mapping for type, allowing any value");
+            json!({})
+        }
+    }
+}
+
+/// Schemas for every struct and enum, keyed by their `$defs` name.
+pub fn generate_json_schemas(
+    structs: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> BTreeMap<String, Value> {
+    tracing::info!(
+        struct_count = structs.len(),
+        enum_count = enums.len(),
+        "Generating JSON schemas"
+    );
+    let mut schemas = BTreeMap::new();
+
+    for schema_enum in enums.values() {
+        schemas.insert(
+            definition_name(&schema_enum.effective().enum_name),
+            enum_to_json_schema(schema_enum, structs, enums, registry),
+        );
+    }
+    for struct_config in structs.values() {
+        schemas.insert(
+            definition_name(&struct_config.effective().struct_name),
+            struct_to_json_schema(struct_config, structs, enums, registry),
+        );
+    }
+
+    schemas
+}
+
+/// A single JSON Schema document holding every struct and enum under `$defs`,
+/// which references between types resolve against.
+pub fn generate_json_schema_document(
+    structs: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> Value {
+    json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "$defs": generate_json_schemas(structs, enums, registry),
+    })
+}
+
+/// [`generate_json_schema_document`] as pretty-printed JSON.
+pub fn generate_json_schema_string(
+    structs: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> String {
+    let document = generate_json_schema_document(structs, enums, registry);
+    let mut output = serde_json::to_string_pretty(&document).expect("JSON values always serialize");
+    output.push('\n');
+    tracing::info!(
+        output_length = output.len(),
+        "JSON Schema string generation complete"
+    );
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ForeignTypeRegistry;
+
+    fn field(name: &str, field_type: FieldType) -> StructField {
+        StructField {
+            field_name: name.to_string(),
+            field_type,
+            ..StructField::default()
+        }
+    }
+
+    fn unit_variant(name: &str) -> Variant {
+        Variant {
+            name: name.to_string(),
+            data: None,
+            doccom: None,
+            annotations: vec![],
+            output_override: None,
+            raw_attributes: BTreeMap::new(),
+            is_default: false,
+        }
+    }
+
+    fn tagged_union(
+        name: &str,
+        variants: Vec<Variant>,
+        representation: EnumRepresentation,
+    ) -> TaggedUnion {
+        TaggedUnion {
+            enum_name: name.to_string(),
+            variants,
+            representation,
+            doccom: None,
+            macroforge_derives: vec![],
+            annotations: vec![],
+            pipeline: crate::types::Pipeline::default(),
+            rust_derives: vec![],
+            output_override: None,
+            raw_attributes: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn wide_integers_are_strings() {
+        let registry = ForeignTypeRegistry::default();
+        let schema = |field_type| {
+            field_type_to_json_schema(&field_type, &BTreeMap::new(), &BTreeMap::new(), &registry)
+        };
+        assert_eq!(schema(FieldType::I64), json!({ "type": "integer" }));
+        assert_eq!(schema(FieldType::I128), json!({ "type": "string" }));
+        assert_eq!(schema(FieldType::U128), json!({ "type": "string" }));
+    }
+
+    #[test]
+    fn struct_with_optional_and_vec_fields() {
+        let user = StructConfig {
+            struct_name: "User".to_string(),
+            fields: vec![
+                field("display_name", FieldType::String),
+                field("nickname", FieldType::Option(Box::new(FieldType::String))),
+                field("tags", FieldType::Vec(Box::new(FieldType::String))),
+                field("role", FieldType::Other("Role".to_string())),
+                field(
+                    "team",
+                    FieldType::RecordLink(Box::new(FieldType::Other("Team".to_string()))),
+                ),
+            ],
+            ..StructConfig::default()
+        };
+        let role = tagged_union(
+            "Role",
+            vec![unit_variant("Admin"), unit_variant("Member")],
+            EnumRepresentation::ExternallyTagged,
+        );
+        let structs = BTreeMap::from([("User".to_string(), user)]);
+        let enums = BTreeMap::from([("Role".to_string(), role)]);
+
+        let schemas = generate_json_schemas(&structs, &enums, &ForeignTypeRegistry::default());
+
+        assert_eq!(
+            schemas["User"],
+            json!({
+                "type": "object",
+                "properties": {
+                    "displayName": { "type": "string" },
+                    "nickname": { "anyOf": [{ "type": "string" }, { "type": "null" }] },
+                    "tags": { "type": "array", "items": { "type": "string" } },
+                    "role": { "$ref": "#/$defs/Role" },
+                    "team": { "type": "string", "format": "record-id" },
+                },
+                "required": ["displayName", "tags", "role", "team"],
+                "additionalProperties": false,
+            })
+        );
+        assert_eq!(
+            schemas["Role"],
+            json!({ "type": "string", "enum": ["Admin", "Member"] })
+        );
+    }
+
+    #[test]
+    fn tagged_enum_with_data_becomes_one_of() {
+        let shape = tagged_union(
+            "Shape",
+            vec![
+                Variant {
+                    data: Some(VariantData::DataStructureRef(FieldType::F64)),
+                    ..unit_variant("Circle")
+                },
+                unit_variant("Point"),
+            ],
+            EnumRepresentation::AdjacentlyTagged {
+                tag: "type".to_string(),
+                content: "value".to_string(),
+            },
+        );
+        let enums = BTreeMap::from([("Shape".to_string(), shape)]);
+
+        let document = generate_json_schema_document(
+            &BTreeMap::new(),
+            &enums,
+            &ForeignTypeRegistry::default(),
+        );
+
+        assert_eq!(document["$schema"], JSON_SCHEMA_DIALECT);
+        assert_eq!(
+            document["$defs"]["Shape"],
+            json!({
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "const": "Circle" },
+                            "value": { "type": "number" },
+                        },
+                        "required": ["type", "value"],
+                        "additionalProperties": false,
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "type": { "const": "Point" } },
+                        "required": ["type"],
+                        "additionalProperties": false,
+                    },
+                ],
+            })
+        );
+    }
+}
@@ -3,6 +3,7 @@
 //! This module generates TypeScript interfaces with `@derive(Deserialize)` at the type level
 //! and `@serde({ validate: [...] })` annotations at the field level for validators.
 
+use crate::schemasync::TableCase;
 use crate::types::{EnumRepresentation, FieldType, StructConfig, TaggedUnion, VariantData};
 use crate::typesync::config::ArrayStyle;
 use crate::typesync::doc_comment::format_jsdoc;
@@ -20,6 +21,8 @@ pub fn generate_macroforge_type_string(
     enums: &BTreeMap<String, TaggedUnion>,
     _print_types: bool,
     array_style: ArrayStyle,
+    branded_record_ids: bool,
+    table_naming: TableCase,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
     tracing::info!(
@@ -71,19 +74,45 @@ pub fn generate_macroforge_type_string(
         )
         .collect();
 
+    let branded = if branded_record_ids {
+        branded_record_id_targets(&unique_structs)
+    } else {
+        BTreeSet::new()
+    };
+    let branded = &branded;
+
     let mut result = String::new();
-    let extra_imports = compute_extra_imports(&all_type_names, structs, enums, registry);
+    let extra_imports = compute_extra_imports(
+        &all_type_names,
+        structs,
+        enums,
+        branded_record_ids,
+        registry,
+    );
     if !extra_imports.is_empty() {
         result.push_str(&extra_imports.join("\n"));
         result.push_str("\n\n");
     }
 
-    let mut parts: Vec<String> = Vec::new();
+    let mut parts: Vec<String> = branded
+        .iter()
+        .map(|name| render_branded_record_id_alias(name, table_naming))
+        .collect();
     for struct_config in &unique_structs {
-        parts.push(generate_struct_block(struct_config, array_style, registry));
+        parts.push(generate_struct_block(
+            struct_config,
+            array_style,
+            branded,
+            registry,
+        ));
     }
     for enum_def in &unique_enums {
-        parts.push(generate_enum_block(enum_def, array_style, registry));
+        parts.push(generate_enum_block(
+            enum_def,
+            array_style,
+            branded,
+            registry,
+        ));
     }
 
     result.push_str(&parts.join("\n"));
@@ -100,6 +129,8 @@ pub fn generate_macroforge_for_types(
     structs: &BTreeMap<String, StructConfig>,
     enums: &BTreeMap<String, TaggedUnion>,
     array_style: ArrayStyle,
+    branded_record_ids: bool,
+    table_naming: TableCase,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
     let type_set: BTreeSet<String> = type_names.iter().cloned().collect();
@@ -136,20 +167,131 @@ pub fn generate_macroforge_for_types(
         .collect();
     filtered_enums.sort_by_key(|e| e.effective().enum_name.to_case(Case::Pascal));
 
-    let mut parts: Vec<String> = Vec::new();
+    // Only brand links to tables declared in this file; links to tables in
+    // other files keep the plain `RecordLink<T>` form.
+    let branded = if branded_record_ids {
+        branded_record_id_targets(&filtered_structs)
+    } else {
+        BTreeSet::new()
+    };
+    let branded = &branded;
+
+    let mut parts: Vec<String> = branded
+        .iter()
+        .map(|name| render_branded_record_id_alias(name, table_naming))
+        .collect();
     for struct_config in &filtered_structs {
-        parts.push(generate_struct_block(struct_config, array_style, registry));
+        parts.push(generate_struct_block(
+            struct_config,
+            array_style,
+            branded,
+            registry,
+        ));
     }
     for enum_def in &filtered_enums {
-        parts.push(generate_enum_block(enum_def, array_style, registry));
+        parts.push(generate_enum_block(
+            enum_def,
+            array_style,
+            branded,
+            registry,
+        ));
     }
     parts.join("\n")
 }
 
+/// Generates the TypeScript side of an `EvenframeUnion`: an interface for each
+/// distinct member struct followed by `export type Union = A | B;`.
+///
+/// Members are deduplicated, so variants wrapping the same struct contribute a
+/// single interface and a single union arm. Members only reference each other
+/// by name, which keeps recursive references between them well-formed.
+pub fn generate_macroforge_union_of_tables(
+    entry: &crate::registry::UnionOfTablesRegistryEntry,
+    structs: &BTreeMap<String, StructConfig>,
+    array_style: ArrayStyle,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> String {
+    let union_name = entry.type_name.to_case(Case::Pascal);
+    tracing::debug!(
+        union = %union_name,
+        member_count = entry.table_names.len(),
+        "Generating Macroforge union of tables"
+    );
+
+    let mut seen_members = BTreeSet::new();
+    let members: Vec<String> = entry
+        .table_names
+        .iter()
+        .map(|name| name.to_case(Case::Pascal))
+        .filter(|name| seen_members.insert(name.clone()))
+        .collect();
+
+    let branded = &BTreeSet::new();
+    let mut parts: Vec<String> = Vec::new();
+    for member in &members {
+        match structs
+            .values()
+            .find(|s| s.effective().struct_name.to_case(Case::Pascal) == *member)
+        {
+            Some(struct_config) => parts.push(generate_struct_block(
+                struct_config,
+                array_style,
+                branded,
+                registry,
+            )),
+            None => tracing::warn!(
+                union = %union_name,
+                member = %member,
+                "Union member has no struct config; emitting type reference only"
+            ),
+        }
+    }
+    parts.push(format!(
+        "export type {} = {};\n",
+        union_name,
+        members.join(" | ")
+    ));
+    parts.join("\n")
+}
+
+/// Collects the PascalCase names of the persistable structs (those with an
+/// `id` field) that get a branded record-id alias.
+fn branded_record_id_targets(structs: &[&StructConfig]) -> BTreeSet<String> {
+    structs
+        .iter()
+        .map(|s| s.effective())
+        .filter(|s| s.fields.iter().any(|f| f.effective().field_name == "id"))
+        .map(|s| s.struct_name.to_case(Case::Pascal))
+        .collect()
+}
+
+/// Renders `export type UserId = string & { __brand: 'user' };` for a table,
+/// branding with the table name `table_naming` gives the struct.
+fn render_branded_record_id_alias(name: &str, table_naming: TableCase) -> String {
+    format!(
+        "export type {}Id = string & {{ __brand: '{}' }};\n",
+        name,
+        table_naming.apply(name)
+    )
+}
+
+/// Returns the PascalCase target of a `RecordLink` inner type when that
+/// target has a branded record-id alias.
+fn branded_record_link_target(inner: &FieldType, branded: &BTreeSet<String>) -> Option<String> {
+    if let FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) = inner {
+        let name = name.to_case(Case::Pascal);
+        if branded.contains(&name) {
+            return Some(name);
+        }
+    }
+    None
+}
+
 /// Generate a single struct's TypeScript interface block.
 fn generate_struct_block(
     struct_config: &StructConfig,
     array_style: ArrayStyle,
+    branded: &BTreeSet<String>,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
     // Resolve `output_override` literally — every read below comes from the
@@ -169,7 +311,7 @@ fn generate_struct_block(
     }
     lines.push(format!("export interface {} {{", name));
     for field in &struct_config.fields {
-        lines.push(render_field_block(field, array_style, registry));
+        lines.push(render_field_block(field, array_style, branded, registry));
     }
     lines.push("}".to_string());
     lines.push(String::new());
@@ -180,6 +322,7 @@ fn generate_struct_block(
 fn generate_enum_block(
     enum_def: &TaggedUnion,
     array_style: ArrayStyle,
+    branded: &BTreeSet<String>,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
     // Resolve `output_override` literally — see [`generate_struct_block`].
@@ -219,18 +362,41 @@ fn generate_enum_block(
     let variant_parts: Vec<String> = enum_def
         .variants
         .iter()
-        .map(|variant| render_variant(variant, &enum_def.representation, array_style, registry))
+        .map(|variant| {
+            render_variant(
+                variant,
+                &enum_def.representation,
+                array_style,
+                branded,
+                registry,
+            )
+        })
         .collect();
 
-    lines.push(format!(
-        "export type {} =\n\t{};",
-        name,
-        variant_parts
-            .iter()
-            .map(|part| format!("| {}", part))
-            .collect::<Vec<_>>()
-            .join("\n\t")
-    ));
+    // Externally tagged unit variants serialize as bare variant-name strings,
+    // so an all-unit enum is just a string literal union.
+    let all_unit = matches!(
+        enum_def.representation,
+        EnumRepresentation::ExternallyTagged
+    ) && enum_def.variants.iter().all(|v| v.data.is_none());
+
+    if all_unit {
+        lines.push(format!(
+            "export type {} = {};",
+            name,
+            variant_parts.join(" | ")
+        ));
+    } else {
+        lines.push(format!(
+            "export type {} =\n\t{};",
+            name,
+            variant_parts
+                .iter()
+                .map(|part| format!("| {}", part))
+                .collect::<Vec<_>>()
+                .join("\n\t")
+        ));
+    }
     lines.push(String::new());
     lines.join("\n")
 }
@@ -240,6 +406,7 @@ fn render_variant(
     variant: &crate::types::Variant,
     representation: &EnumRepresentation,
     array_style: ArrayStyle,
+    branded: &BTreeSet<String>,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
     // Resolve `output_override` literally — see [`generate_struct_block`].
@@ -260,15 +427,17 @@ fn render_variant(
 
     let type_str = match representation {
         EnumRepresentation::ExternallyTagged => {
-            render_variant_externally_tagged(variant, array_style, registry)
+            render_variant_externally_tagged(variant, array_style, branded, registry)
         }
         EnumRepresentation::InternallyTagged { tag } => {
-            render_variant_internally_tagged(variant, tag, array_style, registry)
+            render_variant_internally_tagged(variant, tag, array_style, branded, registry)
         }
         EnumRepresentation::AdjacentlyTagged { tag, content } => {
-            render_variant_adjacently_tagged(variant, tag, content, array_style, registry)
+            render_variant_adjacently_tagged(variant, tag, content, array_style, branded, registry)
+        }
+        EnumRepresentation::Untagged => {
+            render_variant_untagged(variant, array_style, branded, registry)
         }
-        EnumRepresentation::Untagged => render_variant_untagged(variant, array_style, registry),
     };
 
     format!("{}{}", ann_prefix, type_str)
@@ -278,6 +447,7 @@ fn render_variant(
 fn render_variant_externally_tagged(
     variant: &crate::types::Variant,
     array_style: ArrayStyle,
+    branded: &BTreeSet<String>,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
     match &variant.data {
@@ -292,7 +462,7 @@ fn render_variant_externally_tagged(
             format!(
                 "{{ {}: {} }}",
                 variant.name,
-                field_type_to_typescript(ft, array_style, registry).trim()
+                field_type_to_typescript(ft, array_style, branded, registry).trim()
             )
         }
         None => format!("\"{}\"", variant.name),
@@ -307,6 +477,7 @@ fn render_variant_internally_tagged(
     variant: &crate::types::Variant,
     tag: &str,
     array_style: ArrayStyle,
+    branded: &BTreeSet<String>,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
     match &variant.data {
@@ -326,7 +497,7 @@ fn render_variant_internally_tagged(
                 "{{ {}: '{}' }} & {}",
                 tag,
                 variant.name,
-                field_type_to_typescript(ft, array_style, registry).trim()
+                field_type_to_typescript(ft, array_style, branded, registry).trim()
             )
         }
         None => format!("{{ {}: '{}' }}", tag, variant.name),
@@ -340,6 +511,7 @@ fn render_variant_adjacently_tagged(
     tag: &str,
     content: &str,
     array_style: ArrayStyle,
+    branded: &BTreeSet<String>,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
     match &variant.data {
@@ -358,7 +530,7 @@ fn render_variant_adjacently_tagged(
                 tag,
                 variant.name,
                 content,
-                field_type_to_typescript(ft, array_style, registry).trim()
+                field_type_to_typescript(ft, array_style, branded, registry).trim()
             )
         }
         None => format!("{{ {}: '{}' }}", tag, variant.name),
@@ -369,12 +541,13 @@ fn render_variant_adjacently_tagged(
 fn render_variant_untagged(
     variant: &crate::types::Variant,
     array_style: ArrayStyle,
+    branded: &BTreeSet<String>,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
     match &variant.data {
         Some(VariantData::InlineStruct(s)) => s.struct_name.to_case(Case::Pascal),
         Some(VariantData::DataStructureRef(ft)) => {
-            field_type_to_typescript(ft, array_style, registry)
+            field_type_to_typescript(ft, array_style, branded, registry)
                 .trim()
                 .to_string()
         }
@@ -387,6 +560,7 @@ fn render_variant_untagged(
 fn render_field_block(
     field: &crate::types::StructField,
     array_style: ArrayStyle,
+    branded: &BTreeSet<String>,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
     // Resolve `output_override` literally — see [`generate_struct_block`].
@@ -422,17 +596,18 @@ fn render_field_block(
     }
 
     // 5. Field declaration line
-    let field_name = field.field_name.to_case(Case::Camel);
+    let field_name = field.ts_name();
     let type_str = if is_inline && !serde_annotation.is_empty() {
         render_field_type(
             &field.field_type,
             &serde_annotation,
             true,
             array_style,
+            branded,
             registry,
         )
     } else {
-        field_type_to_typescript(&field.field_type, array_style, registry)
+        field_type_to_typescript(&field.field_type, array_style, branded, registry)
     };
 
     lines.push(format!("  {}: {};", field_name, type_str.trim()));
@@ -454,15 +629,22 @@ fn format_derive_line(derives: &[String]) -> String {
 fn field_type_to_typescript(
     field_type: &FieldType,
     array_style: ArrayStyle,
+    branded: &BTreeSet<String>,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
     // Check for foreign type in Other variant before using ts_template
-    if let FieldType::Other(type_name) = field_type
+    if let FieldType::Enum(type_name) | FieldType::Object(type_name) | FieldType::Other(type_name) =
+        field_type
         && let Some(ftc) = registry.lookup(type_name)
         && !ftc.macroforge.is_empty()
     {
         return format!(" {}", ftc.macroforge);
     }
+    if let FieldType::RecordLink(inner) = field_type
+        && let Some(name) = branded_record_link_target(inner, branded)
+    {
+        return format!(" {}Id | {}", name, name);
+    }
     ts_template! {
         {#match field_type}
             {:case FieldType::String | FieldType::Char}
@@ -471,25 +653,35 @@ fn field_type_to_typescript(
                 boolean
             {:case FieldType::Unit}
                 null
+            {:case FieldType::Json}
+                unknown
+            {:case FieldType::Literal(value)}
+                @{value.to_string()}
+            {:case FieldType::Bytes}
+                @{format_array(&FieldType::U8, array_style, branded, registry)}
+            {:case FieldType::Geometry(_)}
+                object
             {:case FieldType::F32 | FieldType::F64}
                 number
-            {:case FieldType::I8 | FieldType::I16 | FieldType::I32 | FieldType::I64 | FieldType::I128 | FieldType::Isize}
+            {:case FieldType::I8 | FieldType::I16 | FieldType::I32 | FieldType::I64 | FieldType::Isize}
                 number
-            {:case FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 | FieldType::U128 | FieldType::Usize}
+            {:case FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 | FieldType::Usize}
                 number
+            {:case FieldType::I128 | FieldType::U128}
+                string
             {:case FieldType::Option(inner)}
-                @{wrap_union_type(inner, array_style, registry)} | null
-            {:case FieldType::Vec(inner)}
-                @{format_array(inner, array_style, registry)}
+                @{wrap_union_type(inner, array_style, branded, registry)} | null
+            {:case FieldType::Vec(inner) | FieldType::Set(inner) | FieldType::Array(inner, _)}
+                @{format_array(inner, array_style, branded, registry)}
             {:case FieldType::Tuple(items)}
-                [@{items.iter().map(|ft| field_type_to_typescript(ft, array_style, registry)).collect::<Vec<_>>().join(", ")}]
+                [@{items.iter().map(|ft| field_type_to_typescript(ft, array_style, branded, registry)).collect::<Vec<_>>().join(", ")}]
             {:case FieldType::Struct(fields)}
-                { @{fields.iter().map(|(name, ft)| format!("{}: {}", name, field_type_to_typescript(ft, array_style, registry))).collect::<Vec<_>>().join("; ")} }
+                { @{fields.iter().map(|(name, ft)| format!("{}: {}", name, field_type_to_typescript(ft, array_style, branded, registry))).collect::<Vec<_>>().join("; ")} }
             {:case FieldType::RecordLink(inner)}
-                RecordLink<@{field_type_to_typescript(inner, array_style, registry).trim()}>
+                RecordLink<@{field_type_to_typescript(inner, array_style, branded, registry).trim()}>
             {:case FieldType::HashMap(key, value) | FieldType::BTreeMap(key, value)}
-                { [key: @{field_type_to_typescript(key, array_style, registry)}]: @{field_type_to_typescript(value, array_style, registry)} }
-            {:case FieldType::Other(type_name)}
+                { [key: @{field_type_to_typescript(key, array_style, branded, registry)}]: @{field_type_to_typescript(value, array_style, branded, registry)} }
+            {:case FieldType::Enum(type_name) | FieldType::Object(type_name) | FieldType::Other(type_name)}
                 @{type_name.to_case(Case::Pascal)}
         {/match}
     }
@@ -501,16 +693,20 @@ fn field_type_to_typescript(
 fn format_array(
     inner: &FieldType,
     array_style: ArrayStyle,
+    branded: &BTreeSet<String>,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
     match array_style {
         ArrayStyle::Shorthand => {
-            format!("{}[]", wrap_union_type(inner, array_style, registry))
+            format!(
+                "{}[]",
+                wrap_union_type(inner, array_style, branded, registry)
+            )
         }
         ArrayStyle::Generic => {
             format!(
                 "Array<{}>",
-                field_type_to_typescript(inner, array_style, registry).trim()
+                field_type_to_typescript(inner, array_style, branded, registry).trim()
             )
         }
     }
@@ -518,16 +714,23 @@ fn format_array(
 
 /// Render a field type for use as an inner type in Option (and, for the
 /// shorthand array style, for Vec as well).
-/// Wraps Option in parentheses for correct `Type[]` semantics; not needed
-/// for generic `Array<Type>` syntax since the angle brackets handle grouping.
+/// Wraps Option and branded RecordLink unions in parentheses for correct
+/// `Type[]` semantics; not needed for generic `Array<Type>` syntax since the
+/// angle brackets handle grouping.
 fn wrap_union_type(
     ft: &FieldType,
     array_style: ArrayStyle,
+    branded: &BTreeSet<String>,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
-    let rendered = field_type_to_typescript(ft, array_style, registry);
+    let rendered = field_type_to_typescript(ft, array_style, branded, registry);
     let trimmed = rendered.trim();
-    if matches!(ft, FieldType::Option(_)) && array_style == ArrayStyle::Shorthand {
+    let is_union = match ft {
+        FieldType::Option(_) => true,
+        FieldType::RecordLink(inner) => branded_record_link_target(inner, branded).is_some(),
+        _ => false,
+    };
+    if is_union && array_style == ArrayStyle::Shorthand {
         format!("({})", trimmed)
     } else {
         trimmed.to_string()
@@ -562,7 +765,7 @@ fn collect_serde_format(
     field_type: &FieldType,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> Option<String> {
-    if let FieldType::Other(name) = field_type
+    if let FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) = field_type
         && let Some(ftc) = registry.lookup(name)
         && !ftc.serde_format.is_empty()
     {
@@ -613,19 +816,23 @@ fn render_field_type(
     serde_annotation: &str,
     inline: bool,
     array_style: ArrayStyle,
+    branded: &BTreeSet<String>,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> String {
     if inline && !serde_annotation.is_empty() {
         // For RecordLink, render @serde inline: /** @serde(...) */ RecordLink<Type>
         if let FieldType::RecordLink(inner) = field_type {
+            if let Some(name) = branded_record_link_target(inner, branded) {
+                return format!("{} {}Id | {}", serde_annotation, name, name);
+            }
             return format!(
                 "{} RecordLink<{}>",
                 serde_annotation,
-                field_type_to_typescript(inner, array_style, registry).trim()
+                field_type_to_typescript(inner, array_style, branded, registry).trim()
             );
         }
     }
-    field_type_to_typescript(field_type, array_style, registry)
+    field_type_to_typescript(field_type, array_style, branded, registry)
 }
 
 /// Compute the `/** import macro {...} from "@dealdraft/macros"; */` line
@@ -685,9 +892,11 @@ fn field_type_contains(ft: &FieldType, predicate: &dyn Fn(&FieldType) -> bool) -
         return true;
     }
     match ft {
-        FieldType::Option(inner) | FieldType::Vec(inner) | FieldType::RecordLink(inner) => {
-            field_type_contains(inner, predicate)
-        }
+        FieldType::Option(inner)
+        | FieldType::Vec(inner)
+        | FieldType::Set(inner)
+        | FieldType::Array(inner, _)
+        | FieldType::RecordLink(inner) => field_type_contains(inner, predicate),
         FieldType::HashMap(k, v) | FieldType::BTreeMap(k, v) => {
             field_type_contains(k, predicate) || field_type_contains(v, predicate)
         }
@@ -707,14 +916,26 @@ fn field_type_contains(ft: &FieldType, predicate: &dyn Fn(&FieldType) -> bool) -
 /// - one import line per foreign type referenced by the given types whose
 ///   TS import is declared in the passed `registry` (built from the user's
 ///   `[general.foreign_types]` config — no foreign types are hardcoded);
-/// - a single `RecordLink` utility import if any field in the types uses it.
+/// - a single `RecordLink` utility import if any field in the types uses it
+///   to a table without a branded id alias in the same set of types (branded
+///   links render as `UserId | User`).
 pub fn compute_extra_imports(
     type_names: &[String],
     structs: &BTreeMap<String, StructConfig>,
     enums: &BTreeMap<String, TaggedUnion>,
+    branded_record_ids: bool,
     registry: &crate::types::ForeignTypeRegistry,
 ) -> Vec<String> {
     let type_set: BTreeSet<String> = type_names.iter().cloned().collect();
+    let branded = if branded_record_ids {
+        let in_set: Vec<&StructConfig> = structs
+            .values()
+            .filter(|s| type_set.contains(&s.effective().struct_name.to_case(Case::Pascal)))
+            .collect();
+        branded_record_id_targets(&in_set)
+    } else {
+        BTreeSet::new()
+    };
     let mut needs_record_link = false;
     let mut foreign_imports: BTreeMap<String, bool> = BTreeMap::new();
 
@@ -723,14 +944,18 @@ pub fn compute_extra_imports(
         registry: &crate::types::ForeignTypeRegistry,
         fi: &mut BTreeMap<String, bool>,
     ) {
-        if let FieldType::Other(name) = ft
+        if let FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) = ft
             && let Some(ftc) = registry.lookup(name)
             && !ftc.ts_import.is_empty()
         {
             fi.insert(ftc.ts_import.name.clone(), ftc.ts_import.is_type_only);
         }
         match ft {
-            FieldType::Option(inner) | FieldType::Vec(inner) | FieldType::RecordLink(inner) => {
+            FieldType::Option(inner)
+            | FieldType::Vec(inner)
+            | FieldType::Set(inner)
+            | FieldType::Array(inner, _)
+            | FieldType::RecordLink(inner) => {
                 collect_foreign_imports_recursive(inner, registry, fi)
             }
             FieldType::HashMap(k, v) | FieldType::BTreeMap(k, v) => {
@@ -752,7 +977,10 @@ pub fn compute_extra_imports(
     }
 
     let check_field_type = |ft: &FieldType, rl: &mut bool, fi: &mut BTreeMap<String, bool>| {
-        if field_type_contains(ft, &|f| matches!(f, FieldType::RecordLink(_))) {
+        if field_type_contains(
+            ft,
+            &|f| matches!(f, FieldType::RecordLink(inner) if branded_record_link_target(inner, &branded).is_none()),
+        ) {
             *rl = true;
         }
         collect_foreign_imports_recursive(ft, registry, fi);
@@ -1161,30 +1389,53 @@ mod tests {
         let registry = crate::types::ForeignTypeRegistry::default();
         let s = ArrayStyle::Shorthand;
         // ts_template! adds whitespace, so we trim for comparison
-        assert!(field_type_to_typescript(&FieldType::String, s, &registry).trim() == "string");
-        assert!(field_type_to_typescript(&FieldType::Bool, s, &registry).trim() == "boolean");
-        assert!(field_type_to_typescript(&FieldType::I32, s, &registry).trim() == "number");
-        assert!(field_type_to_typescript(&FieldType::F64, s, &registry).trim() == "number");
+        assert!(
+            field_type_to_typescript(&FieldType::String, s, &BTreeSet::new(), &registry).trim()
+                == "string"
+        );
+        assert!(
+            field_type_to_typescript(&FieldType::Bool, s, &BTreeSet::new(), &registry).trim()
+                == "boolean"
+        );
+        assert!(
+            field_type_to_typescript(&FieldType::I32, s, &BTreeSet::new(), &registry).trim()
+                == "number"
+        );
+        assert!(
+            field_type_to_typescript(&FieldType::F64, s, &BTreeSet::new(), &registry).trim()
+                == "number"
+        );
         assert!(
             field_type_to_typescript(
                 &FieldType::Option(Box::new(FieldType::String)),
                 s,
+                &BTreeSet::new(),
                 &registry
             )
             .contains("string")
                 && field_type_to_typescript(
                     &FieldType::Option(Box::new(FieldType::String)),
                     s,
+                    &BTreeSet::new(),
                     &registry
                 )
                 .contains("null")
         );
-        let vec_output =
-            field_type_to_typescript(&FieldType::Vec(Box::new(FieldType::I32)), s, &registry);
+        let vec_output = field_type_to_typescript(
+            &FieldType::Vec(Box::new(FieldType::I32)),
+            s,
+            &BTreeSet::new(),
+            &registry,
+        );
         assert!(vec_output.contains("number") && vec_output.contains("[]"));
         assert!(
-            field_type_to_typescript(&FieldType::Other("UserProfile".to_string()), s, &registry)
-                .contains("UserProfile")
+            field_type_to_typescript(
+                &FieldType::Other("UserProfile".to_string()),
+                s,
+                &BTreeSet::new(),
+                &registry
+            )
+            .contains("UserProfile")
         );
     }
 
@@ -1193,8 +1444,12 @@ mod tests {
         let registry = crate::types::ForeignTypeRegistry::default();
         let g = ArrayStyle::Generic;
         // Vec<i32> → Array<number>
-        let vec_output =
-            field_type_to_typescript(&FieldType::Vec(Box::new(FieldType::I32)), g, &registry);
+        let vec_output = field_type_to_typescript(
+            &FieldType::Vec(Box::new(FieldType::I32)),
+            g,
+            &BTreeSet::new(),
+            &registry,
+        );
         assert!(
             vec_output.contains("Array<number>"),
             "Expected Array<number>, got: {}",
@@ -1209,6 +1464,7 @@ mod tests {
         let vec_opt = field_type_to_typescript(
             &FieldType::Vec(Box::new(FieldType::Option(Box::new(FieldType::String)))),
             g,
+            &BTreeSet::new(),
             &registry,
         );
         assert!(
@@ -1306,6 +1562,7 @@ mod tests {
                 ],
                 validators: vec![],
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: Pipeline::default(),
@@ -1321,6 +1578,8 @@ mod tests {
             &BTreeMap::new(),
             true,
             ArrayStyle::default(),
+            false,
+            TableCase::default(),
             &registry,
         );
 
@@ -1405,6 +1664,7 @@ mod tests {
                 ],
                 validators: vec![],
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![
                     "Default".to_string(),
                     "Serialize".to_string(),
@@ -1468,6 +1728,8 @@ mod tests {
             &enums,
             true,
             ArrayStyle::default(),
+            false,
+            TableCase::default(),
             &registry,
         );
 
@@ -1520,6 +1782,7 @@ mod tests {
                 fields: vec![],
                 validators: vec![],
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: Pipeline::default(),
@@ -1535,6 +1798,8 @@ mod tests {
             &BTreeMap::new(),
             true,
             ArrayStyle::default(),
+            false,
+            TableCase::default(),
             &registry,
         );
         assert!(
@@ -1544,6 +1809,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_branded_record_ids_alias_and_record_link_fields() {
+        let table = |name: &str, fields: Vec<StructField>| StructConfig {
+            struct_name: name.to_string(),
+            fields,
+            validators: vec![],
+            doccom: None,
+            doc: None,
+            macroforge_derives: vec![],
+            annotations: vec![],
+            pipeline: Pipeline::default(),
+            rust_derives: vec![],
+            output_override: None,
+            raw_attributes: std::collections::BTreeMap::new(),
+        };
+        let id = || StructField {
+            field_name: "id".to_string(),
+            field_type: FieldType::String,
+            ..Default::default()
+        };
+        let user_link = || FieldType::RecordLink(Box::new(FieldType::Other("User".to_string())));
+
+        let mut structs = BTreeMap::new();
+        structs.insert("user".to_string(), table("user", vec![id()]));
+        structs.insert(
+            "post".to_string(),
+            table(
+                "post",
+                vec![
+                    id(),
+                    StructField {
+                        field_name: "author".to_string(),
+                        field_type: user_link(),
+                        ..Default::default()
+                    },
+                    StructField {
+                        field_name: "likes".to_string(),
+                        field_type: FieldType::Vec(Box::new(user_link())),
+                        ..Default::default()
+                    },
+                ],
+            ),
+        );
+
+        let registry = crate::types::ForeignTypeRegistry::default();
+        let output = generate_macroforge_type_string(
+            &structs,
+            &BTreeMap::new(),
+            true,
+            ArrayStyle::default(),
+            true,
+            TableCase::default(),
+            &registry,
+        );
+
+        assert!(output.contains("export type UserId = string & { __brand: 'user' };"));
+        assert!(output.contains("export type PostId = string & { __brand: 'post' };"));
+        assert!(
+            output.contains("author: UserId | User;"),
+            "Output:\n{}",
+            output
+        );
+        assert!(
+            output.contains("likes: (UserId | User)[];"),
+            "Output:\n{}",
+            output
+        );
+        assert!(!output.contains("RecordLink"), "Output:\n{}", output);
+    }
+
     fn make_datetime_registry() -> crate::types::ForeignTypeRegistry {
         use crate::config::ForeignTypeConfig;
         let mut foreign_types = BTreeMap::new();
@@ -1582,13 +1917,19 @@ mod tests {
         let registry = make_datetime_registry();
         let s = ArrayStyle::Shorthand;
         assert!(
-            field_type_to_typescript(&FieldType::Other("DateTime".to_string()), s, &registry)
-                .contains("DateTime.Utc")
+            field_type_to_typescript(
+                &FieldType::Other("DateTime".to_string()),
+                s,
+                &BTreeSet::new(),
+                &registry
+            )
+            .contains("DateTime.Utc")
         );
         // Option<DateTime> should produce DateTime.Utc | null
         let opt_dt = field_type_to_typescript(
             &FieldType::Option(Box::new(FieldType::Other("DateTime".to_string()))),
             s,
+            &BTreeSet::new(),
             &registry,
         );
         assert!(opt_dt.contains("DateTime.Utc") && opt_dt.contains("null"));
@@ -1596,6 +1937,7 @@ mod tests {
         let vec_dt = field_type_to_typescript(
             &FieldType::Vec(Box::new(FieldType::Other("DateTime".to_string()))),
             s,
+            &BTreeSet::new(),
             &registry,
         );
         assert!(vec_dt.contains("DateTime.Utc") && vec_dt.contains("[]"));
@@ -1606,13 +1948,19 @@ mod tests {
         let registry = make_datetime_registry();
         let s = ArrayStyle::Shorthand;
         assert!(
-            field_type_to_typescript(&FieldType::Other("Decimal".to_string()), s, &registry)
-                .contains("BigDecimal.BigDecimal")
+            field_type_to_typescript(
+                &FieldType::Other("Decimal".to_string()),
+                s,
+                &BTreeSet::new(),
+                &registry
+            )
+            .contains("BigDecimal.BigDecimal")
         );
         // Option<Decimal> should produce BigDecimal.BigDecimal | null
         let opt_dec = field_type_to_typescript(
             &FieldType::Option(Box::new(FieldType::Other("Decimal".to_string()))),
             s,
+            &BTreeSet::new(),
             &registry,
         );
         assert!(opt_dec.contains("BigDecimal.BigDecimal") && opt_dec.contains("null"));
@@ -1633,6 +1981,7 @@ mod tests {
                 }],
                 validators: vec![],
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: Pipeline::default(),
@@ -1642,8 +1991,13 @@ mod tests {
             },
         );
 
-        let imports =
-            compute_extra_imports(&["Event".to_string()], &structs, &BTreeMap::new(), &registry);
+        let imports = compute_extra_imports(
+            &["Event".to_string()],
+            &structs,
+            &BTreeMap::new(),
+            false,
+            &registry,
+        );
         assert_eq!(
             imports,
             vec!["import type { DateTime } from 'effect';".to_string()]
@@ -1665,6 +2019,7 @@ mod tests {
                 }],
                 validators: vec![],
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: Pipeline::default(),
@@ -1678,6 +2033,7 @@ mod tests {
             &["Payment".to_string()],
             &structs,
             &BTreeMap::new(),
+            false,
             &registry,
         );
         assert_eq!(
@@ -1708,6 +2064,7 @@ mod tests {
                 ],
                 validators: vec![],
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: Pipeline::default(),
@@ -1717,8 +2074,13 @@ mod tests {
             },
         );
 
-        let imports =
-            compute_extra_imports(&["Order".to_string()], &structs, &BTreeMap::new(), &registry);
+        let imports = compute_extra_imports(
+            &["Order".to_string()],
+            &structs,
+            &BTreeMap::new(),
+            false,
+            &registry,
+        );
         assert_eq!(
             imports,
             vec![
@@ -1743,6 +2105,7 @@ mod tests {
                 }],
                 validators: vec![],
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: Pipeline::default(),
@@ -1752,8 +2115,13 @@ mod tests {
             },
         );
 
-        let imports =
-            compute_extra_imports(&["User".to_string()], &structs, &BTreeMap::new(), &registry);
+        let imports = compute_extra_imports(
+            &["User".to_string()],
+            &structs,
+            &BTreeMap::new(),
+            false,
+            &registry,
+        );
         assert!(imports.is_empty());
     }
 
@@ -1772,6 +2140,7 @@ mod tests {
                 }],
                 validators: vec![],
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec!["Serialize".to_string(), "Deserialize".to_string()],
                 annotations: vec!["@overview({ dataName: \"order\" })".to_string()],
                 pipeline: Pipeline::default(),
@@ -1787,6 +2156,8 @@ mod tests {
             &structs,
             &BTreeMap::new(),
             ArrayStyle::default(),
+            false,
+            TableCase::default(),
             &registry,
         );
 
@@ -1806,4 +2177,227 @@ mod tests {
             output
         );
     }
+
+    fn union_member(name: &str, fields: Vec<StructField>) -> (String, StructConfig) {
+        (
+            name.to_string(),
+            StructConfig {
+                struct_name: name.to_string(),
+                fields,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn string_field(name: &str) -> StructField {
+        StructField {
+            field_name: name.to_string(),
+            field_type: FieldType::String,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_union_of_tables_two_variants() {
+        let structs: BTreeMap<String, StructConfig> = [
+            union_member("Cat", vec![string_field("name")]),
+            union_member("Dog", vec![string_field("breed_name")]),
+        ]
+        .into_iter()
+        .collect();
+        let entry = crate::registry::UnionOfTablesRegistryEntry {
+            type_name: "Pet",
+            table_names: &["Cat", "Dog"],
+            pipeline: Pipeline::Both,
+        };
+
+        let registry = crate::types::ForeignTypeRegistry::default();
+        let output =
+            generate_macroforge_union_of_tables(&entry, &structs, ArrayStyle::default(), &registry);
+
+        assert_eq!(
+            output,
+            "/** @derive(Deserialize) */\n\
+             export interface Cat {\n  \
+             /** @serde({ validate: [\"nonEmpty\"] }) */\n  \
+             name: string;\n\
+             }\n\n\
+             /** @derive(Deserialize) */\n\
+             export interface Dog {\n  \
+             /** @serde({ validate: [\"nonEmpty\"] }) */\n  \
+             breedName: string;\n\
+             }\n\n\
+             export type Pet = Cat | Dog;\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_union_of_tables_dedupes_shared_struct() {
+        let structs: BTreeMap<String, StructConfig> =
+            [union_member("User", vec![string_field("email")])]
+                .into_iter()
+                .collect();
+        let entry = crate::registry::UnionOfTablesRegistryEntry {
+            type_name: "Principal",
+            table_names: &["User", "User"],
+            pipeline: Pipeline::Both,
+        };
+
+        let registry = crate::types::ForeignTypeRegistry::default();
+        let output =
+            generate_macroforge_union_of_tables(&entry, &structs, ArrayStyle::default(), &registry);
+
+        assert_eq!(output.matches("export interface User {").count(), 1);
+        assert!(output.ends_with("export type Principal = User;\n"));
+    }
+
+    #[test]
+    fn test_generate_union_of_tables_recursive_members() {
+        let children = StructField {
+            field_name: "children".to_string(),
+            field_type: FieldType::Vec(Box::new(FieldType::Other("FsNode".to_string()))),
+            ..Default::default()
+        };
+        let structs: BTreeMap<String, StructConfig> = [
+            union_member("File", vec![string_field("path")]),
+            union_member("Folder", vec![string_field("path"), children]),
+        ]
+        .into_iter()
+        .collect();
+        let entry = crate::registry::UnionOfTablesRegistryEntry {
+            type_name: "FsNode",
+            table_names: &["File", "Folder"],
+            pipeline: Pipeline::Both,
+        };
+
+        let registry = crate::types::ForeignTypeRegistry::default();
+        let output =
+            generate_macroforge_union_of_tables(&entry, &structs, ArrayStyle::default(), &registry);
+
+        assert!(
+            output.contains("children: FsNode[];"),
+            "Output:\n{}",
+            output
+        );
+        assert!(output.ends_with("export type FsNode = File | Folder;\n"));
+    }
+
+    fn variant(name: &str, data: Option<VariantData>) -> Variant {
+        Variant {
+            name: name.to_string(),
+            data,
+            doccom: None,
+            annotations: vec![],
+            output_override: None,
+            raw_attributes: BTreeMap::new(),
+            is_default: false,
+        }
+    }
+
+    fn enum_block(name: &str, variants: Vec<Variant>) -> String {
+        tagged_enum_block(name, EnumRepresentation::default(), variants)
+    }
+
+    fn tagged_enum_block(
+        name: &str,
+        representation: EnumRepresentation,
+        variants: Vec<Variant>,
+    ) -> String {
+        let enum_def = TaggedUnion {
+            enum_name: name.to_string(),
+            variants,
+            doccom: None,
+            macroforge_derives: vec![],
+            annotations: vec![],
+            representation,
+            pipeline: Pipeline::default(),
+            rust_derives: vec![],
+            output_override: None,
+            raw_attributes: BTreeMap::new(),
+        };
+        let registry = crate::types::ForeignTypeRegistry::default();
+        generate_enum_block(
+            &enum_def,
+            ArrayStyle::default(),
+            &BTreeSet::new(),
+            &registry,
+        )
+    }
+
+    #[test]
+    fn test_unit_only_enum_is_string_literal_union() {
+        let output = enum_block(
+            "Status",
+            vec![variant("Active", None), variant("Inactive", None)],
+        );
+
+        assert!(
+            output.contains("export type Status = \"Active\" | \"Inactive\";"),
+            "Output:\n{}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_mixed_enum_keeps_discriminated_union() {
+        let output = enum_block(
+            "Shape",
+            vec![
+                variant("Empty", None),
+                variant(
+                    "Circle",
+                    Some(VariantData::DataStructureRef(FieldType::F64)),
+                ),
+            ],
+        );
+
+        assert!(
+            output.contains("export type Shape =\n\t| \"Empty\"\n\t| { Circle: number };"),
+            "Output:\n{}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_internally_tagged_enum_uses_tag_discriminator() {
+        let variants = || {
+            vec![
+                variant("Empty", None),
+                variant(
+                    "Circle",
+                    Some(VariantData::InlineStruct(StructConfig {
+                        struct_name: "Circle".to_string(),
+                        ..StructConfig::default()
+                    })),
+                ),
+            ]
+        };
+
+        let external = tagged_enum_block("Shape", EnumRepresentation::ExternallyTagged, variants());
+        assert!(
+            external.contains("export type Shape =\n\t| \"Empty\"\n\t| { Circle: Circle };"),
+            "Output:\n{}",
+            external
+        );
+
+        let internal = tagged_enum_block(
+            "Shape",
+            EnumRepresentation::InternallyTagged {
+                tag: "type".to_string(),
+            },
+            variants(),
+        );
+        assert!(
+            internal.contains("/** @serde({ tag: \"type\" }) */"),
+            "Output:\n{}",
+            internal
+        );
+        assert!(
+            internal.contains(
+                "export type Shape =\n\t| { type: 'Empty' }\n\t| { type: 'Circle' } & Circle;"
+            ),
+            "Output:\n{}",
+            internal
+        );
+    }
 }
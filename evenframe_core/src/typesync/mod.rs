@@ -5,7 +5,9 @@ pub mod doc_comment;
 pub mod effect;
 pub mod file_grouping;
 pub mod import_resolver;
+pub mod json_schema;
 pub mod plugin_types;
+pub mod zod;
 
 #[cfg(feature = "wasm-plugins")]
 pub mod plugin;
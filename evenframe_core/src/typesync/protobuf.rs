@@ -214,7 +214,7 @@ fn field_type_to_protobuf_with_prefix(
             let (_, inner_type) = field_type_to_protobuf_with_prefix(inner, registry);
             ("optional ".to_string(), inner_type)
         }
-        FieldType::Vec(inner) => {
+        FieldType::Vec(inner) | FieldType::Set(inner) | FieldType::Array(inner, _) => {
             let (_, inner_type) = field_type_to_protobuf_with_prefix(inner, registry);
             ("repeated ".to_string(), inner_type)
         }
@@ -231,6 +231,12 @@ fn field_type_to_protobuf(
         FieldType::String | FieldType::Char => "string".to_string(),
         FieldType::Bool => "bool".to_string(),
         FieldType::Unit => "bool".to_string(), // Placeholder for unit type
+        FieldType::Json => "string".to_string(), // Serialized JSON text
+        FieldType::Literal(value) => {
+            field_type_to_protobuf(&FieldType::literal_base_type(value), registry)
+        }
+        FieldType::Bytes => "bytes".to_string(),
+        FieldType::Geometry(_) => "string".to_string(), // Serialized GeoJSON
         FieldType::F32 => "float".to_string(),
         FieldType::F64 => "double".to_string(),
         FieldType::I8 | FieldType::I16 | FieldType::I32 => "int32".to_string(),
@@ -246,7 +252,7 @@ fn field_type_to_protobuf(
             field_type_to_protobuf(inner, registry)
         }
 
-        FieldType::Vec(inner) => {
+        FieldType::Vec(inner) | FieldType::Set(inner) | FieldType::Array(inner, _) => {
             // For nested vecs, just return the inner type
             field_type_to_protobuf(inner, registry)
         }
@@ -274,14 +280,17 @@ fn field_type_to_protobuf(
 
         FieldType::RecordLink(inner) => {
             // For record links, use the inner type name
-            if let FieldType::Other(type_name) = inner.as_ref() {
+            if let FieldType::Enum(type_name)
+            | FieldType::Object(type_name)
+            | FieldType::Other(type_name) = inner.as_ref()
+            {
                 type_name.to_case(Case::Pascal)
             } else {
                 field_type_to_protobuf(inner, registry)
             }
         }
 
-        FieldType::Other(type_name) => {
+        FieldType::Enum(type_name) | FieldType::Object(type_name) | FieldType::Other(type_name) => {
             // Check foreign type registry first
             if let Some(ftc) = registry.lookup(type_name)
                 && !ftc.protobuf.is_empty()
@@ -329,11 +338,11 @@ fn get_validate_rule_type(
         FieldType::I64 | FieldType::Isize => "int64".to_string(),
         FieldType::U8 | FieldType::U16 | FieldType::U32 => "uint32".to_string(),
         FieldType::U64 | FieldType::Usize => "uint64".to_string(),
-        FieldType::Vec(_) => "repeated".to_string(),
+        FieldType::Vec(_) | FieldType::Set(_) | FieldType::Array(_, _) => "repeated".to_string(),
         FieldType::Option(inner) => get_validate_rule_type(inner, registry),
         FieldType::HashMap(_, _) | FieldType::BTreeMap(_, _) => "map".to_string(),
         FieldType::I128 | FieldType::U128 => "string".to_string(),
-        FieldType::Other(name) => {
+        FieldType::Enum(name) | FieldType::Object(name) | FieldType::Other(name) => {
             if let Some(ftc) = registry.lookup(name)
                 && !ftc.protobuf_wire_type.is_empty()
             {
@@ -774,6 +783,7 @@ mod tests {
                 ],
                 validators: vec![],
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: crate::types::Pipeline::default(),
@@ -933,6 +943,7 @@ mod tests {
                 ],
                 validators: vec![],
                 doccom: None,
+                doc: None,
                 macroforge_derives: vec![],
                 annotations: vec![],
                 pipeline: crate::types::Pipeline::default(),
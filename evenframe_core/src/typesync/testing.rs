@@ -48,6 +48,7 @@ pub fn generate_struct_with_override(
         fields,
         validators: vec![],
         doccom: None,
+        doc: None,
         macroforge_derives: vec![],
         annotations: vec![],
         pipeline: crate::types::Pipeline::Both,
@@ -76,6 +77,8 @@ pub fn generate_struct_with_override(
         &enums,
         false,
         crate::typesync::config::ArrayStyle::default(),
+        false,
+        crate::schemasync::TableCase::default(),
         &registry,
     );
     GeneratedOutput {
@@ -111,6 +114,8 @@ pub fn generate_enum_with_override(
         &enums,
         false,
         crate::typesync::config::ArrayStyle::default(),
+        false,
+        crate::schemasync::TableCase::default(),
         &registry,
     );
     GeneratedOutput {
@@ -129,9 +134,13 @@ pub fn test_field(name: &str, field_type: crate::types::FieldType) -> StructFiel
         validators: vec![],
         always_regenerate: false,
         doccom: None,
+        doc: None,
         annotations: vec![],
         unique: false,
+        indexed: false,
+        search_analyzer: None,
         mock_plugin: None,
+        rename: None,
         output_override: None,
         raw_attributes: BTreeMap::new(),
     }
@@ -155,6 +164,7 @@ mod tests {
             fields: vec![],
             validators: vec![],
             doccom: None,
+            doc: None,
             macroforge_derives: vec![
                 "Default".into(),
                 "Serialize".into(),
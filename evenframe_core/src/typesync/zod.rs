@@ -0,0 +1,459 @@
+use crate::types::StructConfig;
+use crate::types::{EnumRepresentation, FieldType, TaggedUnion, Variant, VariantData};
+use crate::typesync::doc_comment::format_jsdoc;
+use convert_case::{Case, Casing};
+use std::collections::BTreeMap;
+use tracing;
+
+/// Name of the exported Zod schema constant for a struct or enum.
+fn schema_name(type_name: &str) -> String {
+    format!("{}Schema", type_name.to_case(Case::Pascal))
+}
+
+/// Joins variant schemas into a single schema. `z.union` requires at least
+/// two members, so single-variant enums are emitted bare.
+fn union_of(items: Vec<String>) -> String {
+    match items.len() {
+        0 => "z.never()".to_string(),
+        1 => items.into_iter().next().unwrap(),
+        _ => format!("z.union([{}])", items.join(", ")),
+    }
+}
+
+/// Converts a single enum variant into its Zod representation,
+/// respecting the serde enum representation strategy.
+fn variant_to_zod(
+    variant: &Variant,
+    representation: &EnumRepresentation,
+    structs: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> String {
+    let data_to_zod = |variant_data: &VariantData| match variant_data {
+        VariantData::InlineStruct(enum_struct) => field_type_to_zod(
+            &FieldType::Other(enum_struct.struct_name.clone()),
+            structs,
+            enums,
+            registry,
+        ),
+        VariantData::DataStructureRef(field_type) => {
+            field_type_to_zod(field_type, structs, enums, registry)
+        }
+    };
+
+    match representation {
+        EnumRepresentation::ExternallyTagged => match &variant.data {
+            Some(variant_data) => {
+                format!(
+                    "z.object({{ {}: {} }})",
+                    variant.name,
+                    data_to_zod(variant_data)
+                )
+            }
+            None => format!("z.literal(\"{}\")", variant.name),
+        },
+        EnumRepresentation::InternallyTagged { tag } => match &variant.data {
+            Some(VariantData::InlineStruct(enum_struct)) => {
+                // Merge the tag field into the struct fields
+                let mut fields_parts: Vec<String> =
+                    vec![format!("{}: z.literal(\"{}\")", tag, variant.name)];
+                for field in &enum_struct.fields {
+                    fields_parts.push(format!(
+                        "{}: {}",
+                        field.ts_name(),
+                        field_type_to_zod(&field.field_type, structs, enums, registry)
+                    ));
+                }
+                format!("z.object({{ {} }})", fields_parts.join(", "))
+            }
+            Some(variant_data) => {
+                // Internally tagged doesn't work well with non-struct data;
+                // fall back to externally tagged wrapping.
+                format!(
+                    "z.object({{ {}: {} }})",
+                    variant.name,
+                    data_to_zod(variant_data)
+                )
+            }
+            None => format!("z.object({{ {}: z.literal(\"{}\") }})", tag, variant.name),
+        },
+        EnumRepresentation::AdjacentlyTagged { tag, content } => match &variant.data {
+            Some(variant_data) => format!(
+                "z.object({{ {}: z.literal(\"{}\"), {}: {} }})",
+                tag,
+                variant.name,
+                content,
+                data_to_zod(variant_data)
+            ),
+            None => format!("z.object({{ {}: z.literal(\"{}\") }})", tag, variant.name),
+        },
+        EnumRepresentation::Untagged => match &variant.data {
+            Some(variant_data) => data_to_zod(variant_data),
+            None => format!("z.literal(\"{}\")", variant.name),
+        },
+    }
+}
+
+/// Converts an enum into its Zod schema. Enums whose variants all serialize
+/// as bare strings become `z.enum([...])`; everything else is a `z.union`.
+fn enum_to_zod(
+    schema_enum: &TaggedUnion,
+    structs: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> String {
+    let string_literals = matches!(
+        schema_enum.representation,
+        EnumRepresentation::ExternallyTagged | EnumRepresentation::Untagged
+    ) && schema_enum.variants.iter().all(|v| v.data.is_none());
+
+    if string_literals && !schema_enum.variants.is_empty() {
+        let names: Vec<String> = schema_enum
+            .variants
+            .iter()
+            .map(|v| format!("\"{}\"", v.name))
+            .collect();
+        return format!("z.enum([{}])", names.join(", "));
+    }
+
+    union_of(
+        schema_enum
+            .variants
+            .iter()
+            .map(|variant| {
+                variant_to_zod(
+                    variant,
+                    &schema_enum.representation,
+                    structs,
+                    enums,
+                    registry,
+                )
+            })
+            .collect(),
+    )
+}
+
+pub fn field_type_to_zod(
+    field_type: &FieldType,
+    structs: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> String {
+    tracing::trace!(field_type = ?field_type, "Converting field type to Zod");
+    match field_type {
+        FieldType::String | FieldType::Char => "z.string()".to_string(),
+        FieldType::Bool => "z.boolean()".to_string(),
+        FieldType::Unit => "z.null()".to_string(),
+        FieldType::Json => "z.unknown()".to_string(),
+        // `z.literal` compares by identity, so arrays and objects only get
+        // their base type
+        FieldType::Literal(value) if value.is_array() || value.is_object() => {
+            "z.unknown()".to_string()
+        }
+        FieldType::Literal(value) => format!("z.literal({})", value),
+        FieldType::Bytes => "z.array(z.number().int().min(0).max(255))".to_string(),
+        FieldType::Geometry(_) => "z.record(z.string(), z.unknown())".to_string(),
+        FieldType::F32 | FieldType::F64 => "z.number()".to_string(),
+        FieldType::I8 | FieldType::I16 | FieldType::I32 | FieldType::I64 | FieldType::Isize => {
+            "z.number().int()".to_string()
+        }
+        FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 | FieldType::Usize => {
+            "z.number().int().nonnegative()".to_string()
+        }
+        // Stored as decimal strings, a JS number cannot hold the full range
+        FieldType::I128 | FieldType::U128 => "z.string()".to_string(),
+
+        FieldType::Tuple(types) => {
+            let types_str = types
+                .iter()
+                .map(|t| field_type_to_zod(t, structs, enums, registry))
+                .collect::<Vec<String>>()
+                .join(", ");
+            format!("z.tuple([{}])", types_str)
+        }
+
+        FieldType::Struct(fields) => {
+            let fields_str = fields
+                .iter()
+                .map(|(name, field_type)| {
+                    format!(
+                        "{}: {}",
+                        name,
+                        field_type_to_zod(field_type, structs, enums, registry)
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+            format!("z.object({{ {} }})", fields_str)
+        }
+
+        FieldType::Option(inner) => format!(
+            "{}.nullable().optional()",
+            field_type_to_zod(inner, structs, enums, registry)
+        ),
+
+        FieldType::Vec(inner) | FieldType::Set(inner) | FieldType::Array(inner, _) => format!(
+            "z.array({})",
+            field_type_to_zod(inner, structs, enums, registry)
+        ),
+
+        // JSON object keys are always strings, whatever the Rust key type.
+        FieldType::HashMap(_, value) | FieldType::BTreeMap(_, value) => format!(
+            "z.record(z.string(), {})",
+            field_type_to_zod(value, structs, enums, registry)
+        ),
+
+        // A link is either the fetched record or its id string.
+        FieldType::RecordLink(inner) => format!(
+            "z.union([{}, z.string().brand<\"RecordLink\">()])",
+            field_type_to_zod(inner, structs, enums, registry)
+        ),
+
+        FieldType::Enum(type_name) | FieldType::Object(type_name) | FieldType::Other(type_name) => {
+            // Check foreign type registry first
+            if let Some(ftc) = registry.lookup(type_name)
+                && !ftc.zod.is_empty()
+            {
+                return ftc.zod.clone();
+            }
+
+            // Structs and enums are referenced lazily so declaration order and
+            // recursive types don't matter.
+            let is_known = structs.values().any(|s| s.struct_name == *type_name)
+                || enums.values().any(|e| e.enum_name == *type_name);
+            if is_known {
+                return format!("z.lazy(() => {})", schema_name(type_name));
+            }
+
+            tracing::warn!(type_name = %type_name, "No Zod mapping for type, using z.unknown()");
+            "z.unknown()".to_string()
+        }
+    }
+}
+
+pub fn generate_zod_schema_string(
+    structs: &BTreeMap<String, StructConfig>,
+    enums: &BTreeMap<String, TaggedUnion>,
+    print_types: bool,
+    registry: &crate::types::ForeignTypeRegistry,
+) -> String {
+    tracing::info!(
+        struct_count = structs.len(),
+        enum_count = enums.len(),
+        print_types = print_types,
+        "Generating Zod schema string"
+    );
+    let mut output = String::new();
+
+    // First, process all enums. Use `effective()` so overrides replace
+    // the scanned type.
+    for schema_enum in enums.values() {
+        let schema_enum = schema_enum.effective();
+        let type_name = schema_enum.enum_name.to_case(Case::Pascal);
+
+        if let Some(ref doc) = schema_enum.doccom {
+            output.push_str(&format_jsdoc(doc, ""));
+            output.push('\n');
+        }
+        output.push_str(&format!(
+            "export const {} = {};\n",
+            schema_name(&schema_enum.enum_name),
+            enum_to_zod(schema_enum, structs, enums, registry)
+        ));
+        if print_types {
+            output.push_str(&format!(
+                "export type {} = z.infer<typeof {}>;\n",
+                type_name,
+                schema_name(&schema_enum.enum_name)
+            ));
+        }
+        output.push('\n');
+    }
+
+    // Then, process all structs. Use `effective()` so overrides replace
+    // the scanned type.
+    tracing::debug!("Processing structs for Zod");
+    for struct_config in structs.values() {
+        let struct_config = struct_config.effective();
+        tracing::trace!(struct_name = %struct_config.struct_name, "Processing struct");
+        let type_name = struct_config.struct_name.to_case(Case::Pascal);
+
+        if let Some(ref doc) = struct_config.doccom {
+            output.push_str(&format_jsdoc(doc, ""));
+            output.push('\n');
+        }
+        output.push_str(&format!(
+            "export const {} = z.object({{\n",
+            schema_name(&struct_config.struct_name)
+        ));
+
+        for field in &struct_config.fields {
+            if let Some(ref doc) = field.doccom {
+                output.push_str(&format_jsdoc(doc, "  "));
+                output.push('\n');
+            }
+            output.push_str(&format!(
+                "  {}: {},\n",
+                field.ts_name(),
+                field_type_to_zod(&field.field_type, structs, enums, registry)
+            ));
+        }
+
+        output.push_str("});\n");
+        if print_types {
+            output.push_str(&format!(
+                "export type {} = z.infer<typeof {}>;\n",
+                type_name,
+                schema_name(&struct_config.struct_name)
+            ));
+        }
+        output.push('\n');
+    }
+
+    tracing::info!(
+        output_length = output.len(),
+        "Zod schema string generation complete"
+    );
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ForeignTypeConfig;
+    use crate::types::{ForeignTypeRegistry, StructField};
+
+    fn field(name: &str, field_type: FieldType) -> StructField {
+        StructField {
+            field_name: name.to_string(),
+            field_type,
+            ..StructField::default()
+        }
+    }
+
+    fn unit_variant(name: &str) -> Variant {
+        Variant {
+            name: name.to_string(),
+            data: None,
+            doccom: None,
+            annotations: vec![],
+            output_override: None,
+            raw_attributes: BTreeMap::new(),
+            is_default: false,
+        }
+    }
+
+    #[test]
+    fn wide_integers_are_strings() {
+        let registry = ForeignTypeRegistry::default();
+        let zod = |field_type| {
+            field_type_to_zod(&field_type, &BTreeMap::new(), &BTreeMap::new(), &registry)
+        };
+        assert_eq!(zod(FieldType::I64), "z.number().int()");
+        assert_eq!(zod(FieldType::I128), "z.string()");
+        assert_eq!(zod(FieldType::U128), "z.string()");
+    }
+
+    #[test]
+    fn generates_object_with_optional_vec_and_enum_fields() {
+        let user = StructConfig {
+            struct_name: "User".to_string(),
+            fields: vec![
+                field("display_name", FieldType::String),
+                field("nickname", FieldType::Option(Box::new(FieldType::String))),
+                field("tags", FieldType::Vec(Box::new(FieldType::String))),
+                field("role", FieldType::Other("Role".to_string())),
+                field(
+                    "team",
+                    FieldType::RecordLink(Box::new(FieldType::Other("Team".to_string()))),
+                ),
+                field("created_at", FieldType::Other("DateTime".to_string())),
+            ],
+            ..StructConfig::default()
+        };
+        let team = StructConfig {
+            struct_name: "Team".to_string(),
+            fields: vec![field("name", FieldType::String)],
+            ..StructConfig::default()
+        };
+        let role = TaggedUnion {
+            enum_name: "Role".to_string(),
+            variants: vec![unit_variant("Admin"), unit_variant("Member")],
+            representation: EnumRepresentation::ExternallyTagged,
+            doccom: None,
+            macroforge_derives: vec![],
+            annotations: vec![],
+            pipeline: crate::types::Pipeline::default(),
+            rust_derives: vec![],
+            output_override: None,
+            raw_attributes: BTreeMap::new(),
+        };
+
+        let structs = BTreeMap::from([("User".to_string(), user), ("Team".to_string(), team)]);
+        let enums = BTreeMap::from([("Role".to_string(), role)]);
+        let registry = ForeignTypeRegistry::from_config(&BTreeMap::from([(
+            "DateTime".to_string(),
+            ForeignTypeConfig {
+                rust_type_names: vec!["DateTime".to_string()],
+                zod: "z.string().datetime()".to_string(),
+                ..ForeignTypeConfig::default()
+            },
+        )]));
+
+        let output = generate_zod_schema_string(&structs, &enums, true, &registry);
+
+        assert!(output.contains("export const RoleSchema = z.enum([\"Admin\", \"Member\"]);\n"));
+        assert!(output.contains("export type Role = z.infer<typeof RoleSchema>;\n"));
+        assert!(output.contains(
+            "export const UserSchema = z.object({\n\
+             \x20 displayName: z.string(),\n\
+             \x20 nickname: z.string().nullable().optional(),\n\
+             \x20 tags: z.array(z.string()),\n\
+             \x20 role: z.lazy(() => RoleSchema),\n\
+             \x20 team: z.union([z.lazy(() => TeamSchema), z.string().brand<\"RecordLink\">()]),\n\
+             \x20 createdAt: z.string().datetime(),\n\
+             });\n"
+        ));
+        assert!(output.contains("export type User = z.infer<typeof UserSchema>;\n"));
+    }
+
+    #[test]
+    fn tagged_enum_with_data_becomes_union() {
+        let shape = TaggedUnion {
+            enum_name: "Shape".to_string(),
+            variants: vec![
+                Variant {
+                    data: Some(VariantData::DataStructureRef(FieldType::F64)),
+                    ..unit_variant("Circle")
+                },
+                unit_variant("Point"),
+            ],
+            representation: EnumRepresentation::AdjacentlyTagged {
+                tag: "type".to_string(),
+                content: "value".to_string(),
+            },
+            doccom: None,
+            macroforge_derives: vec![],
+            annotations: vec![],
+            pipeline: crate::types::Pipeline::default(),
+            rust_derives: vec![],
+            output_override: None,
+            raw_attributes: BTreeMap::new(),
+        };
+        let enums = BTreeMap::from([("Shape".to_string(), shape)]);
+
+        let output = generate_zod_schema_string(
+            &BTreeMap::new(),
+            &enums,
+            false,
+            &ForeignTypeRegistry::default(),
+        );
+
+        assert_eq!(
+            output,
+            "export const ShapeSchema = z.union([\
+             z.object({ type: z.literal(\"Circle\"), value: z.number() }), \
+             z.object({ type: z.literal(\"Point\") })]);\n\n"
+        );
+    }
+}
@@ -10,6 +10,7 @@
 
 #![cfg(feature = "schemasync")]
 
+use evenframe_core::schemasync::TableCase;
 use evenframe_core::schemasync::compare::{Comparator, SchemaDefinition};
 use evenframe_core::schemasync::database::surql::define::generate_define_statements;
 use evenframe_core::schemasync::database::surql::remove::generate_remove_index_statements;
@@ -256,7 +257,7 @@ fn orphan_index_is_dropped_when_removed_from_source() {
         "idx_reaction_created_at"
     );
 
-    let remove_sql = generate_remove_index_statements(&changes);
+    let remove_sql = generate_remove_index_statements(&changes, TableCase::default());
     assert!(
         remove_sql.contains("REMOVE INDEX IF EXISTS idx_reaction_created_at ON TABLE reaction;"),
         "missing REMOVE INDEX in generated SurrealQL:\n{}",
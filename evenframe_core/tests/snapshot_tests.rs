@@ -67,6 +67,28 @@ mod effect {
     tests_macros::gen_tests! { "tests/specs/typesync/*.json", crate::effect::run, "typesync" }
 }
 
+mod zod {
+    pub fn run(
+        spec_input_file: &str,
+        _expected_file: &str,
+        _test_directory: &str,
+        _file_type: &str,
+    ) {
+        let (structs, enums, registry) = crate::load_typesync_fixture(spec_input_file);
+        let output = evenframe_core::typesync::zod::generate_zod_schema_string(
+            &structs, &enums, true, &registry,
+        );
+        let name = std::path::Path::new(spec_input_file)
+            .file_stem()
+            .unwrap()
+            .to_str()
+            .unwrap();
+        insta::assert_snapshot!(format!("zod_{name}"), output);
+    }
+
+    tests_macros::gen_tests! { "tests/specs/typesync/*.json", crate::zod::run, "typesync" }
+}
+
 #[cfg(feature = "protobuf")]
 mod protobuf {
     pub fn run(
@@ -102,9 +102,13 @@ pub fn generate_enum_impl(input: DeriveInput, pipeline: PipelineKind) -> TokenSt
                                         validators: vec![],
                                         always_regenerate: false,
                                         doccom: None,
+                                        doc: None,
                                         annotations: vec![],
                                         unique: false,
+                                        indexed: false,
+                                        search_analyzer: None,
                                         mock_plugin: None,
+                                        rename: None,
                                         output_override: None,
                                         raw_attributes: std::collections::BTreeMap::new(),
                                     }
@@ -119,6 +123,7 @@ pub fn generate_enum_impl(input: DeriveInput, pipeline: PipelineKind) -> TokenSt
                                 fields: vec![#(#struct_fields),*],
                                 validators: vec![],
                                 doccom: None,
+                                doc: None,
                                 macroforge_derives: vec![],
                                 annotations: vec![],
                                 pipeline: #pipeline_tokens_inner,
@@ -147,9 +152,13 @@ pub fn generate_enum_impl(input: DeriveInput, pipeline: PipelineKind) -> TokenSt
                                     validators: vec![],
                                     always_regenerate: false,
                                     doccom: None,
+                                    doc: None,
                                     annotations: vec![],
                                     unique: false,
+                                    indexed: false,
+                                    search_analyzer: None,
                                     mock_plugin: None,
+                                    rename: None,
                                     output_override: None,
                                     raw_attributes: std::collections::BTreeMap::new(),
                                 }
@@ -164,6 +173,7 @@ pub fn generate_enum_impl(input: DeriveInput, pipeline: PipelineKind) -> TokenSt
                             fields: vec![#(#struct_fields),*],
                             validators: vec![],
                             doccom: None,
+                            doc: None,
                             macroforge_derives: vec![],
                             annotations: vec![],
                             pipeline: #pipeline_tokens_inner,
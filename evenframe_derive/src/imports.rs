@@ -24,7 +24,6 @@ pub fn generate_table_config_imports() -> proc_macro2::TokenStream {
                 TableConfig,
             },
         };
-        use convert_case::{Case, Casing};
     }
 }
 
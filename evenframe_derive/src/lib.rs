@@ -43,7 +43,8 @@ impl PipelineKind {
         macroforge_derive,
         annotation,
         unique,
-        index
+        index,
+        evenframe
     )
 )]
 pub fn evenframe_derive(input: TokenStream) -> TokenStream {
@@ -94,7 +95,8 @@ pub fn evenframe_union_derive(input: TokenStream) -> TokenStream {
         macroforge_derive,
         annotation,
         unique,
-        index
+        index,
+        evenframe
     )
 )]
 pub fn typesync_derive(input: TokenStream) -> TokenStream {
@@ -128,7 +130,8 @@ pub fn typesync_derive(input: TokenStream) -> TokenStream {
         macroforge_derive,
         annotation,
         unique,
-        index
+        index,
+        evenframe
     )
 )]
 pub fn schemasync_derive(input: TokenStream) -> TokenStream {
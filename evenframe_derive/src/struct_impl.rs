@@ -5,19 +5,26 @@ use crate::{
 use evenframe_core::{
     derive::{
         attributes::{
-            parse_annotation_attributes, parse_event_attributes, parse_format_attribute,
-            parse_index_attributes, parse_macroforge_derive_attribute, parse_mock_data_attribute,
-            parse_mockmake_attribute, parse_relation_attribute, parse_rust_derives,
+            apply_evenframe_default, apply_evenframe_readonly_and_value,
+            evenframe_default_variant_check, parse_annotation_attributes, parse_doc_comment,
+            parse_evenframe_changefeed_attribute, parse_evenframe_default_attribute,
+            parse_evenframe_index_attribute, parse_evenframe_literal_attribute,
+            parse_evenframe_no_overwrite_attribute, parse_evenframe_readonly_attribute,
+            parse_evenframe_rename_attribute, parse_evenframe_search_attribute,
+            parse_evenframe_skip_attribute, parse_evenframe_value_attribute,
+            parse_event_attributes, parse_format_attribute, parse_index_attributes,
+            parse_macroforge_derive_attribute, parse_mock_data_attribute, parse_mockmake_attribute,
+            parse_relation_attribute, parse_rust_derives,
         },
         validator_parser::parse_field_validators,
     },
     schemasync::{DefineConfig, EdgeConfig, PermissionsConfig},
     types::FieldType,
 };
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::TokenStream;
 use quote::quote;
 use syn::spanned::Spanned;
-use syn::{Data, DeriveInput, Fields, LitStr};
+use syn::{Data, DeriveInput, Fields};
 
 pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> TokenStream {
     let ident = input.ident.clone();
@@ -98,7 +105,7 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
                 return syn::Error::new(
                     input.span(),
                     format!(
-                        "Failed to parse event attribute: {}\n\nExample usage:\n#[event(\"DEFINE EVENT my_event ON TABLE my_table WHEN $before != $after THEN ...\")]",
+                        "Failed to parse event attribute: {}\n\nExample usage:\n#[evenframe(event(name = \"my_event\", when = \"$before != $after\", then = \"...\"))]",
                         err
                     ),
                 )
@@ -122,10 +129,11 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
         let rust_derives = parse_rust_derives(&input.attrs);
 
         // Collect known field names so we can validate #[index(fields(...))]
-        // references at parse time.
+        // references at parse time. Skipped fields are not in the schema.
         let known_field_names: std::collections::BTreeSet<String> = fields_named
             .named
             .iter()
+            .filter(|f| !parse_evenframe_skip_attribute(&f.attrs).unwrap_or(false))
             .filter_map(|f| {
                 f.ident
                     .as_ref()
@@ -139,6 +147,19 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
             Err(err) => return err.to_compile_error(),
         };
 
+        // Parse struct-level #[evenframe(no_overwrite)].
+        let no_overwrite = match parse_evenframe_no_overwrite_attribute(&input.attrs) {
+            Ok(v) => v,
+            Err(err) => return err.to_compile_error(),
+        };
+
+        // Parse struct-level #[evenframe(changefeed = "...")].
+        let changefeed_tokens = match parse_evenframe_changefeed_attribute(&input.attrs) {
+            Ok(Some(duration)) => quote! { Some(#duration.to_string()) },
+            Ok(None) => quote! { None },
+            Err(err) => return err.to_compile_error(),
+        };
+
         // Check if an "id" field exists.
         // Structs with an "id" field are treated as persistable entities (database tables).
         // Structs without an "id" field are treated as application-level data structures.
@@ -150,6 +171,7 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
         // Single pass over all fields.
         let mut table_field_tokens = Vec::new();
         let mut json_assignments = Vec::new();
+        let mut default_variant_checks = Vec::new();
 
         for field in fields_named.named.iter() {
             let field_ident = match field.ident.as_ref() {
@@ -166,9 +188,21 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
             // Remove the r# prefix from raw identifiers (e.g., r#type -> type)
             let field_name_trim = field_name.trim_start_matches("r#");
 
-            // Build the field type token.
+            // #[evenframe(skip)] leaves the field out of every generated output
+            match parse_evenframe_skip_attribute(&field.attrs) {
+                Ok(true) => continue,
+                Ok(false) => {}
+                Err(err) => return err.to_compile_error(),
+            }
+
+            // Build the field type token; #[evenframe(literal = ...)] fixes
+            // the field to a single value
             let ty = &field.ty;
-            let field_type = FieldType::parse_syn_ty(ty);
+            let field_type = match parse_evenframe_literal_attribute(&field.attrs) {
+                Ok(Some(value)) => FieldType::Literal(value),
+                Ok(None) => FieldType::parse_syn_ty(ty),
+                Err(err) => return err.to_compile_error(),
+            };
 
             // Parse any edge attribute.
             let edge_config = match EdgeConfig::parse(field) {
@@ -183,7 +217,7 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
             };
 
             // Parse any define details.
-            let define_config = match DefineConfig::parse(field) {
+            let mut define_config = match DefineConfig::parse(field) {
                 Ok(details) => details,
                 Err(err) => {
                     return syn::Error::new(
@@ -194,6 +228,36 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
                 }
             };
 
+            // Fold #[evenframe(default = "...")] into the define config
+            match parse_evenframe_default_attribute(&field.attrs) {
+                Ok(Some(lit)) => {
+                    if let Err(err) = apply_evenframe_default(&mut define_config, &lit, &field_type)
+                    {
+                        return err.to_compile_error();
+                    }
+                    default_variant_checks
+                        .extend(evenframe_default_variant_check(&lit, &field_type));
+                }
+                Ok(None) => {}
+                Err(err) => return err.to_compile_error(),
+            }
+
+            // Fold #[evenframe(readonly)] and #[evenframe(value = "...")] into
+            // the define config
+            let readonly = match parse_evenframe_readonly_attribute(&field.attrs) {
+                Ok(readonly) => readonly,
+                Err(err) => return err.to_compile_error(),
+            };
+            let value = match parse_evenframe_value_attribute(&field.attrs) {
+                Ok(value) => value,
+                Err(err) => return err.to_compile_error(),
+            };
+            if let Err(err) =
+                apply_evenframe_readonly_and_value(&mut define_config, readonly, value.as_ref())
+            {
+                return err.to_compile_error();
+            }
+
             // Parse any format attribute.
             let format = match parse_format_attribute(&field.attrs) {
                 Ok(fmt) => fmt,
@@ -273,6 +337,53 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
                 }
             };
 
+            // Parse #[evenframe(rename = "...")] output name override
+            let rename = match parse_evenframe_rename_attribute(&field.attrs) {
+                Ok(r) => r,
+                Err(err) => {
+                    return syn::Error::new(
+                        field.span(),
+                        format!(
+                            "Failed to parse evenframe attribute for field '{}': {}",
+                            field_name, err
+                        ),
+                    )
+                    .to_compile_error();
+                }
+            };
+
+            // Parse #[evenframe(index)] / #[evenframe(index(unique))]
+            let index = match parse_evenframe_index_attribute(&field.attrs) {
+                Ok(i) => i,
+                Err(err) => {
+                    return syn::Error::new(
+                        field.span(),
+                        format!(
+                            "Failed to parse evenframe attribute for field '{}': {}",
+                            field_name, err
+                        ),
+                    )
+                    .to_compile_error();
+                }
+            };
+            let is_unique = is_unique || index == Some(true);
+            let is_indexed = index == Some(false);
+
+            // Parse #[evenframe(search)] / #[evenframe(search(analyzer = "..."))]
+            let search_analyzer = match parse_evenframe_search_attribute(&field.attrs) {
+                Ok(s) => s,
+                Err(err) => {
+                    return syn::Error::new(
+                        field.span(),
+                        format!(
+                            "Failed to parse evenframe attribute for field '{}': {}",
+                            field_name, err
+                        ),
+                    )
+                    .to_compile_error();
+                }
+            };
+
             // Build validators token for this field
             let validators_tokens = if field_validators.is_empty() {
                 quote! { vec![] }
@@ -291,6 +402,21 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
                 None => quote! { None },
             };
 
+            let rename_tokens = match &rename {
+                Some(name) => quote! { Some(#name.to_string()) },
+                None => quote! { None },
+            };
+
+            let search_analyzer_tokens = match &search_analyzer {
+                Some(analyzer) => quote! { Some(#analyzer.to_string()) },
+                None => quote! { None },
+            };
+
+            let doc_tokens = match parse_doc_comment(&field.attrs) {
+                Some(doc) => quote! { Some(#doc.to_string()) },
+                None => quote! { None },
+            };
+
             table_field_tokens.push(quote! {
                 StructField {
                     field_name: #field_name_trim.to_string(),
@@ -301,9 +427,13 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
                     validators: #validators_tokens,
                     always_regenerate: false,
                     doccom: None,
+                    doc: #doc_tokens,
                     annotations: #field_annotations_tokens,
                     unique: #is_unique,
+                    indexed: #is_indexed,
+                    search_analyzer: #search_analyzer_tokens,
                     mock_plugin: #mock_plugin_tokens,
+                    rename: #rename_tokens,
                     output_override: None,
                     raw_attributes: std::collections::BTreeMap::new(),
                 }
@@ -323,6 +453,9 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
         // Generate tokens for parsed attributes (shared between implementations)
         let struct_name = ident.to_string();
 
+        // Derived configs can't see the project's `table_naming`, so the
+        // generated code applies the default casing; the registry re-cases
+        // table names with the configured one when it is read
         let table_name = ident.to_string();
 
         let permissions_config_tokens = if let Some(ref config) = permissions_config {
@@ -353,11 +486,20 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
         let event_tokens = if events.is_empty() {
             quote! { Vec::new() }
         } else {
-            let event_configs = events.iter().map(|statement| {
-                let lit = LitStr::new(statement, Span::call_site());
+            let event_configs = events.iter().map(|event| {
+                let name = &event.name;
+                let when = &event.when;
+                let then = &event.then;
+                let statement = match &event.statement {
+                    Some(statement) => quote! { Some(String::from(#statement)) },
+                    None => quote! { None },
+                };
                 quote! {
                     ::evenframe::schemasync::EventConfig {
-                        statement: String::from(#lit),
+                        name: String::from(#name),
+                        when: String::from(#when),
+                        then: String::from(#then),
+                        statement: #statement,
                     }
                 }
             });
@@ -377,6 +519,11 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
             quote! { vec![#(#struct_annotations.to_string()),*] }
         };
 
+        let struct_doc_tokens = match parse_doc_comment(&input.attrs) {
+            Some(doc) => quote! { Some(#doc.to_string()) },
+            None => quote! { None },
+        };
+
         let rust_derives_tokens = if rust_derives.is_empty() {
             quote! { vec![] }
         } else {
@@ -406,12 +553,13 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
                 impl EvenframePersistableStruct for #ident {
                     fn static_table_config() -> TableConfig {
                         TableConfig {
-                            table_name: #table_name.to_case(Case::Snake),
+                            table_name: ::evenframe::schemasync::TableCase::default().apply(#table_name),
                             struct_config: ::evenframe::types::StructConfig {
                                 struct_name: #struct_name.to_owned(),
                                 fields: vec![ #(#table_field_tokens),* ],
                                 validators: #table_validators_tokens,
                                 doccom: None,
+                                doc: #struct_doc_tokens,
                                 macroforge_derives: #macroforge_derives_tokens,
                                 annotations: #struct_annotations_tokens,
                                 pipeline: #pipeline_tokens,
@@ -425,6 +573,8 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
                             events: #event_tokens,
                             indexes: #indexes_tokens,
                             output_override: None,
+                            no_overwrite: #no_overwrite,
+                            changefeed: #changefeed_tokens,
                         }
                     }
                 }
@@ -473,15 +623,22 @@ pub fn generate_struct_impl(input: DeriveInput, pipeline: PipelineKind) -> Token
                     #registry_submission
                 };
 
+                #(#default_variant_checks)*
+
                 #deserialize_impl
             }
         } else {
             // For app structs, we only generate deserialization if needed
             // The derive macro itself serves as the marker
-            if has_field_validators {
+            let deserialize_impl = if has_field_validators {
                 deserialize_impl
             } else {
                 quote! {}
+            };
+            quote! {
+                #(#default_variant_checks)*
+
+                #deserialize_impl
             }
         }
     } else {
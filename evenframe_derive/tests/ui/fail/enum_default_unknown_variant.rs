@@ -0,0 +1,18 @@
+use evenframe_derive::Evenframe;
+
+#[derive(Debug, Clone, Evenframe)]
+pub enum Status {
+    Active,
+    Inactive,
+}
+
+/// `Archived` is not a variant of `Status`, so the default must be rejected
+/// at compile time.
+#[derive(Debug, Clone, Evenframe)]
+pub struct Account {
+    pub id: String,
+    #[evenframe(default = "Archived")]
+    pub status: Status,
+}
+
+fn main() {}
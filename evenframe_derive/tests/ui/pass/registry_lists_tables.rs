@@ -0,0 +1,45 @@
+use evenframe::registry;
+use evenframe::schemasync::TableCase;
+use evenframe_derive::Evenframe;
+
+/// Derived tables register themselves; the registry lists them sorted by
+/// table name regardless of declaration order, cased with the given
+/// `table_naming`.
+#[derive(Debug, Clone, Evenframe)]
+pub struct Zebra {
+    pub id: String,
+    pub stripes: u32,
+}
+
+#[derive(Debug, Clone, Evenframe)]
+pub struct Antelope {
+    pub id: String,
+    pub horns: u32,
+}
+
+#[derive(Debug, Clone, Evenframe)]
+pub struct SnowLeopard {
+    pub id: String,
+    pub spots: u32,
+}
+
+fn main() {
+    assert_eq!(
+        registry::table_names(TableCase::Snake),
+        vec!["antelope", "snow_leopard", "zebra"]
+    );
+
+    let tables = registry::all_table_configs(TableCase::Snake);
+    let names: Vec<&str> = tables.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, vec!["antelope", "snow_leopard", "zebra"]);
+    assert_eq!(tables[2].1.struct_config.struct_name, "Zebra");
+
+    // The config carries the same name the registry lists it under
+    let tables = registry::all_table_configs(TableCase::Verbatim);
+    let names: Vec<&str> = tables.iter().map(|(_, t)| t.table_name.as_str()).collect();
+    assert_eq!(names, vec!["Antelope", "SnowLeopard", "Zebra"]);
+    assert_eq!(
+        registry::table_names(TableCase::Kebab),
+        vec!["antelope", "snow-leopard", "zebra"]
+    );
+}
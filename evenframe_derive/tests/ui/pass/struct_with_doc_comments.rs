@@ -0,0 +1,47 @@
+use std::collections::BTreeMap;
+
+use evenframe::traits::EvenframePersistableStruct;
+use evenframe::types::ForeignTypeRegistry;
+use evenframe_derive::Evenframe;
+
+/// A published article.
+#[derive(Debug, Clone, Evenframe)]
+pub struct Article {
+    pub id: String,
+    /// The article's headline.
+    pub title: String,
+    pub body: String,
+}
+
+fn main() {
+    let config = Article::static_table_config();
+    assert_eq!(
+        config.struct_config.doc.as_deref(),
+        Some("A published article.")
+    );
+
+    let define = |name: &str| {
+        config
+            .struct_config
+            .fields
+            .iter()
+            .find(|f| f.field_name == name)
+            .expect("field")
+            .generate_define_statement(
+                BTreeMap::new(),
+                BTreeMap::new(),
+                BTreeMap::new(),
+                &"article".to_string(),
+                &ForeignTypeRegistry::default(),
+            )
+            .expect("define statement")
+    };
+
+    let title = define("title");
+    assert!(
+        title.contains(" COMMENT 'The article\\'s headline.';"),
+        "unexpected statement: {title}"
+    );
+    let body = define("body");
+    assert!(!body.contains(" COMMENT "), "unexpected statement: {body}");
+}
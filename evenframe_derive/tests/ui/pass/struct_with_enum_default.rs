@@ -0,0 +1,53 @@
+use std::collections::BTreeMap;
+
+use evenframe::traits::EvenframePersistableStruct;
+use evenframe::types::ForeignTypeRegistry;
+use evenframe_derive::Evenframe;
+
+#[derive(Debug, Clone, Evenframe)]
+pub enum Status {
+    Active,
+    Inactive,
+}
+
+/// `#[evenframe(default = "...")]` on an enum field must name one of its
+/// variants and becomes the DEFAULT clause of the DEFINE FIELD statement.
+#[derive(Debug, Clone, Evenframe)]
+pub struct Account {
+    pub id: String,
+    #[evenframe(default = "Active")]
+    pub status: Status,
+    #[evenframe(default = "3")]
+    pub retries: u8,
+}
+
+fn main() {
+    let config = Account::static_table_config();
+    let define = |name: &str| {
+        config
+            .struct_config
+            .fields
+            .iter()
+            .find(|f| f.field_name == name)
+            .expect("field")
+            .generate_define_statement(
+                BTreeMap::new(),
+                BTreeMap::new(),
+                BTreeMap::new(),
+                &"account".to_string(),
+                &ForeignTypeRegistry::default(),
+            )
+            .expect("define statement")
+    };
+
+    let status = define("status");
+    assert!(
+        status.contains(" DEFAULT 'Active'"),
+        "unexpected statement: {status}"
+    );
+    let retries = define("retries");
+    assert!(
+        retries.contains(" DEFAULT 3"),
+        "unexpected statement: {retries}"
+    );
+}
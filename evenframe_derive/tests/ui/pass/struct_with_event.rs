@@ -0,0 +1,33 @@
+use evenframe::registry;
+use evenframe::schemasync::TableCase;
+use evenframe_derive::Evenframe;
+
+/// Events declared with `#[evenframe(event(...))]` and as a raw statement.
+#[derive(Debug, Clone, Evenframe)]
+#[evenframe(event(
+    name = "audit_update",
+    when = "$event = 'UPDATE'",
+    then = "CREATE audit SET record = $after.id"
+))]
+#[event("DEFINE EVENT touch ON TABLE order WHEN true THEN { RETURN true }")]
+pub struct Order {
+    pub id: String,
+    pub total: f64,
+}
+
+fn main() {
+    let tables = registry::all_table_configs(TableCase::default());
+    let statements: Vec<String> = tables[0]
+        .1
+        .events
+        .iter()
+        .map(|event| event.define_statement("order"))
+        .collect();
+    assert_eq!(
+        statements,
+        [
+            "DEFINE EVENT OVERWRITE audit_update ON TABLE order WHEN $event = 'UPDATE' THEN { CREATE audit SET record = $after.id };",
+            "DEFINE EVENT touch ON TABLE order WHEN true THEN { RETURN true };",
+        ]
+    );
+}
@@ -0,0 +1,26 @@
+use evenframe::registry;
+use evenframe::schemasync::TableCase;
+use evenframe::types::FieldType;
+use evenframe_derive::Evenframe;
+
+/// A fixed-size array keeps its literal length in the field type.
+#[derive(Debug, Clone, Evenframe)]
+pub struct Upload {
+    pub id: String,
+    pub checksum: [u8; 16],
+}
+
+fn main() {
+    let tables = registry::all_table_configs(TableCase::default());
+    let checksum = tables[0]
+        .1
+        .struct_config
+        .fields
+        .iter()
+        .find(|f| f.field_name == "checksum")
+        .unwrap();
+    assert_eq!(
+        checksum.field_type,
+        FieldType::Array(Box::new(FieldType::U8), 16)
+    );
+}
@@ -0,0 +1,51 @@
+use std::collections::BTreeMap;
+
+use evenframe::traits::EvenframePersistableStruct;
+use evenframe::types::{FieldType, ForeignTypeRegistry};
+use evenframe_derive::Evenframe;
+
+/// `#[evenframe(literal = ...)]` fixes a field to a single value, e.g. a
+/// tagged-union discriminator.
+#[derive(Debug, Clone, Evenframe)]
+pub struct Account {
+    pub id: String,
+    #[evenframe(literal = "user")]
+    pub kind: String,
+    #[evenframe(literal = 2)]
+    pub version: u32,
+}
+
+fn main() {
+    let config = Account::static_table_config();
+    let field = |name: &str| {
+        config
+            .struct_config
+            .fields
+            .iter()
+            .find(|f| f.field_name == name)
+            .expect("field")
+    };
+
+    assert_eq!(
+        field("kind").field_type,
+        FieldType::Literal(serde_json::json!("user"))
+    );
+    assert_eq!(
+        field("version").field_type,
+        FieldType::Literal(serde_json::json!(2))
+    );
+
+    let kind = field("kind")
+        .generate_define_statement(
+            BTreeMap::new(),
+            BTreeMap::new(),
+            BTreeMap::new(),
+            &"account".to_string(),
+            &ForeignTypeRegistry::default(),
+        )
+        .expect("define statement");
+    assert!(
+        kind.contains(" TYPE \"user\""),
+        "unexpected statement: {kind}"
+    );
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+
+use evenframe::traits::EvenframePersistableStruct;
+use evenframe::types::ForeignTypeRegistry;
+use evenframe_derive::Evenframe;
+
+/// `#[evenframe(readonly)]` and `#[evenframe(value = "...")]` become the
+/// READONLY and VALUE clauses of the DEFINE FIELD statement.
+#[derive(Debug, Clone, Evenframe)]
+pub struct Article {
+    pub id: String,
+    pub title: String,
+    #[evenframe(readonly)]
+    pub author: String,
+    #[evenframe(value = "string::lowercase(title)")]
+    pub slug: String,
+    #[evenframe(readonly, value = "time::now()")]
+    pub created_at: String,
+}
+
+fn main() {
+    let config = Article::static_table_config();
+    let field = |name: &str| {
+        config
+            .struct_config
+            .fields
+            .iter()
+            .find(|f| f.field_name == name)
+            .expect("field")
+    };
+    let define = |name: &str| {
+        field(name)
+            .generate_define_statement(
+                BTreeMap::new(),
+                BTreeMap::new(),
+                BTreeMap::new(),
+                &"article".to_string(),
+                &ForeignTypeRegistry::default(),
+            )
+            .expect("define statement")
+    };
+
+    let author = define("author");
+    assert!(
+        author.contains(" READONLY"),
+        "unexpected statement: {author}"
+    );
+    assert!(
+        !author.contains(" VALUE "),
+        "unexpected statement: {author}"
+    );
+
+    let slug = define("slug");
+    assert!(
+        slug.contains(" VALUE string::lowercase(title)"),
+        "unexpected statement: {slug}"
+    );
+    assert!(!slug.contains(" READONLY"), "unexpected statement: {slug}");
+
+    let created_at = define("created_at");
+    assert!(
+        created_at.contains(" READONLY VALUE time::now()"),
+        "unexpected statement: {created_at}"
+    );
+
+    // Both kinds of field are left out of generated mock data
+    for name in ["author", "slug", "created_at"] {
+        let define_config = field(name).define_config.as_ref().expect("define config");
+        assert!(
+            define_config.readonly == Some(true) || define_config.is_database_derived(),
+            "{name} would receive mock data"
+        );
+    }
+    assert!(!define("title").contains(" READONLY"));
+}
@@ -0,0 +1,39 @@
+use std::collections::BTreeMap;
+
+use evenframe::traits::EvenframePersistableStruct;
+use evenframe::types::ForeignTypeRegistry;
+use evenframe_derive::Evenframe;
+
+/// Struct with a `#[evenframe(rename = "...")]` field override; the DEFINE
+/// FIELD statement must use the renamed column.
+#[derive(Debug, Clone, Evenframe)]
+pub struct User {
+    pub id: String,
+    #[evenframe(rename = "userName")]
+    pub user_name: String,
+}
+
+fn main() {
+    let config = User::static_table_config();
+    let field = config
+        .struct_config
+        .fields
+        .iter()
+        .find(|f| f.field_name == "user_name")
+        .expect("user_name field");
+    assert_eq!(field.rename.as_deref(), Some("userName"));
+
+    let define = field
+        .generate_define_statement(
+            BTreeMap::new(),
+            BTreeMap::new(),
+            BTreeMap::new(),
+            &"user".to_string(),
+            &ForeignTypeRegistry::default(),
+        )
+        .expect("define statement");
+    assert!(
+        define.starts_with("DEFINE FIELD OVERWRITE userName ON TABLE user"),
+        "unexpected statement: {define}"
+    );
+}
@@ -0,0 +1,32 @@
+use evenframe::traits::EvenframePersistableStruct;
+use evenframe::types::DEFAULT_SEARCH_ANALYZER;
+use evenframe_derive::Evenframe;
+
+/// Struct with `#[evenframe(search)]` fields; the bare form uses the default
+/// analyzer and `search(analyzer = "...")` names its own.
+#[derive(Debug, Clone, Evenframe)]
+pub struct Article {
+    pub id: String,
+    #[evenframe(search)]
+    pub body: String,
+    #[evenframe(search(analyzer = "ascii"))]
+    pub title: String,
+    pub slug: String,
+}
+
+fn main() {
+    let config = Article::static_table_config();
+    let analyzer = |name: &str| {
+        config
+            .struct_config
+            .fields
+            .iter()
+            .find(|f| f.field_name == name)
+            .expect("field")
+            .search_analyzer
+            .clone()
+    };
+    assert_eq!(analyzer("body").as_deref(), Some(DEFAULT_SEARCH_ANALYZER));
+    assert_eq!(analyzer("title").as_deref(), Some("ascii"));
+    assert_eq!(analyzer("slug"), None);
+}
@@ -0,0 +1,47 @@
+use std::collections::BTreeMap;
+
+use evenframe::schemasync::database::surql::define::generate_define_statements;
+use evenframe::traits::EvenframePersistableStruct;
+use evenframe::types::ForeignTypeRegistry;
+use evenframe_derive::Evenframe;
+
+/// Struct with a transient `#[evenframe(skip)]` field; it stays a normal Rust
+/// field but never reaches the schema, mocks or generated types.
+#[derive(Debug, Clone, Evenframe)]
+pub struct Session {
+    pub id: String,
+    pub token: String,
+    #[evenframe(skip)]
+    pub cached_user: Option<String>,
+}
+
+fn main() {
+    let session = Session {
+        id: "session:1".to_string(),
+        token: "abc".to_string(),
+        cached_user: None,
+    };
+    assert!(session.cached_user.is_none());
+
+    let config = Session::static_table_config();
+    let names: Vec<_> = config
+        .struct_config
+        .fields
+        .iter()
+        .map(|f| f.field_name.as_str())
+        .collect();
+    assert_eq!(names, ["id", "token"]);
+
+    let tables = BTreeMap::from([("session".to_string(), config.clone())]);
+    let statements = generate_define_statements(
+        "session",
+        &config,
+        &tables,
+        &BTreeMap::new(),
+        &BTreeMap::new(),
+        false,
+        &ForeignTypeRegistry::default(),
+    );
+    assert!(statements.contains("DEFINE FIELD OVERWRITE token ON TABLE session"));
+    assert!(!statements.contains("cached_user"), "got: {statements}");
+}
@@ -145,6 +145,7 @@ fn test_generator_type_default_filenames() {
     );
     assert_eq!(GeneratorType::FlatBuffers.default_filename(), "schema.fbs");
     assert_eq!(GeneratorType::Protobuf.default_filename(), "schema.proto");
+    assert_eq!(GeneratorType::JsonSchema.default_filename(), "schema.json");
 }
 
 // ============================================================================
@@ -146,12 +146,7 @@ fn seed_input_with_struct(name: &str) -> SyntheticPluginInput {
         TableConfig {
             table_name: "user".to_string(),
             struct_config: make_struct("User", vec![make_field("id", FieldType::String)]),
-            relation: None,
-            permissions: None,
-            mock_generation_config: None,
-            events: vec![],
-            indexes: vec![],
-            output_override: None,
+            ..Default::default()
         },
     );
 